@@ -40,6 +40,8 @@ export const A320Failure = Object.freeze({
   Generator1: 24020,
   Generator2: 24021,
   ApuGenerator1: 24030,
+  Battery1Overheat: 24040,
+  Battery2Overheat: 24041,
   AlternatingCurrent1: 24100,
   AlternatingCurrent2: 24101,
   AlternatingCurrentEssential: 24102,
@@ -55,6 +57,9 @@ export const A320Failure = Object.freeze({
   DirectCurrentHot2: 24112,
   DirectCurrentGndFltService: 24113,
 
+  Engine1Fire: 26001,
+  Engine2Fire: 26002,
+
   Elac1Failure: 27000,
   Elac2Failure: 27001,
   Sec1Failure: 27002,
@@ -63,6 +68,16 @@ export const A320Failure = Object.freeze({
   Fcdc1Failure: 27005,
   Fcdc2Failure: 27006,
 
+  LeftWingPump1: 28000,
+  LeftWingPump2: 28001,
+  CenterLeftPump: 28002,
+  CenterRightPump: 28003,
+  RightWingPump1: 28004,
+  RightWingPump2: 28005,
+  CrossfeedValveJammed: 28006,
+  LeftTransferValveJammed: 28007,
+  RightTransferValveJammed: 28008,
+
   GreenReservoirLeak: 29000,
   BlueReservoirLeak: 29001,
   YellowReservoirLeak: 29002,
@@ -115,6 +130,15 @@ export const A320Failure = Object.freeze({
   YellowBrakeHydraulicLeak: 32101,
   YellowBrakeAccumulatorGasLeak: 32150,
 
+  TyreBurst1: 32200,
+  TyreBurst2: 32201,
+  TyreBurst3: 32202,
+  TyreBurst4: 32203,
+  TyreDeflation1: 32250,
+  TyreDeflation2: 32251,
+  TyreDeflation3: 32252,
+  TyreDeflation4: 32253,
+
   RadioAltimeter1: 34000,
   RadioAltimeter2: 34001,
   RadioAntennaInterrupted1: 34010,
@@ -122,6 +146,17 @@ export const A320Failure = Object.freeze({
   RadioAntennaDirectCoupling1: 34020,
   RadioAntennaDirectCoupling2: 34021,
   Egpwc: 34030,
+
+  Engine1Flameout: 72000,
+  Engine2Flameout: 72001,
+  Engine1Seizure: 72010,
+  Engine2Seizure: 72011,
+  Engine1OilLeak: 72020,
+  Engine2OilLeak: 72021,
+  Engine1EgtOverlimit: 72030,
+  Engine2EgtOverlimit: 72031,
+  Engine1FanDamage: 72040,
+  Engine2FanDamage: 72041,
 });
 
 export const A320FailureDefinitions: FailureDefinition[] = [
@@ -160,6 +195,8 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [24, A320Failure.Generator1, 'Generator 1'],
   [24, A320Failure.Generator2, 'Generator 2'],
   [24, A320Failure.ApuGenerator1, 'APU Generator'],
+  [24, A320Failure.Battery1Overheat, 'Battery 1 thermal runaway'],
+  [24, A320Failure.Battery2Overheat, 'Battery 2 thermal runaway'],
   [24, A320Failure.AlternatingCurrent1, 'AC 1'],
   [24, A320Failure.AlternatingCurrent2, 'AC 2'],
   [24, A320Failure.AlternatingCurrentEssential, 'AC ESS'],
@@ -175,6 +212,9 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [24, A320Failure.DirectCurrentHot2, 'DC HOT 2'],
   [24, A320Failure.DirectCurrentGndFltService, 'DC GND FLT SRV'],
 
+  [26, A320Failure.Engine1Fire, 'Fire - Engine 1'],
+  [26, A320Failure.Engine2Fire, 'Fire - Engine 2'],
+
   [27, A320Failure.Elac1Failure, 'ELAC 1'],
   [27, A320Failure.Elac2Failure, 'ELAC 2'],
   [27, A320Failure.Sec1Failure, 'SEC 1'],
@@ -183,6 +223,16 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [27, A320Failure.Fcdc1Failure, 'FCDC 1'],
   [27, A320Failure.Fcdc2Failure, 'FCDC 2'],
 
+  [28, A320Failure.LeftWingPump1, 'Left wing tank pump 1'],
+  [28, A320Failure.LeftWingPump2, 'Left wing tank pump 2'],
+  [28, A320Failure.CenterLeftPump, 'Center tank left pump'],
+  [28, A320Failure.CenterRightPump, 'Center tank right pump'],
+  [28, A320Failure.RightWingPump1, 'Right wing tank pump 1'],
+  [28, A320Failure.RightWingPump2, 'Right wing tank pump 2'],
+  [28, A320Failure.CrossfeedValveJammed, 'Crossfeed valve jammed'],
+  [28, A320Failure.LeftTransferValveJammed, 'Left transfer valve jammed'],
+  [28, A320Failure.RightTransferValveJammed, 'Right transfer valve jammed'],
+
   [29, A320Failure.GreenReservoirLeak, 'Green reservoir leak'],
   [29, A320Failure.BlueReservoirLeak, 'Blue reservoir leak'],
   [29, A320Failure.YellowReservoirLeak, 'Yellow reservoir leak'],
@@ -230,6 +280,15 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [32, A320Failure.YellowBrakeHydraulicLeak, 'Yellow brakes circuit leak'],
   [32, A320Failure.YellowBrakeAccumulatorGasLeak, 'Yellow brake accumulator gas leak'],
 
+  [32, A320Failure.TyreBurst1, 'Tyre 1 burst'],
+  [32, A320Failure.TyreBurst2, 'Tyre 2 burst'],
+  [32, A320Failure.TyreBurst3, 'Tyre 3 burst'],
+  [32, A320Failure.TyreBurst4, 'Tyre 4 burst'],
+  [32, A320Failure.TyreDeflation1, 'Tyre 1 deflation'],
+  [32, A320Failure.TyreDeflation2, 'Tyre 2 deflation'],
+  [32, A320Failure.TyreDeflation3, 'Tyre 3 deflation'],
+  [32, A320Failure.TyreDeflation4, 'Tyre 4 deflation'],
+
   [34, A320Failure.RadioAltimeter1, 'RA 1'],
   [34, A320Failure.RadioAltimeter2, 'RA 2'],
   [34, A320Failure.RadioAntennaInterrupted1, 'RA 1 Interrupted'],
@@ -238,4 +297,15 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [34, A320Failure.RadioAntennaDirectCoupling2, 'RA 2 Direct Coupling'],
 
   [34, A320Failure.Egpwc, 'EGPWC'],
+
+  [72, A320Failure.Engine1Flameout, 'Engine 1 flameout'],
+  [72, A320Failure.Engine2Flameout, 'Engine 2 flameout'],
+  [72, A320Failure.Engine1Seizure, 'Engine 1 seized core'],
+  [72, A320Failure.Engine2Seizure, 'Engine 2 seized core'],
+  [72, A320Failure.Engine1OilLeak, 'Engine 1 oil leak'],
+  [72, A320Failure.Engine2OilLeak, 'Engine 2 oil leak'],
+  [72, A320Failure.Engine1EgtOverlimit, 'Engine 1 EGT overlimit'],
+  [72, A320Failure.Engine2EgtOverlimit, 'Engine 2 EGT overlimit'],
+  [72, A320Failure.Engine1FanDamage, 'Engine 1 fan damage (bird strike)'],
+  [72, A320Failure.Engine2FanDamage, 'Engine 2 fan damage (bird strike)'],
 ];
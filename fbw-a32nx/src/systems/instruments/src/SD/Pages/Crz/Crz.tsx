@@ -75,11 +75,19 @@ export const OilComponent = () => {
   const oilLeft = splitDecimals(oilQuantLeft * 0.01 * 25);
   const oilRight = splitDecimals(oilQuantRight * 0.01 * 25);
 
-  const [leftVIBN1] = useSimVar('TURB ENG VIBRATION:1', 'Number', 1000);
-  const [rightVIBN1] = useSimVar('TURB ENG VIBRATION:2', 'Number', 1000);
-
-  const leftVN1 = splitDecimals(leftVIBN1 < 0 ? 0.0 : leftVIBN1);
-  const rightVN1 = splitDecimals(rightVIBN1 < 0 ? 0.0 : rightVIBN1);
+  const leftVibN1 = useArinc429Var('L:A32NX_EVMU_ENG_1_N1_VIBRATION', 1000);
+  const leftVibN2 = useArinc429Var('L:A32NX_EVMU_ENG_1_N2_VIBRATION', 1000);
+  const rightVibN1 = useArinc429Var('L:A32NX_EVMU_ENG_2_N1_VIBRATION', 1000);
+  const rightVibN2 = useArinc429Var('L:A32NX_EVMU_ENG_2_N2_VIBRATION', 1000);
+  const leftVibN1Advisory = useArinc429Var('L:A32NX_EVMU_ENG_1_N1_VIBRATION_ADVISORY', 1000).valueOr(0) === 1;
+  const leftVibN2Advisory = useArinc429Var('L:A32NX_EVMU_ENG_1_N2_VIBRATION_ADVISORY', 1000).valueOr(0) === 1;
+  const rightVibN1Advisory = useArinc429Var('L:A32NX_EVMU_ENG_2_N1_VIBRATION_ADVISORY', 1000).valueOr(0) === 1;
+  const rightVibN2Advisory = useArinc429Var('L:A32NX_EVMU_ENG_2_N2_VIBRATION_ADVISORY', 1000).valueOr(0) === 1;
+
+  const leftVN1 = splitDecimals(leftVibN1.valueOr(0));
+  const leftVN2 = splitDecimals(leftVibN2.valueOr(0));
+  const rightVN1 = splitDecimals(rightVibN1.valueOr(0));
+  const rightVN2 = splitDecimals(rightVibN2.valueOr(0));
 
   return (
     <>
@@ -119,32 +127,80 @@ export const OilComponent = () => {
         {oilRight[1]}
       </text>
 
-      <text id="VibN1Left" className="Large Green" x="195" y="235" textAnchor="end">
+      <text
+        id="VibN1Left"
+        className={`Large ${leftVibN1Advisory ? 'GreenTextPulse' : 'Green'}`}
+        x="195"
+        y="235"
+        textAnchor="end"
+      >
         {leftVN1[0]}.
       </text>
-      <text id="VibN1LeftDecimal" className="Standard Green" x="197" y="235" textAnchor="start">
+      <text
+        id="VibN1LeftDecimal"
+        className={`Standard ${leftVibN1Advisory ? 'GreenTextPulse' : 'Green'}`}
+        x="197"
+        y="235"
+        textAnchor="start"
+      >
         {leftVN1[1]}
       </text>
 
-      <text id="VibN2Left" className="Large Green" x="195" y="265" textAnchor="end">
-        {leftVN1[0]}.
+      <text
+        id="VibN2Left"
+        className={`Large ${leftVibN2Advisory ? 'GreenTextPulse' : 'Green'}`}
+        x="195"
+        y="265"
+        textAnchor="end"
+      >
+        {leftVN2[0]}.
       </text>
-      <text id="VibN2LeftDecimal" className="Standard Green" x="197" y="265" textAnchor="start">
-        {leftVN1[1]}
+      <text
+        id="VibN2LeftDecimal"
+        className={`Standard ${leftVibN2Advisory ? 'GreenTextPulse' : 'Green'}`}
+        x="197"
+        y="265"
+        textAnchor="start"
+      >
+        {leftVN2[1]}
       </text>
 
-      <text id="VibN1Right" className="Large Green" x="440" y="235" textAnchor="end">
+      <text
+        id="VibN1Right"
+        className={`Large ${rightVibN1Advisory ? 'GreenTextPulse' : 'Green'}`}
+        x="440"
+        y="235"
+        textAnchor="end"
+      >
         {rightVN1[0]}.
       </text>
-      <text id="VibN1RightDecimal" className="Standard Green" x="440" y="235" textAnchor="start">
+      <text
+        id="VibN1RightDecimal"
+        className={`Standard ${rightVibN1Advisory ? 'GreenTextPulse' : 'Green'}`}
+        x="440"
+        y="235"
+        textAnchor="start"
+      >
         {rightVN1[1]}
       </text>
 
-      <text id="VibN2Right" className="Large Green" x="440" y="265" textAnchor="end">
-        {rightVN1[0]}.
+      <text
+        id="VibN2Right"
+        className={`Large ${rightVibN2Advisory ? 'GreenTextPulse' : 'Green'}`}
+        x="440"
+        y="265"
+        textAnchor="end"
+      >
+        {rightVN2[0]}.
       </text>
-      <text id="VibN2RightDecimal" className="Standard Green" x="440" y="265" textAnchor="start">
-        {rightVN1[1]}
+      <text
+        id="VibN2RightDecimal"
+        className={`Standard ${rightVibN2Advisory ? 'GreenTextPulse' : 'Green'}`}
+        x="440"
+        y="265"
+        textAnchor="start"
+      >
+        {rightVN2[1]}
       </text>
     </>
   );
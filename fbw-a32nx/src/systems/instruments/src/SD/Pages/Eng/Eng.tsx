@@ -5,7 +5,7 @@
 
 import React, { FC, useState, useEffect } from 'react';
 import { Arc, Needle } from '@instruments/common/gauges';
-import { usePersistentSetting, useSimVar } from '@flybywiresim/fbw-sdk-react';
+import { useArinc429Var, usePersistentSetting, useSimVar } from '@flybywiresim/fbw-sdk-react';
 import { PageTitle } from '../../Common/PageTitle';
 import { EcamPage } from '../../Common/EcamPage';
 import { SvgGroup } from '../../Common/SvgGroup';
@@ -267,8 +267,8 @@ const ValveGroup = ({ x, y, engineNumber, fadecOn }: ComponentPositionProps) =>
   const [isValveOpen] = useSimVar(`L:A32NX_PNEU_ENG_${engineNumber}_STARTER_VALVE_OPEN`, 'bool', 250);
   const [n2Percent] = useSimVar(`ENG N2 RPM:${engineNumber}`, 'percent', 50);
   const [engSelectorPosition] = useSimVar('L:XMLVAR_ENG_MODE_SEL', 'Enum');
-  const [igniterAactive] = useSimVar(`L:A32NX_FADEC_IGNITER_A_ACTIVE_ENG${engineNumber}`, 'bool', 300);
-  const [igniterBactive] = useSimVar(`L:A32NX_FADEC_IGNITER_B_ACTIVE_ENG${engineNumber}`, 'bool', 300);
+  const [igniterAactive] = useSimVar(`L:A32NX_FADEC_${engineNumber}_IGNITOR_A_ACTIVE`, 'bool', 300);
+  const [igniterBactive] = useSimVar(`L:A32NX_FADEC_${engineNumber}_IGNITOR_B_ACTIVE`, 'bool', 300);
   const [precoolerInletPressure] = useSimVar(
     `L:A32NX_PNEU_ENG_${engineNumber}_REGULATED_TRANSDUCER_PRESSURE`,
     'psi',
@@ -333,9 +333,12 @@ const EngineColumn = ({ x, y, engineNumber, fadecOn }: ComponentPositionProps) =
   const [shouldTemperaturePulse, setShouldTemperaturePulse] = useState(false);
   const [tempBeenAboveAdvisory, setTempBeenAboveAdvisory] = useState(false);
 
-  const [n1Vibration] = useSimVar(`TURB ENG VIBRATION:${engineNumber}`, 'Number');
-
-  const [n2Vibration] = useSimVar(`TURB ENG VIBRATION:${engineNumber}`, 'Number'); // FIXME TODO: should have a different value than N1, currently API limited
+  const n1Vibration = useArinc429Var(`L:A32NX_EVMU_ENG_${engineNumber}_N1_VIBRATION`).valueOr(0);
+  const n2Vibration = useArinc429Var(`L:A32NX_EVMU_ENG_${engineNumber}_N2_VIBRATION`).valueOr(0);
+  const n1VibrationAdvisory =
+    useArinc429Var(`L:A32NX_EVMU_ENG_${engineNumber}_N1_VIBRATION_ADVISORY`).valueOr(0) === 1;
+  const n2VibrationAdvisory =
+    useArinc429Var(`L:A32NX_EVMU_ENG_${engineNumber}_N2_VIBRATION_ADVISORY`).valueOr(0) === 1;
 
   const activeVisibility = fadecOn ? 'visible' : 'hidden';
   const inactiveVisibility = fadecOn ? 'hidden' : 'visible';
@@ -397,13 +400,13 @@ const EngineColumn = ({ x, y, engineNumber, fadecOn }: ComponentPositionProps) =
           {displayedEngineOilTemperature}
         </text>
 
-        <text x={x} y={y + 270} className="FillGreen TextCenter">
+        <text x={x} y={y + 270} className={`TextCenter ${n1VibrationAdvisory ? 'FillPulse' : 'FillGreen'}`}>
           <tspan className="FontLarge">{n1Vibration.toFixed(1).toString().split('.')[0]}</tspan>
           <tspan className="FontSmall">.</tspan>
           <tspan className="FontSmall">{n1Vibration.toFixed(1).toString().split('.')[1]}</tspan>
         </text>
 
-        <text x={x} y={y + 300} className="FillGreen TextCenter">
+        <text x={x} y={y + 300} className={`TextCenter ${n2VibrationAdvisory ? 'FillPulse' : 'FillGreen'}`}>
           <tspan className="FontLarge">{n2Vibration.toFixed(1).toString().split('.')[0]}</tspan>
           <tspan className="FontSmall">.</tspan>
           <tspan className="FontSmall">{n2Vibration.toFixed(1).toString().split('.')[1]}</tspan>
@@ -73,6 +73,8 @@ void EngineControl_A32NX::update() {
     const int    engineIgniter = static_cast<int>(simData.simVarsDataPtr->data().engineIgniter[engineIdx]);  // 0: crank, 1:norm, 2: ign
     bool         engineStarter = static_cast<bool>(simData.simVarsDataPtr->data().engineStarter[engineIdx]);
     const double engineStarterPressurized   = simData.engineStarterPressurized[engineIdx]->get();
+    // the systems FADEC aborts automatic starts on ground and cuts the fuel for the dry crank
+    const bool engineStartAborted = simData.fadecStartAborted[engineIdx]->getAsBool();
     const double engineFuelValveOpen        = simData.simVarsDataPtr->data().engineFuelValveOpen[engineIdx];
     const bool   engineFuelValveFullyClosed = engineFuelValveOpen == 0;
     const bool   engineFuelValveFullyOpen   = engineFuelValveOpen == 1;
@@ -82,13 +84,15 @@ void EngineControl_A32NX::update() {
     const bool engineMasterTurnedOff = (prevEngineMasterPos[engineIdx] > 0 && engineFuelValveFullyClosed);
 
     // starts engines if Engine Master is turned on and Starter is pressurized
-    // or the engine is still spinning fast enough
-    if (!engineStarter && engineFuelValveFullyOpen && (engineStarterPressurized || simN2 >= 20)) {
+    // or the engine is still spinning fast enough, unless the FADEC aborted the start
+    if (!engineStarter && !engineStartAborted && engineFuelValveFullyOpen && (engineStarterPressurized || simN2 >= 20)) {
       simData.setStarterHeldEvent[engineIdx]->trigger(1);
       engineStarter = true;
     }
-    // shuts off engines if Engine Master is turned off or starter is depressurized while N2 is below 20%
-    else if (engineStarter && (engineFuelValveFullyClosed || (engineFuelValveFullyOpen && !engineStarterPressurized && simN2 < 20))) {
+    // shuts off engines if Engine Master is turned off, the FADEC aborted the start
+    // or starter is depressurized while N2 is below 20%
+    else if (engineStarter && (engineFuelValveFullyClosed || engineStartAborted ||
+                               (engineFuelValveFullyOpen && !engineStarterPressurized && simN2 < 20))) {
       simData.setStarterHeldEvent[engineIdx]->trigger(0);
       simData.setStarterEvent[engineIdx]->trigger(0);
       engineStarter = false;
@@ -720,20 +724,6 @@ void EngineControl_A32NX::updateFuel(double deltaTimeSeconds) {
 
   const double pumpStateLeft          = simData.fuelPumpState[L]->get();
   const double pumpStateRight         = simData.fuelPumpState[R]->get();
-  const bool   xfrCenterLeftManual    = simData.simVarsDataPtr->data().xfrCenterManual[L] > 1.5;                              // junction 4
-  const bool   xfrCenterRightManual   = simData.simVarsDataPtr->data().xfrCenterManual[R] > 1.5;                              // junction 5
-  const bool   xfrCenterLeftAuto      = simData.simVarsDataPtr->data().xfrValveCenterAuto[L] > 0.0 && !xfrCenterLeftManual;   // valve 11
-  const bool   xfrCenterRightAuto     = simData.simVarsDataPtr->data().xfrValveCenterAuto[R] > 0.0 && !xfrCenterRightManual;  // valve 12
-  const bool   xfrValveCenterLeftOpen = simData.simVarsDataPtr->data().xfrValveCenterOpen[L] > 0.0                            //
-                                      && (xfrCenterLeftAuto || xfrCenterLeftManual);                                          // valve 9
-  const bool xfrValveCenterRightOpen = simData.simVarsDataPtr->data().xfrValveCenterOpen[R] > 0.0                             //
-                                       && (xfrCenterRightAuto || xfrCenterRightManual);                                       // valve 10
-  const double xfrValveOuterLeft1    = simData.simVarsDataPtr->data().xfrValveOuter1[L];                                      // valve 6
-  const double xfrValveOuterRight1   = simData.simVarsDataPtr->data().xfrValveOuter1[R];                                      // valve 7
-  const double xfrValveOuterLeft2    = simData.simVarsDataPtr->data().xfrValveOuter2[L];                                      // valve 4
-  const double xfrValveOuterRight2   = simData.simVarsDataPtr->data().xfrValveOuter2[R];                                      // valve 5
-  const double lineLeftToCenterFlow  = simData.simVarsDataPtr->data().lineToCenterFlow[L];
-  const double lineRightToCenterFlow = simData.simVarsDataPtr->data().lineToCenterFlow[R];
 
   const double engine1PreFF = simData.enginePreFF[L]->get();
   const double engine2PreFF = simData.enginePreFF[R]->get();
@@ -763,15 +753,6 @@ void EngineControl_A32NX::updateFuel(double deltaTimeSeconds) {
   const EngineState engine1State = static_cast<EngineState>(simData.engineState[L]->get());
   const EngineState engine2State = static_cast<EngineState>(simData.engineState[R]->get());
 
-  const double xFeedValve  = simData.simVarsDataPtr->data().xFeedValve;
-  const double leftPump1   = simData.simVarsDataPtr->data().fuelPump1[L];
-  const double rightPump1  = simData.simVarsDataPtr->data().fuelPump1[R];
-  const double leftPump2   = simData.simVarsDataPtr->data().fuelPump2[L];
-  const double rightPump2  = simData.simVarsDataPtr->data().fuelPump2[R];
-  const double apuNpercent = simData.apuRpmPercent->get();
-
-  int isTankClosed = 0;
-
   /// Delta time for this update in hours
   const double deltaTimeHours = deltaTimeSeconds / 3600;
 
@@ -854,142 +835,33 @@ void EngineControl_A32NX::updateFuel(double deltaTimeSeconds) {
     simData.fuelAuxRightPre->set(rightAuxQuantity);  // in Pounds
     simData.fuelCenterPre->set(centerQuantity);      // in Pounds
   } else {
-    if (uiFuelTamper == 1) {
-      fuelLeftPre     = leftQuantity;      // Pounds
-      fuelRightPre    = rightQuantity;     // Pounds
-      fuelAuxLeftPre  = leftAuxQuantity;   // Pounds
-      fuelAuxRightPre = rightAuxQuantity;  // Pounds
-      fuelCenterPre   = centerQuantity;    // Pounds
-    }
-    //-----------------------------------------------------------
-    // Cross-feed Logic
-    // isTankClosed = 0, x-feed valve closed
-    // isTankClosed = 1, left tank does not supply fuel
-    // isTankClosed = 2, right tank does not supply fuel
-    // isTankClosed = 3, left & right tanks do not supply fuel
-    // isTankClosed = 4, both tanks supply fuel
-    if (xFeedValve > 0.0) {
-      if (leftPump1 == 0 && leftPump2 == 0 && rightPump1 == 0 && rightPump2 == 0)
-        isTankClosed = 3;
-      else if (leftPump1 == 0 && leftPump2 == 0)
-        isTankClosed = 1;
-      else if (rightPump1 == 0 && rightPump2 == 0)
-        isTankClosed = 2;
-      else
-        isTankClosed = 4;
-    }
-
-    double xfrCenterToLeft  = 0;
-    double xfrCenterToRight = 0;
-    double xfrAuxLeft       = 0;
-    double xfrAuxRight      = 0;
-
+    // The engine and APU fuel burn, the cross-feed and the transfers between the tanks are handled by the
+    // fuel network of the systems, which owns the tank quantities. Only the fuel used is accounted here.
     double fuelFlowRateChange   = 0;
     double previousFuelFlowRate = 0;
     double fuelBurn1            = 0;
     double fuelBurn2            = 0;
-    double apuBurn1             = 0;
-    double apuBurn2             = 0;
-    double apuFuelConsumption   = 0;
 
     //--------------------------------------------
-    // Left Engine and Wing routine
-    if (fuelLeftPre > 0) {
-      // Cycle Fuel Burn for Engine 1
-      if (aircraftDevelopmentStateVar != 2 && msfsHandlerPtr->getPauseState() == 0) {
-        fuelFlowRateChange   = (engine1FF - engine1PreFF) / deltaTimeHours;
-        previousFuelFlowRate = engine1PreFF;
-        fuelBurn1            = (fuelFlowRateChange * pow(deltaTimeHours, 2) / 2) + (previousFuelFlowRate * deltaTimeHours);  // KG
-      }
-      // Fuel transfer routine for Left Wing
-      if (xfrValveOuterLeft1 > 0.0 || xfrValveOuterLeft2 > 0.0) {
-        xfrAuxLeft = fuelAuxLeftPre - leftAuxQuantity;
-      }
-    } else {
-      fuelBurn1   = 0;
-      fuelLeftPre = 0;
+    // Cycle Fuel Burn for Engine 1
+    if (leftQuantity > 0 && aircraftDevelopmentStateVar != 2 && msfsHandlerPtr->getPauseState() == 0) {
+      fuelFlowRateChange   = (engine1FF - engine1PreFF) / deltaTimeHours;
+      previousFuelFlowRate = engine1PreFF;
+      fuelBurn1            = (fuelFlowRateChange * pow(deltaTimeHours, 2) / 2) + (previousFuelFlowRate * deltaTimeHours);  // KG
     }
 
     //--------------------------------------------
-    // Right Engine and Wing routine
-    if (fuelRightPre > 0) {
-      // Cycle Fuel Burn for Engine 2
-      if (aircraftDevelopmentStateVar != 2 && msfsHandlerPtr->getPauseState() == 0) {
-        fuelFlowRateChange   = (engine2FF - engine2PreFF) / deltaTimeHours;
-        previousFuelFlowRate = engine2PreFF;
-        fuelBurn2            = (fuelFlowRateChange * pow(deltaTimeHours, 2) / 2) + (previousFuelFlowRate * deltaTimeHours);  // KG
-      }
-      // Fuel transfer routine for Right Wing
-      if (xfrValveOuterRight1 > 0.0 || xfrValveOuterRight2 > 0.0) {
-        xfrAuxRight = fuelAuxRightPre - rightAuxQuantity;
-      }
-    } else {
-      fuelBurn2    = 0;
-      fuelRightPre = 0;
-    }
-
-    /// apu fuel consumption for this frame in pounds
-    if (aircraftDevelopmentStateVar != 2 && msfsHandlerPtr->getPauseState() == 0 || apuNpercent <= 0.0) {
-      apuFuelConsumption = simData.simVarsDataPtr->data().apuFuelConsumption * weightLbsPerGallon * deltaTimeHours;
+    // Cycle Fuel Burn for Engine 2
+    if (rightQuantity > 0 && aircraftDevelopmentStateVar != 2 && msfsHandlerPtr->getPauseState() == 0) {
+      fuelFlowRateChange   = (engine2FF - engine2PreFF) / deltaTimeHours;
+      previousFuelFlowRate = engine2PreFF;
+      fuelBurn2            = (fuelFlowRateChange * pow(deltaTimeHours, 2) / 2) + (previousFuelFlowRate * deltaTimeHours);  // KG
     }
 
-    apuBurn1 = apuFuelConsumption;
-    apuBurn2 = 0;
-
     //--------------------------------------------
     // Fuel used accumulators
-    double fuelUsedLeft  = simData.engineFuelUsed[L]->get() + fuelBurn1;
-    double fuelUsedRight = simData.engineFuelUsed[R]->get() + fuelBurn2;
-
-    //--------------------------------------------
-    // Cross-feed fuel burn routine
-    // If fuel pumps for a given tank are closed,
-    // all fuel will be burnt on the other tank
-    switch (isTankClosed) {
-      case 1:
-        fuelBurn2 = fuelBurn1 + fuelBurn2;
-        fuelBurn1 = 0;
-        apuBurn1  = 0;
-        apuBurn2  = apuFuelConsumption;
-        break;
-      case 2:
-        fuelBurn1 = fuelBurn1 + fuelBurn2;
-        fuelBurn2 = 0;
-        break;
-      case 3:
-        fuelBurn1 = 0;
-        fuelBurn2 = 0;
-        apuBurn1  = apuFuelConsumption * 0.5;
-        apuBurn2  = apuFuelConsumption * 0.5;
-        break;
-      case 4:
-        apuBurn1 = apuFuelConsumption * 0.5;
-        apuBurn2 = apuFuelConsumption * 0.5;
-        break;
-      default:
-        break;
-    }
-
-    //--------------------------------------------
-    // Center Tank transfer routine
-    double lineFlowRatio = 0;
-    if (xfrValveCenterLeftOpen && xfrValveCenterRightOpen) {
-      if (lineLeftToCenterFlow < 0.1 && lineRightToCenterFlow < 0.1)
-        lineFlowRatio = 0.5;
-      else
-        lineFlowRatio = lineLeftToCenterFlow / (lineLeftToCenterFlow + lineRightToCenterFlow);
-
-      xfrCenterToLeft  = (fuelCenterPre - centerQuantity) * lineFlowRatio;
-      xfrCenterToRight = (fuelCenterPre - centerQuantity) * (1 - lineFlowRatio);
-    } else if (xfrValveCenterLeftOpen)
-      xfrCenterToLeft = fuelCenterPre - centerQuantity;
-    else if (xfrValveCenterRightOpen)
-      xfrCenterToRight = fuelCenterPre - centerQuantity;
-
-    //--------------------------------------------
-    // Final Fuel levels for left and right inner tanks
-    const double fuelLeft  = (fuelLeftPre - (fuelBurn1 * Fadec::KGS_TO_LBS)) + xfrAuxLeft + xfrCenterToLeft - apuBurn1;     // Pounds
-    const double fuelRight = (fuelRightPre - (fuelBurn2 * Fadec::KGS_TO_LBS)) + xfrAuxRight + xfrCenterToRight - apuBurn2;  // Pounds
+    const double fuelUsedLeft  = simData.engineFuelUsed[L]->get() + fuelBurn1;
+    const double fuelUsedRight = simData.engineFuelUsed[R]->get() + fuelBurn2;
 
     //--------------------------------------------
     // Setting new pre-cycle conditions
@@ -999,16 +871,11 @@ void EngineControl_A32NX::updateFuel(double deltaTimeSeconds) {
     simData.engineFuelUsed[L]->set(fuelUsedLeft);
     simData.engineFuelUsed[R]->set(fuelUsedRight);
 
-    simData.fuelAuxLeftPre->set(leftAuxQuantity);
-    simData.fuelAuxRightPre->set(rightAuxQuantity);
-    simData.fuelCenterPre->set(centerQuantity);
-
-    simData.fuelLeftPre->set(fuelLeft);    // in Pounds
-    simData.fuelRightPre->set(fuelRight);  // in Pounds
-
-    simData.fuelFeedTankDataPtr->data().fuelLeftMain  = (fuelLeft / weightLbsPerGallon);
-    simData.fuelFeedTankDataPtr->data().fuelRightMain = (fuelRight / weightLbsPerGallon);
-    simData.fuelFeedTankDataPtr->writeDataToSim();
+    simData.fuelLeftPre->set(leftQuantity);          // in Pounds
+    simData.fuelRightPre->set(rightQuantity);        // in Pounds
+    simData.fuelAuxLeftPre->set(leftAuxQuantity);    // in Pounds
+    simData.fuelAuxRightPre->set(rightAuxQuantity);  // in Pounds
+    simData.fuelCenterPre->set(centerQuantity);      // in Pounds
   }
 
   //--------------------------------------------
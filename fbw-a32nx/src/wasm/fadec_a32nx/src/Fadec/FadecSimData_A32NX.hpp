@@ -203,6 +203,7 @@ class FadecSimData_A32NX {
   NamedVariablePtr engineStarterPressurized[2];
   NamedVariablePtr engineState[2];
   NamedVariablePtr engineTimer[2];
+  NamedVariablePtr fadecStartAborted[2];
   NamedVariablePtr fuelAuxLeftPre;   // Pounds
   NamedVariablePtr fuelAuxRightPre;  // Pounds
   NamedVariablePtr fuelCenterPre;    // Pounds
@@ -332,6 +333,9 @@ class FadecSimData_A32NX {
     engineStarterPressurized[L] = dm->make_named_var("A32NX_PNEU_ENG_1_STARTER_PRESSURIZED", UNITS.Number, AUTO_READ);
     engineStarterPressurized[R] = dm->make_named_var("A32NX_PNEU_ENG_2_STARTER_PRESSURIZED", UNITS.Number, AUTO_READ);
 
+    fadecStartAborted[L] = dm->make_named_var("A32NX_FADEC_1_START_ABORTED", UNITS.Bool, AUTO_READ);
+    fadecStartAborted[R] = dm->make_named_var("A32NX_FADEC_2_START_ABORTED", UNITS.Bool, AUTO_READ);
+
     fuelAuxLeftPre   = dm->make_named_var("A32NX_FUEL_AUX_LEFT_PRE", UNITS.Number, AUTO_READ_WRITE);
     fuelAuxRightPre  = dm->make_named_var("A32NX_FUEL_AUX_RIGHT_PRE", UNITS.Number, AUTO_READ_WRITE);
     fuelCenterPre    = dm->make_named_var("A32NX_FUEL_CENTER_PRE", UNITS.Number, AUTO_READ_WRITE);
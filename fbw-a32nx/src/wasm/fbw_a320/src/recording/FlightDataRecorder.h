@@ -13,7 +13,8 @@
 
 class FlightDataRecorder {
  public:
-  // IMPORTANT: this constant needs to increased with every interface change
+  // IMPORTANT: this constant needs to increased with every interface change, and a snapshot of the
+  // interface headers needs to be added to tools/fdr2csv/interfaces to keep the files convertible
   const uint64_t INTERFACE_VERSION = 3200006;
 
   const uint32_t NUMBER_OF_ELAC_TO_WRITE = 2;
@@ -118,6 +118,11 @@ impl A320AirConditioning {
             .mix_packs_air_update(pack_container);
     }
 
+    /// The avionics compartment is ventilated with air drawn from the cockpit.
+    pub fn avionics_compartment_temperature(&self) -> ThermodynamicTemperature {
+        self.a320_cabin.cabin_temperature()[0]
+    }
+
     fn update_pressurization_ambient_conditions(
         &mut self,
         context: &UpdateContext,
@@ -192,6 +192,7 @@ impl A320AlternatingCurrentElectrical {
         dc_state: &impl A320DirectCurrentElectricalSystem,
         spd_cond: bool,
     ) {
+        electricity.transform_in(dc_state.static_inverter());
         electricity.flow(dc_state.static_inverter(), &self.ac_stat_inv_bus);
 
         self.static_inv_to_ac_ess_bus_contactor
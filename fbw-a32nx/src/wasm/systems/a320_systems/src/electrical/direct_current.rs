@@ -6,8 +6,10 @@ use systems::shared::AdirsDiscreteOutputs;
 use systems::simulation::InitContext;
 use systems::{
     electrical::{
-        Battery, BatteryChargeLimiter, Contactor, ElectricalBus, Electricity, EmergencyElectrical,
-        EmergencyGenerator, StaticInverter,
+        Battery, BatteryChargeLimiter, ElectricalBus, ElectricalElement, ElectricalTopology,
+        ElectricalTopologyDescription, ElectricalTopologyElements, Electricity, ElectricitySource,
+        ElectricityTransformer, EmergencyElectrical, EmergencyGenerator, StaticInverter,
+        TopologyBus, TopologyContactor, TransformerRectifier,
     },
     shared::{
         ApuMaster, ApuStart, AuxiliaryPowerUnitElectrical, ContactorSignal, ElectricalBusType,
@@ -16,75 +18,285 @@ use systems::{
     simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
 };
 
+use uom::si::f64::*;
+
 pub(crate) const APU_START_MOTOR_BUS_TYPE: ElectricalBusType = ElectricalBusType::Sub("49-42-00");
 
+// The battery charge limiters observe the DC BAT bus as fed by the DC buses and the batteries
+// connected before them. The DC network is therefore split into topologies which are distributed
+// in order: the main DC network, each battery, the APU start motor and the DC ESS network.
+
+pub(super) struct DcMainNodes {
+    tr_1_contactor: TopologyContactor,
+    tr_2_contactor: TopologyContactor,
+    tr_2_to_dc_gnd_flt_service_bus_contactor: TopologyContactor,
+    dc_bus_2_to_dc_gnd_flt_service_bus_contactor: TopologyContactor,
+    dc_bus_1_tie_contactor: TopologyContactor,
+    dc_bus_2_tie_contactor: TopologyContactor,
+    dc_bat_bus: TopologyBus,
+}
+
+/// TR 1 and TR 2 feed the DC buses 1 and 2 (5PU1, 5PU2), which both feed the DC BAT bus (1PC1,
+/// 1PC2). The DC GND/FLT SERVICE bus is fed by DC bus 2 (8PN), or directly by TR 2 (3PX) when AC
+/// bus 2 is unpowered.
+pub(super) fn dc_main_topology() -> (ElectricalTopologyDescription, DcMainNodes) {
+    let mut description = ElectricalTopologyDescription::new()
+        .with_external("TR_1")
+        .with_external("TR_2")
+        .with_bus("DC_1", ElectricalBusType::DirectCurrent(1))
+        .with_bus("DC_2", ElectricalBusType::DirectCurrent(2))
+        .with_bus(
+            "DC_GND_FLT_SVC",
+            ElectricalBusType::DirectCurrentGndFltService,
+        );
+    let nodes = DcMainNodes {
+        tr_1_contactor: description.add_contactor("5PU1"),
+        tr_2_contactor: description.add_contactor("5PU2"),
+        tr_2_to_dc_gnd_flt_service_bus_contactor: description.add_contactor("3PX"),
+        dc_bus_2_to_dc_gnd_flt_service_bus_contactor: description.add_contactor("8PN"),
+        dc_bus_1_tie_contactor: description.add_contactor("1PC1"),
+        dc_bus_2_tie_contactor: description.add_contactor("1PC2"),
+        dc_bat_bus: description.add_bus("DC_BAT", ElectricalBusType::DirectCurrentBattery),
+    };
+
+    let description = description
+        .with_connection("TR_1", "5PU1")
+        .with_connection("5PU1", "DC_1")
+        .with_connection("TR_2", "5PU2")
+        .with_connection("5PU2", "DC_2")
+        .with_connection("TR_2", "3PX")
+        .with_connection("3PX", "DC_GND_FLT_SVC")
+        .with_connection("DC_2", "8PN")
+        .with_connection("8PN", "DC_GND_FLT_SVC")
+        .with_connection("DC_1", "1PC1")
+        .with_connection("1PC1", "DC_BAT")
+        .with_connection("DC_2", "1PC2")
+        .with_connection("1PC2", "DC_BAT");
+
+    (description, nodes)
+}
+
+struct DcMainFeeds<'a> {
+    tr_1: &'a TransformerRectifier,
+    tr_2: &'a TransformerRectifier,
+}
+impl ElectricalTopologyElements for DcMainFeeds<'_> {
+    fn source(&self, _: &str) -> Option<&dyn ElectricitySource> {
+        None
+    }
+
+    fn transformer(&self, _: &str) -> Option<&dyn ElectricityTransformer> {
+        None
+    }
+
+    fn external(&self, name: &str) -> Option<&dyn ElectricalElement> {
+        match name {
+            "TR_1" => Some(self.tr_1),
+            "TR_2" => Some(self.tr_2),
+            _ => None,
+        }
+    }
+}
+
+pub(super) struct BatteryNodes {
+    battery_contactor: TopologyContactor,
+    hot_bus: TopologyBus,
+}
+
+/// The battery feeds its hot bus, which is connected to the DC BAT bus (6PBn).
+pub(super) fn battery_topology(number: u8) -> (ElectricalTopologyDescription, BatteryNodes) {
+    let mut description = ElectricalTopologyDescription::new()
+        .with_source("BAT")
+        .with_external("DC_BAT_BUS");
+    let nodes = BatteryNodes {
+        battery_contactor: description.add_contactor(&format!("6PB{}", number)),
+        hot_bus: description.add_bus("HOT_BUS", ElectricalBusType::DirectCurrentHot(number)),
+    };
+
+    let description = description
+        .with_connection("DC_BAT_BUS", &format!("6PB{}", number))
+        .with_connection(&format!("6PB{}", number), "HOT_BUS")
+        .with_connection("HOT_BUS", "BAT");
+
+    (description, nodes)
+}
+
+struct BatteryFeeds<'a> {
+    battery: &'a Battery,
+    dc_bat_bus: &'a ElectricalBus,
+}
+impl ElectricalTopologyElements for BatteryFeeds<'_> {
+    fn source(&self, name: &str) -> Option<&dyn ElectricitySource> {
+        match name {
+            "BAT" => Some(self.battery),
+            _ => None,
+        }
+    }
+
+    fn transformer(&self, _: &str) -> Option<&dyn ElectricityTransformer> {
+        None
+    }
+
+    fn external(&self, name: &str) -> Option<&dyn ElectricalElement> {
+        match name {
+            "DC_BAT_BUS" => Some(self.dc_bat_bus),
+            _ => None,
+        }
+    }
+}
+
+pub(super) struct ApuStartNodes {
+    apu_start_contactors: TopologyContactor,
+}
+
+/// The DC BAT bus feeds the APU start motor (10KA and 5KA).
+pub(super) fn apu_start_topology() -> (ElectricalTopologyDescription, ApuStartNodes) {
+    let mut description = ElectricalTopologyDescription::new().with_external("DC_BAT_BUS");
+    let nodes = ApuStartNodes {
+        apu_start_contactors: description.add_contactor("10KA_AND_5KA"),
+    };
+
+    let description = description
+        .with_bus("APU_START_MOTOR", APU_START_MOTOR_BUS_TYPE)
+        .with_connection("DC_BAT_BUS", "10KA_AND_5KA")
+        .with_connection("10KA_AND_5KA", "APU_START_MOTOR");
+
+    (description, nodes)
+}
+
+struct ApuStartFeeds<'a> {
+    dc_bat_bus: &'a ElectricalBus,
+}
+impl ElectricalTopologyElements for ApuStartFeeds<'_> {
+    fn source(&self, _: &str) -> Option<&dyn ElectricitySource> {
+        None
+    }
+
+    fn transformer(&self, _: &str) -> Option<&dyn ElectricityTransformer> {
+        None
+    }
+
+    fn external(&self, name: &str) -> Option<&dyn ElectricalElement> {
+        match name {
+            "DC_BAT_BUS" => Some(self.dc_bat_bus),
+            _ => None,
+        }
+    }
+}
+
+pub(super) struct DcEssentialNodes {
+    hot_bus_1_to_static_inv_contactor: TopologyContactor,
+    dc_bat_bus_to_dc_ess_bus_contactor: TopologyContactor,
+    tr_ess_contactor: TopologyContactor,
+    hot_bus_2_to_dc_ess_bus_contactor: TopologyContactor,
+    dc_ess_shed_contactor: TopologyContactor,
+}
+
+/// The DC ESS bus is fed by the DC BAT bus (4PC), the ESS TR (3PE) or by battery 2 through the
+/// hot bus 2 (2XB2). Battery 1 then feeds the static inverter through the hot bus 1 (2XB1). The
+/// static inverter's output belongs to the AC ESS network, which transforms it. The DC ESS SHED
+/// bus is shed (8PH) when the DC ESS bus is fed by battery 2.
+pub(super) fn dc_essential_topology() -> (ElectricalTopologyDescription, DcEssentialNodes) {
+    let mut description = ElectricalTopologyDescription::new()
+        .with_external("DC_BAT_BUS")
+        .with_external("TR_ESS")
+        .with_external("HOT_BUS_1")
+        .with_external("HOT_BUS_2")
+        .with_external("STAT_INV");
+    let nodes = DcEssentialNodes {
+        hot_bus_1_to_static_inv_contactor: description.add_contactor("2XB1"),
+        dc_bat_bus_to_dc_ess_bus_contactor: description.add_contactor("4PC"),
+        tr_ess_contactor: description.add_contactor("3PE"),
+        hot_bus_2_to_dc_ess_bus_contactor: description.add_contactor("2XB2"),
+        dc_ess_shed_contactor: description.add_contactor("8PH"),
+    };
+
+    let description = description
+        .with_bus("DC_ESS", ElectricalBusType::DirectCurrentEssential)
+        .with_bus("DC_ESS_SHED", ElectricalBusType::DirectCurrentEssentialShed)
+        .with_connection("HOT_BUS_1", "2XB1")
+        .with_connection("2XB1", "STAT_INV")
+        .with_connection("DC_BAT_BUS", "4PC")
+        .with_connection("4PC", "DC_ESS")
+        .with_connection("TR_ESS", "3PE")
+        .with_connection("3PE", "DC_ESS")
+        .with_connection("HOT_BUS_2", "2XB2")
+        .with_connection("2XB2", "DC_ESS")
+        .with_connection("DC_ESS", "8PH")
+        .with_connection("8PH", "DC_ESS_SHED");
+
+    (description, nodes)
+}
+
+struct DcEssentialFeeds<'a> {
+    dc_bat_bus: &'a ElectricalBus,
+    tr_ess: &'a TransformerRectifier,
+    hot_bus_1: &'a ElectricalBus,
+    hot_bus_2: &'a ElectricalBus,
+    static_inverter: &'a StaticInverter,
+}
+impl ElectricalTopologyElements for DcEssentialFeeds<'_> {
+    fn source(&self, _: &str) -> Option<&dyn ElectricitySource> {
+        None
+    }
+
+    fn transformer(&self, _: &str) -> Option<&dyn ElectricityTransformer> {
+        None
+    }
+
+    fn external(&self, name: &str) -> Option<&dyn ElectricalElement> {
+        match name {
+            "DC_BAT_BUS" => Some(self.dc_bat_bus),
+            "TR_ESS" => Some(self.tr_ess),
+            "HOT_BUS_1" => Some(self.hot_bus_1),
+            "HOT_BUS_2" => Some(self.hot_bus_2),
+            "STAT_INV" => Some(self.static_inverter),
+            _ => None,
+        }
+    }
+}
+
 pub(super) struct A320DirectCurrentElectrical {
-    dc_bus_1: ElectricalBus,
-    dc_bus_2: ElectricalBus,
-    dc_bus_1_tie_contactor: Contactor,
-    dc_bus_2_tie_contactor: Contactor,
-    dc_bat_bus: ElectricalBus,
-    dc_ess_bus: ElectricalBus,
-    dc_bat_bus_to_dc_ess_bus_contactor: Contactor,
-    dc_ess_shed_bus: ElectricalBus,
-    dc_ess_shed_contactor: Contactor,
+    dc_main: ElectricalTopology,
+    dc_main_nodes: DcMainNodes,
     battery_1: Battery,
-    battery_1_contactor: Contactor,
     battery_1_charge_limiter: BatteryChargeLimiter,
+    battery_1_network: ElectricalTopology,
+    battery_1_nodes: BatteryNodes,
     battery_2: Battery,
-    battery_2_contactor: Contactor,
     battery_2_charge_limiter: BatteryChargeLimiter,
-    hot_bus_2_to_dc_ess_bus_contactor: Contactor,
-    hot_bus_1_to_static_inv_contactor: Contactor,
+    battery_2_network: ElectricalTopology,
+    battery_2_nodes: BatteryNodes,
+    apu_start: ElectricalTopology,
+    apu_start_nodes: ApuStartNodes,
     static_inverter: StaticInverter,
-    hot_bus_1: ElectricalBus,
-    hot_bus_2: ElectricalBus,
-    tr_1_contactor: Contactor,
-    tr_2_contactor: Contactor,
-    tr_ess_contactor: Contactor,
-    apu_start_contactors: Contactor,
-    apu_start_motor_bus: ElectricalBus,
-    dc_gnd_flt_service_bus: ElectricalBus,
-    tr_2_to_dc_gnd_flt_service_bus_contactor: Contactor,
-    dc_bus_2_to_dc_gnd_flt_service_bus_contactor: Contactor,
+    dc_essential: ElectricalTopology,
+    dc_essential_nodes: DcEssentialNodes,
 }
 impl A320DirectCurrentElectrical {
     pub fn new(context: &mut InitContext) -> Self {
+        let (dc_main, dc_main_nodes) = dc_main_topology();
+        let (battery_1_network, battery_1_nodes) = battery_topology(1);
+        let (battery_2_network, battery_2_nodes) = battery_topology(2);
+        let (apu_start, apu_start_nodes) = apu_start_topology();
+        let (dc_essential, dc_essential_nodes) = dc_essential_topology();
+
         A320DirectCurrentElectrical {
-            dc_bus_1: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
-            dc_bus_1_tie_contactor: Contactor::new(context, "1PC1"),
-            dc_bus_2: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(2)),
-            dc_bus_2_tie_contactor: Contactor::new(context, "1PC2"),
-            dc_bat_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentBattery),
-            dc_ess_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentEssential),
-            dc_bat_bus_to_dc_ess_bus_contactor: Contactor::new(context, "4PC"),
-            dc_ess_shed_bus: ElectricalBus::new(
-                context,
-                ElectricalBusType::DirectCurrentEssentialShed,
-            ),
-            dc_ess_shed_contactor: Contactor::new(context, "8PH"),
+            dc_main: ElectricalTopology::new(context, &dc_main),
+            dc_main_nodes,
             battery_1: Battery::full(context, 1),
-            battery_1_contactor: Contactor::new(context, "6PB1"),
             battery_1_charge_limiter: BatteryChargeLimiter::new(context, 1, "6PB1"),
+            battery_1_network: ElectricalTopology::new(context, &battery_1_network),
+            battery_1_nodes,
             battery_2: Battery::full(context, 2),
-            battery_2_contactor: Contactor::new(context, "6PB2"),
             battery_2_charge_limiter: BatteryChargeLimiter::new(context, 2, "6PB2"),
-            hot_bus_2_to_dc_ess_bus_contactor: Contactor::new(context, "2XB2"),
-            hot_bus_1_to_static_inv_contactor: Contactor::new(context, "2XB1"),
+            battery_2_network: ElectricalTopology::new(context, &battery_2_network),
+            battery_2_nodes,
+            apu_start: ElectricalTopology::new(context, &apu_start),
+            apu_start_nodes,
             static_inverter: StaticInverter::new(context),
-            hot_bus_1: ElectricalBus::new(context, ElectricalBusType::DirectCurrentHot(1)),
-            hot_bus_2: ElectricalBus::new(context, ElectricalBusType::DirectCurrentHot(2)),
-            tr_1_contactor: Contactor::new(context, "5PU1"),
-            tr_2_contactor: Contactor::new(context, "5PU2"),
-            tr_ess_contactor: Contactor::new(context, "3PE"),
-            apu_start_contactors: Contactor::new(context, "10KA_AND_5KA"),
-            apu_start_motor_bus: ElectricalBus::new(context, APU_START_MOTOR_BUS_TYPE),
-            dc_gnd_flt_service_bus: ElectricalBus::new(
-                context,
-                ElectricalBusType::DirectCurrentGndFltService,
-            ),
-            tr_2_to_dc_gnd_flt_service_bus_contactor: Contactor::new(context, "3PX"),
-            dc_bus_2_to_dc_gnd_flt_service_bus_contactor: Contactor::new(context, "8PN"),
+            dc_essential: ElectricalTopology::new(context, &dc_essential),
+            dc_essential_nodes,
         }
     }
 
@@ -102,73 +314,49 @@ impl A320DirectCurrentElectrical {
         adirs: &impl AdirsDiscreteOutputs,
         spd_cond: bool,
     ) {
-        self.tr_1_contactor
-            .close_when(electricity.is_powered(ac_state.tr_1()));
-        electricity.flow(ac_state.tr_1(), &self.tr_1_contactor);
-
-        self.tr_2_contactor.close_when(
-            electricity.is_powered(ac_state.tr_2()) && ac_state.ac_bus_2_powered(electricity),
-        );
-        electricity.flow(ac_state.tr_2(), &self.tr_2_contactor);
-
-        self.tr_2_to_dc_gnd_flt_service_bus_contactor.close_when(
+        let dc_bus_1_is_powered = electricity.is_powered(ac_state.tr_1());
+        let dc_bus_2_is_powered =
+            electricity.is_powered(ac_state.tr_2()) && ac_state.ac_bus_2_powered(electricity);
+
+        let nodes = &self.dc_main_nodes;
+        self.dc_main
+            .close_contactor_when(nodes.tr_1_contactor, dc_bus_1_is_powered);
+        self.dc_main
+            .close_contactor_when(nodes.tr_2_contactor, dc_bus_2_is_powered);
+        self.dc_main.close_contactor_when(
+            nodes.tr_2_to_dc_gnd_flt_service_bus_contactor,
             electricity.is_powered(ac_state.tr_2()) && !ac_state.ac_bus_2_powered(electricity),
         );
-        electricity.flow(
-            ac_state.tr_2(),
-            &self.tr_2_to_dc_gnd_flt_service_bus_contactor,
-        );
-
-        self.tr_ess_contactor.close_when(
-            !ac_state.tr_1_and_2_available(electricity)
-                && electricity.is_powered(ac_state.tr_ess()),
-        );
-        electricity.flow(ac_state.tr_ess(), &self.tr_ess_contactor);
-
-        electricity.flow(&self.tr_1_contactor, &self.dc_bus_1);
-        electricity.flow(&self.tr_2_contactor, &self.dc_bus_2);
-
-        self.dc_bus_2_to_dc_gnd_flt_service_bus_contactor
-            .close_when(
-                electricity.is_powered(ac_state.tr_2()) && ac_state.ac_bus_2_powered(electricity),
-            );
-        electricity.flow(
-            &self.dc_bus_2,
-            &self.dc_bus_2_to_dc_gnd_flt_service_bus_contactor,
-        );
-
-        electricity.flow(
-            &self.tr_2_to_dc_gnd_flt_service_bus_contactor,
-            &self.dc_gnd_flt_service_bus,
+        self.dc_main.close_contactor_when(
+            nodes.dc_bus_2_to_dc_gnd_flt_service_bus_contactor,
+            dc_bus_2_is_powered,
         );
-        electricity.flow(
-            &self.dc_bus_2_to_dc_gnd_flt_service_bus_contactor,
-            &self.dc_gnd_flt_service_bus,
+        self.dc_main.close_contactor_when(
+            nodes.dc_bus_1_tie_contactor,
+            dc_bus_1_is_powered || dc_bus_2_is_powered,
         );
-
-        electricity.flow(&self.dc_bus_1, &self.dc_bus_1_tie_contactor);
-        electricity.flow(&self.dc_bus_2, &self.dc_bus_2_tie_contactor);
-
-        let dc_bus_1_is_powered = electricity.is_powered(&self.dc_bus_1);
-        let dc_bus_2_is_powered = electricity.is_powered(&self.dc_bus_2);
-        self.dc_bus_1_tie_contactor
-            .close_when(dc_bus_1_is_powered || dc_bus_2_is_powered);
-        self.dc_bus_2_tie_contactor.close_when(
+        self.dc_main.close_contactor_when(
+            nodes.dc_bus_2_tie_contactor,
             (!dc_bus_1_is_powered && dc_bus_2_is_powered)
                 || (!dc_bus_2_is_powered && dc_bus_1_is_powered),
         );
+        self.dc_main.distribute(
+            electricity,
+            &DcMainFeeds {
+                tr_1: ac_state.tr_1(),
+                tr_2: ac_state.tr_2(),
+            },
+        );
 
-        electricity.flow(&self.dc_bus_1_tie_contactor, &self.dc_bat_bus);
-        electricity.flow(&self.dc_bus_2_tie_contactor, &self.dc_bat_bus);
+        let dc_bat_bus = self.dc_main.bus(self.dc_main_nodes.dc_bat_bus);
 
-        electricity.supplied_by(&self.battery_1);
         self.battery_1_charge_limiter.update(
             context,
             electricity,
             emergency_elec,
             emergency_generator,
             &self.battery_1,
-            &self.dc_bat_bus,
+            dc_bat_bus,
             lgciu1,
             overhead,
             apu,
@@ -176,20 +364,25 @@ impl A320DirectCurrentElectrical {
             ac_state,
             adirs,
         );
-        self.battery_1_contactor
-            .close_when(self.battery_1_charge_limiter.should_close_contactor());
-        electricity.flow(&self.dc_bat_bus, &self.battery_1_contactor);
-        electricity.flow(&self.battery_1_contactor, &self.hot_bus_1);
-        electricity.flow(&self.hot_bus_1, &self.battery_1);
+        self.battery_1_network.close_contactor_when(
+            self.battery_1_nodes.battery_contactor,
+            self.battery_1_charge_limiter.should_close_contactor(),
+        );
+        self.battery_1_network.distribute(
+            electricity,
+            &BatteryFeeds {
+                battery: &self.battery_1,
+                dc_bat_bus,
+            },
+        );
 
-        electricity.supplied_by(&self.battery_2);
         self.battery_2_charge_limiter.update(
             context,
             electricity,
             emergency_elec,
             emergency_generator,
             &self.battery_2,
-            &self.dc_bat_bus,
+            dc_bat_bus,
             lgciu1,
             overhead,
             apu,
@@ -197,48 +390,60 @@ impl A320DirectCurrentElectrical {
             ac_state,
             adirs,
         );
-        self.battery_2_contactor
-            .close_when(self.battery_2_charge_limiter.should_close_contactor());
-        electricity.flow(&self.dc_bat_bus, &self.battery_2_contactor);
-        electricity.flow(&self.battery_2_contactor, &self.hot_bus_2);
-        electricity.flow(&self.hot_bus_2, &self.battery_2);
-
-        self.apu_start_contactors.close_when(
-            self.battery_1_contactor.is_closed()
-                && self.battery_2_contactor.is_closed()
-                && matches!(apu.signal(), Some(ContactorSignal::Close)),
+        self.battery_2_network.close_contactor_when(
+            self.battery_2_nodes.battery_contactor,
+            self.battery_2_charge_limiter.should_close_contactor(),
+        );
+        self.battery_2_network.distribute(
+            electricity,
+            &BatteryFeeds {
+                battery: &self.battery_2,
+                dc_bat_bus,
+            },
         );
-        electricity.flow(&self.dc_bat_bus, &self.apu_start_contactors);
-        electricity.flow(&self.apu_start_contactors, &self.apu_start_motor_bus);
+
+        let should_close_apu_start_contactors = self.batteries_connected_to_bat_bus()
+            && matches!(apu.signal(), Some(ContactorSignal::Close));
+        self.apu_start.close_contactor_when(
+            self.apu_start_nodes.apu_start_contactors,
+            should_close_apu_start_contactors,
+        );
+        self.apu_start
+            .distribute(electricity, &ApuStartFeeds { dc_bat_bus });
 
         let should_close_2xb_contactor =
             self.should_close_2xb_contactors(electricity, emergency_generator, ac_state, spd_cond);
-        self.hot_bus_1_to_static_inv_contactor
-            .close_when(should_close_2xb_contactor);
-        electricity.flow(&self.hot_bus_1, &self.hot_bus_1_to_static_inv_contactor);
-        electricity.flow(
-            &self.hot_bus_1_to_static_inv_contactor,
-            &self.static_inverter,
+        let nodes = &self.dc_essential_nodes;
+        self.dc_essential.close_contactor_when(
+            nodes.hot_bus_1_to_static_inv_contactor,
+            should_close_2xb_contactor,
         );
-        electricity.transform_in(&self.static_inverter);
-
-        self.hot_bus_2_to_dc_ess_bus_contactor
-            .close_when(should_close_2xb_contactor);
-        electricity.flow(&self.hot_bus_2, &self.hot_bus_2_to_dc_ess_bus_contactor);
-
-        self.dc_bat_bus_to_dc_ess_bus_contactor
-            .close_when(ac_state.tr_1_and_2_available(electricity));
-        electricity.flow(&self.dc_bat_bus, &self.dc_bat_bus_to_dc_ess_bus_contactor);
-
-        electricity.flow(&self.dc_bat_bus_to_dc_ess_bus_contactor, &self.dc_ess_bus);
-        electricity.flow(&self.tr_ess_contactor, &self.dc_ess_bus);
-        electricity.flow(&self.hot_bus_2_to_dc_ess_bus_contactor, &self.dc_ess_bus);
-
-        self.dc_ess_shed_contactor
-            .close_when(self.hot_bus_2_to_dc_ess_bus_contactor.is_open());
-        electricity.flow(&self.dc_ess_bus, &self.dc_ess_shed_contactor);
+        self.dc_essential.close_contactor_when(
+            nodes.tr_ess_contactor,
+            !ac_state.tr_1_and_2_available(electricity)
+                && electricity.is_powered(ac_state.tr_ess()),
+        );
+        self.dc_essential.close_contactor_when(
+            nodes.hot_bus_2_to_dc_ess_bus_contactor,
+            should_close_2xb_contactor,
+        );
+        self.dc_essential.close_contactor_when(
+            nodes.dc_bat_bus_to_dc_ess_bus_contactor,
+            ac_state.tr_1_and_2_available(electricity),
+        );
+        self.dc_essential
+            .close_contactor_when(nodes.dc_ess_shed_contactor, !should_close_2xb_contactor);
 
-        electricity.flow(&self.dc_ess_shed_contactor, &self.dc_ess_shed_bus);
+        self.dc_essential.distribute(
+            electricity,
+            &DcEssentialFeeds {
+                dc_bat_bus: self.dc_main.bus(self.dc_main_nodes.dc_bat_bus),
+                tr_ess: ac_state.tr_ess(),
+                hot_bus_1: self.battery_1_network.bus(self.battery_1_nodes.hot_bus),
+                hot_bus_2: self.battery_2_network.bus(self.battery_2_nodes.hot_bus),
+                static_inverter: &self.static_inverter,
+            },
+        );
     }
 
     /// Determines if the 2XB contactors should be closed. 2XB are the two contactors
@@ -256,7 +461,13 @@ impl A320DirectCurrentElectrical {
     }
 
     fn batteries_connected_to_bat_bus(&self) -> bool {
-        self.battery_1_contactor.is_closed() && self.battery_2_contactor.is_closed()
+        self.battery_1_network
+            .contactor(self.battery_1_nodes.battery_contactor)
+            .is_closed()
+            && self
+                .battery_2_network
+                .contactor(self.battery_2_nodes.battery_contactor)
+                .is_closed()
     }
 
     pub fn debug_assert_invariants(&self) {
@@ -267,25 +478,51 @@ impl A320DirectCurrentElectrical {
         );
     }
 
+    fn dc_essential_contactor_is_closed(
+        &self,
+        contactor: fn(&DcEssentialNodes) -> TopologyContactor,
+    ) -> bool {
+        self.dc_essential
+            .contactor(contactor(&self.dc_essential_nodes))
+            .is_closed()
+    }
+
     fn battery_never_powers_dc_ess_shed(&self) -> bool {
-        !(self.hot_bus_2_to_dc_ess_bus_contactor.is_closed()
-            && self.dc_ess_shed_contactor.is_closed())
+        !(self.dc_essential_contactor_is_closed(|n| n.hot_bus_2_to_dc_ess_bus_contactor)
+            && self.dc_essential_contactor_is_closed(|n| n.dc_ess_shed_contactor))
     }
 
     fn max_one_source_powers_dc_ess_bus(&self) -> bool {
-        (!self.hot_bus_2_to_dc_ess_bus_contactor.is_closed()
-            && !self.dc_bat_bus_to_dc_ess_bus_contactor.is_closed()
-            && !self.tr_ess_contactor.is_closed())
-            || (self.hot_bus_2_to_dc_ess_bus_contactor.is_closed()
-                ^ self.dc_bat_bus_to_dc_ess_bus_contactor.is_closed()
-                ^ self.tr_ess_contactor.is_closed())
+        let hot_bus_2 =
+            self.dc_essential_contactor_is_closed(|n| n.hot_bus_2_to_dc_ess_bus_contactor);
+        let dc_bat_bus =
+            self.dc_essential_contactor_is_closed(|n| n.dc_bat_bus_to_dc_ess_bus_contactor);
+        let tr_ess = self.dc_essential_contactor_is_closed(|n| n.tr_ess_contactor);
+
+        (!hot_bus_2 && !dc_bat_bus && !tr_ess) || (hot_bus_2 ^ dc_bat_bus ^ tr_ess)
     }
 
     fn batteries_power_both_static_inv_and_dc_ess_bus_at_the_same_time_or_not_at_all(
         &self,
     ) -> bool {
-        self.hot_bus_1_to_static_inv_contactor.is_closed()
-            == self.hot_bus_2_to_dc_ess_bus_contactor.is_closed()
+        self.dc_essential_contactor_is_closed(|n| n.hot_bus_1_to_static_inv_contactor)
+            == self.dc_essential_contactor_is_closed(|n| n.hot_bus_2_to_dc_ess_bus_contactor)
+    }
+
+    pub fn update_battery_surrounding_temperature(
+        &mut self,
+        temperature: ThermodynamicTemperature,
+    ) {
+        self.battery_1.update_surrounding_temperature(temperature);
+        self.battery_2.update_surrounding_temperature(temperature);
+    }
+
+    pub fn battery_overheat_detected(&self, number: usize) -> bool {
+        match number {
+            1 => self.battery_1_charge_limiter.battery_overheat_detected(),
+            2 => self.battery_2_charge_limiter.battery_overheat_detected(),
+            _ => panic!("Invalid battery number"),
+        }
     }
 
     #[cfg(test)]
@@ -321,34 +558,11 @@ impl SimulationElement for A320DirectCurrentElectrical {
         self.battery_2_charge_limiter.accept(visitor);
         self.static_inverter.accept(visitor);
 
-        self.dc_bus_1_tie_contactor.accept(visitor);
-        self.dc_bus_2_tie_contactor.accept(visitor);
-        self.dc_bat_bus_to_dc_ess_bus_contactor.accept(visitor);
-        self.dc_ess_shed_contactor.accept(visitor);
-        self.battery_1_contactor.accept(visitor);
-        self.battery_2_contactor.accept(visitor);
-        self.hot_bus_2_to_dc_ess_bus_contactor.accept(visitor);
-        self.hot_bus_1_to_static_inv_contactor.accept(visitor);
-        self.tr_1_contactor.accept(visitor);
-        self.tr_2_contactor.accept(visitor);
-        self.tr_ess_contactor.accept(visitor);
-
-        self.dc_bus_1.accept(visitor);
-        self.dc_bus_2.accept(visitor);
-        self.dc_bat_bus.accept(visitor);
-        self.dc_ess_bus.accept(visitor);
-        self.dc_ess_shed_bus.accept(visitor);
-        self.hot_bus_1.accept(visitor);
-        self.hot_bus_2.accept(visitor);
-
-        self.apu_start_contactors.accept(visitor);
-        self.apu_start_motor_bus.accept(visitor);
-
-        self.dc_gnd_flt_service_bus.accept(visitor);
-        self.tr_2_to_dc_gnd_flt_service_bus_contactor
-            .accept(visitor);
-        self.dc_bus_2_to_dc_gnd_flt_service_bus_contactor
-            .accept(visitor);
+        self.dc_main.accept(visitor);
+        self.battery_1_network.accept(visitor);
+        self.battery_2_network.accept(visitor);
+        self.apu_start.accept(visitor);
+        self.dc_essential.accept(visitor);
 
         visitor.visit(self);
     }
@@ -176,6 +176,18 @@ impl A320Electrical {
         self.alternating_current.tr_ess()
     }
 
+    pub fn update_battery_surrounding_temperature(
+        &mut self,
+        temperature: ThermodynamicTemperature,
+    ) {
+        self.direct_current
+            .update_battery_surrounding_temperature(temperature);
+    }
+
+    fn battery_overheat_detected(&self, number: usize) -> bool {
+        self.direct_current.battery_overheat_detected(number)
+    }
+
     #[cfg(test)]
     fn battery_1(&self) -> &Battery {
         self.direct_current.battery_1()
@@ -296,6 +308,13 @@ impl A320ElectricalOverheadPanel {
         self.ac_ess_feed
             .set_fault(!electrical.ac_ess_bus_is_powered(electricity));
 
+        self.batteries
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, bat)| {
+                bat.set_fault(electrical.battery_overheat_detected(index + 1));
+            });
+
         self.generators
             .iter_mut()
             .enumerate()
@@ -447,6 +466,15 @@ mod a320_electrical {
 
         assert!(test_bed.contains_variable_with_name("ELEC_GALLEY_IS_SHED"));
     }
+
+    #[test]
+    fn dc_topologies_are_valid() {
+        assert_eq!(direct_current::dc_main_topology().0.validate(), Ok(()));
+        assert_eq!(direct_current::battery_topology(1).0.validate(), Ok(()));
+        assert_eq!(direct_current::battery_topology(2).0.validate(), Ok(()));
+        assert_eq!(direct_current::apu_start_topology().0.validate(), Ok(()));
+        assert_eq!(direct_current::dc_essential_topology().0.validate(), Ok(()));
+    }
 }
 
 #[cfg(test)]
@@ -479,6 +507,7 @@ mod a320_electrical_circuit_tests {
         length::foot,
         power::watt,
         ratio::{percent, ratio},
+        thermodynamic_temperature::degree_celsius,
         velocity::knot,
     };
 
@@ -1724,6 +1753,30 @@ mod a320_electrical_circuit_tests {
         assert!(test_bed.ac_ess_feed_has_fault());
     }
 
+    #[test]
+    fn when_battery_not_overheating_bat_push_button_does_not_have_fault() {
+        let mut test_bed = test_bed_with().running_engines().run();
+
+        assert!(!test_bed.bat_has_fault(1));
+        assert!(!test_bed.bat_has_fault(2));
+    }
+
+    #[test]
+    fn when_battery_in_thermal_runaway_bat_push_button_has_fault() {
+        let mut test_bed = test_bed_with()
+            .running_engines()
+            .and()
+            .battery_in_thermal_runaway(1)
+            .run();
+
+        for _ in 0..150 {
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(10));
+        }
+
+        assert!(test_bed.bat_has_fault(1));
+        assert!(!test_bed.bat_has_fault(2));
+    }
+
     #[test]
     fn when_single_engine_and_apu_galley_is_not_shed() {
         let mut test_bed = test_bed_with().running_engine(1).and().running_apu().run();
@@ -2370,6 +2423,14 @@ mod a320_electrical_circuit_tests {
                 self.corrected_n2().get::<ratio>() * 16000.,
             )
         }
+
+        fn exhaust_gas_temperature(&self) -> ThermodynamicTemperature {
+            ThermodynamicTemperature::new::<degree_celsius>(15.)
+        }
+
+        fn fan_is_damaged(&self) -> bool {
+            false
+        }
     }
 
     struct TestApuOverhead {
@@ -2760,6 +2821,11 @@ mod a320_electrical_circuit_tests {
             self
         }
 
+        fn battery_in_thermal_runaway(mut self, number: usize) -> Self {
+            self.test_bed.fail(FailureType::BatteryOverheat(number));
+            self
+        }
+
         fn running_emergency_generator(mut self) -> Self {
             self.command(|a| a.running_emergency_generator());
             self.run_waiting_for(Duration::from_secs(100))
@@ -2962,6 +3028,10 @@ mod a320_electrical_circuit_tests {
             self.read_by_name(&format!("OVHD_ELEC_ENG_GEN_{}_PB_HAS_FAULT", number))
         }
 
+        fn bat_has_fault(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("OVHD_ELEC_BAT_{}_PB_HAS_FAULT", number))
+        }
+
         fn apu_gen_has_fault(&mut self) -> bool {
             self.read_by_name("OVHD_ELEC_APU_GEN_PB_HAS_FAULT")
         }
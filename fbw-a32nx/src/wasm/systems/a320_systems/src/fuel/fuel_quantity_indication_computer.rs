@@ -0,0 +1,93 @@
+use super::{A320FuelTankType, FuelTankTemperature};
+use systems::{
+    shared::{
+        arinc429::{Arinc429Word, SignStatus},
+        ElectricalBusType, ElectricalBuses,
+    },
+    simulation::{InitContext, SimulationElement, SimulatorWriter, VariableIdentifier, Write},
+};
+use uom::si::{f64::*, thermodynamic_temperature::degree_celsius};
+
+/// The FQIC measures the fuel temperature in the cells of the wing tanks and publishes it on an
+/// arinc 429 bus. The center tank has no temperature sensor.
+pub(super) struct FuelQuantityIndicationComputer {
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+
+    tank_temperature_identifiers: [VariableIdentifier; 4],
+    tank_temperatures: [Arinc429Word<ThermodynamicTemperature>; 4],
+    low_fuel_temperature_id: VariableIdentifier,
+    low_fuel_temperature: Arinc429Word<u32>,
+}
+impl FuelQuantityIndicationComputer {
+    const MEASURED_TANKS: [(A320FuelTankType, &'static str); 4] = [
+        (A320FuelTankType::LeftOuter, "LEFT_OUTER"),
+        (A320FuelTankType::LeftInner, "LEFT_INNER"),
+        (A320FuelTankType::RightInner, "RIGHT_INNER"),
+        (A320FuelTankType::RightOuter, "RIGHT_OUTER"),
+    ];
+
+    pub(super) fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
+        Self {
+            powered_by,
+            is_powered: false,
+
+            tank_temperature_identifiers: Self::MEASURED_TANKS
+                .map(|(_, name)| context.get_identifier(format!("FQIC_{name}_TANK_TEMPERATURE"))),
+            tank_temperatures: [Arinc429Word::new(
+                ThermodynamicTemperature::default(),
+                SignStatus::FailureWarning,
+            ); 4],
+            low_fuel_temperature_id: context
+                .get_identifier("FQIC_LOW_FUEL_TEMPERATURE_WORD".to_owned()),
+            low_fuel_temperature: Arinc429Word::new(0, SignStatus::FailureWarning),
+        }
+    }
+
+    pub(super) fn update(&mut self, fuel: &impl FuelTankTemperature) {
+        if !self.is_powered {
+            self.tank_temperatures = Default::default();
+            self.low_fuel_temperature = Arinc429Word::default();
+            return;
+        }
+
+        let ssm = SignStatus::NormalOperation;
+
+        self.tank_temperatures = Self::MEASURED_TANKS
+            .map(|(tank, _)| Arinc429Word::new(fuel.tank_temperature(tank), ssm));
+
+        // Bits 11 to 14 indicate the fuel of the corresponding tank approaches its freezing point
+        let low_fuel_temperature =
+            Self::MEASURED_TANKS
+                .iter()
+                .enumerate()
+                .fold(0, |value, (bit, &(tank, _))| {
+                    value | ((fuel.tank_temperature_is_low(tank) as u32) << (11 + bit))
+                });
+        self.low_fuel_temperature = Arinc429Word::new(low_fuel_temperature, ssm);
+    }
+}
+impl SimulationElement for FuelQuantityIndicationComputer {
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for (identifier, temperature) in self
+            .tank_temperature_identifiers
+            .iter()
+            .zip(self.tank_temperatures)
+        {
+            writer.write_arinc429(
+                identifier,
+                temperature.value().get::<degree_celsius>(),
+                temperature.ssm(),
+            );
+        }
+        writer.write_arinc429(
+            &self.low_fuel_temperature_id,
+            self.low_fuel_temperature.value(),
+            self.low_fuel_temperature.ssm(),
+        );
+    }
+}
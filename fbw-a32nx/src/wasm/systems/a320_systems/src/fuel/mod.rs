@@ -1,12 +1,28 @@
-// Note: Fuel system for now is still handled in MSFS. This is used for calculating fuel-related factors.
+// Note: The fuel network owns the tank quantities. It burns the fuel flow of the engines and the
+// APU and transfers fuel between the tanks, while refuelling remains handled by MSFS.
 
 use nalgebra::Vector3;
+use std::time::Duration;
 use systems::{
-    fuel::{FuelCG, FuelInfo, FuelPayload, FuelPump, FuelPumpProperties, FuelSystem},
-    shared::ElectricalBusType,
-    simulation::{InitContext, SimulationElement, SimulationElementVisitor},
+    engine::Engine,
+    failures::FailureType,
+    fuel::{
+        network::{
+            ElectricFuelPump, ElectricFuelPumpProperties, FuelConsumer, FuelNetwork,
+            FuelNetworkController, FuelValve, FuelValveConnection, FuelValveProperties,
+        },
+        FuelCG, FuelInfo, FuelPayload, FuelPump, FuelPumpProperties, FuelSystem,
+    },
+    shared::{DelayedTrueLogicGate, ElectricalBusType},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        UpdateContext, VariableIdentifier,
+    },
 };
-use uom::si::f64::*;
+use uom::si::{f64::*, mass::kilogram, ratio::percent};
+
+mod fuel_quantity_indication_computer;
+use fuel_quantity_indication_computer::FuelQuantityIndicationComputer;
 
 #[cfg(test)]
 mod test;
@@ -20,6 +36,12 @@ pub trait FuelLevel {
     fn center_tank_has_fuel(&self) -> bool;
 }
 
+pub trait FuelTankTemperature {
+    fn tank_temperature(&self, tank: A320FuelTankType) -> ThermodynamicTemperature;
+    fn tank_temperature_is_low(&self, tank: A320FuelTankType) -> bool;
+}
+
+#[derive(Clone, Copy)]
 pub enum A320FuelTankType {
     Center,
     LeftInner,
@@ -46,8 +68,251 @@ impl From<usize> for A320FuelTankType {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum A320FuelPump {
+    LeftOne,
+    LeftTwo,
+    CenterLeft,
+    CenterRight,
+    RightOne,
+    RightTwo,
+}
+impl From<A320FuelPump> for usize {
+    fn from(value: A320FuelPump) -> Self {
+        value as usize
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum A320FuelValve {
+    Crossfeed,
+    LeftTransfer,
+    RightTransfer,
+}
+impl From<A320FuelValve> for usize {
+    fn from(value: A320FuelValve) -> Self {
+        value as usize
+    }
+}
+
+/// The fuel pushbuttons and mode selector of the overhead panel. The pushbuttons are mapped onto
+/// the MSFS fuel system switches used by the cockpit.
+pub struct A320FuelOverheadPanel {
+    wing_pump_switch_ids: [VariableIdentifier; 4],
+    center_pump_switch_ids: [VariableIdentifier; 2],
+    crossfeed_switch_id: VariableIdentifier,
+    mode_select_manual_id: VariableIdentifier,
+
+    wing_pumps_on: [bool; 4],
+    center_pumps_on: [bool; 2],
+    crossfeed_on: bool,
+    mode_select_manual: bool,
+}
+impl A320FuelOverheadPanel {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            // Left 1, left 2, right 1, right 2
+            wing_pump_switch_ids: [2, 5, 3, 6]
+                .map(|id| context.get_identifier(format!("FUELSYSTEM PUMP SWITCH:{id}"))),
+            // Left, right
+            center_pump_switch_ids: [9, 10]
+                .map(|id| context.get_identifier(format!("FUELSYSTEM VALVE SWITCH:{id}"))),
+            crossfeed_switch_id: context.get_identifier("FUELSYSTEM VALVE SWITCH:3".to_owned()),
+            mode_select_manual_id: context.get_identifier("OVHD_FUEL_MODESEL_MANUAL".to_owned()),
+
+            wing_pumps_on: [true; 4],
+            center_pumps_on: [true; 2],
+            crossfeed_on: false,
+            mode_select_manual: false,
+        }
+    }
+
+    fn pump_is_on(&self, pump: A320FuelPump) -> bool {
+        match pump {
+            A320FuelPump::LeftOne => self.wing_pumps_on[0],
+            A320FuelPump::LeftTwo => self.wing_pumps_on[1],
+            A320FuelPump::RightOne => self.wing_pumps_on[2],
+            A320FuelPump::RightTwo => self.wing_pumps_on[3],
+            A320FuelPump::CenterLeft => self.center_pumps_on[0],
+            A320FuelPump::CenterRight => self.center_pumps_on[1],
+        }
+    }
+
+    fn crossfeed_is_on(&self) -> bool {
+        self.crossfeed_on
+    }
+
+    fn mode_select_is_manual(&self) -> bool {
+        self.mode_select_manual
+    }
+}
+impl SimulationElement for A320FuelOverheadPanel {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        for (on, id) in self
+            .wing_pumps_on
+            .iter_mut()
+            .zip(&self.wing_pump_switch_ids)
+        {
+            *on = reader.read(id);
+        }
+        for (on, id) in self
+            .center_pumps_on
+            .iter_mut()
+            .zip(&self.center_pump_switch_ids)
+        {
+            *on = reader.read(id);
+        }
+        self.crossfeed_on = reader.read(&self.crossfeed_switch_id);
+        self.mode_select_manual = reader.read(&self.mode_select_manual_id);
+    }
+}
+
+/// Controls the pumps and valves of the fuel network, as done by the FLSCU on the real aircraft.
+///
+/// In auto mode the center tank pumps run when the slats are retracted and during two minutes after
+/// each engine start. They stop when their associated inner tank is full, until 500 kg have been
+/// used from it, and are shut off five minutes after the center tank reached its low level. The
+/// transfer valves open when either inner tank reaches 750 kg and stay latched open until the next
+/// refuelling on ground.
+struct A320FuelController {
+    slats_position_id: VariableIdentifier,
+    slats_position: Ratio,
+
+    engine_was_running: [bool; 2],
+    engine_start_run_time_remaining: Duration,
+    center_tank_low_level: DelayedTrueLogicGate,
+    inner_tank_full: [bool; 2],
+    transfer_valves_latched_open: bool,
+
+    pumps_should_run: [bool; 6],
+    valves_should_open: [bool; 3],
+}
+impl A320FuelController {
+    const ENGINE_START_RUN_TIME: Duration = Duration::from_secs(2 * 60);
+    const CENTER_TANK_AUTO_SHUTOFF_DELAY: Duration = Duration::from_secs(5 * 60);
+    const CENTER_TANK_LOW_LEVEL_KG: f64 = 30.;
+    const INNER_TANK_FULL_MARGIN_KG: f64 = 10.;
+    const INNER_TANK_REFILL_MARGIN_KG: f64 = 500.;
+    const INNER_TANK_LOW_LEVEL_KG: f64 = 750.;
+    const SLATS_RETRACTED_THRESHOLD_PERCENT: f64 = 1.;
+
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            slats_position_id: context.get_identifier("LEFT_SLATS_POSITION_PERCENT".to_owned()),
+            slats_position: Ratio::default(),
+
+            engine_was_running: [false; 2],
+            engine_start_run_time_remaining: Duration::ZERO,
+            center_tank_low_level: DelayedTrueLogicGate::new(Self::CENTER_TANK_AUTO_SHUTOFF_DELAY),
+            inner_tank_full: [false; 2],
+            transfer_valves_latched_open: false,
+
+            pumps_should_run: [false; 6],
+            valves_should_open: [false; 3],
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        overhead: &A320FuelOverheadPanel,
+        engines: [&impl Engine; 2],
+        fuel_system: &FuelSystem<5, 1>,
+    ) {
+        self.update_engine_start(context, engines);
+
+        let center_quantity = fuel_system.tank_mass(A320FuelTankType::Center.into());
+        self.center_tank_low_level.update(
+            context,
+            center_quantity.get::<kilogram>() < Self::CENTER_TANK_LOW_LEVEL_KG,
+        );
+
+        let inner_tanks =
+            [A320FuelTankType::LeftInner, A320FuelTankType::RightInner].map(usize::from);
+        for (full, &tank) in self.inner_tank_full.iter_mut().zip(&inner_tanks) {
+            let free_capacity = fuel_system.tank_free_capacity(tank).get::<kilogram>();
+            if free_capacity < Self::INNER_TANK_FULL_MARGIN_KG {
+                *full = true;
+            } else if free_capacity > Self::INNER_TANK_REFILL_MARGIN_KG {
+                *full = false;
+            }
+        }
+
+        let inner_tank_low_level = inner_tanks.iter().any(|&tank| {
+            fuel_system.tank_mass(tank).get::<kilogram>() <= Self::INNER_TANK_LOW_LEVEL_KG
+        });
+        if inner_tank_low_level {
+            self.transfer_valves_latched_open = true;
+        } else if context.is_on_ground() {
+            self.transfer_valves_latched_open = false;
+        }
+
+        for pump in [
+            A320FuelPump::LeftOne,
+            A320FuelPump::LeftTwo,
+            A320FuelPump::RightOne,
+            A320FuelPump::RightTwo,
+        ] {
+            self.pumps_should_run[usize::from(pump)] = overhead.pump_is_on(pump);
+        }
+
+        let center_pumps_auto_run = !self.center_tank_low_level.output()
+            && (self.slats_are_retracted()
+                || self.engine_start_run_time_remaining > Duration::ZERO);
+        for (pump, inner_tank_full) in [
+            (A320FuelPump::CenterLeft, self.inner_tank_full[0]),
+            (A320FuelPump::CenterRight, self.inner_tank_full[1]),
+        ] {
+            self.pumps_should_run[usize::from(pump)] = overhead.pump_is_on(pump)
+                && (overhead.mode_select_is_manual()
+                    || (center_pumps_auto_run && !inner_tank_full));
+        }
+
+        self.valves_should_open[usize::from(A320FuelValve::Crossfeed)] = overhead.crossfeed_is_on();
+        self.valves_should_open[usize::from(A320FuelValve::LeftTransfer)] =
+            self.transfer_valves_latched_open;
+        self.valves_should_open[usize::from(A320FuelValve::RightTransfer)] =
+            self.transfer_valves_latched_open;
+    }
+
+    fn update_engine_start(&mut self, context: &UpdateContext, engines: [&impl Engine; 2]) {
+        self.engine_start_run_time_remaining = self
+            .engine_start_run_time_remaining
+            .saturating_sub(context.delta());
+
+        for (was_running, engine) in self.engine_was_running.iter_mut().zip(engines) {
+            let is_running = engine.is_above_minimum_idle();
+            if is_running && !*was_running {
+                self.engine_start_run_time_remaining = Self::ENGINE_START_RUN_TIME;
+            }
+            *was_running = is_running;
+        }
+    }
+
+    fn slats_are_retracted(&self) -> bool {
+        self.slats_position.get::<percent>() < Self::SLATS_RETRACTED_THRESHOLD_PERCENT
+    }
+}
+impl FuelNetworkController for A320FuelController {
+    fn pump_should_run(&self, pump: usize) -> bool {
+        self.pumps_should_run[pump]
+    }
+
+    fn valve_should_open(&self, valve: usize) -> bool {
+        self.valves_should_open[valve]
+    }
+}
+impl SimulationElement for A320FuelController {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.slats_position = reader.read(&self.slats_position_id);
+    }
+}
+
 pub struct A320Fuel {
-    fuel_system: FuelSystem<5, 5>,
+    fuel_system: FuelSystem<5, 1>,
+    network: FuelNetwork,
+    controller: A320FuelController,
+    fuel_quantity_indication_computer: FuelQuantityIndicationComputer,
 }
 impl A320Fuel {
     pub const A320_FUEL: [FuelInfo<'static>; 5] = [
@@ -78,59 +343,178 @@ impl A320Fuel {
         },
     ];
 
-    // TODO: connect MSFS fuel pumps to power logic
-    const FUEL_PUMPS: [(usize, FuelPumpProperties); 5] = [
-        // Left main tank pump 1
+    const APU_FUEL_PUMP: (usize, FuelPumpProperties) = (
+        7,
+        FuelPumpProperties {
+            powered_by: ElectricalBusType::Virtual("FUEL_PUMP_APU_SUPPLY"),
+            consumption_current_ampere: 1.,
+        },
+    );
+
+    const WING_PUMP_PRESSURE_PSI: f64 = 23.;
+    // The center tank pumps deliver a higher pressure, such that they take priority over the wing
+    // tank pumps while running.
+    const CENTER_PUMP_PRESSURE_PSI: f64 = 29.;
+    const PUMP_MAX_FLOW_KG_PER_S: f64 = 2.;
+
+    const FUEL_PUMPS: [(
+        &'static str,
+        A320FuelTankType,
+        usize,
+        ElectricalBusType,
+        f64,
+    ); 6] = [
         (
-            2,
-            FuelPumpProperties {
-                powered_by: ElectricalBusType::Virtual("FUEL_PUMP_1_SUPPLY"),
-                consumption_current_ampere: 8.,
-            },
+            "LEFT_1",
+            A320FuelTankType::LeftInner,
+            0,
+            ElectricalBusType::Virtual("FUEL_PUMP_1_SUPPLY"),
+            Self::WING_PUMP_PRESSURE_PSI,
         ),
-        // Left main tank pump 2
         (
-            5,
-            FuelPumpProperties {
-                powered_by: ElectricalBusType::Virtual("FUEL_PUMP_2_SUPPLY"),
-                consumption_current_ampere: 8.,
-            },
+            "LEFT_2",
+            A320FuelTankType::LeftInner,
+            0,
+            ElectricalBusType::Virtual("FUEL_PUMP_2_SUPPLY"),
+            Self::WING_PUMP_PRESSURE_PSI,
         ),
-        // Right main tank pump 1
         (
-            3,
-            FuelPumpProperties {
-                powered_by: ElectricalBusType::Virtual("FUEL_PUMP_1_SUPPLY"),
-                consumption_current_ampere: 8.,
-            },
+            "CENTER_LEFT",
+            A320FuelTankType::Center,
+            0,
+            ElectricalBusType::AlternatingCurrent(1),
+            Self::CENTER_PUMP_PRESSURE_PSI,
         ),
-        // Right main tank pump 2
         (
-            6,
-            FuelPumpProperties {
-                powered_by: ElectricalBusType::Virtual("FUEL_PUMP_2_SUPPLY"),
-                consumption_current_ampere: 8.,
-            },
+            "CENTER_RIGHT",
+            A320FuelTankType::Center,
+            1,
+            ElectricalBusType::AlternatingCurrent(2),
+            Self::CENTER_PUMP_PRESSURE_PSI,
         ),
-        // APU fuel pump
         (
-            7,
-            FuelPumpProperties {
-                powered_by: ElectricalBusType::Virtual("FUEL_PUMP_APU_SUPPLY"),
-                consumption_current_ampere: 1.,
-            },
+            "RIGHT_1",
+            A320FuelTankType::RightInner,
+            1,
+            ElectricalBusType::Virtual("FUEL_PUMP_1_SUPPLY"),
+            Self::WING_PUMP_PRESSURE_PSI,
+        ),
+        (
+            "RIGHT_2",
+            A320FuelTankType::RightInner,
+            1,
+            ElectricalBusType::Virtual("FUEL_PUMP_2_SUPPLY"),
+            Self::WING_PUMP_PRESSURE_PSI,
         ),
     ];
 
+    const TRANSFER_VALVE_MAX_FLOW_KG_PER_S: f64 = 0.6;
+
     pub fn new(context: &mut InitContext) -> Self {
-        let fuel_tanks = Self::A320_FUEL.map(|f| f.into_fuel_tank(context, false));
-        let fuel_pumps =
-            Self::FUEL_PUMPS.map(|(id, properties)| FuelPump::new(context, id, properties));
+        let fuel_tanks = Self::A320_FUEL.map(|f| f.into_fuel_tank(context, true));
+        let (apu_pump_id, apu_pump_properties) = Self::APU_FUEL_PUMP;
+        let fuel_pumps = [FuelPump::new(context, apu_pump_id, apu_pump_properties)];
+
+        let pumps = Self::FUEL_PUMPS
+            .into_iter()
+            .enumerate()
+            .map(|(i, (id, tank, feed_line, powered_by, pressure))| {
+                ElectricFuelPump::new(
+                    context,
+                    id,
+                    FailureType::FuelPump(i + 1),
+                    ElectricFuelPumpProperties {
+                        tank: tank.into(),
+                        feed_line,
+                        powered_by,
+                        consumption_current_ampere: 8.,
+                        nominal_pressure_psi: pressure,
+                        max_flow_kg_per_s: Self::PUMP_MAX_FLOW_KG_PER_S,
+                    },
+                )
+            })
+            .collect();
+        let valves = [
+            ("CROSSFEED", FuelValveConnection::FeedLines(0, 1)),
+            (
+                "LEFT_TRANSFER",
+                FuelValveConnection::Gravity {
+                    from_tank: A320FuelTankType::LeftOuter.into(),
+                    to_tank: A320FuelTankType::LeftInner.into(),
+                },
+            ),
+            (
+                "RIGHT_TRANSFER",
+                FuelValveConnection::Gravity {
+                    from_tank: A320FuelTankType::RightOuter.into(),
+                    to_tank: A320FuelTankType::RightInner.into(),
+                },
+            ),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, (id, connection))| {
+            FuelValve::new(
+                context,
+                id,
+                FailureType::FuelValveJammed(i + 1),
+                FuelValveProperties {
+                    connection,
+                    powered_by: ElectricalBusType::DirectCurrentEssential,
+                    travel_time_s: 2.,
+                    max_flow_kg_per_s: Self::TRANSFER_VALVE_MAX_FLOW_KG_PER_S,
+                },
+            )
+        })
+        .collect();
+        let consumers = vec![
+            FuelConsumer {
+                feed_line: 0,
+                suction_tank: Some(A320FuelTankType::LeftInner.into()),
+            },
+            FuelConsumer {
+                feed_line: 1,
+                suction_tank: Some(A320FuelTankType::RightInner.into()),
+            },
+            // The APU fuel pump draws from the left feed line
+            FuelConsumer {
+                feed_line: 0,
+                suction_tank: Some(A320FuelTankType::LeftInner.into()),
+            },
+        ];
+
         A320Fuel {
             fuel_system: FuelSystem::new(context, fuel_tanks, fuel_pumps),
+            network: FuelNetwork::new(2, pumps, valves, consumers),
+            controller: A320FuelController::new(context),
+            fuel_quantity_indication_computer: FuelQuantityIndicationComputer::new(
+                context,
+                ElectricalBusType::DirectCurrentEssential,
+            ),
         }
     }
 
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        overhead: &A320FuelOverheadPanel,
+        engines: [&impl Engine; 2],
+        engine_fuel_flows: [MassRate; 2],
+        apu_fuel_flow: MassRate,
+    ) {
+        self.fuel_system.update_temperatures(context);
+        self.fuel_quantity_indication_computer
+            .update(&self.fuel_system);
+        self.controller
+            .update(context, overhead, engines, &self.fuel_system);
+        self.fuel_system.update_network(
+            context,
+            &mut self.network,
+            &self.controller,
+            &[engine_fuel_flows[0], engine_fuel_flows[1], apu_fuel_flow],
+        );
+    }
+
     pub fn left_inner_tank_has_fuel_remaining(&self) -> bool {
         self.fuel_system
             .tank_has_fuel(A320FuelTankType::LeftInner.into())
@@ -195,6 +579,24 @@ impl FuelLevel for A320Fuel {
         self.center_tank_has_fuel()
     }
 }
+impl FuelTankTemperature for A320Fuel {
+    fn tank_temperature(&self, tank: A320FuelTankType) -> ThermodynamicTemperature {
+        self.fuel_system.tank_temperature(tank.into())
+    }
+
+    fn tank_temperature_is_low(&self, tank: A320FuelTankType) -> bool {
+        self.fuel_system.tank_temperature_is_low(tank.into())
+    }
+}
+impl FuelTankTemperature for FuelSystem<5, 1> {
+    fn tank_temperature(&self, tank: A320FuelTankType) -> ThermodynamicTemperature {
+        self.tank_temperature(tank.into())
+    }
+
+    fn tank_temperature_is_low(&self, tank: A320FuelTankType) -> bool {
+        self.tank_temperature_is_low(tank.into())
+    }
+}
 impl FuelPayload for A320Fuel {
     fn total_load(&self) -> Mass {
         self.total_load()
@@ -211,6 +613,9 @@ impl FuelCG for A320Fuel {
 impl SimulationElement for A320Fuel {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_system.accept(visitor);
+        self.network.accept(visitor);
+        self.controller.accept(visitor);
+        self.fuel_quantity_indication_computer.accept(visitor);
         visitor.visit(self);
     }
 }
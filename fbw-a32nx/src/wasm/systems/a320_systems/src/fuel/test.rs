@@ -1,19 +1,110 @@
 use std::time::Duration;
 
 use super::*;
-use crate::systems::simulation::{
-    test::{SimulationTestBed, TestBed, WriteByName},
-    Aircraft, SimulationElement, SimulationElementVisitor,
+use crate::systems::{
+    electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+    engine::Engine,
+    shared::{
+        arinc429::Arinc429Word, EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2,
+        PotentialOrigin,
+    },
+    simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft, SimulationElement, SimulationElementVisitor,
+    },
 };
+use uom::si::{
+    angular_velocity::revolution_per_minute, electric_potential::volt,
+    mass_rate::kilogram_per_second, thermodynamic_temperature::degree_celsius,
+};
+
+struct TestEngine {
+    is_running: bool,
+}
+impl TestEngine {
+    fn new() -> Self {
+        Self { is_running: false }
+    }
+}
+impl EngineCorrectedN1 for TestEngine {
+    fn corrected_n1(&self) -> Ratio {
+        unimplemented!()
+    }
+}
+impl EngineCorrectedN2 for TestEngine {
+    fn corrected_n2(&self) -> Ratio {
+        unimplemented!()
+    }
+}
+impl EngineUncorrectedN2 for TestEngine {
+    fn uncorrected_n2(&self) -> Ratio {
+        unimplemented!()
+    }
+}
+impl Engine for TestEngine {
+    fn hydraulic_pump_output_speed(&self) -> AngularVelocity {
+        AngularVelocity::new::<revolution_per_minute>(0.)
+    }
+
+    fn oil_pressure_is_low(&self) -> bool {
+        !self.is_running
+    }
+
+    fn is_above_minimum_idle(&self) -> bool {
+        self.is_running
+    }
+
+    fn net_thrust(&self) -> Mass {
+        Mass::default()
+    }
+
+    fn gearbox_speed(&self) -> AngularVelocity {
+        AngularVelocity::new::<revolution_per_minute>(0.)
+    }
+
+    fn exhaust_gas_temperature(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(15.)
+    }
+
+    fn fan_is_damaged(&self) -> bool {
+        false
+    }
+}
 
 struct FuelTestAircraft {
+    powered_source: TestElectricitySource,
+    buses: [ElectricalBus; 5],
+    is_powered: bool,
+
     fuel: A320Fuel,
+    overhead: A320FuelOverheadPanel,
+    engines: [TestEngine; 2],
+    engine_fuel_flows: [MassRate; 2],
+    apu_fuel_flow: MassRate,
 }
 
 impl FuelTestAircraft {
     fn new(context: &mut InitContext) -> Self {
         Self {
+            powered_source: TestElectricitySource::powered(
+                context,
+                PotentialOrigin::EngineGenerator(1),
+            ),
+            buses: [
+                ElectricalBusType::Virtual("FUEL_PUMP_1_SUPPLY"),
+                ElectricalBusType::Virtual("FUEL_PUMP_2_SUPPLY"),
+                ElectricalBusType::AlternatingCurrent(1),
+                ElectricalBusType::AlternatingCurrent(2),
+                ElectricalBusType::DirectCurrentEssential,
+            ]
+            .map(|bus_type| ElectricalBus::new(context, bus_type)),
+            is_powered: true,
+
             fuel: A320Fuel::new(context),
+            overhead: A320FuelOverheadPanel::new(context),
+            engines: [TestEngine::new(), TestEngine::new()],
+            engine_fuel_flows: [MassRate::default(); 2],
+            apu_fuel_flow: MassRate::default(),
         }
     }
 
@@ -22,10 +113,37 @@ impl FuelTestAircraft {
     }
 }
 
-impl Aircraft for FuelTestAircraft {}
+impl Aircraft for FuelTestAircraft {
+    fn update_before_power_distribution(
+        &mut self,
+        _: &UpdateContext,
+        electricity: &mut Electricity,
+    ) {
+        self.powered_source
+            .power_with_potential(ElectricPotential::new::<volt>(115.));
+        electricity.supplied_by(&self.powered_source);
+
+        if self.is_powered {
+            for bus in &self.buses {
+                electricity.flow(&self.powered_source, bus);
+            }
+        }
+    }
+
+    fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+        self.fuel.update(
+            context,
+            &self.overhead,
+            [&self.engines[0], &self.engines[1]],
+            self.engine_fuel_flows,
+            self.apu_fuel_flow,
+        );
+    }
+}
 impl SimulationElement for FuelTestAircraft {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel.accept(visitor);
+        self.overhead.accept(visitor);
 
         visitor.visit(self);
     }
@@ -81,6 +199,106 @@ impl FuelTestBed {
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.query(|a: &FuelTestAircraft| a.fore_aft_center_of_gravity())
     }
+
+    fn with_tank_quantity(mut self, tank: &str, kg: f64) -> Self {
+        self.write_by_name(
+            &format!("FUEL TANK {tank} QUANTITY"),
+            kg / FUEL_GALLONS_TO_KG,
+        );
+
+        self
+    }
+
+    fn with_all_pumps_on(mut self) -> Self {
+        for id in 1..=7 {
+            self.write_by_name(&format!("FUELSYSTEM PUMP SWITCH:{id}"), true);
+        }
+        for id in [9, 10] {
+            self.write_by_name(&format!("FUELSYSTEM VALVE SWITCH:{id}"), true);
+        }
+
+        self
+    }
+
+    fn wing_pump_switch_off(mut self, id: usize) -> Self {
+        self.write_by_name(&format!("FUELSYSTEM PUMP SWITCH:{id}"), false);
+
+        self
+    }
+
+    fn crossfeed_on(mut self) -> Self {
+        self.write_by_name("FUELSYSTEM VALVE SWITCH:3", true);
+
+        self
+    }
+
+    fn mode_select_manual(mut self) -> Self {
+        self.write_by_name("OVHD_FUEL_MODESEL_MANUAL", true);
+
+        self
+    }
+
+    fn slats_extended(mut self) -> Self {
+        self.write_by_name("LEFT_SLATS_POSITION_PERCENT", 100.);
+
+        self
+    }
+
+    fn in_flight(mut self) -> Self {
+        self.set_on_ground(false);
+
+        self
+    }
+
+    fn start_engines(mut self) -> Self {
+        self.command(|a| a.engines.iter_mut().for_each(|e| e.is_running = true));
+
+        self
+    }
+
+    fn engines_burning(mut self, kg_per_s: f64) -> Self {
+        self.command(|a| a.engine_fuel_flows = [MassRate::new::<kilogram_per_second>(kg_per_s); 2]);
+
+        self
+    }
+
+    fn apu_burning(mut self, kg_per_s: f64) -> Self {
+        self.command(|a| a.apu_fuel_flow = MassRate::new::<kilogram_per_second>(kg_per_s));
+
+        self
+    }
+
+    fn run_for(mut self, duration: Duration) -> Self {
+        self.test_bed.run_multiple_frames(duration);
+
+        self
+    }
+
+    fn pump_is_pressurised(&self, pump: A320FuelPump) -> bool {
+        self.query(|a| a.fuel.network.pump(pump.into()).is_pressurised())
+    }
+
+    fn valve_is_open(&self, valve: A320FuelValve) -> bool {
+        self.query(|a| a.fuel.network.valve(valve.into()).is_open())
+    }
+
+    fn tank_quantity(&mut self, tank: &str) -> f64 {
+        let gallons: f64 = self.read_by_name(&format!("FUEL TANK {tank} QUANTITY"));
+        gallons * FUEL_GALLONS_TO_KG
+    }
+
+    fn engine_fuel_flow(&self, engine: usize) -> f64 {
+        self.query(|a| {
+            a.fuel
+                .network
+                .consumer_fuel_flow(engine - 1)
+                .get::<kilogram_per_second>()
+        })
+    }
+
+    fn engine_is_starved(&self, engine: usize) -> bool {
+        self.query(|a| a.fuel.network.consumer_is_starved(engine - 1))
+    }
 }
 
 impl TestBed for FuelTestBed {
@@ -103,6 +321,24 @@ fn test_bed_with() -> FuelTestBed {
     test_bed()
 }
 
+fn test_bed_with_fuel() -> FuelTestBed {
+    test_bed_with()
+        .with_tank_quantity("LEFT MAIN", 5000.)
+        .with_tank_quantity("LEFT AUX", 690.)
+        .with_tank_quantity("RIGHT MAIN", 5000.)
+        .with_tank_quantity("RIGHT AUX", 690.)
+        .with_tank_quantity("CENTER", 3000.)
+        .with_all_pumps_on()
+}
+
+const WING_PUMPS: [A320FuelPump; 4] = [
+    A320FuelPump::LeftOne,
+    A320FuelPump::LeftTwo,
+    A320FuelPump::RightOne,
+    A320FuelPump::RightTwo,
+];
+const CENTER_PUMPS: [A320FuelPump; 2] = [A320FuelPump::CenterLeft, A320FuelPump::CenterRight];
+
 #[test]
 fn init() {
     let test_bed = test_bed_with().fuel_low();
@@ -117,7 +353,7 @@ fn init() {
 #[test]
 fn low_fuel() {
     let mut test_bed = test_bed_with().fuel_low();
-    test_bed = test_bed.and_run().and_stabilize();
+    test_bed = test_bed.and_run();
 
     assert_eq!(
         (test_bed.fore_aft_center_of_gravity() * 100.).round() / 100.,
@@ -125,6 +361,14 @@ fn low_fuel() {
         "Expected cg: -11.12, cg: {}",
         (test_bed.fore_aft_center_of_gravity() * 100.).round() / 100.,
     );
+
+    // The inner tanks are below their low level, thus the outer tank fuel is transferred inboard
+    test_bed = test_bed.and_stabilize();
+
+    assert!(test_bed.tank_quantity("LEFT AUX") < 219.);
+    assert!(test_bed.tank_quantity("LEFT MAIN") > 405.);
+    assert!(test_bed.tank_quantity("RIGHT AUX") < 219.);
+    assert!(test_bed.tank_quantity("RIGHT MAIN") > 405.);
 }
 
 #[test]
@@ -139,3 +383,308 @@ fn high_fuel() {
         (test_bed.fore_aft_center_of_gravity() * 100.).round() / 100.,
     );
 }
+
+#[test]
+fn wing_pumps_are_pressurised_when_switched_on() {
+    let mut test_bed = test_bed_with_fuel().run_for(Duration::from_secs(5));
+
+    for pump in WING_PUMPS {
+        assert!(test_bed.pump_is_pressurised(pump));
+    }
+    assert!(!ReadByName::<FuelTestBed, bool>::read_by_name(
+        &mut test_bed,
+        "FUEL_PUMP_LEFT_1_LOW_PRESSURE"
+    ));
+}
+
+#[test]
+fn wing_pump_switched_off_is_not_pressurised() {
+    let mut test_bed = test_bed_with_fuel()
+        .wing_pump_switch_off(2)
+        .run_for(Duration::from_secs(5));
+
+    assert!(!test_bed.pump_is_pressurised(A320FuelPump::LeftOne));
+    assert!(test_bed.pump_is_pressurised(A320FuelPump::LeftTwo));
+    assert!(ReadByName::<FuelTestBed, bool>::read_by_name(
+        &mut test_bed,
+        "FUEL_PUMP_LEFT_1_LOW_PRESSURE"
+    ));
+}
+
+#[test]
+fn unpowered_pumps_are_not_pressurised() {
+    let mut test_bed = test_bed_with_fuel();
+    test_bed.command(|a| a.is_powered = false);
+    let test_bed = test_bed.run_for(Duration::from_secs(5));
+
+    for pump in WING_PUMPS.into_iter().chain(CENTER_PUMPS) {
+        assert!(!test_bed.pump_is_pressurised(pump));
+    }
+}
+
+#[test]
+fn failed_pump_is_not_pressurised() {
+    let mut test_bed = test_bed_with_fuel();
+    test_bed.fail(FailureType::FuelPump(1));
+    let test_bed = test_bed.run_for(Duration::from_secs(5));
+
+    assert!(!test_bed.pump_is_pressurised(A320FuelPump::LeftOne));
+    assert!(test_bed.pump_is_pressurised(A320FuelPump::LeftTwo));
+}
+
+#[test]
+fn engines_burn_the_fuel_of_their_inner_tanks() {
+    let mut test_bed = test_bed_with_fuel()
+        .with_tank_quantity("CENTER", 0.)
+        .in_flight()
+        .engines_burning(1.)
+        .run_for(Duration::from_secs(100));
+
+    assert!((test_bed.tank_quantity("LEFT MAIN") - 4900.).abs() < 1.);
+    assert!((test_bed.tank_quantity("RIGHT MAIN") - 4900.).abs() < 1.);
+    assert!((test_bed.engine_fuel_flow(1) - 1.).abs() < 1e-6);
+    assert!(!test_bed.engine_is_starved(1));
+}
+
+#[test]
+fn apu_burns_the_fuel_of_the_left_inner_tank() {
+    let mut test_bed = test_bed_with_fuel()
+        .with_tank_quantity("CENTER", 0.)
+        .wing_pump_switch_off(2)
+        .wing_pump_switch_off(5)
+        .wing_pump_switch_off(3)
+        .wing_pump_switch_off(6)
+        .apu_burning(0.05)
+        .run_for(Duration::from_secs(200));
+
+    assert!((test_bed.tank_quantity("LEFT MAIN") - 4990.).abs() < 0.1);
+    assert!((test_bed.tank_quantity("RIGHT MAIN") - 5000.).abs() < 0.1);
+}
+
+#[test]
+fn failed_wing_pumps_with_crossfeed_open_feed_both_engines_from_the_other_side() {
+    let mut test_bed = test_bed_with_fuel()
+        .with_tank_quantity("CENTER", 0.)
+        .in_flight()
+        .crossfeed_on();
+    test_bed.fail(FailureType::FuelPump(1));
+    test_bed.fail(FailureType::FuelPump(2));
+    let mut test_bed = test_bed
+        .run_for(Duration::from_secs(5))
+        .engines_burning(1.)
+        .run_for(Duration::from_secs(100));
+
+    assert!((test_bed.tank_quantity("LEFT MAIN") - 5000.).abs() < 1.);
+    assert!((test_bed.tank_quantity("RIGHT MAIN") - 4800.).abs() < 1.);
+    assert!(!test_bed.engine_is_starved(1));
+    assert!(!test_bed.engine_is_starved(2));
+}
+
+#[test]
+fn engine_starves_when_its_inner_tank_is_empty_and_the_crossfeed_valve_is_jammed() {
+    let mut test_bed = test_bed_with_fuel()
+        .with_tank_quantity("CENTER", 0.)
+        .with_tank_quantity("LEFT MAIN", 0.)
+        .with_tank_quantity("LEFT AUX", 0.)
+        .in_flight()
+        .crossfeed_on();
+    test_bed.fail(FailureType::FuelValveJammed(1));
+    let mut test_bed = test_bed.engines_burning(1.).run_for(Duration::from_secs(5));
+
+    assert!(test_bed.engine_is_starved(1));
+    assert!(test_bed.engine_fuel_flow(1) < 1e-6);
+    assert!(!test_bed.engine_is_starved(2));
+    assert!(test_bed.tank_quantity("LEFT MAIN") < 1.);
+
+    test_bed.unfail(FailureType::FuelValveJammed(1));
+    let test_bed = test_bed.run_for(Duration::from_secs(10));
+
+    assert!(!test_bed.engine_is_starved(1));
+}
+
+#[test]
+fn center_pumps_run_in_auto_mode_with_slats_retracted() {
+    let test_bed = test_bed_with_fuel()
+        .with_tank_quantity("LEFT MAIN", 4000.)
+        .with_tank_quantity("RIGHT MAIN", 4000.)
+        .run_for(Duration::from_secs(5));
+
+    for pump in CENTER_PUMPS {
+        assert!(test_bed.pump_is_pressurised(pump));
+    }
+}
+
+#[test]
+fn center_pumps_do_not_run_in_auto_mode_with_slats_extended() {
+    let test_bed = test_bed_with_fuel()
+        .slats_extended()
+        .run_for(Duration::from_secs(5));
+
+    for pump in CENTER_PUMPS {
+        assert!(!test_bed.pump_is_pressurised(pump));
+    }
+}
+
+#[test]
+fn center_pumps_run_for_two_minutes_after_engine_start() {
+    let test_bed = test_bed_with_fuel()
+        .with_tank_quantity("LEFT MAIN", 4000.)
+        .with_tank_quantity("RIGHT MAIN", 4000.)
+        .slats_extended()
+        .run_for(Duration::from_secs(5))
+        .start_engines()
+        .run_for(Duration::from_secs(100));
+
+    for pump in CENTER_PUMPS {
+        assert!(test_bed.pump_is_pressurised(pump));
+    }
+
+    let test_bed = test_bed.run_for(Duration::from_secs(30));
+
+    for pump in CENTER_PUMPS {
+        assert!(!test_bed.pump_is_pressurised(pump));
+    }
+}
+
+#[test]
+fn center_pumps_run_in_manual_mode_with_slats_extended() {
+    let test_bed = test_bed_with_fuel()
+        .slats_extended()
+        .mode_select_manual()
+        .run_for(Duration::from_secs(5));
+
+    for pump in CENTER_PUMPS {
+        assert!(test_bed.pump_is_pressurised(pump));
+    }
+}
+
+#[test]
+fn center_pump_stops_when_its_inner_tank_is_full() {
+    let test_bed = test_bed_with_fuel()
+        .with_tank_quantity("LEFT MAIN", 5600.)
+        .with_tank_quantity("RIGHT MAIN", 4000.)
+        .run_for(Duration::from_secs(5));
+
+    assert!(!test_bed.pump_is_pressurised(A320FuelPump::CenterLeft));
+    assert!(test_bed.pump_is_pressurised(A320FuelPump::CenterRight));
+
+    let test_bed = test_bed
+        .with_tank_quantity("LEFT MAIN", 5300.)
+        .run_for(Duration::from_secs(5));
+
+    assert!(!test_bed.pump_is_pressurised(A320FuelPump::CenterLeft));
+
+    let test_bed = test_bed
+        .with_tank_quantity("LEFT MAIN", 4900.)
+        .run_for(Duration::from_secs(5));
+
+    assert!(test_bed.pump_is_pressurised(A320FuelPump::CenterLeft));
+}
+
+#[test]
+fn center_pumps_shut_off_five_minutes_after_center_tank_low_level() {
+    let test_bed = test_bed_with_fuel()
+        .with_tank_quantity("LEFT MAIN", 4000.)
+        .with_tank_quantity("RIGHT MAIN", 4000.)
+        .with_tank_quantity("CENTER", 20.)
+        .run_for(Duration::from_secs(4 * MINUTES_TO_SECONDS));
+
+    for pump in CENTER_PUMPS {
+        assert!(test_bed.pump_is_pressurised(pump));
+    }
+
+    let test_bed = test_bed.run_for(Duration::from_secs(2 * MINUTES_TO_SECONDS));
+
+    for pump in CENTER_PUMPS {
+        assert!(!test_bed.pump_is_pressurised(pump));
+    }
+}
+
+#[test]
+fn crossfeed_valve_opens_when_switched_on() {
+    let test_bed = test_bed_with_fuel().run_for(Duration::from_secs(5));
+
+    assert!(!test_bed.valve_is_open(A320FuelValve::Crossfeed));
+
+    let test_bed = test_bed.crossfeed_on().run_for(Duration::from_secs(5));
+
+    assert!(test_bed.valve_is_open(A320FuelValve::Crossfeed));
+}
+
+#[test]
+fn jammed_crossfeed_valve_does_not_open() {
+    let mut test_bed = test_bed_with_fuel().crossfeed_on();
+    test_bed.fail(FailureType::FuelValveJammed(1));
+    let test_bed = test_bed.run_for(Duration::from_secs(5));
+
+    assert!(!test_bed.valve_is_open(A320FuelValve::Crossfeed));
+}
+
+#[test]
+fn transfer_valves_open_and_latch_when_an_inner_tank_reaches_low_level() {
+    let test_bed = test_bed_with_fuel()
+        .in_flight()
+        .run_for(Duration::from_secs(5));
+
+    assert!(!test_bed.valve_is_open(A320FuelValve::LeftTransfer));
+    assert!(!test_bed.valve_is_open(A320FuelValve::RightTransfer));
+
+    let test_bed = test_bed
+        .with_tank_quantity("RIGHT MAIN", 700.)
+        .run_for(Duration::from_secs(5));
+
+    assert!(test_bed.valve_is_open(A320FuelValve::LeftTransfer));
+    assert!(test_bed.valve_is_open(A320FuelValve::RightTransfer));
+
+    let test_bed = test_bed
+        .with_tank_quantity("RIGHT MAIN", 1200.)
+        .run_for(Duration::from_secs(5));
+
+    assert!(test_bed.valve_is_open(A320FuelValve::RightTransfer));
+}
+
+#[test]
+fn transfer_valves_close_after_refuelling_on_ground() {
+    let mut test_bed = test_bed_with_fuel()
+        .in_flight()
+        .with_tank_quantity("LEFT MAIN", 700.)
+        .run_for(Duration::from_secs(5));
+
+    assert!(test_bed.valve_is_open(A320FuelValve::LeftTransfer));
+
+    test_bed.set_on_ground(true);
+    let test_bed = test_bed
+        .with_tank_quantity("LEFT MAIN", 5000.)
+        .run_for(Duration::from_secs(5));
+
+    assert!(!test_bed.valve_is_open(A320FuelValve::LeftTransfer));
+}
+
+#[test]
+fn fqic_publishes_wing_tank_temperatures() {
+    let mut test_bed = test_bed_with_fuel().run_for(Duration::from_secs(5));
+
+    let expected = test_bed
+        .query(|a| a.fuel.tank_temperature(A320FuelTankType::LeftInner))
+        .get::<degree_celsius>();
+    let temperature: Arinc429Word<f64> =
+        test_bed.read_arinc429_by_name("FQIC_LEFT_INNER_TANK_TEMPERATURE");
+
+    assert!(temperature.is_normal_operation());
+    assert!((temperature.value() - expected).abs() < 0.01);
+}
+
+#[test]
+fn unpowered_fqic_publishes_failure_warning() {
+    let mut test_bed = test_bed_with_fuel();
+    test_bed.command(|a| a.is_powered = false);
+    let mut test_bed = test_bed.run_for(Duration::from_secs(5));
+
+    let temperature: Arinc429Word<f64> =
+        test_bed.read_arinc429_by_name("FQIC_LEFT_INNER_TANK_TEMPERATURE");
+    let low_fuel_temperature: Arinc429Word<f64> =
+        test_bed.read_arinc429_by_name("FQIC_LOW_FUEL_TEMPERATURE_WORD");
+
+    assert!(temperature.is_failure_warning());
+    assert!(low_fuel_temperature.is_failure_warning());
+}
@@ -10,9 +10,12 @@ use uom::si::{
     f64::*,
     length::{inch, meter},
     mass::kilogram,
+    moment_of_inertia::kilogram_square_meter,
     pressure::psi,
     ratio::{percent, ratio},
+    thermal_conductance::watt_per_kelvin,
     thermodynamic_temperature::degree_celsius,
+    torque::newton_meter,
     velocity::knot,
     volume::{cubic_inch, gallon, liter},
     volume_rate::gallon_per_second,
@@ -23,6 +26,7 @@ use systems::{
     engine::Engine,
     hydraulic::{
         aerodynamic_model::AerodynamicModel,
+        anti_skid::{AntiSkidController, BrakedWheel, WheelProperties},
         brake::{BrakeAssembly, BrakeFanPanel, BrakeProperties},
         brake_circuit::{
             AutobrakeDecelerationGovernor, AutobrakeMode, AutobrakePanel,
@@ -58,7 +62,10 @@ use systems::{
         PowerTransferUnitCharacteristics, PowerTransferUnitController, PressureSwitch,
         PressureSwitchType, PriorityValve, PumpController, RamAirTurbine, Reservoir,
     },
-    landing_gear::{GearSystemSensors, LandingGearControlInterfaceUnitSet},
+    landing_gear::{
+        GearSystemSensors, LandingGearControlInterfaceUnitSet, TyrePressureIndicationSystem,
+        TyreProperties,
+    },
     overhead::{
         AutoOffFaultPushButton, AutoOnFaultPushButton, MomentaryOnPushButton, MomentaryPushButton,
     },
@@ -78,6 +85,8 @@ use systems::{
     },
 };
 
+use crate::fuel::{A320FuelTankType, FuelTankTemperature};
+
 mod sfcc;
 use sfcc::SlatFlapComplex;
 
@@ -164,6 +173,8 @@ impl A320HydraulicCircuitFactory {
     const ACCUMULATOR_GAS_PRE_CHARGE_PSI: f64 = 1885.0;
     const ACCUMULATOR_MAX_VOLUME_GALLONS: f64 = 0.264;
 
+    const FUEL_HEAT_EXCHANGER_CONDUCTANCE_WATT_PER_KELVIN: f64 = 60.;
+
     pub fn new_green_circuit(context: &mut InitContext) -> HydraulicCircuit {
         let reservoir = A320HydraulicReservoirFactory::new_green_reservoir(context);
         HydraulicCircuit::new(
@@ -187,6 +198,9 @@ impl A320HydraulicCircuitFactory {
             ),
             Pressure::new::<psi>(Self::ACCUMULATOR_GAS_PRE_CHARGE_PSI),
             Volume::new::<gallon>(Self::ACCUMULATOR_MAX_VOLUME_GALLONS),
+            ThermalConductance::new::<watt_per_kelvin>(
+                Self::FUEL_HEAT_EXCHANGER_CONDUCTANCE_WATT_PER_KELVIN,
+            ),
         )
     }
 
@@ -213,6 +227,9 @@ impl A320HydraulicCircuitFactory {
             ),
             Pressure::new::<psi>(Self::ACCUMULATOR_GAS_PRE_CHARGE_PSI),
             Volume::new::<gallon>(Self::ACCUMULATOR_MAX_VOLUME_GALLONS),
+            ThermalConductance::new::<watt_per_kelvin>(
+                Self::FUEL_HEAT_EXCHANGER_CONDUCTANCE_WATT_PER_KELVIN,
+            ),
         )
     }
 
@@ -245,6 +262,9 @@ impl A320HydraulicCircuitFactory {
             ),
             Pressure::new::<psi>(Self::ACCUMULATOR_GAS_PRE_CHARGE_PSI),
             Volume::new::<gallon>(Self::ACCUMULATOR_MAX_VOLUME_GALLONS),
+            ThermalConductance::new::<watt_per_kelvin>(
+                Self::FUEL_HEAT_EXCHANGER_CONDUCTANCE_WATT_PER_KELVIN,
+            ),
         )
     }
 }
@@ -1553,6 +1573,11 @@ pub(super) struct A320Hydraulic {
     brake_properties: BrakeProperties,
     left_brake_assembly: BrakeAssembly<2>,
     right_brake_assembly: BrakeAssembly<2>,
+    tyre_properties: TyreProperties,
+    tyre_pressure_indication_system: TyrePressureIndicationSystem<4>,
+    wheel_properties: WheelProperties,
+    left_braked_wheels: BrakedWheel,
+    right_braked_wheels: BrakedWheel,
     // The relays for enabling the brake fans are powered by DC2
     dc2_powered: bool,
 
@@ -1612,6 +1637,8 @@ impl A320Hydraulic {
 
     const ALTERNATE_BRAKE_ACCUMULATOR_GAS_PRE_CHARGE: f64 = 1000.0; // Nitrogen PSI
 
+    const MAIN_TYRE_INFLATION_PRESSURE_PSI: f64 = 200.;
+
     // Refresh rate of core hydraulic simulation
     const HYDRAULIC_SIM_TIME_STEP: Duration = Duration::from_millis(10);
 
@@ -1852,6 +1879,26 @@ impl A320Hydraulic {
                 [ElectricalBusType::DirectCurrent(1); 2],
                 Some(ElectricalBusType::AlternatingCurrent(2)),
             ),
+            tyre_properties: TyreProperties::new(
+                Length::new::<inch>(46.) / 2.,
+                Mass::new::<kilogram>(120.),
+                Pressure::new::<psi>(Self::MAIN_TYRE_INFLATION_PRESSURE_PSI),
+            ),
+            tyre_pressure_indication_system: TyrePressureIndicationSystem::new(
+                context,
+                ElectricalBusType::DirectCurrent(1),
+                [1, 2, 3, 4],
+                Pressure::new::<psi>(Self::MAIN_TYRE_INFLATION_PRESSURE_PSI),
+            ),
+            wheel_properties: WheelProperties::new(
+                Length::new::<inch>(46.) / 2.,
+                MomentOfInertia::new::<kilogram_square_meter>(35.),
+                Torque::new::<newton_meter>(90000.),
+                Pressure::new::<psi>(3000.),
+                Ratio::new::<ratio>(0.46),
+            ),
+            left_braked_wheels: BrakedWheel::new(context, "LEFT"),
+            right_braked_wheels: BrakedWheel::new(context, "RIGHT"),
             dc2_powered: false,
 
             ptu_high_pitch_sound_active: DelayedFalseLogicGate::new(
@@ -1893,6 +1940,7 @@ impl A320Hydraulic {
         emergency_elec: &(impl EmergencyElectricalState + EmergencyGeneratorPower),
         reservoir_pneumatics: &impl ReservoirAirPressure,
         adirs: &(impl AdirsDiscreteOutputs + AdirsMeasurementOutputs),
+        fuel: &impl FuelTankTemperature,
     ) {
         self.core_hydraulic_updater.update(context);
 
@@ -1928,6 +1976,7 @@ impl A320Hydraulic {
                 lgcius.lgciu1(),
                 lgcius.lgciu2(),
                 reservoir_pneumatics,
+                fuel,
             );
         }
 
@@ -2216,18 +2265,30 @@ impl A320Hydraulic {
             brake_assembly.update(
                 context,
                 &self.brake_properties,
+                &self.tyre_properties,
                 braking_pressure_norm.max(braking_pressure_altn),
                 brake_fan_turned_on,
                 gear_position.get::<ratio>() > 0.25,
             );
         }
 
+        self.tyre_pressure_indication_system.update(
+            self.left_brake_assembly
+                .tyres()
+                .iter()
+                .chain(self.right_brake_assembly.tyres()),
+        );
+
         self.braking_force.update_forces(
             context,
             &self.braking_circuit_norm,
             &self.braking_circuit_altn,
             engine1,
             engine2,
+            self.left_brake_assembly.braking_efficiency()
+                * self.left_braked_wheels.braking_efficiency(),
+            self.right_brake_assembly.braking_efficiency()
+                * self.right_braked_wheels.braking_efficiency(),
         );
 
         self.slats_flaps_complex.update(
@@ -2426,6 +2487,7 @@ impl A320Hydraulic {
         lgciu1: &impl LgciuInterface,
         lgciu2: &impl LgciuInterface,
         reservoir_pneumatics: &impl ReservoirAirPressure,
+        fuel: &impl FuelTankTemperature,
     ) {
         // First update what is currently consumed and given back by each actuator
         // Todo: might have to split the actuator volumes by expected number of loops
@@ -2539,6 +2601,7 @@ impl A320Hydraulic {
             Some(&self.power_transfer_unit),
             &self.green_circuit_controller,
             reservoir_pneumatics.green_reservoir_pressure(),
+            fuel.tank_temperature(A320FuelTankType::LeftInner),
         );
 
         self.yellow_circuit_controller.update(
@@ -2555,6 +2618,7 @@ impl A320Hydraulic {
             Some(&self.power_transfer_unit),
             &self.yellow_circuit_controller,
             reservoir_pneumatics.yellow_reservoir_pressure(),
+            fuel.tank_temperature(A320FuelTankType::RightInner),
         );
 
         self.blue_circuit_controller.update(
@@ -2571,6 +2635,17 @@ impl A320Hydraulic {
             None,
             &self.blue_circuit_controller,
             reservoir_pneumatics.blue_reservoir_pressure(),
+            fuel.tank_temperature(A320FuelTankType::LeftInner),
+        );
+
+        self.brake_steer_computer.update_anti_skid(
+            context,
+            self.left_braked_wheels
+                .rolling_speed(&self.wheel_properties),
+            self.right_braked_wheels
+                .rolling_speed(&self.wheel_properties),
+            lgciu1,
+            lgciu2,
         );
 
         self.braking_circuit_norm.update(
@@ -2584,6 +2659,28 @@ impl A320Hydraulic {
             self.brake_steer_computer.alternate_controller(),
         );
 
+        for (braked_wheels, braking_pressure_norm, braking_pressure_altn, is_compressed) in [
+            (
+                &mut self.left_braked_wheels,
+                self.braking_circuit_norm.left_brake_pressure(),
+                self.braking_circuit_altn.left_brake_pressure(),
+                lgciu1.left_gear_compressed(false) || lgciu2.left_gear_compressed(false),
+            ),
+            (
+                &mut self.right_braked_wheels,
+                self.braking_circuit_norm.right_brake_pressure(),
+                self.braking_circuit_altn.right_brake_pressure(),
+                lgciu1.right_gear_compressed(false) || lgciu2.right_gear_compressed(false),
+            ),
+        ] {
+            braked_wheels.update(
+                context,
+                &self.wheel_properties,
+                braking_pressure_norm.max(braking_pressure_altn),
+                is_compressed,
+            );
+        }
+
         // TODO CHECK LGCIU USAGE for reversers
         self.engine_reverser_control[0].update(
             context,
@@ -2728,6 +2825,9 @@ impl SimulationElement for A320Hydraulic {
         self.gear_system.accept(visitor);
         self.left_brake_assembly.accept(visitor);
         self.right_brake_assembly.accept(visitor);
+        self.tyre_pressure_indication_system.accept(visitor);
+        self.left_braked_wheels.accept(visitor);
+        self.right_braked_wheels.accept(visitor);
 
         self.trim_controller.accept(visitor);
         self.trim_assembly.accept(visitor);
@@ -3614,6 +3714,8 @@ impl SimulationElement for A320RamAirTurbineController {
 struct A320BrakeSystemOutputs {
     left_demand: Ratio,
     right_demand: Ratio,
+    left_anti_skid_ratio: Ratio,
+    right_anti_skid_ratio: Ratio,
     pressure_limit: Pressure,
 }
 impl A320BrakeSystemOutputs {
@@ -3621,10 +3723,18 @@ impl A320BrakeSystemOutputs {
         Self {
             left_demand: Ratio::new::<ratio>(0.),
             right_demand: Ratio::new::<ratio>(0.),
+            left_anti_skid_ratio: Ratio::new::<ratio>(1.),
+            right_anti_skid_ratio: Ratio::new::<ratio>(1.),
             pressure_limit: Pressure::new::<psi>(3000.),
         }
     }
 
+    /// Anti skid modulation of the demands, 1 meaning no pressure release
+    fn set_anti_skid_ratios(&mut self, left_ratio: Ratio, right_ratio: Ratio) {
+        self.left_anti_skid_ratio = left_ratio;
+        self.right_anti_skid_ratio = right_ratio;
+    }
+
     fn set_pressure_limit(&mut self, pressure_limit: Pressure) {
         self.pressure_limit = pressure_limit;
     }
@@ -3662,11 +3772,11 @@ impl BrakeCircuitController for A320BrakeSystemOutputs {
     }
 
     fn left_brake_demand(&self) -> Ratio {
-        self.left_demand
+        self.left_demand * self.left_anti_skid_ratio
     }
 
     fn right_brake_demand(&self) -> Ratio {
-        self.right_demand
+        self.right_demand * self.right_anti_skid_ratio
     }
 }
 
@@ -3700,6 +3810,9 @@ struct A320HydraulicBrakeSteerComputerUnit {
     normal_brakes_available: bool,
     should_disable_auto_brake_when_retracting: DelayedTrueLogicGate,
     anti_skid_activated: bool,
+    anti_skid_modulates_brakes: bool,
+    left_anti_skid: AntiSkidController,
+    right_anti_skid: AntiSkidController,
 
     tiller_pedal_disconnect: bool,
     tiller_handle_position: Ratio,
@@ -3780,6 +3893,9 @@ impl A320HydraulicBrakeSteerComputerUnit {
                 Duration::from_secs_f64(Self::AUTOBRAKE_GEAR_RETRACTION_DURATION_S),
             ),
             anti_skid_activated: true,
+            anti_skid_modulates_brakes: false,
+            left_anti_skid: AntiSkidController::new(),
+            right_anti_skid: AntiSkidController::new(),
 
             tiller_pedal_disconnect: false,
             tiller_handle_position: Ratio::new::<ratio>(0.),
@@ -3902,6 +4018,9 @@ impl A320HydraulicBrakeSteerComputerUnit {
         self.should_disable_auto_brake_when_retracting
             .update(context, is_in_flight_gear_lever_up);
 
+        self.anti_skid_modulates_brakes =
+            !is_in_flight_gear_lever_up && self.anti_skid_activated && !self.parking_brake_demand;
+
         if is_in_flight_gear_lever_up {
             if self.should_disable_auto_brake_when_retracting.output() {
                 self.norm_brake_outputs.set_no_demands();
@@ -3956,6 +4075,47 @@ impl A320HydraulicBrakeSteerComputerUnit {
         }
     }
 
+    /// Updates anti skid channels from wheel speeds. Needs to run at hydraulic fixed step rate
+    /// to be able to catch a skidding wheel before it locks.
+    fn update_anti_skid(
+        &mut self,
+        context: &UpdateContext,
+        left_wheel_speed: Velocity,
+        right_wheel_speed: Velocity,
+        lgciu1: &impl LgciuInterface,
+        lgciu2: &impl LgciuInterface,
+    ) {
+        // TODO Reference speed would be computed from wheel speeds and ADIRS acceleration
+        let reference_speed = context.ground_speed();
+
+        self.left_anti_skid.update(
+            context,
+            reference_speed,
+            left_wheel_speed,
+            lgciu1.left_gear_compressed(false) || lgciu2.left_gear_compressed(false),
+        );
+        self.right_anti_skid.update(
+            context,
+            reference_speed,
+            right_wheel_speed,
+            lgciu1.right_gear_compressed(false) || lgciu2.right_gear_compressed(false),
+        );
+
+        let (left_ratio, right_ratio) = if self.anti_skid_modulates_brakes {
+            (
+                self.left_anti_skid.pressure_ratio(),
+                self.right_anti_skid.pressure_ratio(),
+            )
+        } else {
+            (Ratio::new::<ratio>(1.), Ratio::new::<ratio>(1.))
+        };
+
+        self.norm_brake_outputs
+            .set_anti_skid_ratios(left_ratio, right_ratio);
+        self.alternate_brake_outputs
+            .set_anti_skid_ratios(left_ratio, right_ratio);
+    }
+
     fn update_steering_demands(
         &mut self,
         context: &UpdateContext,
@@ -4028,6 +4188,8 @@ impl A320HydraulicBrakeSteerComputerUnit {
 impl SimulationElement for A320HydraulicBrakeSteerComputerUnit {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.autobrake_controller.accept(visitor);
+        self.left_anti_skid.accept(visitor);
+        self.right_anti_skid.accept(visitor);
         visitor.visit(self);
     }
 
@@ -4133,6 +4295,8 @@ impl A320BrakingForce {
         altn_brakes: &BrakeCircuit,
         engine1: &impl Engine,
         engine2: &impl Engine,
+        left_tyres_braking_efficiency: Ratio,
+        right_tyres_braking_efficiency: Ratio,
     ) {
         // Base formula for output force is output_force[0:1] = 50 * sqrt(current_pressure) / Max_brake_pressure
         // This formula gives a bit more punch for lower brake pressures (like 1000 psi alternate braking), as linear formula
@@ -4152,11 +4316,27 @@ impl A320BrakingForce {
         self.right_braking_force = right_force_norm + right_force_altn;
         self.right_braking_force = self.right_braking_force.clamp(0., 1.);
 
+        // Deflated or burst tyres cannot transfer the full brake force to the ground
+        self.left_braking_force *= left_tyres_braking_efficiency.get::<ratio>();
+        self.right_braking_force *= right_tyres_braking_efficiency.get::<ratio>();
+
+        self.limit_with_runway_condition(context);
+
         self.correct_with_flaps_state(context);
 
         self.update_chocks_braking(context, engine1, engine2);
     }
 
+    fn limit_with_runway_condition(&mut self, context: &UpdateContext) {
+        let max_braking_force = context
+            .runway_condition()
+            .max_braking_force(context.ground_speed())
+            .get::<ratio>();
+
+        self.left_braking_force = self.left_braking_force.min(max_braking_force);
+        self.right_braking_force = self.right_braking_force.min(max_braking_force);
+    }
+
     fn correct_with_flaps_state(&mut self, context: &UpdateContext) {
         let flap_correction = Ratio::new::<percent>(interpolation(
             &Self::FLAPS_BREAKPOINTS,
@@ -6305,6 +6485,25 @@ mod tests {
             }
         }
 
+        #[derive(Default)]
+        struct A320TestFuel {
+            temperature: ThermodynamicTemperature,
+        }
+        impl A320TestFuel {
+            fn update(&mut self, context: &UpdateContext) {
+                self.temperature = context.ambient_temperature();
+            }
+        }
+        impl FuelTankTemperature for A320TestFuel {
+            fn tank_temperature(&self, _tank: A320FuelTankType) -> ThermodynamicTemperature {
+                self.temperature
+            }
+
+            fn tank_temperature_is_low(&self, _tank: A320FuelTankType) -> bool {
+                false
+            }
+        }
+
         struct A320TestPneumatics {
             pressure: Pressure,
         }
@@ -6378,6 +6577,7 @@ mod tests {
         }
         struct A320HydraulicsTestAircraft {
             pneumatics: A320TestPneumatics,
+            fuel: A320TestFuel,
             engine_1: LeapEngine,
             engine_2: LeapEngine,
             hydraulics: A320Hydraulic,
@@ -6418,6 +6618,7 @@ mod tests {
             fn new(context: &mut InitContext) -> Self {
                 Self {
                     pneumatics: A320TestPneumatics::new(),
+                    fuel: A320TestFuel::default(),
                     engine_1: LeapEngine::new(context, 1),
                     engine_2: LeapEngine::new(context, 2),
                     hydraulics: A320Hydraulic::new(context),
@@ -6669,6 +6870,7 @@ mod tests {
                 self.electrical.update(&self.hydraulics.gcu, context);
 
                 self.adirus.update(context);
+                self.fuel.update(context);
 
                 self.lgcius.update(
                     context,
@@ -6690,6 +6892,7 @@ mod tests {
                     &self.electrical,
                     &self.pneumatics,
                     &self.adirus,
+                    &self.fuel,
                 );
 
                 self.overhead.update(&self.hydraulics);
@@ -6845,6 +7048,10 @@ mod tests {
                 self.read_by_name("HYD_GREEN_RESERVOIR_OVHT")
             }
 
+            fn green_reservoir_temperature(&mut self) -> ThermodynamicTemperature {
+                self.read_by_name("HYD_GREEN_RESERVOIR_TEMPERATURE")
+            }
+
             fn ptu_has_fault(&mut self) -> bool {
                 self.read_by_name("OVHD_HYD_PTU_PB_HAS_FAULT")
             }
@@ -7080,6 +7287,11 @@ mod tests {
                     .external_power(false)
             }
 
+            fn with_ambient_temperature(mut self, temperature: ThermodynamicTemperature) -> Self {
+                self.set_ambient_temperature(temperature);
+                self
+            }
+
             fn sim_not_ready(mut self) -> Self {
                 self.set_sim_is_ready(false);
                 self
@@ -7240,6 +7452,11 @@ mod tests {
                 self
             }
 
+            fn set_ground_speed(mut self, speed: Velocity) -> Self {
+                self.write_by_name("GPS GROUND SPEED", speed);
+                self
+            }
+
             fn set_anti_skid(mut self, is_set: bool) -> Self {
                 self.write_by_name("ANTISKID BRAKES ACTIVE", is_set);
                 self
@@ -8091,6 +8308,26 @@ mod tests {
             assert_lt!(test_bed.yellow_pressure(), Pressure::new::<psi>(50.));
         }
 
+        #[test]
+        fn green_edp_pressure_is_lost_on_engine_1_flameout() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(test_bed.is_green_pressure_switch_pressurised());
+
+            test_bed.fail(FailureType::EngineFlameout(1));
+            test_bed = test_bed
+                .start_eng1(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs(20));
+
+            assert!(!test_bed.is_green_pressure_switch_pressurised());
+            assert_lt!(test_bed.green_pressure(), Pressure::new::<psi>(500.));
+        }
+
         #[test]
         fn green_edp_no_fault_on_ground_eng_off() {
             let mut test_bed = test_bed_on_ground_with()
@@ -9625,6 +9862,31 @@ mod tests {
             );
         }
 
+        #[test]
+        fn brakes_released_by_touchdown_protection_in_flight_gear_down() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_ground_speed(Velocity::new::<knot>(140.))
+                .set_gear_lever_up()
+                .run_waiting_for(Duration::from_secs(10));
+
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .set_gear_lever_down()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert_lt!(
+                test_bed.get_brake_left_green_pressure(),
+                Pressure::new::<psi>(50.)
+            );
+            assert_lt!(
+                test_bed.get_brake_right_green_pressure(),
+                Pressure::new::<psi>(50.)
+            );
+        }
+
         #[test]
         fn brakes_norm_active_in_flight_gear_down() {
             let mut test_bed = test_bed_on_ground_with()
@@ -12051,6 +12313,48 @@ mod tests {
             assert_eq!(test_bed.gear_system_state(), GearSystemState::AllUpLocked);
         }
 
+        #[test]
+        fn cold_soaked_hydraulic_fluid_slows_down_gear_retraction() {
+            let mut test_bed = test_bed_on_ground_with()
+                .with_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-55.))
+                .set_cold_dark_inputs()
+                .in_flight();
+
+            assert_eq!(test_bed.gear_system_state(), GearSystemState::AllDownLocked);
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(25.));
+            assert_eq!(test_bed.gear_system_state(), GearSystemState::Retracting);
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(20.));
+            assert_eq!(test_bed.gear_system_state(), GearSystemState::AllUpLocked);
+        }
+
+        #[test]
+        fn hydraulic_fluid_warms_up_without_overheating_in_normal_operation() {
+            let mut test_bed = test_bed_in_flight_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            let initial_temperature = test_bed.green_reservoir_temperature();
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(60. * 20.));
+
+            assert!(
+                test_bed
+                    .green_reservoir_temperature()
+                    .get::<degree_celsius>()
+                    > initial_temperature.get::<degree_celsius>() + 5.
+            );
+            assert!(
+                test_bed
+                    .green_reservoir_temperature()
+                    .get::<degree_celsius>()
+                    < 60.
+            );
+            assert!(!test_bed.green_reservoir_has_overheat_fault());
+        }
+
         #[test]
         fn gear_retracts_using_yellow_epump_plus_ptu() {
             let mut test_bed = test_bed_on_ground_with()
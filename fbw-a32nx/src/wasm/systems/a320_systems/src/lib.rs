@@ -13,7 +13,7 @@ mod surveillance;
 
 use self::{
     air_conditioning::A320AirConditioning,
-    fuel::A320Fuel,
+    fuel::{A320Fuel, A320FuelOverheadPanel},
     payload::A320Payload,
     pneumatic::{A320Pneumatic, A320PneumaticOverheadPanel},
     surveillance::A320EgpwsElectricalHarness,
@@ -42,14 +42,19 @@ use systems::{
         AuxiliaryPowerUnitOverheadPanel,
     },
     electrical::{Electricity, ElectricitySource, ExternalPowerSource},
-    engine::{leap_engine::LeapEngine, reverser_thrust::ReverserForce, EngineFireOverheadPanel},
+    engine::{
+        leap_engine::LeapEngine, reverser_thrust::ReverserForce,
+        vibration_monitoring::EngineVibrationMonitoringUnit, EngineFireOverheadPanel,
+    },
     hydraulic::brake_circuit::AutobrakePanel,
     landing_gear::{LandingGear, LandingGearControlInterfaceUnitSet},
     navigation::adirs::{
         AirDataInertialReferenceSystem, AirDataInertialReferenceSystemOverheadPanel,
     },
     shared::ElectricalBusType,
-    simulation::{Aircraft, SimulationElement, SimulationElementVisitor, UpdateContext},
+    simulation::{
+        profile_section, Aircraft, SimulationElement, SimulationElementVisitor, UpdateContext,
+    },
 };
 
 pub struct A320 {
@@ -66,9 +71,11 @@ pub struct A320 {
     payload: A320Payload,
     airframe: A320Airframe,
     fuel: A320Fuel,
+    fuel_overhead: A320FuelOverheadPanel,
     engine_1: LeapEngine,
     engine_2: LeapEngine,
     engine_fire_overhead: EngineFireOverheadPanel<2>,
+    engine_vibration_monitoring: EngineVibrationMonitoringUnit<2>,
     electrical: A320Electrical,
     power_consumption: A320PowerConsumption,
     ext_pwr: ExternalPowerSource,
@@ -108,9 +115,16 @@ impl A320 {
             payload: A320Payload::new(context),
             airframe: A320Airframe::new(context),
             fuel: A320Fuel::new(context),
+            fuel_overhead: A320FuelOverheadPanel::new(context),
             engine_1: LeapEngine::new(context, 1),
             engine_2: LeapEngine::new(context, 2),
             engine_fire_overhead: EngineFireOverheadPanel::new(context),
+            engine_vibration_monitoring: EngineVibrationMonitoringUnit::new(
+                context,
+                ElectricalBusType::AlternatingCurrent(1),
+                6.,
+                4.3,
+            ),
             electrical: A320Electrical::new(context),
             power_consumption: A320PowerConsumption::new(context),
             ext_pwr: ExternalPowerSource::new(context, 1),
@@ -155,6 +169,9 @@ impl Aircraft for A320 {
         context: &UpdateContext,
         electricity: &mut Electricity,
     ) {
+        self.engine_1.update(context, &self.pneumatic);
+        self.engine_2.update(context, &self.pneumatic);
+
         self.apu.update_before_electrical(
             context,
             &self.apu_overhead,
@@ -171,20 +188,22 @@ impl Aircraft for A320 {
             self.fuel.left_inner_tank_has_fuel_remaining(),
         );
 
-        self.electrical.update(
-            context,
-            electricity,
-            &self.ext_pwr,
-            &self.electrical_overhead,
-            &self.emergency_electrical_overhead,
-            &mut self.apu,
-            &self.apu_overhead,
-            &self.engine_fire_overhead,
-            [&self.engine_1, &self.engine_2],
-            &self.hydraulic,
-            self.lgcius.lgciu1(),
-            &self.adirs,
-        );
+        profile_section("electrical", || {
+            self.electrical.update(
+                context,
+                electricity,
+                &self.ext_pwr,
+                &self.electrical_overhead,
+                &self.emergency_electrical_overhead,
+                &mut self.apu,
+                &self.apu_overhead,
+                &self.engine_fire_overhead,
+                [&self.engine_1, &self.engine_2],
+                &self.hydraulic,
+                self.lgcius.lgciu1(),
+                &self.adirs,
+            )
+        });
 
         self.electrical_overhead
             .update_after_electrical(&self.electrical, electricity, &self.apu);
@@ -213,20 +232,23 @@ impl Aircraft for A320 {
 
         self.radio_altimeters.update(context);
 
-        self.hydraulic.update(
-            context,
-            &self.engine_1,
-            &self.engine_2,
-            &self.hydraulic_overhead,
-            &self.autobrake_panel,
-            &self.brake_fan_panel,
-            &self.engine_fire_overhead,
-            &self.lgcius,
-            &self.emergency_electrical_overhead,
-            &self.electrical,
-            &self.pneumatic,
-            &self.adirs,
-        );
+        profile_section("hydraulic", || {
+            self.hydraulic.update(
+                context,
+                &self.engine_1,
+                &self.engine_2,
+                &self.hydraulic_overhead,
+                &self.autobrake_panel,
+                &self.brake_fan_panel,
+                &self.engine_fire_overhead,
+                &self.lgcius,
+                &self.emergency_electrical_overhead,
+                &self.electrical,
+                &self.pneumatic,
+                &self.adirs,
+                &self.fuel,
+            )
+        });
 
         self.reverse_thrust.update(
             context,
@@ -234,6 +256,14 @@ impl Aircraft for A320 {
             self.hydraulic.reversers_position(),
         );
 
+        self.fuel.update(
+            context,
+            &self.fuel_overhead,
+            [&self.engine_1, &self.engine_2],
+            [self.engine_1.fuel_flow(), self.engine_2.fuel_flow()],
+            self.apu.fuel_flow(),
+        );
+
         self.pneumatic.update_hydraulic_reservoir_spatial_volumes(
             self.hydraulic.green_reservoir(),
             self.hydraulic.blue_reservoir(),
@@ -243,31 +273,45 @@ impl Aircraft for A320 {
         self.hydraulic_overhead.update(&self.hydraulic);
         self.brake_fan_panel.update(self.hydraulic.brakes_hot());
 
-        self.adirs.update(context, &self.adirs_overhead);
+        profile_section("adirs", || self.adirs.update(context, &self.adirs_overhead));
         self.adirs_overhead.update(context, &self.adirs);
 
         self.power_consumption.update(context);
 
-        self.pneumatic.update(
-            context,
-            [&self.engine_1, &self.engine_2],
-            &self.pneumatic_overhead,
-            &self.engine_fire_overhead,
-            &self.apu,
-            &self.asu,
-            &self.air_conditioning,
-            [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
-        );
+        profile_section("pneumatic", || {
+            self.pneumatic.update(
+                context,
+                [&self.engine_1, &self.engine_2],
+                &self.pneumatic_overhead,
+                &self.engine_fire_overhead,
+                &self.apu,
+                &self.asu,
+                &self.air_conditioning,
+                [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
+            )
+        });
         self.air_conditioning
             .mix_packs_air_update(self.pneumatic.packs());
-        self.air_conditioning.update(
+        profile_section("air_conditioning", || {
+            self.air_conditioning.update(
+                context,
+                &self.adirs,
+                [&self.engine_1, &self.engine_2],
+                &self.engine_fire_overhead,
+                &self.payload,
+                &self.pneumatic,
+                [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
+            )
+        });
+
+        self.electrical.update_battery_surrounding_temperature(
+            self.air_conditioning.avionics_compartment_temperature(),
+        );
+
+        self.engine_vibration_monitoring.update(
             context,
-            &self.adirs,
             [&self.engine_1, &self.engine_2],
-            &self.engine_fire_overhead,
-            &self.payload,
-            &self.pneumatic,
-            [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
+            [Length::default(); 2],
         );
 
         self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
@@ -297,10 +341,12 @@ impl SimulationElement for A320 {
         self.electrical_overhead.accept(visitor);
         self.emergency_electrical_overhead.accept(visitor);
         self.fuel.accept(visitor);
+        self.fuel_overhead.accept(visitor);
         self.pneumatic_overhead.accept(visitor);
         self.engine_1.accept(visitor);
         self.engine_2.accept(visitor);
         self.engine_fire_overhead.accept(visitor);
+        self.engine_vibration_monitoring.accept(visitor);
         self.electrical.accept(visitor);
         self.power_consumption.accept(visitor);
         self.ext_pwr.accept(visitor);
@@ -4,7 +4,7 @@ use uom::si::{
     f64::*,
     length::foot,
     pressure::psi,
-    ratio::{percent, ratio},
+    ratio::ratio,
     thermodynamic_temperature::degree_celsius,
     velocity::foot_per_minute,
     volume::{cubic_meter, gallon},
@@ -13,6 +13,7 @@ use uom::si::{
 use systems::{
     accept_iterable,
     air_conditioning::PackFlowControllers,
+    engine::{fadec::FullAuthorityDigitalEngineControl, Engine},
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
         valve::*, BleedMonitoringComputerChannelOperationMode,
@@ -27,13 +28,13 @@ use systems::{
     shared::{
         pid::PidController, update_iterator::MaxStepLoop, ControllerSignal, DelayedTrueLogicGate,
         ElectricalBusType, ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1,
-        EngineCorrectedN2, EngineFirePushButtons, EngineStartState, HydraulicColor,
-        LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed, PneumaticValve,
+        EngineCorrectedN2, EngineFirePushButtons, EngineStartControl, EngineStartState,
+        HydraulicColor, LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed, PneumaticValve,
         ReservoirAirPressure,
     },
     simulation::{
-        InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
     },
     valve_signal_implementation,
 };
@@ -100,7 +101,7 @@ pub struct A320Pneumatic {
 
     cross_bleed_valve: CrossBleedValve,
 
-    fadec: FullAuthorityDigitalEngineControl,
+    fadecs: [FullAuthorityDigitalEngineControl; 2],
     engine_starter_valve_controllers: [EngineStarterValveController; 2],
 
     apu_compression_chamber: CompressionChamber,
@@ -155,10 +156,20 @@ impl A320Pneumatic {
                 EngineBleedAirSystem::new(context, 2, ElectricalBusType::DirectCurrent(2)),
             ],
             cross_bleed_valve: CrossBleedValve::new(Ratio::new::<ratio>(0.4)),
-            fadec: FullAuthorityDigitalEngineControl::new(context),
+            fadecs: [1, 2].map(|number| {
+                FullAuthorityDigitalEngineControl::new(
+                    context,
+                    number,
+                    [
+                        ElectricalBusType::DirectCurrentEssential,
+                        ElectricalBusType::DirectCurrent(number as u8),
+                    ],
+                    ThermodynamicTemperature::new::<degree_celsius>(750.),
+                )
+            }),
             engine_starter_valve_controllers: [
-                EngineStarterValveController::new(1),
-                EngineStarterValveController::new(2),
+                EngineStarterValveController::new(),
+                EngineStarterValveController::new(),
             ],
             apu_compression_chamber: CompressionChamber::new(Volume::new::<cubic_meter>(5.)),
             apu_bleed_air_valve: DefaultValve::new_closed(),
@@ -220,7 +231,7 @@ impl A320Pneumatic {
     pub(crate) fn update(
         &mut self,
         context: &UpdateContext,
-        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2); 2],
+        engines: [&impl Engine; 2],
         overhead_panel: &A320PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         apu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
@@ -228,6 +239,10 @@ impl A320Pneumatic {
         pack_flow_valve_signals: &impl PackFlowControllers,
         lgciu: [&impl LgciuWeightOnWheels; 2],
     ) {
+        for (fadec, engine) in self.fadecs.iter_mut().zip(engines) {
+            fadec.update(context, engine);
+        }
+
         self.physics_updater.update(context);
 
         for cur_time_step in self.physics_updater {
@@ -247,7 +262,7 @@ impl A320Pneumatic {
     pub(crate) fn update_physics(
         &mut self,
         context: &UpdateContext,
-        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2); 2],
+        engines: [&impl Engine; 2],
         overhead_panel: &A320PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         apu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
@@ -266,7 +281,7 @@ impl A320Pneumatic {
                 overhead_panel,
                 engine_fire_push_buttons,
                 &self.cross_bleed_valve,
-                &self.fadec,
+                &self.fadecs,
                 &self.wing_anti_ice,
             );
 
@@ -280,8 +295,12 @@ impl A320Pneumatic {
         bmc_one.check_for_failure(bmc_two);
         bmc_two.check_for_failure(bmc_one);
 
-        for controller in self.engine_starter_valve_controllers.iter_mut() {
-            controller.update(&self.fadec);
+        for (controller, fadec) in self
+            .engine_starter_valve_controllers
+            .iter_mut()
+            .zip(&self.fadecs)
+        {
+            controller.update(fadec);
         }
 
         for (engine_system, hydraulic_valve) in self
@@ -387,10 +406,18 @@ impl PneumaticBleed for A320Pneumatic {
 }
 impl EngineStartState for A320Pneumatic {
     fn engine_state(&self, engine_number: usize) -> EngineState {
-        self.fadec.engine_state(engine_number)
+        self.fadecs[engine_number - 1].engine_state()
     }
     fn engine_mode_selector(&self) -> EngineModeSelector {
-        self.fadec.engine_mode_selector()
+        self.fadecs[0].engine_mode_selector()
+    }
+}
+impl EngineStartControl for A320Pneumatic {
+    fn fuel_is_commanded(&self, engine_number: usize) -> bool {
+        self.fadecs[engine_number - 1].fuel_is_commanded()
+    }
+    fn ignition_is_commanded(&self, engine_number: usize) -> bool {
+        self.fadecs[engine_number - 1].ignition_is_commanded()
     }
 }
 impl PackFlowValveState for A320Pneumatic {
@@ -408,7 +435,7 @@ impl PackFlowValveState for A320Pneumatic {
 impl SimulationElement for A320Pneumatic {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.cross_bleed_valve.accept(visitor);
-        self.fadec.accept(visitor);
+        accept_iterable!(self.fadecs, visitor);
         self.wing_anti_ice.accept(visitor);
 
         accept_iterable!(self.bleed_monitoring_computers, visitor);
@@ -456,33 +483,26 @@ impl ReservoirAirPressure for A320Pneumatic {
 }
 
 struct EngineStarterValveController {
-    number: usize,
-    engine_state: EngineState,
-    engine_n2_percent: f64,
+    is_commanded_open: bool,
 }
 impl ControllerSignal<EngineStarterValveSignal> for EngineStarterValveController {
     fn signal(&self) -> Option<EngineStarterValveSignal> {
-        match self.engine_state {
-            //FIXME should start at around 60% N2 and complete at 65% N2 because of traveltime of valve
-            EngineState::Starting | EngineState::Restarting if self.engine_n2_percent < 65. => {
-                Some(EngineStarterValveSignal::new_open())
-            }
-            _ => Some(EngineStarterValveSignal::new_closed()),
+        if self.is_commanded_open {
+            Some(EngineStarterValveSignal::new_open())
+        } else {
+            Some(EngineStarterValveSignal::new_closed())
         }
     }
 }
 impl EngineStarterValveController {
-    fn new(number: usize) -> Self {
+    fn new() -> Self {
         Self {
-            number,
-            engine_state: EngineState::Off,
-            engine_n2_percent: 0.,
+            is_commanded_open: false,
         }
     }
 
     fn update(&mut self, fadec: &FullAuthorityDigitalEngineControl) {
-        self.engine_state = fadec.engine_state(self.number);
-        self.engine_n2_percent = fadec.engine_n2_percent(self.number);
+        self.is_commanded_open = fadec.starter_valve_is_commanded_open();
     }
 }
 
@@ -527,7 +547,7 @@ impl BleedMonitoringComputer {
         overhead_panel: &A320PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         cross_bleed_valve: &impl PneumaticValve,
-        fadec: &FullAuthorityDigitalEngineControl,
+        fadecs: &[FullAuthorityDigitalEngineControl; 2],
         wing_anti_ice: &impl WingAntiIceSelected,
     ) {
         self.main_channel.update(
@@ -537,7 +557,7 @@ impl BleedMonitoringComputer {
             apu_bleed_valve,
             cross_bleed_valve,
             overhead_panel,
-            fadec,
+            fadecs,
             wing_anti_ice,
         );
 
@@ -548,7 +568,7 @@ impl BleedMonitoringComputer {
             apu_bleed_valve,
             cross_bleed_valve,
             overhead_panel,
-            fadec,
+            fadecs,
             wing_anti_ice,
         );
     }
@@ -712,7 +732,7 @@ impl BleedMonitoringComputerChannel {
         apu_bleed_valve: &impl PneumaticValve,
         cross_bleed_valve: &impl PneumaticValve,
         overhead_panel: &A320PneumaticOverheadPanel,
-        fadec: &FullAuthorityDigitalEngineControl,
+        fadecs: &[FullAuthorityDigitalEngineControl; 2],
         wing_anti_ice: &impl WingAntiIceSelected,
     ) {
         // READ IN SENSORS
@@ -735,7 +755,7 @@ impl BleedMonitoringComputerChannel {
             overhead_panel,
             engine_fire_pushbuttons,
             cross_bleed_valve,
-            fadec,
+            fadecs,
         );
         self.update_low_temperature_regulation(context, wing_anti_ice);
 
@@ -902,7 +922,7 @@ impl BleedMonitoringComputerChannel {
         overhead_panel: &A320PneumaticOverheadPanel,
         engine_fire_pushbuttons: &impl EngineFirePushButtons,
         cross_bleed_valve: &impl PneumaticValve,
-        fadec: &FullAuthorityDigitalEngineControl,
+        fadecs: &[FullAuthorityDigitalEngineControl; 2],
     ) {
         self.should_command_onside_prv_closed = self.should_command_prv_closed(
             self.engine_number,
@@ -920,7 +940,7 @@ impl BleedMonitoringComputerChannel {
             cross_bleed_valve,
         );
 
-        self.is_in_dual_bleed_config = !fadec.is_single_vs_dual_bleed_config()
+        self.is_in_dual_bleed_config = !is_single_vs_dual_bleed_config(fadecs)
             && !self.should_command_onside_prv_closed
             && !should_command_offside_prv_closed;
     }
@@ -1583,82 +1603,9 @@ impl SimulationElement for A320PneumaticOverheadPanel {
     }
 }
 
-/// We use this simply as an interface to engine parameter simvars. It should probably not be part of the pneumatic system.
-pub struct FullAuthorityDigitalEngineControl {
-    engine_1_state_id: VariableIdentifier,
-    engine_2_state_id: VariableIdentifier,
-    engine_1_state: EngineState,
-    engine_2_state: EngineState,
-
-    engine_mode_selector1_id: VariableIdentifier,
-    engine_mode_selector1_position: EngineModeSelector,
-
-    engine_1_n2_percent_id: VariableIdentifier,
-    engine_2_n2_percent_id: VariableIdentifier,
-    engine_1_n2_percent: Ratio,
-    engine_2_n2_percent: Ratio,
-}
-impl FullAuthorityDigitalEngineControl {
-    fn new(context: &mut InitContext) -> Self {
-        Self {
-            engine_1_state_id: context.get_identifier("ENGINE_STATE:1".to_owned()),
-            engine_2_state_id: context.get_identifier("ENGINE_STATE:2".to_owned()),
-            engine_1_state: EngineState::Off,
-            engine_2_state: EngineState::Off,
-            engine_mode_selector1_id: context
-                .get_identifier("TURB ENG IGNITION SWITCH EX1:1".to_owned()),
-            engine_mode_selector1_position: EngineModeSelector::Norm,
-            engine_1_n2_percent_id: context.get_identifier("ENGINE_N2:1".to_owned()),
-            engine_2_n2_percent_id: context.get_identifier("ENGINE_N2:2".to_owned()),
-            engine_1_n2_percent: Ratio::new::<percent>(0.),
-            engine_2_n2_percent: Ratio::new::<percent>(0.),
-        }
-    }
-
-    fn engine_state(&self, number: usize) -> EngineState {
-        match number {
-            1 => self.engine_1_state,
-            2 => self.engine_2_state,
-            _ => panic!("Invalid engine number"),
-        }
-    }
-
-    fn engine_n2_percent(&self, number: usize) -> f64 {
-        match number {
-            1 => self.engine_1_n2_percent.get::<percent>(),
-            2 => self.engine_2_n2_percent.get::<percent>(),
-            _ => panic!("Invalid engine number"),
-        }
-    }
-
-    fn is_single_vs_dual_bleed_config(&self) -> bool {
-        (self.engine_1_state == EngineState::On) ^ (self.engine_2_state == EngineState::On)
-    }
-
-    fn engine_mode_selector(&self) -> EngineModeSelector {
-        self.engine_mode_selector1_position
-    }
-}
-impl SimulationElement for FullAuthorityDigitalEngineControl {
-    fn read(&mut self, reader: &mut SimulatorReader) {
-        self.engine_1_state = reader.read_discrete_or_fallback(
-            &self.engine_1_state_id,
-            "EngineState1",
-            EngineState::Off,
-        );
-        self.engine_2_state = reader.read_discrete_or_fallback(
-            &self.engine_2_state_id,
-            "EngineState2",
-            EngineState::Off,
-        );
-        self.engine_mode_selector1_position = reader.read_discrete_or_fallback(
-            &self.engine_mode_selector1_id,
-            "EngineModeSelector",
-            EngineModeSelector::Norm,
-        );
-        self.engine_1_n2_percent = Ratio::new::<percent>(reader.read(&self.engine_1_n2_percent_id));
-        self.engine_2_n2_percent = Ratio::new::<percent>(reader.read(&self.engine_2_n2_percent_id));
-    }
+fn is_single_vs_dual_bleed_config(fadecs: &[FullAuthorityDigitalEngineControl; 2]) -> bool {
+    let [one, two] = fadecs;
+    (one.engine_state() == EngineState::On) ^ (two.engine_state() == EngineState::On)
 }
 
 /// A struct to hold all the pack related components
@@ -1953,7 +1900,7 @@ pub mod tests {
 
     use crate::air_conditioning::{A320AirConditioningSystem, A320PressurizationOverheadPanel};
 
-    use super::{A320Pneumatic, A320PneumaticOverheadPanel};
+    use super::{is_single_vs_dual_bleed_config, A320Pneumatic, A320PneumaticOverheadPanel};
 
     struct TestAirConditioning {
         a320_air_conditioning_system: A320AirConditioningSystem,
@@ -2748,7 +2695,7 @@ pub mod tests {
         }
 
         fn engine_state(&self, number: usize) -> EngineState {
-            self.query(|a| a.pneumatic.fadec.engine_state(number))
+            self.query(|a| a.pneumatic.engine_state(number))
         }
 
         fn cross_bleed_valve_is_open(&self) -> bool {
@@ -2825,7 +2772,7 @@ pub mod tests {
         }
 
         fn fadec_single_vs_dual_bleed_config(&self) -> bool {
-            self.query(|a| a.pneumatic.fadec.is_single_vs_dual_bleed_config())
+            self.query(|a| is_single_vs_dual_bleed_config(&a.pneumatic.fadecs))
         }
 
         fn bmc_in_low_temperature_regulation(&self, bmc_number: usize) -> bool {
@@ -3419,6 +3366,67 @@ pub mod tests {
         assert!(!test_bed.cross_bleed_valve_is_open());
     }
 
+    #[test]
+    fn engine_bleed_pressure_is_lost_on_engine_flameout() {
+        let altitude = Length::new::<foot>(0.);
+        let mut test_bed = test_bed_with()
+            .idle_eng1()
+            .stop_eng2()
+            .in_isa_atmosphere(altitude)
+            .mach_number(MachNumber(0.))
+            .and_stabilize();
+
+        let ambient_pressure = InternationalStandardAtmosphere::pressure_at_altitude(altitude);
+        assert_gt!(
+            test_bed.ip_pressure(1) - ambient_pressure,
+            pressure_tolerance()
+        );
+
+        test_bed.fail(FailureType::EngineFlameout(1));
+        test_bed = test_bed.and_stabilize();
+
+        assert_lt!(
+            (test_bed.ip_pressure(1) - ambient_pressure).abs(),
+            pressure_tolerance()
+        );
+        assert_lt!(
+            (test_bed.hp_pressure(1) - ambient_pressure).abs(),
+            pressure_tolerance()
+        );
+        assert_lt!(
+            (test_bed.transfer_pressure(1) - ambient_pressure).abs(),
+            pressure_tolerance()
+        );
+    }
+
+    #[test]
+    fn engine_bleed_pressure_is_lost_on_engine_seizure() {
+        let altitude = Length::new::<foot>(0.);
+        let mut test_bed = test_bed_with()
+            .idle_eng1()
+            .idle_eng2()
+            .in_isa_atmosphere(altitude)
+            .mach_number(MachNumber(0.))
+            .and_stabilize();
+
+        test_bed.fail(FailureType::EngineSeizure(2));
+        test_bed = test_bed.and_stabilize();
+
+        let ambient_pressure = InternationalStandardAtmosphere::pressure_at_altitude(altitude);
+        assert_gt!(
+            test_bed.ip_pressure(1) - ambient_pressure,
+            pressure_tolerance()
+        );
+        assert_lt!(
+            (test_bed.ip_pressure(2) - ambient_pressure).abs(),
+            pressure_tolerance()
+        );
+        assert_lt!(
+            (test_bed.transfer_pressure(2) - ambient_pressure).abs(),
+            pressure_tolerance()
+        );
+    }
+
     #[test]
     fn two_engine_idle_full_state() {
         let altitude = Length::new::<foot>(0.);
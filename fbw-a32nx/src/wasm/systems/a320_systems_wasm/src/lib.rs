@@ -30,7 +30,7 @@ use systems::air_conditioning::{
 use systems::failures::FailureType;
 use systems::shared::{
     report_diagnostic, AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType,
-    GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
+    FireDetectionZone, GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
 };
 use systems_wasm::aspects::ExecuteOn;
 use systems_wasm::{MsfsSimulationBuilder, Variable};
@@ -72,6 +72,9 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .with_auxiliary_power_unit(Variable::named("OVHD_APU_START_PB_IS_AVAILABLE"), 8, 7)?
     .with_engine_anti_ice(2)?
     .with_wing_anti_ice()?
+    .with_engine_fuel_cut_off(2)?
+    .with_engine_fire(2)?
+    .with_variable_trace_recording(Variable::named("SYSTEMS_TRACE_RECORDING"))
     .with_failures([
         (
             21_000,
@@ -112,6 +115,8 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         (24_020, FailureType::Generator(1)),
         (24_021, FailureType::Generator(2)),
         (24_030, FailureType::ApuGenerator(1)),
+        (24_040, FailureType::BatteryOverheat(1)),
+        (24_041, FailureType::BatteryOverheat(2)),
         (
             24_100,
             FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(1)),
@@ -168,6 +173,17 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             24_113,
             FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
         ),
+        (26_001, FailureType::SetOnFire(FireDetectionZone::Engine(1))),
+        (26_002, FailureType::SetOnFire(FireDetectionZone::Engine(2))),
+        (28_000, FailureType::FuelPump(1)),
+        (28_001, FailureType::FuelPump(2)),
+        (28_002, FailureType::FuelPump(3)),
+        (28_003, FailureType::FuelPump(4)),
+        (28_004, FailureType::FuelPump(5)),
+        (28_005, FailureType::FuelPump(6)),
+        (28_006, FailureType::FuelValveJammed(1)),
+        (28_007, FailureType::FuelValveJammed(2)),
+        (28_008, FailureType::FuelValveJammed(3)),
         (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
         (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
         (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
@@ -290,6 +306,14 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             FailureType::BrakeHydraulicLeak(HydraulicColor::Yellow),
         ),
         (32_150, FailureType::BrakeAccumulatorGasLeak),
+        (32_200, FailureType::TyreBurst(1)),
+        (32_201, FailureType::TyreBurst(2)),
+        (32_202, FailureType::TyreBurst(3)),
+        (32_203, FailureType::TyreBurst(4)),
+        (32_250, FailureType::TyreDeflation(1)),
+        (32_251, FailureType::TyreDeflation(2)),
+        (32_252, FailureType::TyreDeflation(3)),
+        (32_253, FailureType::TyreDeflation(4)),
         (34_000, FailureType::RadioAltimeter(1)),
         (34_001, FailureType::RadioAltimeter(2)),
         (34_010, FailureType::RadioAntennaInterrupted(1)),
@@ -300,6 +324,16 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             34_030,
             FailureType::EnhancedGroundProximityWarningSystemComputer,
         ),
+        (72_000, FailureType::EngineFlameout(1)),
+        (72_001, FailureType::EngineFlameout(2)),
+        (72_010, FailureType::EngineSeizure(1)),
+        (72_011, FailureType::EngineSeizure(2)),
+        (72_020, FailureType::EngineOilLeak(1)),
+        (72_021, FailureType::EngineOilLeak(2)),
+        (72_030, FailureType::EngineEgtOverlimit(1)),
+        (72_031, FailureType::EngineEgtOverlimit(2)),
+        (72_040, FailureType::EngineFanDamage(1)),
+        (72_041, FailureType::EngineFanDamage(2)),
     ])
     .provides_aircraft_variable("ACCELERATION BODY X", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Y", "feet per second squared", 0)?
@@ -328,6 +362,10 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("FUEL TANK RIGHT AUX QUANTITY", "gallons", 0)?
     .provides_aircraft_variable("FUELSYSTEM LINE FUEL FLOW", "gallons per hour", 18)?
     .provides_aircraft_variable_range("FUELSYSTEM PUMP ACTIVE", "Bool", 1..=7)?
+    .provides_aircraft_variable_range("FUELSYSTEM PUMP SWITCH", "Bool", 1..=7)?
+    .provides_aircraft_variable_range("FUELSYSTEM VALVE SWITCH", "Bool", 1..=3)?
+    .provides_aircraft_variable("FUELSYSTEM VALVE SWITCH", "Bool", 9)?
+    .provides_aircraft_variable("FUELSYSTEM VALVE SWITCH", "Bool", 10)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 0)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 1)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 2)?
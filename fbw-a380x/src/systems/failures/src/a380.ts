@@ -82,6 +82,10 @@ export const A380Failure = Object.freeze({
   Generator4: 24023,
   ApuGenerator1: 24030,
   ApuGenerator2: 24031,
+  Battery1Overheat: 24040,
+  Battery2Overheat: 24041,
+  BatteryEssOverheat: 24042,
+  BatteryApuOverheat: 24043,
   AlternatingCurrent1: 24100,
   AlternatingCurrent2: 24101,
   AlternatingCurrent3: 24102,
@@ -129,6 +133,23 @@ export const A380Failure = Object.freeze({
   Fcdc1: 27006,
   Fcdc2: 27007,
 
+  LeftOuterPump: 28000,
+  LeftMidFwdPump: 28001,
+  LeftMidAftPump: 28002,
+  LeftInnerFwdPump: 28003,
+  LeftInnerAftPump: 28004,
+  RightOuterPump: 28005,
+  RightMidFwdPump: 28006,
+  RightMidAftPump: 28007,
+  RightInnerFwdPump: 28008,
+  RightInnerAftPump: 28009,
+  TrimLeftPump: 28010,
+  TrimRightPump: 28011,
+  Feed1InletValveJammed: 28012,
+  Feed2InletValveJammed: 28013,
+  Feed3InletValveJammed: 28014,
+  Feed4InletValveJammed: 28015,
+
   GreenReservoirLeak: 29000,
   YellowReservoirLeak: 29001,
   GreenReservoirAirLeak: 29002,
@@ -164,6 +185,39 @@ export const A380Failure = Object.freeze({
   GearProxSensorDamageGearUplockNose1: 32006,
   GearProxSensorDamageDoorUplockLeft2: 32007,
 
+  TyreBurst1: 32200,
+  TyreBurst2: 32201,
+  TyreBurst3: 32202,
+  TyreBurst4: 32203,
+  TyreBurst5: 32204,
+  TyreBurst6: 32205,
+  TyreBurst7: 32206,
+  TyreBurst8: 32207,
+  TyreBurst9: 32208,
+  TyreBurst10: 32209,
+  TyreBurst11: 32210,
+  TyreBurst12: 32211,
+  TyreBurst13: 32212,
+  TyreBurst14: 32213,
+  TyreBurst15: 32214,
+  TyreBurst16: 32215,
+  TyreDeflation1: 32250,
+  TyreDeflation2: 32251,
+  TyreDeflation3: 32252,
+  TyreDeflation4: 32253,
+  TyreDeflation5: 32254,
+  TyreDeflation6: 32255,
+  TyreDeflation7: 32256,
+  TyreDeflation8: 32257,
+  TyreDeflation9: 32258,
+  TyreDeflation10: 32259,
+  TyreDeflation11: 32260,
+  TyreDeflation12: 32261,
+  TyreDeflation13: 32262,
+  TyreDeflation14: 32263,
+  TyreDeflation15: 32264,
+  TyreDeflation16: 32265,
+
   RadioAltimeter1: 34000,
   RadioAltimeter2: 34001,
   RadioAltimeter3: 34002,
@@ -182,6 +236,27 @@ export const A380Failure = Object.freeze({
   Gpws1: 34007,
   Gpws2: 34008,
 
+  Engine1Flameout: 72000,
+  Engine2Flameout: 72001,
+  Engine3Flameout: 72002,
+  Engine4Flameout: 72003,
+  Engine1Seizure: 72010,
+  Engine2Seizure: 72011,
+  Engine3Seizure: 72012,
+  Engine4Seizure: 72013,
+  Engine1OilLeak: 72020,
+  Engine2OilLeak: 72021,
+  Engine3OilLeak: 72022,
+  Engine4OilLeak: 72023,
+  Engine1EgtOverlimit: 72030,
+  Engine2EgtOverlimit: 72031,
+  Engine3EgtOverlimit: 72032,
+  Engine4EgtOverlimit: 72033,
+  Engine1FanDamage: 72040,
+  Engine2FanDamage: 72041,
+  Engine3FanDamage: 72042,
+  Engine4FanDamage: 72043,
+
   NssAnsu1: 46001,
   NssAnsu2: 46002,
   FltOpsAnsu: 46003,
@@ -270,6 +345,10 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [24, A380Failure.Generator4, 'Generator 4'],
   [24, A380Failure.ApuGenerator1, 'APU Generator 1'],
   [24, A380Failure.ApuGenerator2, 'APU Generator 2'],
+  [24, A380Failure.Battery1Overheat, 'Battery 1 thermal runaway'],
+  [24, A380Failure.Battery2Overheat, 'Battery 2 thermal runaway'],
+  [24, A380Failure.BatteryEssOverheat, 'ESS Battery thermal runaway'],
+  [24, A380Failure.BatteryApuOverheat, 'APU Battery thermal runaway'],
   [24, A380Failure.AlternatingCurrent1, 'AC 1'],
   [24, A380Failure.AlternatingCurrent2, 'AC 2'],
   [24, A380Failure.AlternatingCurrent3, 'AC 3'],
@@ -317,6 +396,23 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [27, A380Failure.Fcdc1, 'FCDC 1'],
   [27, A380Failure.Fcdc2, 'FCDC 2'],
 
+  [28, A380Failure.LeftOuterPump, 'Left outer tank pump'],
+  [28, A380Failure.LeftMidFwdPump, 'Left mid tank fwd pump'],
+  [28, A380Failure.LeftMidAftPump, 'Left mid tank aft pump'],
+  [28, A380Failure.LeftInnerFwdPump, 'Left inner tank fwd pump'],
+  [28, A380Failure.LeftInnerAftPump, 'Left inner tank aft pump'],
+  [28, A380Failure.RightOuterPump, 'Right outer tank pump'],
+  [28, A380Failure.RightMidFwdPump, 'Right mid tank fwd pump'],
+  [28, A380Failure.RightMidAftPump, 'Right mid tank aft pump'],
+  [28, A380Failure.RightInnerFwdPump, 'Right inner tank fwd pump'],
+  [28, A380Failure.RightInnerAftPump, 'Right inner tank aft pump'],
+  [28, A380Failure.TrimLeftPump, 'Trim tank left pump'],
+  [28, A380Failure.TrimRightPump, 'Trim tank right pump'],
+  [28, A380Failure.Feed1InletValveJammed, 'Feed tank 1 inlet valve jammed'],
+  [28, A380Failure.Feed2InletValveJammed, 'Feed tank 2 inlet valve jammed'],
+  [28, A380Failure.Feed3InletValveJammed, 'Feed tank 3 inlet valve jammed'],
+  [28, A380Failure.Feed4InletValveJammed, 'Feed tank 4 inlet valve jammed'],
+
   [29, A380Failure.GreenReservoirLeak, 'Green reservoir leak'],
   [29, A380Failure.YellowReservoirLeak, 'Yellow reservoir leak'],
   [29, A380Failure.GreenReservoirAirLeak, 'Green reservoir air leak'],
@@ -349,6 +445,39 @@ export const A380FailureDefinitions: FailureDefinition[] = [
 
   [32, A380Failure.GearProxSensorDamageGearUplockNose1, 'Proximity sensor damage uplock nose gear #1'],
 
+  [32, A380Failure.TyreBurst1, 'Tyre 1 burst'],
+  [32, A380Failure.TyreBurst2, 'Tyre 2 burst'],
+  [32, A380Failure.TyreBurst3, 'Tyre 3 burst'],
+  [32, A380Failure.TyreBurst4, 'Tyre 4 burst'],
+  [32, A380Failure.TyreBurst5, 'Tyre 5 burst'],
+  [32, A380Failure.TyreBurst6, 'Tyre 6 burst'],
+  [32, A380Failure.TyreBurst7, 'Tyre 7 burst'],
+  [32, A380Failure.TyreBurst8, 'Tyre 8 burst'],
+  [32, A380Failure.TyreBurst9, 'Tyre 9 burst'],
+  [32, A380Failure.TyreBurst10, 'Tyre 10 burst'],
+  [32, A380Failure.TyreBurst11, 'Tyre 11 burst'],
+  [32, A380Failure.TyreBurst12, 'Tyre 12 burst'],
+  [32, A380Failure.TyreBurst13, 'Tyre 13 burst'],
+  [32, A380Failure.TyreBurst14, 'Tyre 14 burst'],
+  [32, A380Failure.TyreBurst15, 'Tyre 15 burst'],
+  [32, A380Failure.TyreBurst16, 'Tyre 16 burst'],
+  [32, A380Failure.TyreDeflation1, 'Tyre 1 deflation'],
+  [32, A380Failure.TyreDeflation2, 'Tyre 2 deflation'],
+  [32, A380Failure.TyreDeflation3, 'Tyre 3 deflation'],
+  [32, A380Failure.TyreDeflation4, 'Tyre 4 deflation'],
+  [32, A380Failure.TyreDeflation5, 'Tyre 5 deflation'],
+  [32, A380Failure.TyreDeflation6, 'Tyre 6 deflation'],
+  [32, A380Failure.TyreDeflation7, 'Tyre 7 deflation'],
+  [32, A380Failure.TyreDeflation8, 'Tyre 8 deflation'],
+  [32, A380Failure.TyreDeflation9, 'Tyre 9 deflation'],
+  [32, A380Failure.TyreDeflation10, 'Tyre 10 deflation'],
+  [32, A380Failure.TyreDeflation11, 'Tyre 11 deflation'],
+  [32, A380Failure.TyreDeflation12, 'Tyre 12 deflation'],
+  [32, A380Failure.TyreDeflation13, 'Tyre 13 deflation'],
+  [32, A380Failure.TyreDeflation14, 'Tyre 14 deflation'],
+  [32, A380Failure.TyreDeflation15, 'Tyre 15 deflation'],
+  [32, A380Failure.TyreDeflation16, 'Tyre 16 deflation'],
+
   [34, A380Failure.RadioAltimeter1, 'RA SYS A'],
   [34, A380Failure.RadioAltimeter2, 'RA SYS B'],
   [34, A380Failure.RadioAltimeter3, 'RA SYS C'],
@@ -372,4 +501,25 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [46, A380Failure.FirstOfficerLaptop, 'F/O Laptop'],
   [46, A380Failure.CaptainOit, 'Captain OIT'],
   [46, A380Failure.FirstOfficerOit, 'F/O OIT'],
+
+  [72, A380Failure.Engine1Flameout, 'Engine 1 flameout'],
+  [72, A380Failure.Engine2Flameout, 'Engine 2 flameout'],
+  [72, A380Failure.Engine3Flameout, 'Engine 3 flameout'],
+  [72, A380Failure.Engine4Flameout, 'Engine 4 flameout'],
+  [72, A380Failure.Engine1Seizure, 'Engine 1 seized core'],
+  [72, A380Failure.Engine2Seizure, 'Engine 2 seized core'],
+  [72, A380Failure.Engine3Seizure, 'Engine 3 seized core'],
+  [72, A380Failure.Engine4Seizure, 'Engine 4 seized core'],
+  [72, A380Failure.Engine1OilLeak, 'Engine 1 oil leak'],
+  [72, A380Failure.Engine2OilLeak, 'Engine 2 oil leak'],
+  [72, A380Failure.Engine3OilLeak, 'Engine 3 oil leak'],
+  [72, A380Failure.Engine4OilLeak, 'Engine 4 oil leak'],
+  [72, A380Failure.Engine1EgtOverlimit, 'Engine 1 EGT overlimit'],
+  [72, A380Failure.Engine2EgtOverlimit, 'Engine 2 EGT overlimit'],
+  [72, A380Failure.Engine3EgtOverlimit, 'Engine 3 EGT overlimit'],
+  [72, A380Failure.Engine4EgtOverlimit, 'Engine 4 EGT overlimit'],
+  [72, A380Failure.Engine1FanDamage, 'Engine 1 fan damage (bird strike)'],
+  [72, A380Failure.Engine2FanDamage, 'Engine 2 fan damage (bird strike)'],
+  [72, A380Failure.Engine3FanDamage, 'Engine 3 fan damage (bird strike)'],
+  [72, A380Failure.Engine4FanDamage, 'Engine 4 fan damage (bird strike)'],
 ];
@@ -1,4 +1,5 @@
 import { useSimVar } from '@instruments/common/simVars';
+import { useArinc429Var } from '@instruments/common/arinc429';
 import { EngineNumber, IgnitionActive, Position } from '@instruments/common/types';
 import React, { FC } from 'react';
 import DecimalValues from './DecimalValues';
@@ -32,9 +33,9 @@ const EngineColumn: FC<Position & EngineNumber & IgnitionActive & EngineColumnPr
 
   const [fuelFlow] = useSimVar(`L:A32NX_ENGINE_FF:${engine}`, 'number', 100);
 
-  const [n1Vibration] = useSimVar(`TURB ENG VIBRATION:${engine}`, 'number', 100);
-  const n2Vibration = n1Vibration;
-  const n3Vibration = n1Vibration;
+  const n1Vibration = useArinc429Var(`L:A32NX_EVMU_ENG_${engine}_N1_VIBRATION`, 1000).valueOr(0);
+  const n2Vibration = useArinc429Var(`L:A32NX_EVMU_ENG_${engine}_N2_VIBRATION`, 1000).valueOr(0);
+  const n3Vibration = n2Vibration;
 
   const [oilQuantity] = useSimVar(`L:A32NX_ENGINE_OIL_QTY:${engine}`, 'number', 500); // TODO: Update with correct SimVars
   const [engineOilTemperature] = useSimVar(`GENERAL ENG OIL TEMPERATURE:${engine}`, 'celsius', 100); // TODO: Update with correct SimVars
@@ -6,16 +6,18 @@ import React from 'react';
 const StartValve: React.FC<Position & EngineNumber> = ({ x, y, engine }) => {
   const [startValveOpen] = useSimVar(`L:A32NX_PNEU_ENG_${engine}_STARTER_VALVE_OPEN`, 'boolean', 500);
   const [starterInletPressure] = useSimVar(`L:A32NX_PNEU_ENG_${engine}_REGULATED_TRANSDUCER_PRESSURE`, 'psi', 100);
-
-  const [N2] = useSimVar(`L:A32NX_ENGINE_N2:${engine}`, 'number', 100);
-  const showIgniter = !!(N2 > 9 && N2 < 25); // TODO Use SimVars for igniter once available
+  const [ignitorAActive] = useSimVar(`L:A32NX_FADEC_${engine}_IGNITOR_A_ACTIVE`, 'bool', 300);
+  const [ignitorBActive] = useSimVar(`L:A32NX_FADEC_${engine}_IGNITOR_B_ACTIVE`, 'bool', 300);
 
   return (
     <g id={`SD-start-valve-${engine}`}>
       <Valve x={x} y={y - 14} radius={24} css="Green SW2" position={startValveOpen ? 'V' : 'H'} sdacDatum />
-      <text x={x - 10} y={y - 60} className={`Green F25 MiddleAlign ${!(showIgniter && startValveOpen) && 'Hide'}`}>
+      <text x={x - 10} y={y - 60} className={`Green F25 MiddleAlign ${!ignitorAActive && 'Hide'}`}>
         A
       </text>
+      <text x={x + 10} y={y - 60} className={`Green F25 MiddleAlign ${!ignitorBActive && 'Hide'}`}>
+        B
+      </text>
       <text
         x={x}
         y={y + 38}
@@ -12,6 +12,20 @@ interface FqmsBusBaseEvents {
    * | 12  | FMS Data disagrees                |
    */
   fqms_status_word: number;
+  /**
+   * Discrete word of the active in-flight fuel transfers, raw ARINC word.
+   * | Bit |            Description            |
+   * |:---:|:---------------------------------:|
+   * | 11  | Left inner to feed transfer       |
+   * | 12  | Right inner to feed transfer      |
+   * | 13  | Left mid to feed transfer         |
+   * | 14  | Right mid to feed transfer        |
+   * | 15  | Left outer to feed transfer       |
+   * | 16  | Right outer to feed transfer      |
+   * | 17  | Trim to feed CG control transfer  |
+   * | 18  | Trim to feed end of cruise        |
+   */
+  fqms_fuel_transfer_status_word: number;
   /**
    * FQMS fuel on board in kilograms.
    * Raw ARINC word.
@@ -77,6 +91,10 @@ export class FqmsBusPublisher extends SimVarPublisher<FqmsBusEvents> {
   public constructor(bus: EventBus, pacer?: PublishPacer<FqmsBusEvents>) {
     const simvars = new Map<keyof FqmsBusEvents, SimVarPublisherEntry<any>>([
       ['fqms_status_word', { name: 'L:A32NX_FQMS_STATUS_WORD', type: SimVarValueType.Enum }],
+      [
+        'fqms_fuel_transfer_status_word',
+        { name: 'L:A32NX_FQMS_FUEL_TRANSFER_STATUS_WORD', type: SimVarValueType.Enum },
+      ],
       ['fqms_total_fuel_on_board', { name: 'L:A32NX_FQMS_TOTAL_FUEL_ON_BOARD', type: SimVarValueType.Enum }],
       ['fqms_gross_weight', { name: 'L:A32NX_FQMS_GROSS_WEIGHT', type: SimVarValueType.Enum }],
       ['fqms_center_of_gravity_mac', { name: 'L:A32NX_FQMS_CENTER_OF_GRAVITY_MAC', type: SimVarValueType.Enum }],
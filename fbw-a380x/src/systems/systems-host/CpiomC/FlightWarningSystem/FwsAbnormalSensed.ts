@@ -4717,6 +4717,46 @@ export class FwsAbnormalSensed {
       failure: 2,
       sysPage: SdPages.None,
     },
+    701800117: {
+      // ENG 1 START FAULT
+      simVarIsActive: this.fws.eng1StartFault,
+      flightPhaseInhib: this.fws.phase56Inhibition,
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [],
+      whichItemsChecked: () => [],
+      sysPage: SdPages.Eng,
+      failure: 2,
+    },
+    701800118: {
+      // ENG 2 START FAULT
+      simVarIsActive: this.fws.eng2StartFault,
+      flightPhaseInhib: this.fws.phase56Inhibition,
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [],
+      whichItemsChecked: () => [],
+      sysPage: SdPages.Eng,
+      failure: 2,
+    },
+    701800119: {
+      // ENG 3 START FAULT
+      simVarIsActive: this.fws.eng3StartFault,
+      flightPhaseInhib: this.fws.phase56Inhibition,
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [],
+      whichItemsChecked: () => [],
+      sysPage: SdPages.Eng,
+      failure: 2,
+    },
+    701800120: {
+      // ENG 4 START FAULT
+      simVarIsActive: this.fws.eng4StartFault,
+      flightPhaseInhib: this.fws.phase56Inhibition,
+      notActiveWhenItemActive: [],
+      whichItemsToShow: () => [],
+      whichItemsChecked: () => [],
+      sysPage: SdPages.Eng,
+      failure: 2,
+    },
     701800151: {
       // ALL ENGINES FAILURE
       simVarIsActive: this.fws.allEnginesFailure,
@@ -1972,6 +1972,11 @@ export class FwsCore {
   private readonly eng4NotStartingConfNode = new NXLogicConfirmNode(5, false);
   private readonly eng4WasRunningMemoryNode = new NXLogicMemoryNode();
 
+  public readonly eng1StartFault = Subject.create(false);
+  public readonly eng2StartFault = Subject.create(false);
+  public readonly eng3StartFault = Subject.create(false);
+  public readonly eng4StartFault = Subject.create(false);
+
   private readonly eng1ShutDown = Subject.create(false);
   private readonly eng2ShutDown = Subject.create(false);
   private readonly eng3ShutDown = Subject.create(false);
@@ -3006,6 +3011,11 @@ export class FwsCore {
     this.engine3State.set(engine3StateSiMVar);
     this.engine4State.set(engine4StateSiMVar);
 
+    this.eng1StartFault.set(SimVar.GetSimVarValue('L:A32NX_FADEC_1_START_FAULT', 'Enum') !== 0);
+    this.eng2StartFault.set(SimVar.GetSimVarValue('L:A32NX_FADEC_2_START_FAULT', 'Enum') !== 0);
+    this.eng3StartFault.set(SimVar.GetSimVarValue('L:A32NX_FADEC_3_START_FAULT', 'Enum') !== 0);
+    this.eng4StartFault.set(SimVar.GetSimVarValue('L:A32NX_FADEC_4_START_FAULT', 'Enum') !== 0);
+
     this.engine1Running.set(engine1StatesimVar === engineState.ON);
     this.engine2Running.set(engine2StateSimVar === engineState.ON);
     this.engine3Running.set(engine3StateSiMVar === engineState.ON);
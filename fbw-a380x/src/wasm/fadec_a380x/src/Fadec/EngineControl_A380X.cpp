@@ -59,8 +59,15 @@ void EngineControl_A380X::update() {
   for (int engine = 1; engine <= 4; engine++) {
     const int engineIdx = engine - 1;
 
-    const bool engineStarter = static_cast<bool>(simData.simVarsDataPtr->data().engineStarter[engineIdx]);
-    const int  engineIgniter = static_cast<int>(simData.simVarsDataPtr->data().engineIgniter[engineIdx]);
+    bool      engineStarter = static_cast<bool>(simData.simVarsDataPtr->data().engineStarter[engineIdx]);
+    const int engineIgniter = static_cast<int>(simData.simVarsDataPtr->data().engineIgniter[engineIdx]);
+
+    // the systems FADEC aborts automatic starts on ground and cuts the fuel for the dry crank
+    if (engineStarter && simData.fadecStartAborted[engineIdx]->getAsBool()) {
+      simData.setStarterHeldEvent[engineIdx]->trigger(0);
+      simData.setStarterEvent[engineIdx]->trigger(0);
+      engineStarter = false;
+    }
 
     // determine the current engine state based on the previous state and the current ignition, starter and other parameters
     // also resets the engine timer if the engine is starting or restarting
@@ -226,6 +226,7 @@ class FadecSimData_A380X {
   NamedVariablePtr enginePreFF[4];  // kg/hour
   NamedVariablePtr engineState[4];
   NamedVariablePtr engineTimer[4];
+  NamedVariablePtr fadecStartAborted[4];
   NamedVariablePtr fuelLeftOuterPre;   // Pounds
   NamedVariablePtr fuelFeedOnePre;     // Pounds
   NamedVariablePtr fuelLeftMidPre;     // Pounds
@@ -410,6 +411,11 @@ class FadecSimData_A380X {
     engineTimer[E3] = dm->make_named_var("A32NX_ENGINE_TIMER:3", UNITS.Number, AUTO_READ_WRITE);
     engineTimer[E4] = dm->make_named_var("A32NX_ENGINE_TIMER:4", UNITS.Number, AUTO_READ_WRITE);
 
+    fadecStartAborted[E1] = dm->make_named_var("A32NX_FADEC_1_START_ABORTED", UNITS.Bool, AUTO_READ);
+    fadecStartAborted[E2] = dm->make_named_var("A32NX_FADEC_2_START_ABORTED", UNITS.Bool, AUTO_READ);
+    fadecStartAborted[E3] = dm->make_named_var("A32NX_FADEC_3_START_ABORTED", UNITS.Bool, AUTO_READ);
+    fadecStartAborted[E4] = dm->make_named_var("A32NX_FADEC_4_START_ABORTED", UNITS.Bool, AUTO_READ);
+
     fuelLeftOuterPre  = dm->make_named_var("A32NX_FUEL_LEFTOUTER_PRE", UNITS.Number, AUTO_READ_WRITE);
     fuelFeedOnePre    = dm->make_named_var("A32NX_FUEL_FEED1_PRE", UNITS.Number, AUTO_READ_WRITE);
     fuelLeftMidPre    = dm->make_named_var("A32NX_FUEL_LEFTMID_PRE", UNITS.Number, AUTO_READ_WRITE);
@@ -148,6 +148,8 @@ bool FlyByWireInterface::update(double sampleTime) {
 
   result &= updateServoSolenoidStatus();
 
+  result &= updateEhaBackupInhibit();
+
   // update recording data
   result &= updateBaseData(calculatedSampleTime);
   result &= updateAircraftSpecificData(calculatedSampleTime);
@@ -737,6 +739,11 @@ void FlyByWireInterface::setupLocalVariables() {
   }
   idRudderTrimActualPosition = std::make_unique<LocalVariable>("A32NX_RUDDER_TRIM_ACTUAL_POSITION");
 
+  idAileronEhaBackupInhibited = std::make_unique<LocalVariable>("A32NX_AIL_EHA_BACKUP_INHIBITED");
+  idElevatorEhaBackupInhibited = std::make_unique<LocalVariable>("A32NX_ELEV_EHA_BACKUP_INHIBITED");
+  idRudderEbhaBackupInhibited = std::make_unique<LocalVariable>("A32NX_RUDDER_EBHA_BACKUP_INHIBITED");
+  idSpoilerEbhaBackupInhibited = std::make_unique<LocalVariable>("A32NX_SPOILER_EBHA_BACKUP_INHIBITED");
+
   idLeftAileronInwardPosition = std::make_unique<LocalVariable>("A32NX_HYD_AILERON_LEFT_INWARD_DEFLECTION");
   idLeftAileronMiddlePosition = std::make_unique<LocalVariable>("A32NX_HYD_AILERON_LEFT_MIDDLE_DEFLECTION");
   idLeftAileronOutwardPosition = std::make_unique<LocalVariable>("A32NX_HYD_AILERON_LEFT_OUTWARD_DEFLECTION");
@@ -1714,6 +1721,10 @@ bool FlyByWireInterface::updatePrim(double sampleTime, int primIndex) {
                           primIndex == primDisabled && primFctlDisabled, primIndex == primDisabled && primFeDisabled,
                           primIndex == primDisabled && primFgDisabled);
 
+  bool anyEngineStarterActive = simData.engine_starter_active_1 || simData.engine_starter_active_2 ||
+                                simData.engine_starter_active_3 || simData.engine_starter_active_4;
+  prims[primIndex].updateEhaBackupInhibit(sampleTime, simData.simOnGround, anyEngineStarterActive);
+
   primsDiscreteOutputs[primIndex] = prims[primIndex].getDiscreteOutputs();
   primsAnalogOutputs[primIndex] = prims[primIndex].getAnalogOutputs();
   primsBusOutputs[primIndex] = prims[primIndex].getBusOutputs();
@@ -2199,6 +2210,9 @@ bool FlyByWireInterface::updateSec(double sampleTime, int secIndex) {
 
     Failures failureIndex = secIndex == 0 ? Failures::Sec1 : (secIndex == 1 ? Failures::Sec2 : Failures::Sec3);
     secs[secIndex].update(sampleTime, simData.simulationTime, failuresConsumer.isActive(failureIndex), powerSupplyAvailable);
+    bool anyEngineStarterActive = simData.engine_starter_active_1 || simData.engine_starter_active_2 ||
+                                  simData.engine_starter_active_3 || simData.engine_starter_active_4;
+    secs[secIndex].updateEhaBackupInhibit(sampleTime, simData.simOnGround, anyEngineStarterActive);
 
     secsDiscreteOutputs[secIndex] = secs[secIndex].getDiscreteOutputs();
     secsAnalogOutputs[secIndex] = secs[secIndex].getAnalogOutputs();
@@ -2879,6 +2893,33 @@ bool FlyByWireInterface::updateServoSolenoidStatus() {
   return true;
 }
 
+bool FlyByWireInterface::updateEhaBackupInhibit() {
+  // The electrical backup of a surface is only inhibited when every computer able to command its EHA/EBHA inhibits it
+  bool primsInhibitAileron = primsDiscreteOutputs[0].aileron_eha_backup_inhibited && primsDiscreteOutputs[1].aileron_eha_backup_inhibited &&
+                             primsDiscreteOutputs[2].aileron_eha_backup_inhibited;
+  bool secsInhibitAileron = secsDiscreteOutputs[0].aileron_eha_backup_inhibited && secsDiscreteOutputs[1].aileron_eha_backup_inhibited &&
+                            secsDiscreteOutputs[2].aileron_eha_backup_inhibited;
+  bool primsInhibitElevator = primsDiscreteOutputs[0].elevator_eha_backup_inhibited &&
+                              primsDiscreteOutputs[1].elevator_eha_backup_inhibited &&
+                              primsDiscreteOutputs[2].elevator_eha_backup_inhibited;
+  bool secsInhibitElevator = secsDiscreteOutputs[0].elevator_eha_backup_inhibited && secsDiscreteOutputs[1].elevator_eha_backup_inhibited &&
+                             secsDiscreteOutputs[2].elevator_eha_backup_inhibited;
+  bool primsInhibitRudder = primsDiscreteOutputs[0].rudder_ebha_backup_inhibited && primsDiscreteOutputs[1].rudder_ebha_backup_inhibited &&
+                            primsDiscreteOutputs[2].rudder_ebha_backup_inhibited;
+  bool secsInhibitRudder = secsDiscreteOutputs[0].rudder_ebha_backup_inhibited && secsDiscreteOutputs[1].rudder_ebha_backup_inhibited &&
+                           secsDiscreteOutputs[2].rudder_ebha_backup_inhibited;
+  bool primsInhibitSpoiler = primsDiscreteOutputs[0].spoiler_ebha_backup_inhibited &&
+                             primsDiscreteOutputs[1].spoiler_ebha_backup_inhibited &&
+                             primsDiscreteOutputs[2].spoiler_ebha_backup_inhibited;
+
+  idAileronEhaBackupInhibited->set(primsInhibitAileron && secsInhibitAileron);
+  idElevatorEhaBackupInhibited->set(primsInhibitElevator && secsInhibitElevator);
+  idRudderEbhaBackupInhibited->set(primsInhibitRudder && secsInhibitRudder);
+  idSpoilerEbhaBackupInhibited->set(primsInhibitSpoiler);
+
+  return true;
+}
+
 bool FlyByWireInterface::updateFlyByWire(double sampleTime) {
   // get data from interface ------------------------------------------------------------------------------------------
   SimData simData = simConnectInterface.getSimData();
@@ -534,6 +534,12 @@ class FlyByWireInterface {
   std::unique_ptr<LocalVariable> idRudderTrimCommandedPosition[2];
   std::unique_ptr<LocalVariable> idRudderTrimActualPosition;
 
+  // Flight controls EHA/EBHA electrical backup inhibit Lvars
+  std::unique_ptr<LocalVariable> idAileronEhaBackupInhibited;
+  std::unique_ptr<LocalVariable> idElevatorEhaBackupInhibited;
+  std::unique_ptr<LocalVariable> idRudderEbhaBackupInhibited;
+  std::unique_ptr<LocalVariable> idSpoilerEbhaBackupInhibited;
+
   std::unique_ptr<LocalVariable> idLeftAileronInwardPosition;
   std::unique_ptr<LocalVariable> idLeftAileronMiddlePosition;
   std::unique_ptr<LocalVariable> idLeftAileronOutwardPosition;
@@ -745,6 +751,8 @@ class FlyByWireInterface {
 
   bool updateServoSolenoidStatus();
 
+  bool updateEhaBackupInhibit();
+
   bool updateSpoilers(double sampleTime);
 
   bool updateAltimeterSetting(double sampleTime);
@@ -103,6 +103,10 @@ struct SimData {
   unsigned long long engine_combustion_2;
   unsigned long long engine_combustion_3;
   unsigned long long engine_combustion_4;
+  unsigned long long engine_starter_active_1;
+  unsigned long long engine_starter_active_2;
+  unsigned long long engine_starter_active_3;
+  unsigned long long engine_starter_active_4;
   unsigned long long is_mach_mode_active;
   unsigned long long speed_slot_index;
   unsigned long long engineAntiIce_1;
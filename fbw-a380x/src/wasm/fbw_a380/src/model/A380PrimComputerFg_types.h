@@ -1414,6 +1414,10 @@ struct base_prim_discrete_outputs
   boolean_T fcu_2_select;
   boolean_T ap_engaged;
   boolean_T reverser_tertiary_lock;
+  boolean_T aileron_eha_backup_inhibited;
+  boolean_T elevator_eha_backup_inhibited;
+  boolean_T rudder_ebha_backup_inhibited;
+  boolean_T spoiler_ebha_backup_inhibited;
 };
 
 #endif
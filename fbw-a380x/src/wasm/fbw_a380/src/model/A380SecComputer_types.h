@@ -173,6 +173,9 @@ struct base_sec_discrete_outputs
   boolean_T rudder_2_electric_active_mode;
   boolean_T rudder_trim_active_mode;
   boolean_T sec_healthy;
+  boolean_T aileron_eha_backup_inhibited;
+  boolean_T elevator_eha_backup_inhibited;
+  boolean_T rudder_ebha_backup_inhibited;
 };
 
 #endif
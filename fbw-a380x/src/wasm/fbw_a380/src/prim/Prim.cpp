@@ -1,4 +1,5 @@
 #include "Prim.h"
+#include <algorithm>
 #include <iostream>
 #include "../Arinc429.h"
 #include "../Arinc429Utils.h"
@@ -209,6 +210,20 @@ void Prim::updateSelfTest(double deltaTime) {
   }
 }
 
+// Inhibit the electrical backup of the EHA/EBHA actuators after some time on ground with no engine starting or
+// running, so that the actuators do not drain the aircraft batteries while parked. A computer which is not healthy
+// cannot command the backup either.
+void Prim::updateEhaBackupInhibit(double deltaTime, bool onGround, bool anyEngineStarterActive) {
+  if (onGround) {
+    ehaBackupInhibitOnGroundTime = std::min(ehaBackupInhibitOnGroundTime + deltaTime, ehaBackupInhibitOnGroundDelay);
+  } else {
+    ehaBackupInhibitOnGroundTime = 0;
+  }
+
+  ehaBackupInhibited =
+      !monitoringHealthy || (ehaBackupInhibitOnGroundTime >= ehaBackupInhibitOnGroundDelay && !anyEngineStarterActive);
+}
+
 // Write the bus output data and return it.
 base_prim_out_bus Prim::getBusOutputs() {
   base_prim_out_bus output = {};
@@ -248,6 +263,11 @@ base_prim_discrete_outputs Prim::getDiscreteOutputs() {
     output = modelOutputs.discrete_outputs;
   }
 
+  output.aileron_eha_backup_inhibited = ehaBackupInhibited;
+  output.elevator_eha_backup_inhibited = ehaBackupInhibited;
+  output.rudder_ebha_backup_inhibited = ehaBackupInhibited;
+  output.spoiler_ebha_backup_inhibited = ehaBackupInhibited;
+
   return output;
 }
 
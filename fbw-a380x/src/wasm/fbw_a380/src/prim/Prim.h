@@ -26,6 +26,8 @@ class Prim {
               bool feDisabled,
               bool fgDisabled);
 
+  void updateEhaBackupInhibit(double deltaTime, bool onGround, bool anyEngineStarterActive);
+
   base_prim_out_bus getBusOutputs();
 
   base_prim_discrete_outputs getDiscreteOutputs();
@@ -80,4 +82,10 @@ class Prim {
   const double minimumPowerOutageTimeForFailure = 0.02;
   const double shortSelfTestDuration = 1;
   const double longSelfTestDuration = 36;
+  const double ehaBackupInhibitOnGroundDelay = 120;
+
+  // EHA/EBHA electrical backup inhibit vars
+  double ehaBackupInhibitOnGroundTime = ehaBackupInhibitOnGroundDelay;
+
+  bool ehaBackupInhibited = true;
 };
@@ -1,4 +1,5 @@
 #include "Sec.h"
+#include <algorithm>
 #include <iostream>
 #include "../Arinc429.h"
 #include "../Arinc429Utils.h"
@@ -107,6 +108,20 @@ void Sec::updateSelfTest(double deltaTime) {
   }
 }
 
+// Inhibit the electrical backup of the EHA/EBHA actuators after some time on ground with no engine starting or
+// running, so that the actuators do not drain the aircraft batteries while parked. A computer which is not healthy
+// cannot command the backup either.
+void Sec::updateEhaBackupInhibit(double deltaTime, bool onGround, bool anyEngineStarterActive) {
+  if (onGround) {
+    ehaBackupInhibitOnGroundTime = std::min(ehaBackupInhibitOnGroundTime + deltaTime, ehaBackupInhibitOnGroundDelay);
+  } else {
+    ehaBackupInhibitOnGroundTime = 0;
+  }
+
+  ehaBackupInhibited =
+      !monitoringHealthy || (ehaBackupInhibitOnGroundTime >= ehaBackupInhibitOnGroundDelay && !anyEngineStarterActive);
+}
+
 // Write the bus output data and return it.
 base_sec_out_bus Sec::getBusOutputs() {
   base_sec_out_bus output = {};
@@ -170,6 +185,10 @@ base_sec_discrete_outputs Sec::getDiscreteOutputs() {
     output = modelOutputs.discrete_outputs;
   }
 
+  output.aileron_eha_backup_inhibited = ehaBackupInhibited;
+  output.elevator_eha_backup_inhibited = ehaBackupInhibited;
+  output.rudder_ebha_backup_inhibited = ehaBackupInhibited;
+
   return output;
 }
 
@@ -13,6 +13,8 @@ class Sec {
 
   void update(double deltaTime, double simulationTime, bool faultActive, bool isPowered);
 
+  void updateEhaBackupInhibit(double deltaTime, bool onGround, bool anyEngineStarterActive);
+
   base_sec_out_bus getBusOutputs();
 
   base_sec_discrete_outputs getDiscreteOutputs();
@@ -65,4 +67,10 @@ class Sec {
   const double minimumPowerOutageTimeForFailure = 0.02;
   const double shortSelfTestDuration = 1;
   const double longSelfTestDuration = 22;
+  const double ehaBackupInhibitOnGroundDelay = 120;
+
+  // EHA/EBHA electrical backup inhibit vars
+  double ehaBackupInhibitOnGroundTime = ehaBackupInhibitOnGroundDelay;
+
+  bool ehaBackupInhibited = true;
 };
@@ -202,6 +202,11 @@ impl A380AirConditioning {
             .mix_packs_air_update(pack_container);
     }
 
+    /// The avionics compartment is ventilated with air drawn from the cockpit.
+    pub(super) fn avionics_compartment_temperature(&self) -> ThermodynamicTemperature {
+        self.a380_cabin.cabin_temperature()[0]
+    }
+
     fn update_pressurization_ambient_conditions(
         &mut self,
         context: &UpdateContext,
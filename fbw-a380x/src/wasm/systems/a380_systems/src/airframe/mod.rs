@@ -35,6 +35,10 @@ impl A380Airframe {
         &Self::LOADSHEET
     }
 
+    pub(crate) fn center_of_gravity(&self) -> &CenterOfGravityData {
+        &self.center_of_gravity
+    }
+
     #[cfg(test)]
     fn zero_fuel_weight_center_of_gravity(&self) -> f64 {
         self.center_of_gravity.zero_fuel_weight_center_of_gravity()
@@ -13,6 +13,8 @@ use systems::{
     simulation::{SimulationElement, SimulationElementVisitor},
 };
 
+use uom::si::f64::*;
+
 pub(crate) const APU_START_MOTOR_BUS_TYPE: ElectricalBusType = ElectricalBusType::Sub("49-42-00");
 
 pub(super) struct A380DirectCurrentElectrical {
@@ -376,6 +378,16 @@ impl A380DirectCurrentElectrical {
         electricity.flow(&self.dc_bus_2, &self.refuel_on_bat_contactors[1]);
     }
 
+    pub fn update_battery_surrounding_temperature(
+        &mut self,
+        temperature: ThermodynamicTemperature,
+    ) {
+        self.battery_1.update_surrounding_temperature(temperature);
+        self.battery_2.update_surrounding_temperature(temperature);
+        self.battery_ess.update_surrounding_temperature(temperature);
+        self.battery_apu.update_surrounding_temperature(temperature);
+    }
+
     #[cfg(test)]
     pub fn battery_1(&self) -> &Battery {
         &self.battery_1
@@ -248,6 +248,14 @@ impl A380Electrical {
         self.alternating_current.tr_apu()
     }
 
+    pub fn update_battery_surrounding_temperature(
+        &mut self,
+        temperature: ThermodynamicTemperature,
+    ) {
+        self.direct_current
+            .update_battery_surrounding_temperature(temperature);
+    }
+
     #[cfg(test)]
     fn battery_1(&self) -> &Battery {
         self.direct_current.battery_1()
@@ -3342,6 +3350,14 @@ mod a380_electrical_circuit_tests {
                 self.corrected_n2().get::<ratio>() * 12200.,
             )
         }
+
+        fn exhaust_gas_temperature(&self) -> ThermodynamicTemperature {
+            ThermodynamicTemperature::new::<degree_celsius>(15.)
+        }
+
+        fn fan_is_damaged(&self) -> bool {
+            false
+        }
     }
 
     struct TestApuOverhead {
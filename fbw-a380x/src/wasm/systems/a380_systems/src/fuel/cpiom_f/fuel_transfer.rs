@@ -0,0 +1,666 @@
+use super::FEED_TANKS;
+use crate::fuel::{A380FuelPump, A380FuelTankType};
+use bitflags::bitflags;
+use enum_map::EnumMap;
+use systems::{fuel::network::FuelNetworkController, simulation::UpdateContext};
+use uom::si::{
+    f64::{Length, Mass, Ratio},
+    length::foot,
+    mass::kilogram,
+    ratio::percent,
+};
+
+bitflags! {
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+    pub(super) struct FuelTransferFlags: u32 {
+        const LEFT_INNER_TO_FEED = 1 << 0;
+        const RIGHT_INNER_TO_FEED = 1 << 1;
+        const LEFT_MID_TO_FEED = 1 << 2;
+        const RIGHT_MID_TO_FEED = 1 << 3;
+        const LEFT_OUTER_TO_FEED = 1 << 4;
+        const RIGHT_OUTER_TO_FEED = 1 << 5;
+        const TRIM_CG_CONTROL = 1 << 6;
+        const TRIM_END_OF_CRUISE = 1 << 7;
+    }
+}
+
+/// A wing tank supplying the feed tanks, with the transfer pumps emptying it.
+struct WingTransferSource {
+    tank: A380FuelTankType,
+    flag: FuelTransferFlags,
+    pumps: &'static [A380FuelPump],
+}
+
+/// The wing tanks of one side, which supply the feed tanks of the same side.
+struct WingTransferSide {
+    feed_tanks: [A380FuelTankType; 2],
+    /// Source tanks in the order in which they are emptied into the feed tanks.
+    sources: [WingTransferSource; 3],
+}
+
+const WING_TRANSFER_SIDES: [WingTransferSide; 2] = [
+    WingTransferSide {
+        feed_tanks: [A380FuelTankType::FeedOne, A380FuelTankType::FeedTwo],
+        sources: [
+            WingTransferSource {
+                tank: A380FuelTankType::LeftInner,
+                flag: FuelTransferFlags::LEFT_INNER_TO_FEED,
+                pumps: &[A380FuelPump::LeftInnerFwd, A380FuelPump::LeftInnerAft],
+            },
+            WingTransferSource {
+                tank: A380FuelTankType::LeftMid,
+                flag: FuelTransferFlags::LEFT_MID_TO_FEED,
+                pumps: &[A380FuelPump::LeftMidFwd, A380FuelPump::LeftMidAft],
+            },
+            WingTransferSource {
+                tank: A380FuelTankType::LeftOuter,
+                flag: FuelTransferFlags::LEFT_OUTER_TO_FEED,
+                pumps: &[A380FuelPump::LeftOuter],
+            },
+        ],
+    },
+    WingTransferSide {
+        feed_tanks: [A380FuelTankType::FeedThree, A380FuelTankType::FeedFour],
+        sources: [
+            WingTransferSource {
+                tank: A380FuelTankType::RightInner,
+                flag: FuelTransferFlags::RIGHT_INNER_TO_FEED,
+                pumps: &[A380FuelPump::RightInnerFwd, A380FuelPump::RightInnerAft],
+            },
+            WingTransferSource {
+                tank: A380FuelTankType::RightMid,
+                flag: FuelTransferFlags::RIGHT_MID_TO_FEED,
+                pumps: &[A380FuelPump::RightMidFwd, A380FuelPump::RightMidAft],
+            },
+            WingTransferSource {
+                tank: A380FuelTankType::RightOuter,
+                flag: FuelTransferFlags::RIGHT_OUTER_TO_FEED,
+                pumps: &[A380FuelPump::RightOuter],
+            },
+        ],
+    },
+];
+
+const TRIM_PUMPS: [A380FuelPump; 2] = [A380FuelPump::TrimLeft, A380FuelPump::TrimRight];
+
+/// # In-flight fuel transfer application of the FQMS
+///
+/// Keeps the feed tanks topped up from the wing tanks (inner, then mid, then outer tanks),
+/// controls the CG during cruise by transferring trim tank fuel forward into the feed tanks,
+/// and empties the trim tank into the feed tanks at the end of cruise.
+/// Transfer decisions are based on the measured quantities. The fuel is moved by the transfer
+/// pumps and feed tank inlet valves of the fuel network, which this application commands.
+// TODO: Take the end of cruise from the FMS
+#[derive(Default)]
+pub(super) struct FuelTransferApplication {
+    cruise_reached: bool,
+    cg_control_altitude_reached: bool,
+    end_of_cruise: bool,
+    cg_control_active: bool,
+    feed_tank_refill: EnumMap<A380FuelTankType, bool>,
+    transfers: FuelTransferFlags,
+
+    pumps_should_run: EnumMap<A380FuelPump, bool>,
+    /// The inlet valves of the feed tanks, in the order of the feed tanks.
+    inlet_valves_should_open: [bool; FEED_TANKS.len()],
+}
+impl FuelTransferApplication {
+    /// Quantity up to which the feed tanks are refilled.
+    const FEED_TANK_TARGET_KG: f64 = 20_000.;
+    /// A refill of a feed tank starts once its quantity has dropped this far below the target.
+    const FEED_TANK_REFILL_HYSTERESIS_KG: f64 = 1_500.;
+    /// Tanks holding less fuel than this are considered empty.
+    const UNUSABLE_FUEL_KG: f64 = 10.;
+
+    /// CG control is only performed above FL255. Below FL245 the cruise is considered to be over.
+    const CG_CONTROL_MIN_ALTITUDE_FT: f64 = 25_500.;
+    const END_OF_CRUISE_ALTITUDE_FT: f64 = 24_500.;
+
+    /// The cruise CG target lies this far aft of the ZFWCG entered in the FMS, but never aft of
+    /// the maximum target, which leaves margin for the CG travel towards the aft limit.
+    const CRUISE_CG_TARGET_AFT_OF_ZFWCG_PERCENT_MAC: f64 = 2.;
+    const MAX_CRUISE_CG_TARGET_PERCENT_MAC: f64 = 40.;
+    const CRUISE_CG_DEADBAND_PERCENT_MAC: f64 = 0.5;
+
+    pub(super) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(super) fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    pub(super) fn update(
+        &mut self,
+        context: &UpdateContext,
+        tank_quantities: &EnumMap<A380FuelTankType, Option<Mass>>,
+        altitude: Option<Length>,
+        center_of_gravity: Ratio,
+        zero_fuel_weight_cg: Option<Ratio>,
+    ) {
+        if context.is_on_ground() {
+            self.reset();
+            return;
+        }
+
+        self.update_flight_phase(altitude);
+        self.update_feed_tank_refill(tank_quantities);
+        self.update_cg_control(tank_quantities, center_of_gravity, zero_fuel_weight_cg);
+
+        self.transfers = FuelTransferFlags::empty();
+        self.pumps_should_run = EnumMap::default();
+        self.inlet_valves_should_open = Default::default();
+
+        let trim_has_fuel = Self::has_fuel(tank_quantities, A380FuelTankType::Trim);
+        let feed_tanks_below_target = FEED_TANKS.iter().any(|&feed_tank| {
+            tank_quantities[feed_tank].is_some_and(|quantity| quantity < Self::feed_tank_target())
+        });
+        if trim_has_fuel && feed_tanks_below_target {
+            if self.end_of_cruise {
+                self.transfers |= FuelTransferFlags::TRIM_END_OF_CRUISE;
+            } else if self.cg_control_active {
+                self.transfers |= FuelTransferFlags::TRIM_CG_CONTROL;
+            }
+        }
+
+        if self.is_trim_transfer_active() {
+            for pump in TRIM_PUMPS {
+                self.pumps_should_run[pump] = true;
+            }
+            for (open, &feed_tank) in self.inlet_valves_should_open.iter_mut().zip(&FEED_TANKS) {
+                *open = tank_quantities[feed_tank]
+                    .is_some_and(|quantity| quantity < Self::feed_tank_target());
+            }
+            // The trim transfer has priority, the wing tanks wait until it is finished
+            return;
+        }
+
+        for side in &WING_TRANSFER_SIDES {
+            if !side
+                .feed_tanks
+                .iter()
+                .any(|&tank| self.feed_tank_refill[tank])
+            {
+                continue;
+            }
+
+            if let Some(source) = side
+                .sources
+                .iter()
+                .find(|source| Self::has_fuel(tank_quantities, source.tank))
+            {
+                self.transfers |= source.flag;
+                for &pump in source.pumps {
+                    self.pumps_should_run[pump] = true;
+                }
+                for (open, feed_tank) in self.inlet_valves_should_open.iter_mut().zip(FEED_TANKS) {
+                    *open |=
+                        side.feed_tanks.contains(&feed_tank) && self.feed_tank_refill[feed_tank];
+                }
+            }
+        }
+    }
+
+    pub(super) fn transfers(&self) -> FuelTransferFlags {
+        self.transfers
+    }
+
+    fn is_trim_transfer_active(&self) -> bool {
+        self.transfers
+            .intersects(FuelTransferFlags::TRIM_CG_CONTROL | FuelTransferFlags::TRIM_END_OF_CRUISE)
+    }
+
+    fn update_flight_phase(&mut self, altitude: Option<Length>) {
+        let Some(altitude) = altitude else {
+            self.cg_control_altitude_reached = false;
+            return;
+        };
+
+        self.cg_control_altitude_reached =
+            altitude.get::<foot>() >= Self::CG_CONTROL_MIN_ALTITUDE_FT;
+        self.cruise_reached |= self.cg_control_altitude_reached;
+        self.end_of_cruise |=
+            self.cruise_reached && altitude.get::<foot>() < Self::END_OF_CRUISE_ALTITUDE_FT;
+    }
+
+    fn update_feed_tank_refill(
+        &mut self,
+        tank_quantities: &EnumMap<A380FuelTankType, Option<Mass>>,
+    ) {
+        for feed_tank in FEED_TANKS {
+            self.feed_tank_refill[feed_tank] = match tank_quantities[feed_tank] {
+                Some(quantity) if quantity >= Self::feed_tank_target() => false,
+                Some(quantity)
+                    if quantity
+                        < Self::feed_tank_target()
+                            - Mass::new::<kilogram>(Self::FEED_TANK_REFILL_HYSTERESIS_KG) =>
+                {
+                    true
+                }
+                Some(_) => self.feed_tank_refill[feed_tank],
+                None => false,
+            };
+        }
+    }
+
+    /// Without a ZFWCG from the FMS there is no CG target, thus no CG control.
+    fn update_cg_control(
+        &mut self,
+        tank_quantities: &EnumMap<A380FuelTankType, Option<Mass>>,
+        center_of_gravity: Ratio,
+        zero_fuel_weight_cg: Option<Ratio>,
+    ) {
+        let center_of_gravity = center_of_gravity.get::<percent>();
+        self.cg_control_active = match Self::cruise_cg_target(zero_fuel_weight_cg) {
+            Some(target) => {
+                self.cg_control_altitude_reached
+                    && !self.end_of_cruise
+                    && Self::has_fuel(tank_quantities, A380FuelTankType::Trim)
+                    && if self.cg_control_active {
+                        center_of_gravity > target
+                    } else {
+                        center_of_gravity > target + Self::CRUISE_CG_DEADBAND_PERCENT_MAC
+                    }
+            }
+            None => false,
+        };
+    }
+
+    fn cruise_cg_target(zero_fuel_weight_cg: Option<Ratio>) -> Option<f64> {
+        zero_fuel_weight_cg.map(|zero_fuel_weight_cg| {
+            (zero_fuel_weight_cg.get::<percent>() + Self::CRUISE_CG_TARGET_AFT_OF_ZFWCG_PERCENT_MAC)
+                .min(Self::MAX_CRUISE_CG_TARGET_PERCENT_MAC)
+        })
+    }
+
+    fn has_fuel(
+        tank_quantities: &EnumMap<A380FuelTankType, Option<Mass>>,
+        tank: A380FuelTankType,
+    ) -> bool {
+        tank_quantities[tank]
+            .is_some_and(|quantity| quantity > Mass::new::<kilogram>(Self::UNUSABLE_FUEL_KG))
+    }
+
+    fn feed_tank_target() -> Mass {
+        Mass::new::<kilogram>(Self::FEED_TANK_TARGET_KG)
+    }
+}
+impl FuelNetworkController for FuelTransferApplication {
+    fn pump_should_run(&self, pump: usize) -> bool {
+        self.pumps_should_run[A380FuelPump::from_transfer_pump_index(pump)]
+    }
+
+    fn valve_should_open(&self, valve: usize) -> bool {
+        self.inlet_valves_should_open[valve]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fuel::{A380FuelSystem, FuelPumpStatus};
+    use std::time::Duration;
+    use systems::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        fuel::{FuelPayload, FUEL_GALLONS_TO_KG},
+        shared::{ElectricalBusType, PotentialOrigin},
+        simulation::{
+            test::{SimulationTestBed, TestBed, WriteByName},
+            Aircraft, InitContext, SimulationElement, SimulationElementVisitor,
+        },
+    };
+    use uom::si::{electric_potential::volt, f64::ElectricPotential};
+
+    struct TestAircraft {
+        powered_source: TestElectricitySource,
+        buses: Vec<ElectricalBus>,
+
+        fuel_transfer_application: FuelTransferApplication,
+        fuel_system: A380FuelSystem,
+        altitude: Option<Length>,
+        center_of_gravity: Ratio,
+        zero_fuel_weight_cg: Option<Ratio>,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                buses: [
+                    ElectricalBusType::AlternatingCurrent(1),
+                    ElectricalBusType::AlternatingCurrent(2),
+                    ElectricalBusType::AlternatingCurrent(3),
+                    ElectricalBusType::AlternatingCurrent(4),
+                    ElectricalBusType::AlternatingCurrentEssential,
+                    ElectricalBusType::DirectCurrent(1),
+                    ElectricalBusType::DirectCurrent(2),
+                ]
+                .into_iter()
+                .map(|bus_type| ElectricalBus::new(context, bus_type))
+                .collect(),
+
+                fuel_transfer_application: FuelTransferApplication::new(),
+                fuel_system: A380FuelSystem::new(context),
+                altitude: Some(Length::new::<foot>(35_000.)),
+                center_of_gravity: Ratio::new::<percent>(38.),
+                zero_fuel_weight_cg: Some(Ratio::new::<percent>(38.)),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.powered_source
+                .power_with_potential(ElectricPotential::new::<volt>(115.));
+            electricity.supplied_by(&self.powered_source);
+            for bus in &self.buses {
+                electricity.flow(&self.powered_source, bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            let tank_quantities = EnumMap::from_fn(|tank: A380FuelTankType| {
+                Some(self.fuel_system.tank_mass(tank as usize))
+            });
+            self.fuel_transfer_application.update(
+                context,
+                &tank_quantities,
+                self.altitude,
+                self.center_of_gravity,
+                self.zero_fuel_weight_cg,
+            );
+            self.fuel_system
+                .update_transfers(context, &self.fuel_transfer_application);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.fuel_system.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct FuelTransferTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl FuelTransferTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_on_ground(false);
+            test_bed
+                .with_tank(A380FuelTankType::FeedOne, 20_000.)
+                .with_tank(A380FuelTankType::FeedTwo, 20_000.)
+                .with_tank(A380FuelTankType::FeedThree, 20_000.)
+                .with_tank(A380FuelTankType::FeedFour, 20_000.)
+        }
+
+        fn with_tank(mut self, tank: A380FuelTankType, quantity_kg: f64) -> Self {
+            self.write_by_name(
+                &format!("FUEL_TANK_QUANTITY_{}", tank as usize + 1),
+                quantity_kg / FUEL_GALLONS_TO_KG,
+            );
+            self
+        }
+
+        fn at_altitude(mut self, altitude_ft: f64) -> Self {
+            self.command(|a| a.altitude = Some(Length::new::<foot>(altitude_ft)));
+            self
+        }
+
+        fn with_cg(mut self, cg_percent_mac: f64) -> Self {
+            self.command(|a| a.center_of_gravity = Ratio::new::<percent>(cg_percent_mac));
+            self
+        }
+
+        fn with_fms_zero_fuel_weight_cg(mut self, cg_percent_mac: Option<f64>) -> Self {
+            self.command(|a| a.zero_fuel_weight_cg = cg_percent_mac.map(Ratio::new::<percent>));
+            self
+        }
+
+        fn on_ground(mut self) -> Self {
+            self.set_on_ground(true);
+            self
+        }
+
+        fn and_run_for(mut self, duration: Duration) -> Self {
+            self.test_bed.run_multiple_frames(duration);
+            self
+        }
+
+        fn tank_quantity(&self, tank: A380FuelTankType) -> Mass {
+            self.query(|a| a.fuel_system.tank_mass(tank as usize))
+        }
+
+        fn assert_tank_quantity_about(&self, tank: A380FuelTankType, quantity_kg: f64) {
+            let quantity = self.tank_quantity(tank).get::<kilogram>();
+            assert!(
+                (quantity - quantity_kg).abs() < 5.,
+                "{tank} holds {quantity} kg instead of {quantity_kg} kg"
+            );
+        }
+
+        fn is_fuel_pump_running(&self, pump: A380FuelPump) -> bool {
+            self.query(|a| a.fuel_system.is_fuel_pump_running(pump))
+        }
+
+        fn transfers(&self) -> FuelTransferFlags {
+            self.query(|a| a.fuel_transfer_application.transfers())
+        }
+    }
+    impl TestBed for FuelTransferTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> FuelTransferTestBed {
+        FuelTransferTestBed::new()
+    }
+
+    #[test]
+    fn no_transfer_while_feed_tanks_are_full() {
+        let test_bed = test_bed()
+            .with_tank(A380FuelTankType::LeftInner, 10_000.)
+            .and_run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.transfers(), FuelTransferFlags::empty());
+        test_bed.assert_tank_quantity_about(A380FuelTankType::LeftInner, 10_000.);
+    }
+
+    #[test]
+    fn inner_tank_refills_feed_tanks_of_the_same_side() {
+        let test_bed = test_bed()
+            .with_tank(A380FuelTankType::FeedOne, 15_000.)
+            .with_tank(A380FuelTankType::LeftInner, 10_000.)
+            .with_tank(A380FuelTankType::LeftMid, 10_000.)
+            .with_tank(A380FuelTankType::RightInner, 10_000.)
+            .and_run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.transfers(), FuelTransferFlags::LEFT_INNER_TO_FEED);
+        assert!(test_bed.is_fuel_pump_running(A380FuelPump::LeftInnerFwd));
+        assert!(test_bed.is_fuel_pump_running(A380FuelPump::LeftInnerAft));
+        assert!(!test_bed.is_fuel_pump_running(A380FuelPump::LeftMidFwd));
+        assert!(test_bed.tank_quantity(A380FuelTankType::FeedOne) > Mass::new::<kilogram>(15_000.));
+        assert!(
+            test_bed.tank_quantity(A380FuelTankType::LeftInner) < Mass::new::<kilogram>(10_000.)
+        );
+        test_bed.assert_tank_quantity_about(A380FuelTankType::LeftMid, 10_000.);
+        test_bed.assert_tank_quantity_about(A380FuelTankType::RightInner, 10_000.);
+    }
+
+    #[test]
+    fn feed_tank_refill_stops_at_target() {
+        let test_bed = test_bed()
+            .with_tank(A380FuelTankType::FeedTwo, 18_000.)
+            .with_tank(A380FuelTankType::LeftInner, 10_000.)
+            .and_run_for(Duration::from_secs(1_000));
+
+        assert_eq!(test_bed.transfers(), FuelTransferFlags::empty());
+        test_bed.assert_tank_quantity_about(A380FuelTankType::FeedTwo, 20_000.);
+        test_bed.assert_tank_quantity_about(A380FuelTankType::LeftInner, 8_000.);
+    }
+
+    #[test]
+    fn mid_and_outer_tanks_are_used_once_inner_tanks_are_empty() {
+        let test_bed = test_bed()
+            .with_tank(A380FuelTankType::FeedFour, 15_000.)
+            .with_tank(A380FuelTankType::RightMid, 1_000.)
+            .with_tank(A380FuelTankType::RightOuter, 5_000.)
+            .and_run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.transfers(), FuelTransferFlags::RIGHT_MID_TO_FEED);
+
+        let test_bed = test_bed.and_run_for(Duration::from_secs(600));
+
+        assert_eq!(test_bed.transfers(), FuelTransferFlags::RIGHT_OUTER_TO_FEED);
+        assert!(test_bed.tank_quantity(A380FuelTankType::RightMid) < Mass::new::<kilogram>(11.));
+    }
+
+    #[test]
+    fn no_transfer_on_ground() {
+        let test_bed = test_bed()
+            .on_ground()
+            .with_tank(A380FuelTankType::FeedOne, 15_000.)
+            .with_tank(A380FuelTankType::LeftInner, 10_000.)
+            .and_run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.transfers(), FuelTransferFlags::empty());
+        test_bed.assert_tank_quantity_about(A380FuelTankType::FeedOne, 15_000.);
+    }
+
+    #[test]
+    fn trim_fuel_is_transferred_forward_when_cg_is_aft_of_target_in_cruise() {
+        let test_bed = test_bed()
+            .with_tank(A380FuelTankType::FeedOne, 19_000.)
+            .with_tank(A380FuelTankType::Trim, 10_000.)
+            .with_tank(A380FuelTankType::LeftInner, 10_000.)
+            .with_cg(41.)
+            .and_run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.transfers(), FuelTransferFlags::TRIM_CG_CONTROL);
+        assert!(test_bed.tank_quantity(A380FuelTankType::Trim) < Mass::new::<kilogram>(10_000.));
+        assert!(test_bed.tank_quantity(A380FuelTankType::FeedOne) > Mass::new::<kilogram>(19_000.));
+        test_bed.assert_tank_quantity_about(A380FuelTankType::LeftInner, 10_000.);
+    }
+
+    #[test]
+    fn cg_control_stops_once_cg_reaches_target() {
+        let test_bed = test_bed()
+            .with_tank(A380FuelTankType::FeedOne, 19_000.)
+            .with_tank(A380FuelTankType::Trim, 10_000.)
+            .with_cg(41.)
+            .and_run_for(Duration::from_secs(10))
+            .with_cg(40.2)
+            .and_run_for(Duration::from_secs(1));
+
+        assert_eq!(test_bed.transfers(), FuelTransferFlags::TRIM_CG_CONTROL);
+
+        let test_bed = test_bed.with_cg(39.9).and_run_for(Duration::from_secs(1));
+
+        assert_eq!(test_bed.transfers(), FuelTransferFlags::empty());
+    }
+
+    #[test]
+    fn cruise_cg_target_follows_the_fms_zero_fuel_weight_cg() {
+        let test_bed = test_bed()
+            .with_tank(A380FuelTankType::FeedOne, 19_000.)
+            .with_tank(A380FuelTankType::Trim, 10_000.)
+            .with_fms_zero_fuel_weight_cg(Some(35.))
+            .with_cg(38.)
+            .and_run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.transfers(), FuelTransferFlags::TRIM_CG_CONTROL);
+
+        let test_bed = test_bed
+            .with_fms_zero_fuel_weight_cg(Some(36.))
+            .and_run_for(Duration::from_secs(1));
+
+        assert_eq!(test_bed.transfers(), FuelTransferFlags::empty());
+    }
+
+    #[test]
+    fn cruise_cg_target_is_limited() {
+        let test_bed = test_bed()
+            .with_tank(A380FuelTankType::FeedOne, 19_000.)
+            .with_tank(A380FuelTankType::Trim, 10_000.)
+            .with_fms_zero_fuel_weight_cg(Some(41.))
+            .with_cg(41.)
+            .and_run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.transfers(), FuelTransferFlags::TRIM_CG_CONTROL);
+    }
+
+    #[test]
+    fn no_cg_control_without_fms_zero_fuel_weight_cg() {
+        let test_bed = test_bed()
+            .with_tank(A380FuelTankType::FeedOne, 19_000.)
+            .with_tank(A380FuelTankType::Trim, 10_000.)
+            .with_fms_zero_fuel_weight_cg(None)
+            .with_cg(41.)
+            .and_run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.transfers(), FuelTransferFlags::empty());
+        test_bed.assert_tank_quantity_about(A380FuelTankType::Trim, 10_000.);
+    }
+
+    #[test]
+    fn no_cg_control_below_fl255() {
+        let test_bed = test_bed()
+            .at_altitude(20_000.)
+            .with_tank(A380FuelTankType::FeedOne, 19_000.)
+            .with_tank(A380FuelTankType::Trim, 10_000.)
+            .with_cg(41.)
+            .and_run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.transfers(), FuelTransferFlags::empty());
+        test_bed.assert_tank_quantity_about(A380FuelTankType::Trim, 10_000.);
+    }
+
+    #[test]
+    fn trim_tank_is_emptied_into_feed_tanks_at_end_of_cruise() {
+        let test_bed = test_bed()
+            .with_tank(A380FuelTankType::FeedOne, 15_000.)
+            .with_tank(A380FuelTankType::FeedTwo, 15_000.)
+            .with_tank(A380FuelTankType::FeedThree, 15_000.)
+            .with_tank(A380FuelTankType::FeedFour, 15_000.)
+            .with_tank(A380FuelTankType::Trim, 3_000.)
+            .and_run_for(Duration::from_secs(1))
+            .at_altitude(20_000.)
+            .and_run_for(Duration::from_secs(10));
+
+        assert_eq!(test_bed.transfers(), FuelTransferFlags::TRIM_END_OF_CRUISE);
+
+        let test_bed = test_bed.and_run_for(Duration::from_secs(1_000));
+
+        assert!(test_bed.tank_quantity(A380FuelTankType::Trim) < Mass::new::<kilogram>(11.));
+        test_bed.assert_tank_quantity_about(A380FuelTankType::FeedOne, 15_750.);
+    }
+
+    #[test]
+    fn no_end_of_cruise_transfer_before_cruise_was_reached() {
+        let test_bed = test_bed()
+            .at_altitude(20_000.)
+            .with_tank(A380FuelTankType::FeedOne, 15_000.)
+            .with_tank(A380FuelTankType::Trim, 3_000.)
+            .and_run_for(Duration::from_secs(10));
+
+        assert!(!test_bed
+            .transfers()
+            .contains(FuelTransferFlags::TRIM_END_OF_CRUISE));
+        test_bed.assert_tank_quantity_about(A380FuelTankType::Trim, 3_000.);
+    }
+}
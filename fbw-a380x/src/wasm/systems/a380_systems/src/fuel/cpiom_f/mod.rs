@@ -1,4 +1,5 @@
 mod fuel_measuring;
+mod fuel_transfer;
 
 use super::{A380FuelTankType, SetFuelLevel};
 use crate::{
@@ -6,18 +7,21 @@ use crate::{
     systems::simulation::SimulationElement,
 };
 use bitflags::{bitflags, Flags};
-use enum_map::Enum;
+use enum_map::{Enum, EnumMap};
 use fuel_measuring::FuelMeasuringApplication;
+use fuel_transfer::FuelTransferApplication;
 use serde::Deserialize;
 use serde_with::{serde_as, DisplayFromStr};
 use std::{collections::HashMap, time::Duration};
 use systems::{
-    fuel::{self, FuelPayload, RefuelRate},
+    airframe::CenterOfGravityData,
+    fuel::{self, network::FuelNetworkController, FuelPayload, RefuelRate},
     payload::LoadsheetInfo,
     pneumatic::EngineState,
     shared::{
         arinc429::{Arinc429Word, SignStatus},
-        ConsumePower, DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, Resolution,
+        AdirsMeasurementOutputs, ConsumePower, DelayedTrueLogicGate, ElectricalBusType,
+        ElectricalBuses, Resolution,
     },
     simulation::{
         InitContext, Read, Reader, SimulationElementVisitor, SimulatorReader, SimulatorWriter,
@@ -25,14 +29,14 @@ use systems::{
     },
 };
 use uom::si::{
-    f64::{Mass, Power, Ratio, Velocity},
+    f64::{Length, Mass, Power, Ratio, Velocity},
     mass::kilogram,
     power::watt,
     ratio::percent,
     velocity::knot,
 };
 
-const FEED_TANKS: [A380FuelTankType; 4] = [
+pub(super) const FEED_TANKS: [A380FuelTankType; 4] = [
     A380FuelTankType::FeedOne,
     A380FuelTankType::FeedTwo,
     A380FuelTankType::FeedThree,
@@ -635,7 +639,7 @@ bitflags! {
 
 /// # A380 Fuel Quantity Management System (FQMS)
 ///
-/// Handles fuel quantity measurement, refueling, and pump state reporting.
+/// Handles fuel quantity measurement, refueling, in-flight fuel transfers and pump state reporting.
 /// We simulate both FQMS sides together here for simplicity and performance reasons.
 // TODO: This is a preliminary implementation and needs to be expanded to cover all FQMS functionalities
 // TODO: implement AFDX communication (FMS data, pump states, valve states, etc.)
@@ -643,6 +647,7 @@ pub(super) struct A380FuelQuantityManagementSystem {
     self_test_finished: DelayedTrueLogicGate,
     cpioms_available: [bool; 4],
     fuel_measuring_application: FuelMeasuringApplication,
+    fuel_transfer_application: FuelTransferApplication,
     refuel_application: RefuelApplication,
     integrated_refuel_panel: IntegratedRefuelPanel,
 
@@ -666,6 +671,8 @@ pub(super) struct A380FuelQuantityManagementSystem {
 
     fqms_status_word_id: VariableIdentifier,
     fqms_status_word: FQMSDiscreteFlags,
+
+    fuel_transfer_status_word_id: VariableIdentifier,
 }
 impl A380FuelQuantityManagementSystem {
     // Self test time (reference: FFS)
@@ -679,6 +686,7 @@ impl A380FuelQuantityManagementSystem {
             self_test_finished: DelayedTrueLogicGate::new(Self::SELF_TEST_DURATION),
             cpioms_available: [false; 4],
             fuel_measuring_application: FuelMeasuringApplication::new(),
+            fuel_transfer_application: FuelTransferApplication::new(),
             refuel_application: RefuelApplication::new(context),
             integrated_refuel_panel: IntegratedRefuelPanel::new(
                 context,
@@ -709,6 +717,9 @@ impl A380FuelQuantityManagementSystem {
 
             fqms_status_word_id: context.get_identifier("FQMS_STATUS_WORD".to_owned()),
             fqms_status_word: Default::default(),
+
+            fuel_transfer_status_word_id: context
+                .get_identifier("FQMS_FUEL_TRANSFER_STATUS_WORD".to_owned()),
         }
     }
 
@@ -719,6 +730,8 @@ impl A380FuelQuantityManagementSystem {
         loadsheet: &LoadsheetInfo,
         fqdcs: &[FuelQuantityDataConcentrator; 2],
         cpioms_available: [bool; 4],
+        adirs: &impl AdirsMeasurementOutputs,
+        center_of_gravity: &CenterOfGravityData,
     ) {
         // Currently this is excluded from the powered check to support
         // the current "legacy" refuel system implementation.
@@ -755,10 +768,26 @@ impl A380FuelQuantityManagementSystem {
 
         self.fuel_measuring_application
             .update(loadsheet, selected_fqdc, fms_zfw, fms_zfwcg);
+
+        let tank_quantities =
+            EnumMap::from_fn(|tank| self.fuel_measuring_application.tank_quantity(tank));
+        self.fuel_transfer_application.update(
+            context,
+            &tank_quantities,
+            Self::altitude(adirs),
+            Ratio::new::<percent>(center_of_gravity.gross_weight_center_of_gravity()),
+            fms_zfwcg,
+        );
+    }
+
+    /// The transfer pumps and feed tank inlet valves are commanded by the fuel transfer application.
+    pub(super) fn fuel_transfer_controller(&self) -> &impl FuelNetworkController {
+        &self.fuel_transfer_application
     }
 
     fn reset(&mut self) {
         self.fuel_measuring_application.reset();
+        self.fuel_transfer_application.reset();
         self.fuel_pump_running_words = Default::default();
         self.fms_zero_fuel_weights = Default::default();
         self.fms_zero_fuel_weight_cgs = Default::default();
@@ -770,6 +799,11 @@ impl A380FuelQuantityManagementSystem {
         &mut self.refuel_application
     }
 
+    /// Returns the altitude of the first ADIRU providing valid data.
+    fn altitude(adirs: &impl AdirsMeasurementOutputs) -> Option<Length> {
+        (1..=3).find_map(|adiru_number| adirs.altitude(adiru_number).normal_value())
+    }
+
     /// Extracts the FMS data by get the first one available.
     /// Simultaniously it is checked that both values agree if multiple values are available.
     fn get_fms_data_and_status<T: PartialEq>(
@@ -904,5 +938,11 @@ impl SimulationElement for A380FuelQuantityManagementSystem {
             self.fqms_status_word,
             is_powered,
         );
+        self.write_arinc429_bitflags(
+            writer,
+            &self.fuel_transfer_status_word_id,
+            self.fuel_transfer_application.transfers(),
+            is_powered,
+        );
     }
 }
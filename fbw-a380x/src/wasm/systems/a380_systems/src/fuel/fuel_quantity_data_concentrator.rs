@@ -1,4 +1,4 @@
-use super::{A380FuelPump, FuelLevel, FuelPumpStatus};
+use super::{A380FuelPump, FuelLevel, FuelPumpStatus, FuelTankTemperature};
 use crate::fuel::{A380FuelTankType, ArincFuelPumpStatusProvider, ArincFuelQuantityProvider};
 use enum_map::Enum;
 use systems::{
@@ -9,7 +9,7 @@ use systems::{
     simulation::{InitContext, SimulationElement, SimulatorWriter, VariableIdentifier, Write},
 };
 use uom::{
-    si::{f64::*, mass::kilogram},
+    si::{f64::*, mass::kilogram, thermodynamic_temperature::degree_celsius},
     ConstZero,
 };
 
@@ -20,6 +20,11 @@ pub(super) struct FuelQuantityDataConcentrator {
     tank_quantity_identifiers: [VariableIdentifier; A380FuelTankType::LENGTH],
     tank_quantities: [Arinc429Word<Mass>; A380FuelTankType::LENGTH],
 
+    tank_temperature_identifiers: [VariableIdentifier; A380FuelTankType::LENGTH],
+    tank_temperatures: [Arinc429Word<ThermodynamicTemperature>; A380FuelTankType::LENGTH],
+    low_fuel_temperature_id: VariableIdentifier,
+    low_fuel_temperature: Arinc429Word<u32>,
+
     left_fuel_pump_running: Arinc429Word<u32>,
     right_fuel_pump_running: Arinc429Word<u32>,
 }
@@ -63,14 +68,34 @@ impl FuelQuantityDataConcentrator {
             tank_quantities: [Arinc429Word::new(Mass::ZERO, SignStatus::FailureWarning);
                 A380FuelTankType::LENGTH],
 
+            tank_temperature_identifiers: A380FuelTankType::iterator()
+                .map(|tank_type| {
+                    context.get_identifier(format!("FQDC_{id}_{tank_type}_TEMPERATURE"))
+                })
+                .collect::<Vec<_>>()
+                .try_into()
+                .expect("Failed to create fuel temperature identifiers array"),
+            tank_temperatures: [Arinc429Word::new(
+                ThermodynamicTemperature::default(),
+                SignStatus::FailureWarning,
+            ); A380FuelTankType::LENGTH],
+            low_fuel_temperature_id: context
+                .get_identifier(format!("FQDC_{id}_LOW_FUEL_TEMPERATURE_WORD")),
+            low_fuel_temperature: Arinc429Word::new(0, SignStatus::FailureWarning),
+
             left_fuel_pump_running: Arinc429Word::new(0, SignStatus::FailureWarning),
             right_fuel_pump_running: Arinc429Word::new(0, SignStatus::FailureWarning),
         }
     }
 
-    pub(super) fn update(&mut self, fuel_levels: &(impl FuelLevel + FuelPumpStatus)) {
+    pub(super) fn update(
+        &mut self,
+        fuel_levels: &(impl FuelLevel + FuelPumpStatus + FuelTankTemperature),
+    ) {
         if !self.is_powered {
             self.tank_quantities = Default::default();
+            self.tank_temperatures = Default::default();
+            self.low_fuel_temperature = Arinc429Word::default();
             self.left_fuel_pump_running = Arinc429Word::default();
             self.right_fuel_pump_running = Arinc429Word::default();
             return;
@@ -101,6 +126,16 @@ impl FuelQuantityDataConcentrator {
         self.tank_quantities[A380FuelTankType::Trim.into_usize()] =
             Arinc429Word::new(fuel_levels.trim_tank_quantity(), ssm);
 
+        for tank in A380FuelTankType::iterator() {
+            self.tank_temperatures[tank.into_usize()] =
+                Arinc429Word::new(fuel_levels.tank_temperature(tank), ssm);
+        }
+        // Bits 11 to 21 indicate the fuel of the corresponding tank approaches its freezing point
+        let low_fuel_temperature = A380FuelTankType::iterator().fold(0, |value, tank| {
+            value | ((fuel_levels.tank_temperature_is_low(tank) as u32) << (11 + tank.into_usize()))
+        });
+        self.low_fuel_temperature = Arinc429Word::new(low_fuel_temperature, ssm);
+
         self.left_fuel_pump_running =
             Self::update_fuel_pump_state(fuel_levels, ssm, Self::LEFT_FUEL_PUMPS);
         self.right_fuel_pump_running =
@@ -153,6 +188,22 @@ impl SimulationElement for FuelQuantityDataConcentrator {
                 quantity.ssm(),
             );
         }
+        for (identifier, temperature) in self
+            .tank_temperature_identifiers
+            .iter()
+            .zip(self.tank_temperatures)
+        {
+            writer.write_arinc429(
+                identifier,
+                temperature.value().get::<degree_celsius>(),
+                temperature.ssm(),
+            );
+        }
+        writer.write_arinc429(
+            &self.low_fuel_temperature_id,
+            self.low_fuel_temperature.value(),
+            self.low_fuel_temperature.ssm(),
+        );
     }
 }
 
@@ -179,6 +230,9 @@ mod tests {
             "FQDC_0_LEFT_INNER_TANK_QUANTITY",
             "FQDC_0_RIGHT_INNER_TANK_QUANTITY",
             "FQDC_0_TRIM_TANK_QUANTITY",
+            "FQDC_0_FEED_1_TANK_TEMPERATURE",
+            "FQDC_0_TRIM_TANK_TEMPERATURE",
+            "FQDC_0_LOW_FUEL_TEMPERATURE_WORD",
         ] {
             assert!(
                 test_bed.contains_variable_with_name(var_name),
@@ -1,20 +1,29 @@
-// Note: Fuel system for now is still handled in MSFS. This is used for calculating fuel-related factors.
+// Note: The engine feed is still handled in MSFS. The fuel transfers run through the transfer network
+// of the A380 fuel system, the rest is used for calculating fuel-related factors.
 
 mod cpiom_f;
 mod fuel_quantity_data_concentrator;
 use crate::{
     avionics_data_communication_network::A380AvionicsDataCommunicationNetwork,
-    fuel::cpiom_f::A380FuelQuantityManagementSystem,
+    fuel::cpiom_f::{A380FuelQuantityManagementSystem, FEED_TANKS},
 };
-use enum_map::{enum_map, Enum};
+use enum_map::Enum;
 use fuel_quantity_data_concentrator::FuelQuantityDataConcentrator;
 use nalgebra::Vector3;
 use systems::{
     accept_iterable,
-    fuel::{FuelCG, FuelInfo, FuelPayload, FuelPump, FuelPumpProperties, FuelSystem},
+    airframe::CenterOfGravityData,
+    failures::FailureType,
+    fuel::{
+        network::{
+            ElectricFuelPump, ElectricFuelPumpProperties, FuelNetwork, FuelNetworkController,
+            FuelValve, FuelValveConnection, FuelValveProperties,
+        },
+        FuelCG, FuelInfo, FuelPayload, FuelPump, FuelPumpProperties, FuelSystem,
+    },
     integrated_modular_avionics::AvionicsDataCommunicationNetwork,
     payload::LoadsheetInfo,
-    shared::{arinc429::Arinc429Word, ElectricalBusType},
+    shared::{arinc429::Arinc429Word, AdirsMeasurementOutputs, ElectricalBusType},
     simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
 use uom::si::f64::*;
@@ -36,6 +45,11 @@ pub trait FuelLevel {
     fn trim_tank_quantity(&self) -> Mass;
 }
 
+pub(crate) trait FuelTankTemperature {
+    fn tank_temperature(&self, tank: A380FuelTankType) -> ThermodynamicTemperature;
+    fn tank_temperature_is_low(&self, tank: A380FuelTankType) -> bool;
+}
+
 trait FuelPumpStatus {
     fn is_fuel_pump_running(&self, pump: A380FuelPump) -> bool;
 }
@@ -128,6 +142,19 @@ enum A380FuelPump {
     TrimLeft,
     TrimRight,
 }
+impl A380FuelPump {
+    /// The transfer pumps follow the feed tank pumps.
+    const FIRST_TRANSFER_PUMP: A380FuelPump = A380FuelPump::LeftOuter;
+
+    fn transfer_pump_index(self) -> Option<usize> {
+        self.into_usize()
+            .checked_sub(Self::FIRST_TRANSFER_PUMP.into_usize())
+    }
+
+    fn from_transfer_pump_index(index: usize) -> Self {
+        Self::from_usize(Self::FIRST_TRANSFER_PUMP.into_usize() + index)
+    }
+}
 
 pub(crate) struct A380Fuel {
     fuel_system: A380FuelSystem,
@@ -153,8 +180,11 @@ impl A380Fuel {
         context: &UpdateContext,
         acdn: &A380AvionicsDataCommunicationNetwork,
         loadsheet: &LoadsheetInfo,
+        adirs: &impl AdirsMeasurementOutputs,
+        center_of_gravity: &CenterOfGravityData,
     ) {
         let cpioms = ["F1", "F2", "F3", "F4"].map(|id| acdn.get_cpiom(id));
+        self.fuel_system.update_temperatures(context);
         for fqdc in &mut self.fuel_quantity_data_concentrators {
             fqdc.update(&self.fuel_system);
         }
@@ -164,6 +194,13 @@ impl A380Fuel {
             loadsheet,
             &self.fuel_quantity_data_concentrators,
             cpioms.map(|cpiom| cpiom.is_available()),
+            adirs,
+            center_of_gravity,
+        );
+        self.fuel_system.update_transfers(
+            context,
+            self.fuel_quantity_management_system
+                .fuel_transfer_controller(),
         );
     }
 
@@ -188,6 +225,15 @@ impl FuelCG for A380Fuel {
         self.fuel_system.center_of_gravity()
     }
 }
+impl FuelTankTemperature for A380Fuel {
+    fn tank_temperature(&self, tank: A380FuelTankType) -> ThermodynamicTemperature {
+        self.fuel_system.tank_temperature(tank)
+    }
+
+    fn tank_temperature_is_low(&self, tank: A380FuelTankType) -> bool {
+        self.fuel_system.tank_temperature_is_low(tank)
+    }
+}
 impl SimulationElement for A380Fuel {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_system.accept(visitor);
@@ -198,8 +244,13 @@ impl SimulationElement for A380Fuel {
     }
 }
 
+/// The engines are still fed by the MSFS fuel system, thus the network only models the transfer
+/// galleries: the wing and trim transfer pumps of each side deliver into a gallery, from which
+/// the inlet valves of the feed tanks of that side are filled.
+// TODO: Split the galleries into the forward and aft galleries and model the trim gallery
 struct A380FuelSystem {
-    fuel_system: FuelSystem<11, 20>,
+    fuel_system: FuelSystem<11, 8>,
+    transfer_network: FuelNetwork,
 }
 
 impl A380FuelSystem {
@@ -273,9 +324,122 @@ impl A380FuelSystem {
         },
     ];
 
+    const TRANSFER_PUMP_PRESSURE_PSI: f64 = 20.;
+    const WING_TRANSFER_PUMP_MAX_FLOW_KG_PER_S: f64 = 5.;
+    const TRIM_TRANSFER_PUMP_MAX_FLOW_KG_PER_S: f64 = 2.5;
+
+    /// The transfer pumps, in the order of [`A380FuelPump`], with their tank and transfer gallery.
+    const TRANSFER_PUMPS: [(
+        &'static str,
+        A380FuelTankType,
+        usize,
+        ElectricalBusType,
+        f64,
+        f64,
+    ); 12] = [
+        (
+            "LEFT_OUTER",
+            A380FuelTankType::LeftOuter,
+            0,
+            ElectricalBusType::AlternatingCurrent(2), // TODO: + DC 1
+            8.,
+            Self::WING_TRANSFER_PUMP_MAX_FLOW_KG_PER_S,
+        ),
+        (
+            "LEFT_MID_FWD",
+            A380FuelTankType::LeftMid,
+            0,
+            ElectricalBusType::AlternatingCurrent(3), // TODO: + DC 2
+            8.,
+            Self::WING_TRANSFER_PUMP_MAX_FLOW_KG_PER_S,
+        ),
+        (
+            "LEFT_MID_AFT",
+            A380FuelTankType::LeftMid,
+            0,
+            ElectricalBusType::AlternatingCurrent(1), // TODO: + DC 1
+            8.,
+            Self::WING_TRANSFER_PUMP_MAX_FLOW_KG_PER_S,
+        ),
+        (
+            "LEFT_INNER_FWD",
+            A380FuelTankType::LeftInner,
+            0,
+            ElectricalBusType::AlternatingCurrent(4), // TODO: + DC 2
+            8.,
+            Self::WING_TRANSFER_PUMP_MAX_FLOW_KG_PER_S,
+        ),
+        (
+            "LEFT_INNER_AFT",
+            A380FuelTankType::LeftInner,
+            0,
+            ElectricalBusType::AlternatingCurrent(2), // TODO: + DC 1
+            8.,
+            Self::WING_TRANSFER_PUMP_MAX_FLOW_KG_PER_S,
+        ),
+        (
+            "RIGHT_OUTER",
+            A380FuelTankType::RightOuter,
+            1,
+            ElectricalBusType::AlternatingCurrent(2), // TODO: + DC 1
+            8.,
+            Self::WING_TRANSFER_PUMP_MAX_FLOW_KG_PER_S,
+        ),
+        (
+            "RIGHT_MID_FWD",
+            A380FuelTankType::RightMid,
+            1,
+            ElectricalBusType::AlternatingCurrent(3), // TODO: + DC 2
+            8.,
+            Self::WING_TRANSFER_PUMP_MAX_FLOW_KG_PER_S,
+        ),
+        (
+            "RIGHT_MID_AFT",
+            A380FuelTankType::RightMid,
+            1,
+            ElectricalBusType::AlternatingCurrent(1), // TODO: + DC 1
+            8.,
+            Self::WING_TRANSFER_PUMP_MAX_FLOW_KG_PER_S,
+        ),
+        (
+            "RIGHT_INNER_FWD",
+            A380FuelTankType::RightInner,
+            1,
+            ElectricalBusType::AlternatingCurrent(4), // TODO: + DC 2
+            8.,
+            Self::WING_TRANSFER_PUMP_MAX_FLOW_KG_PER_S,
+        ),
+        (
+            "RIGHT_INNER_AFT",
+            A380FuelTankType::RightInner,
+            1,
+            ElectricalBusType::AlternatingCurrent(2), // TODO: + DC 1
+            8.,
+            Self::WING_TRANSFER_PUMP_MAX_FLOW_KG_PER_S,
+        ),
+        (
+            "TRIM_LEFT",
+            A380FuelTankType::Trim,
+            0,
+            ElectricalBusType::AlternatingCurrentEssential, // TODO: + DC ESS
+            5.,
+            Self::TRIM_TRANSFER_PUMP_MAX_FLOW_KG_PER_S,
+        ),
+        (
+            "TRIM_RIGHT",
+            A380FuelTankType::Trim,
+            1,
+            ElectricalBusType::AlternatingCurrent(2), // TODO: + DC 1
+            5.,
+            Self::TRIM_TRANSFER_PUMP_MAX_FLOW_KG_PER_S,
+        ),
+    ];
+
+    const FEED_TANK_INLET_VALVE_MAX_FLOW_KG_PER_S: f64 = 2.5;
+
     fn new(context: &mut InitContext) -> Self {
-        let fuel_pumps = enum_map! {
-            A380FuelPump::Feed1Main => (
+        let fuel_pumps = [
+            (
                 // Feed 1 main pump
                 1,
                 FuelPumpProperties {
@@ -283,7 +447,7 @@ impl A380FuelSystem {
                     consumption_current_ampere: 9.,
                 },
             ),
-            A380FuelPump::Feed1Stby => (
+            (
                 // Feed 1 stby pump
                 2,
                 FuelPumpProperties {
@@ -291,7 +455,7 @@ impl A380FuelSystem {
                     consumption_current_ampere: 9.,
                 },
             ),
-            A380FuelPump::Feed2Main => (
+            (
                 // Feed 2 main pump
                 3,
                 FuelPumpProperties {
@@ -299,7 +463,7 @@ impl A380FuelSystem {
                     consumption_current_ampere: 9.,
                 },
             ),
-            A380FuelPump::Feed2Stby => (
+            (
                 // Feed 2 stby pump
                 4,
                 FuelPumpProperties {
@@ -307,7 +471,7 @@ impl A380FuelSystem {
                     consumption_current_ampere: 9.,
                 },
             ),
-            A380FuelPump::Feed3Main => (
+            (
                 // Feed 3 main pump
                 5,
                 FuelPumpProperties {
@@ -315,7 +479,7 @@ impl A380FuelSystem {
                     consumption_current_ampere: 9.,
                 },
             ),
-            A380FuelPump::Feed3Stby => (
+            (
                 // Feed 3 stby pump
                 6,
                 FuelPumpProperties {
@@ -323,7 +487,7 @@ impl A380FuelSystem {
                     consumption_current_ampere: 9.,
                 },
             ),
-            A380FuelPump::Feed4Main => (
+            (
                 // Feed 4 main pump
                 7,
                 FuelPumpProperties {
@@ -331,7 +495,7 @@ impl A380FuelSystem {
                     consumption_current_ampere: 9.,
                 },
             ),
-            A380FuelPump::Feed4Stby => (
+            (
                 // Feed 4 stby pump
                 8,
                 FuelPumpProperties {
@@ -339,117 +503,82 @@ impl A380FuelSystem {
                     consumption_current_ampere: 9.,
                 },
             ),
-            A380FuelPump::LeftOuter => (
-                // Left outer pump
-                9,
-                FuelPumpProperties {
-                    powered_by: ElectricalBusType::AlternatingCurrent(2), // TODO: + DC 1
-                    consumption_current_ampere: 8.,
-                },
-            ),
-            A380FuelPump::LeftMidFwd => (
-                // Left mid fwd pump
-                10,
-                FuelPumpProperties {
-                    powered_by: ElectricalBusType::AlternatingCurrent(3), // TODO: + DC 2
-                    consumption_current_ampere: 8.,
-                },
-            ),
-            A380FuelPump::LeftMidAft => (
-                // Left mid aft pump
-                11,
-                FuelPumpProperties {
-                    powered_by: ElectricalBusType::AlternatingCurrent(1), // TODO: + DC 1
-                    consumption_current_ampere: 8.,
-                },
-            ),
-            A380FuelPump::LeftInnerFwd => (
-                // Left inner fwd pump
-                12,
-                FuelPumpProperties {
-                    powered_by: ElectricalBusType::AlternatingCurrent(4), // TODO: + DC 2
-                    consumption_current_ampere: 8.,
-                },
-            ),
-            A380FuelPump::LeftInnerAft => (
-                // Left inner aft pump
-                17,
-                FuelPumpProperties {
-                    powered_by: ElectricalBusType::AlternatingCurrent(2), // TODO: + DC 1
-                    consumption_current_ampere: 8.,
-                },
-            ),
-            A380FuelPump::RightOuter => (
-                // Right outer pump
-                14,
-                FuelPumpProperties {
-                    powered_by: ElectricalBusType::AlternatingCurrent(2), // TODO: + DC 1
-                    consumption_current_ampere: 8.,
-                },
-            ),
-            A380FuelPump::RightMidFwd => (
-                // Right mid fwd pump
-                15,
-                FuelPumpProperties {
-                    powered_by: ElectricalBusType::AlternatingCurrent(3), // TODO: + DC 2
-                    consumption_current_ampere: 8.,
-                },
-            ),
-            A380FuelPump::RightMidAft => (
-                // Right mid aft pump
-                16,
-                FuelPumpProperties {
-                    powered_by: ElectricalBusType::AlternatingCurrent(1), // TODO: + DC 1
-                    consumption_current_ampere: 8.,
-                },
-            ),
-            A380FuelPump::RightInnerFwd => (
-                // Right inner fwd pump
-                13,
-                FuelPumpProperties {
-                    powered_by: ElectricalBusType::AlternatingCurrent(4), // TODO: + DC 2
-                    consumption_current_ampere: 8.,
-                },
-            ),
-            A380FuelPump::RightInnerAft => (
-                // Right inner aft pump
-                18,
-                FuelPumpProperties {
-                    powered_by: ElectricalBusType::AlternatingCurrent(2), // TODO: + DC 1
-                    consumption_current_ampere: 8.,
-                },
-            ),
-            A380FuelPump::TrimLeft => (
-                // Trim left pump
-                19,
-                FuelPumpProperties {
-                    powered_by: ElectricalBusType::AlternatingCurrentEssential, // TODO: + DC ESS
-                    consumption_current_ampere: 5.,
-                },
-            ),
-            A380FuelPump::TrimRight => (
-                // Trim right pump
-                20,
-                FuelPumpProperties {
-                    powered_by: ElectricalBusType::AlternatingCurrent(2), // TODO: + DC 1
-                    consumption_current_ampere: 5.,
-                },
-            ),
-        };
+        ];
 
         let fuel_tanks = Self::A380_FUEL.map(|f| f.into_fuel_tank(context, true));
-        let fuel_pumps = fuel_pumps
-            .into_array()
-            .map(|(id, properties)| FuelPump::new(context, id, properties));
+        let fuel_pumps = fuel_pumps.map(|(id, properties)| FuelPump::new(context, id, properties));
+
+        let pumps = Self::TRANSFER_PUMPS
+            .into_iter()
+            .enumerate()
+            .map(
+                |(i, (id, tank, gallery, powered_by, consumption_current_ampere, max_flow))| {
+                    ElectricFuelPump::new(
+                        context,
+                        id,
+                        FailureType::FuelPump(i + 1),
+                        ElectricFuelPumpProperties {
+                            tank: tank as usize,
+                            feed_line: gallery,
+                            powered_by,
+                            consumption_current_ampere,
+                            nominal_pressure_psi: Self::TRANSFER_PUMP_PRESSURE_PSI,
+                            max_flow_kg_per_s: max_flow,
+                        },
+                    )
+                },
+            )
+            .collect();
+        // One inlet valve per feed tank, in the order of the feed tanks
+        let valves = FEED_TANKS
+            .into_iter()
+            .enumerate()
+            .map(|(i, tank)| {
+                let (gallery, powered_by) = if i < 2 {
+                    (0, ElectricalBusType::DirectCurrent(1))
+                } else {
+                    (1, ElectricalBusType::DirectCurrent(2))
+                };
+                FuelValve::new(
+                    context,
+                    &format!("FEED_{}_INLET", i + 1),
+                    FailureType::FuelValveJammed(i + 1),
+                    FuelValveProperties {
+                        connection: FuelValveConnection::FeedLineToTank {
+                            feed_line: gallery,
+                            tank: tank as usize,
+                        },
+                        powered_by,
+                        travel_time_s: 1.,
+                        max_flow_kg_per_s: Self::FEED_TANK_INLET_VALVE_MAX_FLOW_KG_PER_S,
+                    },
+                )
+            })
+            .collect();
+
         A380FuelSystem {
             fuel_system: FuelSystem::new(context, fuel_tanks, fuel_pumps),
+            transfer_network: FuelNetwork::new(2, pumps, valves, Vec::new()),
         }
     }
 
-    fn fuel_system(&self) -> &FuelSystem<11, 20> {
+    fn update_transfers(
+        &mut self,
+        context: &UpdateContext,
+        controller: &impl FuelNetworkController,
+    ) {
+        self.fuel_system
+            .update_network(context, &mut self.transfer_network, controller, &[]);
+    }
+
+    fn fuel_system(&self) -> &FuelSystem<11, 8> {
         &self.fuel_system
     }
 
+    fn update_temperatures(&mut self, context: &UpdateContext) {
+        self.fuel_system.update_temperatures(context);
+    }
+
     fn tank_has_fuel(&self, tank: A380FuelTankType) -> bool {
         self.fuel_system().tank_has_fuel(tank as usize)
     }
@@ -544,14 +673,27 @@ impl FuelCG for A380FuelSystem {
         self.center_of_gravity()
     }
 }
+impl FuelTankTemperature for A380FuelSystem {
+    fn tank_temperature(&self, tank: A380FuelTankType) -> ThermodynamicTemperature {
+        self.fuel_system().tank_temperature(tank as usize)
+    }
+
+    fn tank_temperature_is_low(&self, tank: A380FuelTankType) -> bool {
+        self.fuel_system().tank_temperature_is_low(tank as usize)
+    }
+}
 impl FuelPumpStatus for A380FuelSystem {
     fn is_fuel_pump_running(&self, pump: A380FuelPump) -> bool {
-        self.fuel_system().is_fuel_pump_running(pump.into_usize())
+        match pump.transfer_pump_index() {
+            Some(index) => self.transfer_network.pump(index).is_running(),
+            None => self.fuel_system().is_fuel_pump_running(pump.into_usize()),
+        }
     }
 }
 impl SimulationElement for A380FuelSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_system.accept(visitor);
+        self.transfer_network.accept(visitor);
 
         visitor.visit(self);
     }
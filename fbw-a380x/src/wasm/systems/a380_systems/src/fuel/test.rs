@@ -1,8 +1,15 @@
 use std::{collections::HashMap, time::Duration};
 
 use ntest::{assert_false, assert_true};
-use systems::{electrical::Electricity, fuel::RefuelRate, simulation::test::ReadByName};
-use uom::si::mass::kilogram;
+use systems::{
+    electrical::Electricity, fuel::RefuelRate, shared::arinc429::SignStatus,
+    simulation::test::ReadByName,
+};
+use uom::si::{
+    angle::degree,
+    f64::{Angle, Length, Velocity},
+    mass::kilogram,
+};
 
 use super::*;
 use crate::{
@@ -12,8 +19,49 @@ use crate::{
         Aircraft, SimulationElement, SimulationElementVisitor,
     },
 };
+struct TestAdirs;
+impl AdirsMeasurementOutputs for TestAdirs {
+    fn is_fully_aligned(&self, _adiru_number: usize) -> bool {
+        false
+    }
+
+    fn latitude(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+        Arinc429Word::new(Angle::new::<degree>(0.), SignStatus::FailureWarning)
+    }
+
+    fn longitude(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+        Arinc429Word::new(Angle::new::<degree>(0.), SignStatus::FailureWarning)
+    }
+
+    fn heading(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+        Arinc429Word::new(Angle::new::<degree>(0.), SignStatus::FailureWarning)
+    }
+
+    fn true_heading(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+        Arinc429Word::new(Angle::new::<degree>(0.), SignStatus::FailureWarning)
+    }
+
+    fn vertical_speed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
+        Arinc429Word::new(Velocity::default(), SignStatus::FailureWarning)
+    }
+
+    fn altitude(&self, _adiru_number: usize) -> Arinc429Word<Length> {
+        Arinc429Word::new(Length::default(), SignStatus::FailureWarning)
+    }
+
+    fn angle_of_attack(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+        Arinc429Word::new(Angle::new::<degree>(0.), SignStatus::FailureWarning)
+    }
+
+    fn computed_airspeed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
+        Arinc429Word::new(Velocity::default(), SignStatus::FailureWarning)
+    }
+}
+
 struct FuelTestAircraft {
     acdn: A380AvionicsDataCommunicationNetwork,
+    adirs: TestAdirs,
+    center_of_gravity: CenterOfGravityData,
     fuel: A380Fuel,
 }
 
@@ -21,6 +69,8 @@ impl FuelTestAircraft {
     fn new(context: &mut InitContext) -> Self {
         Self {
             acdn: A380AvionicsDataCommunicationNetwork::new(context),
+            adirs: TestAdirs,
+            center_of_gravity: CenterOfGravityData::new(context),
             fuel: A380Fuel::new(context),
         }
     }
@@ -41,13 +91,19 @@ impl Aircraft for FuelTestAircraft {
         _electricity: &mut Electricity,
     ) {
         self.acdn.update();
-        self.fuel
-            .update(context, &self.acdn, A380Airframe::get_loadsheet());
+        self.fuel.update(
+            context,
+            &self.acdn,
+            A380Airframe::get_loadsheet(),
+            &self.adirs,
+            &self.center_of_gravity,
+        );
     }
 }
 impl SimulationElement for FuelTestAircraft {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.acdn.accept(visitor);
+        self.center_of_gravity.accept(visitor);
         self.fuel.accept(visitor);
 
         visitor.visit(self);
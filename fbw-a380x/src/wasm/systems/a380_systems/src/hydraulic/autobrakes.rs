@@ -906,7 +906,7 @@ impl BrakingDistanceCalculator {
                 context.delta(),
                 self.stopping_distance_estimation_for_decel(
                     context.ground_speed(),
-                    Acceleration::new::<meter_per_second_squared>(Self::MAX_DECEL_DRY_MS2),
+                    Self::max_decel_for_runway_condition(context),
                 ),
             );
         } else {
@@ -917,6 +917,12 @@ impl BrakingDistanceCalculator {
         }
     }
 
+    /// Max braking deceleration is limited by the friction the runway can currently provide
+    fn max_decel_for_runway_condition(context: &UpdateContext) -> Acceleration {
+        Acceleration::new::<meter_per_second_squared>(Self::MAX_DECEL_DRY_MS2)
+            * context.runway_condition().braking_capacity().get::<ratio>()
+    }
+
     fn stopping_distance_estimation_for_dry(&self, current_speed: Velocity) -> Length {
         self.distance_run_before_autobrake_active(current_speed)
             + self.stopping_distance_estimation_for_decel(
@@ -1207,6 +1213,7 @@ impl BtvDecelScheduler {
                     // Target deceleration shoots up when nearing release speed, hence only check above twice the release speed
                     self.exit_missed = target_deceleration_safety_corrected
                         < Self::MAX_DECEL_DRY_MS2
+                            * context.runway_condition().braking_capacity().get::<ratio>()
                         && delta_speed_to_achieve
                             > Velocity::new::<meter_per_second>(
                                 Self::TARGET_SPEED_TO_RELEASE_BTV_M_S,
@@ -1491,4 +1498,24 @@ mod braking_distance_tests {
                 && test_bed.query_element(|e| e.wet_landing().get::<meter>() < 2300.))
         );
     }
+    #[test]
+    fn max_braking_distance_longer_on_contaminated_runway() {
+        let max_braking_distance = |precipitation_rate_mm: f64| {
+            let mut test_bed =
+                SimulationTestBed::from(ElementCtorFn(BrakingDistanceCalculator::new))
+                    .with_update_after_power_distribution(|e, context| {
+                        e.update_braking_estimations(context, Acceleration::default())
+                    });
+
+            test_bed.set_on_ground(true);
+            test_bed.set_long_acceleration(Acceleration::new::<meter_per_second_squared>(-2.));
+            test_bed.write_by_name("AMBIENT PRECIP RATE", precipitation_rate_mm);
+            test_bed.write_by_name("GPS GROUND SPEED", 140.);
+            test_bed.run_multiple_frames(Duration::from_secs(5));
+
+            test_bed.query_element(|e| e.max_braking())
+        };
+
+        assert!(max_braking_distance(5.) > max_braking_distance(0.));
+    }
 }
@@ -8,9 +8,12 @@ use uom::si::{
     f64::*,
     length::{inch, meter},
     mass::kilogram,
+    moment_of_inertia::kilogram_square_meter,
     pressure::psi,
     ratio::{percent, ratio},
+    thermal_conductance::watt_per_kelvin,
     thermodynamic_temperature::degree_celsius,
+    torque::newton_meter,
     velocity::knot,
     volume::{cubic_inch, gallon, liter},
     volume_rate::gallon_per_second,
@@ -21,6 +24,7 @@ use systems::{
     engine::Engine,
     hydraulic::{
         aerodynamic_model::AerodynamicModel,
+        anti_skid::{AntiSkidController, BrakedWheel, WheelProperties},
         brake::{BrakeAssembly, BrakeProperties},
         brake_circuit::{BrakeAccumulatorCharacteristics, BrakeCircuit, BrakeCircuitController},
         bypass_pin::BypassPin,
@@ -47,7 +51,10 @@ use systems::{
         HydraulicCircuitController, HydraulicPressureSensors, ManualPump, PressureSwitch,
         PressureSwitchType, PriorityValve, PumpController, Reservoir,
     },
-    landing_gear::{GearSystemSensors, LandingGearControlInterfaceUnitSet, TiltingGear},
+    landing_gear::{
+        GearSystemSensors, LandingGearControlInterfaceUnitSet, TiltingGear,
+        TyrePressureIndicationSystem, TyreProperties,
+    },
     overhead::{AutoOffFaultPushButton, AutoOnFaultPushButton},
     shared::{
         interpolation, random_from_range, update_iterator::MaxStepLoop, AdirsDiscreteOutputs,
@@ -65,6 +72,8 @@ use systems::{
 
 use std::fmt::Debug;
 
+use crate::fuel::{A380FuelTankType, FuelTankTemperature};
+
 mod sfcc;
 use sfcc::SlatFlapComplex;
 mod engine_pump_disc;
@@ -217,6 +226,8 @@ impl A380HydraulicCircuitFactory {
     const PRIORITY_VALVE_PRESSURE_CUTOFF_PSI: f64 = 3000.;
     const PRIORITY_VALVE_PRESSURE_OPENED_PSI: f64 = 3800.;
 
+    const FUEL_HEAT_EXCHANGER_CONDUCTANCE_WATT_PER_KELVIN: f64 = 160.;
+
     pub fn new_green_circuit(context: &mut InitContext) -> HydraulicCircuit {
         let reservoir = A380HydraulicReservoirFactory::new_green_reservoir(context);
 
@@ -241,6 +252,9 @@ impl A380HydraulicCircuitFactory {
             ),
             Pressure::new::<psi>(Self::ACCUMULATOR_GAS_PRE_CHARGE_PSI),
             Volume::new::<gallon>(Self::ACCUMULATOR_MAX_VOLUME_GALLONS),
+            ThermalConductance::new::<watt_per_kelvin>(
+                Self::FUEL_HEAT_EXCHANGER_CONDUCTANCE_WATT_PER_KELVIN,
+            ),
         )
     }
 
@@ -267,6 +281,9 @@ impl A380HydraulicCircuitFactory {
             ),
             Pressure::new::<psi>(Self::ACCUMULATOR_GAS_PRE_CHARGE_PSI),
             Volume::new::<gallon>(Self::ACCUMULATOR_MAX_VOLUME_GALLONS),
+            ThermalConductance::new::<watt_per_kelvin>(
+                Self::FUEL_HEAT_EXCHANGER_CONDUCTANCE_WATT_PER_KELVIN,
+            ),
         )
     }
 }
@@ -1569,66 +1586,53 @@ impl A380GearSystemFactory {
     }
 }
 
-// FIXME Placeholder logic to remove when eha inhibit is handled on flight computer side
-struct A380EhaInhibitPlaceholder {
-    general_eng_1_starter_active_id: VariableIdentifier,
-    general_eng_2_starter_active_id: VariableIdentifier,
-    general_eng_3_starter_active_id: VariableIdentifier,
-    general_eng_4_starter_active_id: VariableIdentifier,
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum A380EhaBackupSurface {
+    Aileron = 0,
+    Elevator = 1,
+    Rudder = 2,
+    Spoiler = 3,
+}
 
-    any_engine_master_on: bool,
-    elec_backup_allowed: bool,
+/// Inhibit commands for the electrical backup of the EHA/EBHA actuators, sent per surface by the PRIM/SEC
+/// flight control computers.
+struct A380FlightControlEhaBackupInhibit {
+    backup_inhibited_ids: [VariableIdentifier; 4],
 
-    on_ground_delay: DelayedTrueLogicGate,
+    backup_inhibited: [bool; 4],
 }
-impl A380EhaInhibitPlaceholder {
+impl A380FlightControlEhaBackupInhibit {
     fn new(context: &mut InitContext) -> Self {
         Self {
-            general_eng_1_starter_active_id: context
-                .get_identifier("GENERAL ENG STARTER ACTIVE:1".to_owned()),
-            general_eng_2_starter_active_id: context
-                .get_identifier("GENERAL ENG STARTER ACTIVE:2".to_owned()),
-            general_eng_3_starter_active_id: context
-                .get_identifier("GENERAL ENG STARTER ACTIVE:3".to_owned()),
-            general_eng_4_starter_active_id: context
-                .get_identifier("GENERAL ENG STARTER ACTIVE:4".to_owned()),
-
-            any_engine_master_on: false,
-            elec_backup_allowed: false,
+            backup_inhibited_ids: [
+                context.get_identifier("AIL_EHA_BACKUP_INHIBITED".to_owned()),
+                context.get_identifier("ELEV_EHA_BACKUP_INHIBITED".to_owned()),
+                context.get_identifier("RUDDER_EBHA_BACKUP_INHIBITED".to_owned()),
+                context.get_identifier("SPOILER_EBHA_BACKUP_INHIBITED".to_owned()),
+            ],
 
-            on_ground_delay: DelayedTrueLogicGate::new(Duration::from_secs(120)).starting_as(true),
+            backup_inhibited: [false; 4],
         }
     }
 
-    fn update(
-        &mut self,
-        context: &UpdateContext,
-        lgciu1: &impl LgciuWeightOnWheels,
-        lgciu2: &impl LgciuWeightOnWheels,
-    ) {
-        let is_on_ground = lgciu1.left_and_right_gear_compressed(true)
-            || lgciu2.left_and_right_gear_compressed(true);
-
-        self.on_ground_delay.update(context, is_on_ground);
-
-        self.elec_backup_allowed = !self.on_ground_delay.output() || self.any_engine_master_on;
-    }
-
-    fn is_allowed(&self) -> bool {
-        self.elec_backup_allowed
+    fn is_inhibited(&self, surface: A380EhaBackupSurface) -> bool {
+        self.backup_inhibited[surface as usize]
     }
 }
-impl SimulationElement for A380EhaInhibitPlaceholder {
+impl SimulationElement for A380FlightControlEhaBackupInhibit {
     fn read(&mut self, reader: &mut SimulatorReader) {
-        self.any_engine_master_on = reader.read(&self.general_eng_1_starter_active_id)
-            || reader.read(&self.general_eng_2_starter_active_id)
-            || reader.read(&self.general_eng_3_starter_active_id)
-            || reader.read(&self.general_eng_4_starter_active_id);
+        for (inhibited, id) in self
+            .backup_inhibited
+            .iter_mut()
+            .zip(self.backup_inhibited_ids.iter())
+        {
+            *inhibited = reader.read(id);
+        }
     }
 }
 
 pub(super) struct A380Hydraulic {
-    eha_backup_inhibit_logic: A380EhaInhibitPlaceholder,
+    eha_backup_inhibit: A380FlightControlEhaBackupInhibit,
     nose_steering: SteeringActuator,
     body_wheel_steering_left: SteeringActuator,
     body_wheel_steering_right: SteeringActuator,
@@ -1719,6 +1723,11 @@ pub(super) struct A380Hydraulic {
     right_wing_brake_assembly: BrakeAssembly<4>,
     left_body_brake_assembly: BrakeAssembly<4>,
     right_body_brake_assembly: BrakeAssembly<4>,
+    tyre_properties: TyreProperties,
+    tyre_pressure_indication_system: TyrePressureIndicationSystem<16>,
+    wheel_properties: WheelProperties,
+    left_braked_wheels: BrakedWheel,
+    right_braked_wheels: BrakedWheel,
 
     // FIXME: remove when CPIOM G is implemented
     brake_temperature_ids: [VariableIdentifier; 16],
@@ -1766,7 +1775,10 @@ impl A380Hydraulic {
     const EDP_CONTROL_POWER_BUS1: ElectricalBusType = ElectricalBusType::DirectCurrentEssential;
 
     const ALTERNATE_BRAKE_ACCUMULATOR_GAS_PRE_CHARGE: f64 = 1000.0; // Nitrogen PSI
-                                                                    // Refresh rate of core hydraulic simulation
+
+    const MAIN_TYRE_INFLATION_PRESSURE_PSI: f64 = 218.;
+
+    // Refresh rate of core hydraulic simulation
     const HYDRAULIC_SIM_TIME_STEP: Duration = Duration::from_millis(10);
 
     pub fn new(context: &mut InitContext) -> A380Hydraulic {
@@ -1785,7 +1797,7 @@ impl A380Hydraulic {
             A380SlatsFactory::a380_slats_factory(context, SecondarySurfaceSide::Right);
 
         A380Hydraulic {
-            eha_backup_inhibit_logic: A380EhaInhibitPlaceholder::new(context),
+            eha_backup_inhibit: A380FlightControlEhaBackupInhibit::new(context),
             nose_steering: SteeringActuator::new(
                 context,
                 "NOSE_WHEEL",
@@ -2099,6 +2111,27 @@ impl A380Hydraulic {
                 [ElectricalBusType::DirectCurrent(1); 4], // TODO
                 None,
             ),
+            tyre_properties: TyreProperties::new(
+                Length::new::<inch>(56.) / 2.,
+                Mass::new::<kilogram>(220.),
+                Pressure::new::<psi>(Self::MAIN_TYRE_INFLATION_PRESSURE_PSI),
+            ),
+            tyre_pressure_indication_system: TyrePressureIndicationSystem::new(
+                context,
+                ElectricalBusType::DirectCurrent(1),
+                [1, 2, 5, 6, 3, 4, 7, 8, 9, 10, 13, 14, 11, 12, 15, 16],
+                Pressure::new::<psi>(Self::MAIN_TYRE_INFLATION_PRESSURE_PSI),
+            ),
+            // All braked wheels of a side, wing and body gears, share the same brake circuit side
+            wheel_properties: WheelProperties::new(
+                Length::new::<inch>(56.) / 2.,
+                MomentOfInertia::new::<kilogram_square_meter>(240.),
+                Torque::new::<newton_meter>(640000.),
+                Pressure::new::<psi>(3000.),
+                Ratio::new::<ratio>(0.38),
+            ),
+            left_braked_wheels: BrakedWheel::new(context, "LEFT"),
+            right_braked_wheels: BrakedWheel::new(context, "RIGHT"),
 
             brake_temperature_ids: (1..=16)
                 .map(|index| context.get_identifier(format!("REPORTED_BRAKE_TEMPERATURE_{index}")))
@@ -2141,6 +2174,7 @@ impl A380Hydraulic {
         lgcius: &LandingGearControlInterfaceUnitSet,
         reservoir_pneumatics: &impl ReservoirAirPressure,
         adirs: &(impl AdirsDiscreteOutputs + AdirsMeasurementOutputs),
+        fuel: &impl FuelTankTemperature,
     ) {
         self.core_hydraulic_updater.update(context);
 
@@ -2167,6 +2201,7 @@ impl A380Hydraulic {
                 lgcius.lgciu1(),
                 lgcius.lgciu2(),
                 reservoir_pneumatics,
+                fuel,
             );
         }
     }
@@ -2393,14 +2428,14 @@ impl A380Hydraulic {
             context,
             self.green_circuit.system_section(),
             self.yellow_circuit.system_section(),
-            &self.eha_backup_inhibit_logic,
+            &self.eha_backup_inhibit,
         );
 
         self.right_spoilers.update(
             context,
             self.green_circuit.system_section(),
             self.yellow_circuit.system_section(),
-            &self.eha_backup_inhibit_logic,
+            &self.eha_backup_inhibit,
         );
 
         self.gear_system.update(
@@ -2423,22 +2458,19 @@ impl A380Hydraulic {
         adirs: &impl AdirsMeasurementOutputs,
         lgcius: &LandingGearControlInterfaceUnitSet,
     ) {
-        self.eha_backup_inhibit_logic
-            .update(context, lgciu1, lgciu2);
-
         self.gear_system_gravity_extension_controller
             .update(context);
 
         self.aileron_system_controller
-            .update(&self.eha_backup_inhibit_logic);
+            .update(&self.eha_backup_inhibit);
 
         self.elevator_system_controller
-            .update(&self.eha_backup_inhibit_logic);
+            .update(&self.eha_backup_inhibit);
 
         self.ths_system_controller.update();
 
         self.rudder_system_controller
-            .update(&self.eha_backup_inhibit_logic);
+            .update(&self.eha_backup_inhibit);
 
         self.tilting_gears.update(context);
 
@@ -2513,12 +2545,22 @@ impl A380Hydraulic {
             brake_assembly.update(
                 context,
                 &self.brake_properties,
+                &self.tyre_properties,
                 braking_pressure_norm.max(braking_pressure_altn),
                 false,
                 gear_position.get::<ratio>() > 0.25,
             );
         }
 
+        self.tyre_pressure_indication_system.update(
+            self.left_wing_brake_assembly
+                .tyres()
+                .iter()
+                .chain(self.right_wing_brake_assembly.tyres())
+                .chain(self.left_body_brake_assembly.tyres())
+                .chain(self.right_body_brake_assembly.tyres()),
+        );
+
         self.braking_force.update_forces(
             context,
             &self.braking_circuit_norm,
@@ -2526,6 +2568,14 @@ impl A380Hydraulic {
             engine1,
             engine2,
             &self.bypass_pin,
+            (self.left_wing_brake_assembly.braking_efficiency()
+                + self.left_body_brake_assembly.braking_efficiency())
+                / 2.
+                * self.left_braked_wheels.braking_efficiency(),
+            (self.right_wing_brake_assembly.braking_efficiency()
+                + self.right_body_brake_assembly.braking_efficiency())
+                / 2.
+                * self.right_braked_wheels.braking_efficiency(),
         );
 
         self.slats_flaps_complex
@@ -2823,6 +2873,7 @@ impl A380Hydraulic {
         lgciu1: &impl LgciuInterface,
         lgciu2: &impl LgciuInterface,
         reservoir_pneumatics: &impl ReservoirAirPressure,
+        fuel: &impl FuelTankTemperature,
     ) {
         // First update what is currently consumed and given back by each actuator
         // Todo: might have to split the actuator volumes by expected number of loops
@@ -3077,6 +3128,7 @@ impl A380Hydraulic {
             None,
             &self.green_circuit_controller,
             reservoir_pneumatics.green_reservoir_pressure(),
+            fuel.tank_temperature(A380FuelTankType::FeedTwo),
         );
 
         self.yellow_circuit_controller.update(
@@ -3102,6 +3154,17 @@ impl A380Hydraulic {
             None,
             &self.yellow_circuit_controller,
             reservoir_pneumatics.yellow_reservoir_pressure(),
+            fuel.tank_temperature(A380FuelTankType::FeedThree),
+        );
+
+        self.brake_steer_computer.update_anti_skid(
+            context,
+            self.left_braked_wheels
+                .rolling_speed(&self.wheel_properties),
+            self.right_braked_wheels
+                .rolling_speed(&self.wheel_properties),
+            lgciu1,
+            lgciu2,
         );
 
         self.braking_circuit_norm.update(
@@ -3114,6 +3177,28 @@ impl A380Hydraulic {
             self.yellow_circuit.system_section(),
             self.brake_steer_computer.alternate_controller(),
         );
+
+        for (braked_wheels, braking_pressure_norm, braking_pressure_altn, is_compressed) in [
+            (
+                &mut self.left_braked_wheels,
+                self.braking_circuit_norm.left_brake_pressure(),
+                self.braking_circuit_altn.left_brake_pressure(),
+                lgciu1.left_gear_compressed(false) || lgciu2.left_gear_compressed(false),
+            ),
+            (
+                &mut self.right_braked_wheels,
+                self.braking_circuit_norm.right_brake_pressure(),
+                self.braking_circuit_altn.right_brake_pressure(),
+                lgciu1.right_gear_compressed(false) || lgciu2.right_gear_compressed(false),
+            ),
+        ] {
+            braked_wheels.update(
+                context,
+                &self.wheel_properties,
+                braking_pressure_norm.max(braking_pressure_altn),
+                is_compressed,
+            );
+        }
     }
 
     pub fn gear_system(&self) -> &impl GearSystemSensors {
@@ -3158,7 +3243,7 @@ impl CargoDoorLocked for A380Hydraulic {
 
 impl SimulationElement for A380Hydraulic {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        self.eha_backup_inhibit_logic.accept(visitor);
+        self.eha_backup_inhibit.accept(visitor);
 
         self.engine_driven_pump_1a.accept(visitor);
         self.engine_driven_pump_1a_controller.accept(visitor);
@@ -3244,6 +3329,9 @@ impl SimulationElement for A380Hydraulic {
         self.right_wing_brake_assembly.accept(visitor);
         self.left_body_brake_assembly.accept(visitor);
         self.right_body_brake_assembly.accept(visitor);
+        self.tyre_pressure_indication_system.accept(visitor);
+        self.left_braked_wheels.accept(visitor);
+        self.right_braked_wheels.accept(visitor);
 
         self.ths_system_controller.accept(visitor);
         self.ths.accept(visitor);
@@ -4084,6 +4172,8 @@ impl PumpController for A380AuxiliaryPumpController {
 struct A380BrakeSystemOutputs {
     left_demand: Ratio,
     right_demand: Ratio,
+    left_anti_skid_ratio: Ratio,
+    right_anti_skid_ratio: Ratio,
     pressure_limit: Pressure,
 }
 impl A380BrakeSystemOutputs {
@@ -4091,10 +4181,18 @@ impl A380BrakeSystemOutputs {
         Self {
             left_demand: Ratio::new::<ratio>(0.),
             right_demand: Ratio::new::<ratio>(0.),
+            left_anti_skid_ratio: Ratio::new::<ratio>(1.),
+            right_anti_skid_ratio: Ratio::new::<ratio>(1.),
             pressure_limit: Pressure::new::<psi>(3000.),
         }
     }
 
+    /// Anti skid modulation of the demands, 1 meaning no pressure release
+    fn set_anti_skid_ratios(&mut self, left_ratio: Ratio, right_ratio: Ratio) {
+        self.left_anti_skid_ratio = left_ratio;
+        self.right_anti_skid_ratio = right_ratio;
+    }
+
     fn set_pressure_limit(&mut self, pressure_limit: Pressure) {
         self.pressure_limit = pressure_limit;
     }
@@ -4132,11 +4230,11 @@ impl BrakeCircuitController for A380BrakeSystemOutputs {
     }
 
     fn left_brake_demand(&self) -> Ratio {
-        self.left_demand
+        self.left_demand * self.left_anti_skid_ratio
     }
 
     fn right_brake_demand(&self) -> Ratio {
-        self.right_demand
+        self.right_demand * self.right_anti_skid_ratio
     }
 }
 
@@ -4295,6 +4393,9 @@ struct A380HydraulicBrakeSteerComputerUnit {
     normal_brakes_available: bool,
     should_disable_auto_brake_when_retracting: DelayedTrueLogicGate,
     anti_skid_activated: bool,
+    anti_skid_modulates_brakes: bool,
+    left_anti_skid: AntiSkidController,
+    right_anti_skid: AntiSkidController,
 
     tiller_pedal_disconnect: bool,
     tiller_handle_position: Ratio,
@@ -4368,6 +4469,9 @@ impl A380HydraulicBrakeSteerComputerUnit {
                 Duration::from_secs_f64(Self::AUTOBRAKE_GEAR_RETRACTION_DURATION_S),
             ),
             anti_skid_activated: true,
+            anti_skid_modulates_brakes: false,
+            left_anti_skid: AntiSkidController::new(),
+            right_anti_skid: AntiSkidController::new(),
 
             tiller_pedal_disconnect: false,
             tiller_handle_position: Ratio::new::<ratio>(0.),
@@ -4494,6 +4598,9 @@ impl A380HydraulicBrakeSteerComputerUnit {
         self.should_disable_auto_brake_when_retracting
             .update(context, is_in_flight_gear_lever_up);
 
+        self.anti_skid_modulates_brakes =
+            !is_in_flight_gear_lever_up && self.anti_skid_activated && !self.parking_brake_demand;
+
         if is_in_flight_gear_lever_up {
             if self.should_disable_auto_brake_when_retracting.output() {
                 self.norm_brake_outputs.set_no_demands();
@@ -4548,6 +4655,47 @@ impl A380HydraulicBrakeSteerComputerUnit {
         }
     }
 
+    /// Updates anti skid channels from wheel speeds. Needs to run at hydraulic fixed step rate
+    /// to be able to catch a skidding wheel before it locks.
+    fn update_anti_skid(
+        &mut self,
+        context: &UpdateContext,
+        left_wheel_speed: Velocity,
+        right_wheel_speed: Velocity,
+        lgciu1: &impl LgciuInterface,
+        lgciu2: &impl LgciuInterface,
+    ) {
+        // TODO Reference speed would be computed from wheel speeds and ADIRS acceleration
+        let reference_speed = context.ground_speed();
+
+        self.left_anti_skid.update(
+            context,
+            reference_speed,
+            left_wheel_speed,
+            lgciu1.left_gear_compressed(false) || lgciu2.left_gear_compressed(false),
+        );
+        self.right_anti_skid.update(
+            context,
+            reference_speed,
+            right_wheel_speed,
+            lgciu1.right_gear_compressed(false) || lgciu2.right_gear_compressed(false),
+        );
+
+        let (left_ratio, right_ratio) = if self.anti_skid_modulates_brakes {
+            (
+                self.left_anti_skid.pressure_ratio(),
+                self.right_anti_skid.pressure_ratio(),
+            )
+        } else {
+            (Ratio::new::<ratio>(1.), Ratio::new::<ratio>(1.))
+        };
+
+        self.norm_brake_outputs
+            .set_anti_skid_ratios(left_ratio, right_ratio);
+        self.alternate_brake_outputs
+            .set_anti_skid_ratios(left_ratio, right_ratio);
+    }
+
     fn update_steering_demands(
         &mut self,
         context: &UpdateContext,
@@ -4646,6 +4794,8 @@ impl A380HydraulicBrakeSteerComputerUnit {
 impl SimulationElement for A380HydraulicBrakeSteerComputerUnit {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.autobrake_controller.accept(visitor);
+        self.left_anti_skid.accept(visitor);
+        self.right_anti_skid.accept(visitor);
         visitor.visit(self);
     }
 
@@ -4728,6 +4878,8 @@ impl A380BrakingForce {
         engine1: &impl Engine,
         engine2: &impl Engine,
         bypass_pin: &BypassPin,
+        left_tyres_braking_efficiency: Ratio,
+        right_tyres_braking_efficiency: Ratio,
     ) {
         // Base formula for output force is output_force[0:1] = 50 * sqrt(current_pressure) / Max_brake_pressure
         // This formula gives a bit more punch for lower brake pressures (like 1000 psi alternate braking), as linear formula
@@ -4747,11 +4899,27 @@ impl A380BrakingForce {
         self.right_braking_force = right_force_norm + right_force_altn;
         self.right_braking_force = self.right_braking_force.clamp(0., 1.);
 
+        // Deflated or burst tyres cannot transfer the full brake force to the ground
+        self.left_braking_force *= left_tyres_braking_efficiency.get::<ratio>();
+        self.right_braking_force *= right_tyres_braking_efficiency.get::<ratio>();
+
+        self.limit_with_runway_condition(context);
+
         self.correct_with_flaps_state(context);
 
         self.update_chocks_braking(context, engine1, engine2, bypass_pin);
     }
 
+    fn limit_with_runway_condition(&mut self, context: &UpdateContext) {
+        let max_braking_force = context
+            .runway_condition()
+            .max_braking_force(context.ground_speed())
+            .get::<ratio>();
+
+        self.left_braking_force = self.left_braking_force.min(max_braking_force);
+        self.right_braking_force = self.right_braking_force.min(max_braking_force);
+    }
+
     fn correct_with_flaps_state(&mut self, context: &UpdateContext) {
         let flap_correction = Ratio::new::<percent>(interpolation(
             &Self::FLAPS_BREAKPOINTS,
@@ -5050,6 +5218,7 @@ impl SimulationElement for A380HydraulicOverheadPanel {
 struct AileronController {
     mode: LinearActuatorMode,
     requested_position: Ratio,
+    electrical_mode_inhibited: bool,
 }
 impl AileronController {
     fn new() -> Self {
@@ -5057,6 +5226,8 @@ impl AileronController {
             mode: LinearActuatorMode::ClosedCircuitDamping,
 
             requested_position: Ratio::new::<ratio>(0.),
+
+            electrical_mode_inhibited: false,
         }
     }
 
@@ -5064,6 +5235,10 @@ impl AileronController {
         self.mode = mode;
     }
 
+    fn set_electrical_mode_inhibited(&mut self, is_inhibited: bool) {
+        self.electrical_mode_inhibited = is_inhibited;
+    }
+
     /// Receives a [0;1] position request, 0 is down 1 is up
     fn set_requested_position(&mut self, requested_position: Ratio) {
         self.requested_position = requested_position
@@ -5092,6 +5267,7 @@ impl HydraulicLocking for AileronController {}
 impl ElectroHydrostaticPowered for AileronController {
     fn should_activate_electrical_mode(&self) -> bool {
         self.requested_mode() == LinearActuatorMode::PositionControl
+            && !self.electrical_mode_inhibited
     }
 }
 
@@ -5137,8 +5313,6 @@ struct AileronSystemHydraulicController {
 
     left_aileron_controllers: [[AileronController; 2]; 3],
     right_aileron_controllers: [[AileronController; 2]; 3],
-
-    eha_backup_allowed: bool,
 }
 impl AileronSystemHydraulicController {
     fn new(context: &mut InitContext) -> Self {
@@ -5209,8 +5383,6 @@ impl AileronSystemHydraulicController {
             // Controllers are in outward->inward order, so for aileron [Blue circuit, Green circuit]
             left_aileron_controllers: [[AileronController::new(), AileronController::new()]; 3],
             right_aileron_controllers: [[AileronController::new(), AileronController::new()]; 3],
-
-            eha_backup_allowed: false,
         }
     }
 
@@ -5228,8 +5400,16 @@ impl AileronSystemHydraulicController {
         &self.right_aileron_controllers[panel as usize][..]
     }
 
-    fn update(&mut self, eha_backup_placeholder: &A380EhaInhibitPlaceholder) {
-        self.eha_backup_allowed = eha_backup_placeholder.is_allowed();
+    fn update(&mut self, eha_backup_inhibit: &A380FlightControlEhaBackupInhibit) {
+        let is_inhibited = eha_backup_inhibit.is_inhibited(A380EhaBackupSurface::Aileron);
+        for controller in self
+            .left_aileron_controllers
+            .iter_mut()
+            .chain(self.right_aileron_controllers.iter_mut())
+            .flatten()
+        {
+            controller.set_electrical_mode_inhibited(is_inhibited);
+        }
 
         self.update_aileron_controllers_positions();
         self.update_aileron_controllers_modes();
@@ -5528,8 +5708,7 @@ impl SimulationElement for AileronSystemHydraulicController {
         ];
         self.left_inboard_solenoid_energized_from_fbw = [
             reader.read(&self.left_inboard_aileron_green_actuator_solenoid_id),
-            reader.read(&self.left_inboard_aileron_eha_actuator_solenoid_id)
-                && self.eha_backup_allowed,
+            reader.read(&self.left_inboard_aileron_eha_actuator_solenoid_id),
         ];
 
         self.left_midboard_position_requests_from_fbw = [
@@ -5542,8 +5721,7 @@ impl SimulationElement for AileronSystemHydraulicController {
         ];
         self.left_midboard_solenoid_energized_from_fbw = [
             reader.read(&self.left_midboard_aileron_yellow_actuator_solenoid_id),
-            reader.read(&self.left_midboard_aileron_eha_actuator_solenoid_id)
-                && self.eha_backup_allowed,
+            reader.read(&self.left_midboard_aileron_eha_actuator_solenoid_id),
         ];
 
         self.left_outboard_position_requests_from_fbw = [
@@ -5569,8 +5747,7 @@ impl SimulationElement for AileronSystemHydraulicController {
         ];
         self.right_inboard_solenoid_energized_from_fbw = [
             reader.read(&self.right_inboard_aileron_green_actuator_solenoid_id),
-            reader.read(&self.right_inboard_aileron_eha_actuator_solenoid_id)
-                && self.eha_backup_allowed,
+            reader.read(&self.right_inboard_aileron_eha_actuator_solenoid_id),
         ];
 
         self.right_midboard_position_requests_from_fbw = [
@@ -5583,8 +5760,7 @@ impl SimulationElement for AileronSystemHydraulicController {
         ];
         self.right_midboard_solenoid_energized_from_fbw = [
             reader.read(&self.right_midboard_aileron_yellow_actuator_solenoid_id),
-            reader.read(&self.right_midboard_aileron_eha_actuator_solenoid_id)
-                && self.eha_backup_allowed,
+            reader.read(&self.right_midboard_aileron_eha_actuator_solenoid_id),
         ];
 
         self.right_outboard_position_requests_from_fbw = [
@@ -5632,8 +5808,6 @@ struct ElevatorSystemHydraulicController {
 
     left_controllers: [[AileronController; 2]; 2],
     right_controllers: [[AileronController; 2]; 2],
-
-    eha_backup_allowed: bool,
 }
 impl ElevatorSystemHydraulicController {
     fn new(context: &mut InitContext) -> Self {
@@ -5684,8 +5858,6 @@ impl ElevatorSystemHydraulicController {
             // Controllers are in outboard->inboard order
             left_controllers: [[AileronController::new(), AileronController::new()]; 2],
             right_controllers: [[AileronController::new(), AileronController::new()]; 2],
-
-            eha_backup_allowed: false,
         }
     }
 
@@ -5703,8 +5875,16 @@ impl ElevatorSystemHydraulicController {
         &self.right_controllers[panel as usize][..]
     }
 
-    fn update(&mut self, eha_backup_placeholder: &A380EhaInhibitPlaceholder) {
-        self.eha_backup_allowed = eha_backup_placeholder.is_allowed();
+    fn update(&mut self, eha_backup_inhibit: &A380FlightControlEhaBackupInhibit) {
+        let is_inhibited = eha_backup_inhibit.is_inhibited(A380EhaBackupSurface::Elevator);
+        for controller in self
+            .left_controllers
+            .iter_mut()
+            .chain(self.right_controllers.iter_mut())
+            .flatten()
+        {
+            controller.set_electrical_mode_inhibited(is_inhibited);
+        }
 
         self.update_elevator_controllers_positions();
         self.update_elevator_controllers_solenoids();
@@ -5878,8 +6058,7 @@ impl SimulationElement for ElevatorSystemHydraulicController {
         ];
         self.left_inboard_solenoid_energized_from_fbw = [
             reader.read(&self.left_inboard_elevator_green_actuator_solenoid_id),
-            reader.read(&self.left_inboard_elevator_eha_actuator_solenoid_id)
-                && self.eha_backup_allowed,
+            reader.read(&self.left_inboard_elevator_eha_actuator_solenoid_id),
         ];
 
         self.left_outboard_position_requests_from_fbw = [
@@ -5892,8 +6071,7 @@ impl SimulationElement for ElevatorSystemHydraulicController {
         ];
         self.left_outboard_solenoid_energized_from_fbw = [
             reader.read(&self.left_outboard_elevator_green_actuator_solenoid_id),
-            reader.read(&self.left_outboard_elevator_eha_actuator_solenoid_id)
-                && self.eha_backup_allowed,
+            reader.read(&self.left_outboard_elevator_eha_actuator_solenoid_id),
         ];
 
         self.right_inboard_position_requests_from_fbw = [
@@ -5906,8 +6084,7 @@ impl SimulationElement for ElevatorSystemHydraulicController {
         ];
         self.right_inboard_solenoid_energized_from_fbw = [
             reader.read(&self.right_inboard_elevator_yellow_actuator_solenoid_id),
-            reader.read(&self.right_inboard_elevator_eha_actuator_solenoid_id)
-                && self.eha_backup_allowed,
+            reader.read(&self.right_inboard_elevator_eha_actuator_solenoid_id),
         ];
 
         self.right_outboard_position_requests_from_fbw = [
@@ -5920,8 +6097,7 @@ impl SimulationElement for ElevatorSystemHydraulicController {
         ];
         self.right_outboard_solenoid_energized_from_fbw = [
             reader.read(&self.right_outboard_elevator_yellow_actuator_solenoid_id),
-            reader.read(&self.right_outboard_elevator_eha_actuator_solenoid_id)
-                && self.eha_backup_allowed,
+            reader.read(&self.right_outboard_elevator_eha_actuator_solenoid_id),
         ];
     }
 }
@@ -6047,6 +6223,7 @@ impl SimulationElement for TrimmableHorizontalStabilizerSystemHydraulicControlle
 struct RudderController {
     mode: LinearActuatorMode,
     electric_mode_active: bool,
+    electric_mode_inhibited: bool,
     requested_position: Ratio,
 }
 impl RudderController {
@@ -6055,6 +6232,7 @@ impl RudderController {
             mode: LinearActuatorMode::ClosedCircuitDamping,
 
             electric_mode_active: false,
+            electric_mode_inhibited: false,
 
             requested_position: Ratio::new::<ratio>(0.),
         }
@@ -6065,6 +6243,10 @@ impl RudderController {
         self.electric_mode_active = electric_mode_active;
     }
 
+    fn set_electrical_mode_inhibited(&mut self, is_inhibited: bool) {
+        self.electric_mode_inhibited = is_inhibited;
+    }
+
     /// Receives a [0;1] position request, 0 is down 1 is up
     fn set_requested_position(&mut self, requested_position: Ratio) {
         self.requested_position = requested_position
@@ -6092,7 +6274,7 @@ impl HydraulicAssemblyController for RudderController {
 impl HydraulicLocking for RudderController {}
 impl ElectroHydrostaticPowered for RudderController {
     fn should_activate_electrical_mode(&self) -> bool {
-        self.electric_mode_active
+        self.electric_mode_active && !self.electric_mode_inhibited
     }
 }
 
@@ -6119,8 +6301,6 @@ struct RudderSystemHydraulicController {
     lower_electric_mode_solenoid_energized_from_fbw: [bool; 2],
 
     rudder_controllers: [[RudderController; 2]; 2],
-
-    eha_backup_allowed: bool,
 }
 impl RudderSystemHydraulicController {
     fn new(context: &mut InitContext) -> Self {
@@ -6168,8 +6348,6 @@ impl RudderSystemHydraulicController {
 
             // Controllers are in Upper -> Lower order
             rudder_controllers: [[RudderController::new(), RudderController::new()]; 2],
-
-            eha_backup_allowed: false,
         }
     }
 
@@ -6180,8 +6358,12 @@ impl RudderSystemHydraulicController {
         &self.rudder_controllers[panel as usize][..]
     }
 
-    fn update(&mut self, eha_backup_placeholder: &A380EhaInhibitPlaceholder) {
-        self.eha_backup_allowed = eha_backup_placeholder.is_allowed();
+    fn update(&mut self, eha_backup_inhibit: &A380FlightControlEhaBackupInhibit) {
+        let is_inhibited = eha_backup_inhibit.is_inhibited(A380EhaBackupSurface::Rudder);
+        for controller in self.rudder_controllers.iter_mut().flatten() {
+            controller.set_electrical_mode_inhibited(is_inhibited);
+        }
+
         self.update_rudder_controllers_positions();
         self.update_rudder_controllers_solenoids();
     }
@@ -6315,10 +6497,8 @@ impl SimulationElement for RudderSystemHydraulicController {
             reader.read(&self.upper_rudder_green_actuator_hydraulic_solenoid_id),
         ];
         self.upper_electric_mode_solenoid_energized_from_fbw = [
-            reader.read(&self.upper_rudder_yellow_actuator_electric_solenoid_id)
-                && self.eha_backup_allowed,
-            reader.read(&self.upper_rudder_green_actuator_electric_solenoid_id)
-                && self.eha_backup_allowed,
+            reader.read(&self.upper_rudder_yellow_actuator_electric_solenoid_id),
+            reader.read(&self.upper_rudder_green_actuator_electric_solenoid_id),
         ];
 
         self.lower_position_requests_from_fbw = [
@@ -6334,10 +6514,8 @@ impl SimulationElement for RudderSystemHydraulicController {
             reader.read(&self.lower_rudder_yellow_actuator_hydraulic_solenoid_id),
         ];
         self.lower_electric_mode_solenoid_energized_from_fbw = [
-            reader.read(&self.lower_rudder_green_actuator_electric_solenoid_id)
-                && self.eha_backup_allowed,
-            reader.read(&self.lower_rudder_yellow_actuator_electric_solenoid_id)
-                && self.eha_backup_allowed,
+            reader.read(&self.lower_rudder_green_actuator_electric_solenoid_id),
+            reader.read(&self.lower_rudder_yellow_actuator_electric_solenoid_id),
         ];
     }
 }
@@ -6787,8 +6965,6 @@ struct SpoilerGroup {
     spoilers: [SpoilerElement; 8],
     hydraulic_controllers: [SpoilerController; 8],
     spoiler_positions: [f64; 8],
-
-    eha_backup_allowed: bool,
 }
 impl SpoilerGroup {
     const PLACE_HOLDER_POSITION_DEMAND_THRESHOLD_TO_DECLARE_GROUND_SPOILER_RATIO: f64 = 0.55;
@@ -6807,8 +6983,6 @@ impl SpoilerGroup {
                 SpoilerController::new(context, spoiler_side, 8),
             ],
             spoiler_positions: [0.; 8],
-
-            eha_backup_allowed: false,
         }
     }
 
@@ -6817,11 +6991,13 @@ impl SpoilerGroup {
         context: &UpdateContext,
         green_section: &impl SectionPressure,
         yellow_section: &impl SectionPressure,
-        eha_backup_placeholder: &A380EhaInhibitPlaceholder,
+        eha_backup_inhibit: &A380FlightControlEhaBackupInhibit,
     ) {
-        // FIXME REMOVE elec backup inhibit once handles from flight computers
-        self.eha_backup_allowed = eha_backup_placeholder.is_allowed();
-        self.hydraulic_controllers[5].set_elec_backup_allowed(self.eha_backup_allowed);
+        for controller in &mut self.hydraulic_controllers {
+            controller.set_elec_backup_inhibited(
+                eha_backup_inhibit.is_inhibited(A380EhaBackupSurface::Spoiler),
+            );
+        }
 
         self.spoilers[0].update(
             context,
@@ -6916,7 +7092,7 @@ struct SpoilerController {
     requested_position: Ratio,
     elec_backup_active: bool,
 
-    elec_backup_allowed: bool,
+    elec_backup_inhibited: bool,
 }
 impl SpoilerController {
     fn new(context: &mut InitContext, spoiler_side: &str, spoiler_id_number: usize) -> Self {
@@ -6939,12 +7115,12 @@ impl SpoilerController {
             requested_position: Ratio::new::<ratio>(0.),
             elec_backup_active: false,
 
-            elec_backup_allowed: false,
+            elec_backup_inhibited: false,
         }
     }
 
-    fn set_elec_backup_allowed(&mut self, elec_backup_allowed: bool) {
-        self.elec_backup_allowed = elec_backup_allowed;
+    fn set_elec_backup_inhibited(&mut self, elec_backup_inhibited: bool) {
+        self.elec_backup_inhibited = elec_backup_inhibited;
     }
 
     fn spoiler_actuator_position_from_surface_angle(surface_angle: Angle) -> Ratio {
@@ -6983,7 +7159,7 @@ impl SimulationElement for SpoilerController {
 impl HydraulicLocking for SpoilerController {}
 impl ElectroHydrostaticPowered for SpoilerController {
     fn should_activate_electrical_mode(&self) -> bool {
-        self.elec_backup_active && self.elec_backup_allowed
+        self.elec_backup_active && !self.elec_backup_inhibited
     }
 }
 
@@ -7197,6 +7373,25 @@ mod tests {
             }
         }
 
+        #[derive(Default)]
+        struct A380TestFuel {
+            temperature: ThermodynamicTemperature,
+        }
+        impl A380TestFuel {
+            fn update(&mut self, context: &UpdateContext) {
+                self.temperature = context.ambient_temperature();
+            }
+        }
+        impl FuelTankTemperature for A380TestFuel {
+            fn tank_temperature(&self, _tank: A380FuelTankType) -> ThermodynamicTemperature {
+                self.temperature
+            }
+
+            fn tank_temperature_is_low(&self, _tank: A380FuelTankType) -> bool {
+                false
+            }
+        }
+
         struct A380TestPneumatics {
             pressure: Pressure,
         }
@@ -7258,6 +7453,7 @@ mod tests {
         }
         struct A380HydraulicsTestAircraft {
             pneumatics: A380TestPneumatics,
+            fuel: A380TestFuel,
             engine_1: TrentEngine,
             engine_2: TrentEngine,
             engine_3: TrentEngine,
@@ -7307,6 +7503,7 @@ mod tests {
             fn new(context: &mut InitContext) -> Self {
                 Self {
                     pneumatics: A380TestPneumatics::new(),
+                    fuel: A380TestFuel::default(),
                     engine_1: TrentEngine::new(context, 1),
                     engine_2: TrentEngine::new(context, 2),
                     engine_3: TrentEngine::new(context, 3),
@@ -7573,6 +7770,7 @@ mod tests {
                 self.electrical.update(context);
 
                 self.adirus.update(context);
+                self.fuel.update(context);
 
                 self.lgcius.update(
                     context,
@@ -7595,6 +7793,7 @@ mod tests {
                     &self.lgcius,
                     &self.pneumatics,
                     &self.adirus,
+                    &self.fuel,
                 );
 
                 self.overhead.update(&self.hydraulics);
@@ -7905,7 +8104,9 @@ mod tests {
                 self.set_pressure_altitude(Length::new::<foot>(0.));
                 self.set_on_ground(true);
                 self.set_indicated_airspeed(Velocity::new::<knot>(100.));
-                self
+                // Wheels need weight on them to spin up after touchdown
+                self.write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(390000.));
+                self.set_ground_speed(Velocity::new::<knot>(100.))
             }
 
             fn air_press_low(mut self) -> Self {
@@ -8417,6 +8618,17 @@ mod tests {
                 self
             }
 
+            fn set_eha_backup_inhibited(mut self, surface: A380EhaBackupSurface) -> Self {
+                let name = match surface {
+                    A380EhaBackupSurface::Aileron => "AIL_EHA_BACKUP_INHIBITED",
+                    A380EhaBackupSurface::Elevator => "ELEV_EHA_BACKUP_INHIBITED",
+                    A380EhaBackupSurface::Rudder => "RUDDER_EBHA_BACKUP_INHIBITED",
+                    A380EhaBackupSurface::Spoiler => "SPOILER_EBHA_BACKUP_INHIBITED",
+                };
+                self.write_by_name(name, true);
+                self
+            }
+
             fn set_deploy_ground_spoilers(mut self) -> Self {
                 self = self.set_right_spoilers_out();
                 self = self.set_left_spoilers_out();
@@ -9182,9 +9394,6 @@ mod tests {
                 )
                 .run_waiting_for(Duration::from_secs(1));
 
-            // Artificially set plane not on ground to allow EHA activation
-            test_bed.set_on_ground(false);
-
             assert!(!test_bed.is_green_pressure_switch_pressurised());
             assert!(!test_bed.is_yellow_pressure_switch_pressurised());
 
@@ -9234,9 +9443,6 @@ mod tests {
                 )
                 .run_waiting_for(Duration::from_secs(1));
 
-            // Artificially set plane not on ground to allow EHA activation
-            test_bed.set_on_ground(false);
-
             assert!(!test_bed.is_green_pressure_switch_pressurised());
             assert!(!test_bed.is_yellow_pressure_switch_pressurised());
 
@@ -9400,9 +9606,6 @@ mod tests {
                     < 0.1
             );
 
-            // Artificially set plane not on ground to allow EHA activation
-            test_bed.set_on_ground(false);
-
             test_bed = test_bed
                 .reset_all_aileron_commands()
                 .ac_eha_active()
@@ -9442,9 +9645,6 @@ mod tests {
                 )
                 .run_waiting_for(Duration::from_secs(1));
 
-            // Artificially set plane not on ground to allow EHA activation
-            test_bed.set_on_ground(false);
-
             assert!(!test_bed.is_green_pressure_switch_pressurised());
             assert!(!test_bed.is_yellow_pressure_switch_pressurised());
 
@@ -9633,9 +9833,6 @@ mod tests {
                 .set_right_spoilers_out()
                 .run_waiting_for(Duration::from_secs(5));
 
-            // Artificially set plane not on ground to allow EHA activation
-            test_bed.set_on_ground(false);
-
             assert!(!test_bed.is_green_pressure_switch_pressurised());
             assert!(!test_bed.is_yellow_pressure_switch_pressurised());
 
@@ -9666,6 +9863,61 @@ mod tests {
             assert_gt!(test_bed.get_right_spoiler_position(6).get::<ratio>(), 0.5);
         }
 
+        #[test]
+        fn spoilers_6_do_not_deploy_in_elec_mode_when_backup_inhibited() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_left_spoilers_out()
+                .set_right_spoilers_out()
+                .set_eha_backup_inhibited(A380EhaBackupSurface::Spoiler)
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(!test_bed.is_green_pressure_switch_pressurised());
+            assert!(!test_bed.is_yellow_pressure_switch_pressurised());
+
+            test_bed = test_bed
+                .ac_ess_active()
+                .set_left_spoiler_6_elec_backup_active()
+                .set_right_spoiler_6_elec_backup_active()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert_lt!(test_bed.get_left_spoiler_position(6).get::<ratio>(), 0.1);
+            assert_lt!(test_bed.get_right_spoiler_position(6).get::<ratio>(), 0.1);
+        }
+
+        #[test]
+        fn middle_left_aileron_panel_does_not_respond_on_eha_jack_when_backup_inhibited() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .reset_all_aileron_commands()
+                .set_eha_backup_inhibited(A380EhaBackupSurface::Aileron)
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(!test_bed.is_green_pressure_switch_pressurised());
+            assert!(!test_bed.is_yellow_pressure_switch_pressurised());
+
+            test_bed = test_bed
+                .reset_all_aileron_commands()
+                .ac_ess_active()
+                .set_aileron_panel_neutral(
+                    ActuatorSide::Left,
+                    AileronPanelPosition::Middle,
+                    AileronActuatorPosition::Inward,
+                )
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(
+                test_bed
+                    .get_left_aileron_panel_position(AileronPanelPosition::Middle)
+                    .get::<ratio>()
+                    < 0.1
+            );
+        }
+
         #[test]
         fn pressure_state_at_init_one_simulation_step() {
             let mut test_bed = test_bed_on_ground_with()
@@ -10117,7 +10369,7 @@ mod tests {
         }
 
         #[test]
-        fn brakes_norm_active_in_flight_gear_down() {
+        fn brakes_released_by_touchdown_protection_in_flight_gear_down() {
             let mut test_bed = test_bed_on_ground_with()
                 .set_cold_dark_inputs()
                 .in_flight()
@@ -10131,12 +10383,12 @@ mod tests {
                 .set_gear_lever_down()
                 .run_waiting_for(Duration::from_secs(1));
 
-            // Brakes norm should work normally
-            assert_gt!(
+            // Brakes norm are selected but anti skid keeps them released until touchdown
+            assert_lt!(
                 test_bed.get_brake_left_green_pressure(),
                 Pressure::new::<psi>(50.)
             );
-            assert_gt!(
+            assert_lt!(
                 test_bed.get_brake_right_green_pressure(),
                 Pressure::new::<psi>(50.)
             );
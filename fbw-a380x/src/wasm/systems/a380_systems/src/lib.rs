@@ -46,7 +46,10 @@ use systems::{
         AuxiliaryPowerUnitOverheadPanel, Pw980ApuGenerator, Pw980Constants, Pw980StartMotor,
     },
     electrical::{Electricity, ElectricitySource, ExternalPowerSource},
-    engine::{reverser_thrust::ReverserForce, trent_engine::TrentEngine, EngineFireOverheadPanel},
+    engine::{
+        reverser_thrust::ReverserForce, trent_engine::TrentEngine,
+        vibration_monitoring::EngineVibrationMonitoringUnit, EngineFireOverheadPanel,
+    },
     enhanced_gpwc::EnhancedGroundProximityWarningComputer,
     landing_gear::{LandingGear, LandingGearControlInterfaceUnitSet},
     navigation::adirs::{
@@ -54,7 +57,8 @@ use systems::{
     },
     shared::ElectricalBusType,
     simulation::{
-        Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
+        profile_section, Aircraft, InitContext, SimulationElement, SimulationElementVisitor,
+        UpdateContext,
     },
 };
 
@@ -80,6 +84,7 @@ pub struct A380 {
     engine_3: TrentEngine,
     engine_4: TrentEngine,
     engine_fire_overhead: EngineFireOverheadPanel<4>,
+    engine_vibration_monitoring: EngineVibrationMonitoringUnit<4>,
     electrical: A380Electrical,
     power_consumption: A380PowerConsumption,
     ext_pwrs: [ExternalPowerSource; 4],
@@ -131,6 +136,12 @@ impl A380 {
             engine_3: TrentEngine::new(context, 3),
             engine_4: TrentEngine::new(context, 4),
             engine_fire_overhead: EngineFireOverheadPanel::new(context),
+            engine_vibration_monitoring: EngineVibrationMonitoringUnit::new(
+                context,
+                ElectricalBusType::AlternatingCurrentEssential,
+                5.,
+                5.,
+            ),
             electrical: A380Electrical::new(context),
             power_consumption: A380PowerConsumption::new(context),
             ext_pwrs: [1, 2, 3, 4].map(|i| ExternalPowerSource::new(context, i)),
@@ -179,6 +190,11 @@ impl Aircraft for A380 {
         context: &UpdateContext,
         electricity: &mut Electricity,
     ) {
+        self.engine_1.update(context, &self.pneumatic);
+        self.engine_2.update(context, &self.pneumatic);
+        self.engine_3.update(context, &self.pneumatic);
+        self.engine_4.update(context, &self.pneumatic);
+
         self.apu.update_before_electrical(
             context,
             &self.apu_overhead,
@@ -196,23 +212,25 @@ impl Aircraft for A380 {
             self.fuel.feed_four_tank_has_fuel(),
         );
 
-        self.electrical.update(
-            context,
-            electricity,
-            &self.ext_pwrs,
-            &self.electrical_overhead,
-            &self.emergency_electrical_overhead,
-            &mut self.apu,
-            &self.engine_fire_overhead,
-            [
-                &self.engine_1,
-                &self.engine_2,
-                &self.engine_3,
-                &self.engine_4,
-            ],
-            self.lgcius.lgciu1(),
-            &self.adirs,
-        );
+        profile_section("electrical", || {
+            self.electrical.update(
+                context,
+                electricity,
+                &self.ext_pwrs,
+                &self.electrical_overhead,
+                &self.emergency_electrical_overhead,
+                &mut self.apu,
+                &self.engine_fire_overhead,
+                [
+                    &self.engine_1,
+                    &self.engine_2,
+                    &self.engine_3,
+                    &self.engine_4,
+                ],
+                self.lgcius.lgciu1(),
+                &self.adirs,
+            )
+        });
 
         self.electrical_overhead
             .update_after_electrical(&self.electrical, electricity);
@@ -244,29 +262,34 @@ impl Aircraft for A380 {
             self.ext_pwrs[0].output_potential().is_powered(),
         );
 
-        self.fire_and_smoke_protection.update(
-            context,
-            &self.engine_fire_overhead,
-            [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
-        );
+        profile_section("fire_and_smoke_protection", || {
+            self.fire_and_smoke_protection.update(
+                context,
+                &self.engine_fire_overhead,
+                [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
+            )
+        });
 
         self.radio_altimeters.update(context);
 
-        self.hydraulic.update(
-            context,
-            [
-                &self.engine_1,
-                &self.engine_2,
-                &self.engine_3,
-                &self.engine_4,
-            ],
-            &self.hydraulic_overhead,
-            &self.autobrake_panel,
-            &self.engine_fire_overhead,
-            &self.lgcius,
-            &self.pneumatic,
-            &self.adirs,
-        );
+        profile_section("hydraulic", || {
+            self.hydraulic.update(
+                context,
+                [
+                    &self.engine_1,
+                    &self.engine_2,
+                    &self.engine_3,
+                    &self.engine_4,
+                ],
+                &self.hydraulic_overhead,
+                &self.autobrake_panel,
+                &self.engine_fire_overhead,
+                &self.lgcius,
+                &self.pneumatic,
+                &self.adirs,
+                &self.fuel,
+            )
+        });
 
         self.pneumatic.update_hydraulic_reservoir_spatial_volumes(
             self.hydraulic.green_reservoir(),
@@ -275,66 +298,91 @@ impl Aircraft for A380 {
 
         self.hydraulic_overhead.update(&self.hydraulic);
 
-        self.adirs.update(context, &self.adirs_overhead);
+        profile_section("adirs", || self.adirs.update(context, &self.adirs_overhead));
         self.adirs_overhead.update(context, &self.adirs);
 
         self.power_consumption.update(context);
 
-        self.pneumatic.update(
-            context,
-            [
-                &self.engine_1,
-                &self.engine_2,
-                &self.engine_3,
-                &self.engine_4,
-            ],
-            &self.pneumatic_overhead,
-            &self.engine_fire_overhead,
-            &self.apu,
-            &self.air_conditioning,
-        );
+        profile_section("pneumatic", || {
+            self.pneumatic.update(
+                context,
+                [
+                    &self.engine_1,
+                    &self.engine_2,
+                    &self.engine_3,
+                    &self.engine_4,
+                ],
+                &self.pneumatic_overhead,
+                &self.engine_fire_overhead,
+                &self.apu,
+                &self.air_conditioning,
+            )
+        });
         self.air_conditioning
             .mix_packs_air_update(self.pneumatic.packs());
-        self.air_conditioning.update(
-            context,
-            &self.adirs,
-            &self.hydraulic,
-            &self.adcn,
-            [
-                &self.engine_1,
-                &self.engine_2,
-                &self.engine_3,
-                &self.engine_4,
-            ],
-            &self.engine_fire_overhead,
-            &self.payload,
-            &self.pneumatic,
-            &self.pneumatic_overhead,
-            &self.pressurization_overhead,
-            [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
+        profile_section("air_conditioning", || {
+            self.air_conditioning.update(
+                context,
+                &self.adirs,
+                &self.hydraulic,
+                &self.adcn,
+                [
+                    &self.engine_1,
+                    &self.engine_2,
+                    &self.engine_3,
+                    &self.engine_4,
+                ],
+                &self.engine_fire_overhead,
+                &self.payload,
+                &self.pneumatic,
+                &self.pneumatic_overhead,
+                &self.pressurization_overhead,
+                [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
+            )
+        });
+
+        self.electrical.update_battery_surrounding_temperature(
+            self.air_conditioning.avionics_compartment_temperature(),
         );
 
         self.cds.update();
 
         self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
 
-        self.structural_flex.update(
+        profile_section("structural_flex", || {
+            self.structural_flex.update(
+                context,
+                [
+                    self.hydraulic.left_elevator_aero_torques(),
+                    self.hydraulic.right_elevator_aero_torques(),
+                ],
+                self.hydraulic.up_down_rudder_aero_torques(),
+                &self.hydraulic,
+                &self.fuel,
+            )
+        });
+        self.engine_vibration_monitoring.update(
             context,
             [
-                self.hydraulic.left_elevator_aero_torques(),
-                self.hydraulic.right_elevator_aero_torques(),
+                &self.engine_1,
+                &self.engine_2,
+                &self.engine_3,
+                &self.engine_4,
             ],
-            self.hydraulic.up_down_rudder_aero_torques(),
-            &self.hydraulic,
-            &self.fuel,
+            self.structural_flex.engines_displacements(),
         );
         self.cds.update();
 
         self.icing_simulation.update(context);
 
         self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
-        self.fuel
-            .update(context, &self.adcn, A380Airframe::get_loadsheet());
+        self.fuel.update(
+            context,
+            &self.adcn,
+            A380Airframe::get_loadsheet(),
+            &self.adirs,
+            self.airframe.center_of_gravity(),
+        );
 
         self.engine_reverser_control[0].update(
             &self.engine_2,
@@ -380,6 +428,7 @@ impl SimulationElement for A380 {
         self.engine_3.accept(visitor);
         self.engine_4.accept(visitor);
         self.engine_fire_overhead.accept(visitor);
+        self.engine_vibration_monitoring.accept(visitor);
         self.electrical.accept(visitor);
         self.power_consumption.accept(visitor);
         accept_iterable!(self.ext_pwrs, visitor);
@@ -12,6 +12,7 @@ use uom::si::{
 use systems::{
     accept_iterable,
     air_conditioning::PackFlowControllers,
+    engine::{fadec::FullAuthorityDigitalEngineControl, Engine},
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
         valve::*, BleedMonitoringComputerIsAliveSignal, CompressionChamber,
@@ -24,12 +25,12 @@ use systems::{
     shared::{
         pid::PidController, update_iterator::MaxStepLoop, ControllerSignal, ElectricalBusType,
         ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1, EngineCorrectedN2,
-        EngineFirePushButtons, EngineStartState, HydraulicColor, PackFlowValveState,
-        PneumaticBleed, PneumaticValve, ReservoirAirPressure,
+        EngineFirePushButtons, EngineStartControl, EngineStartState, HydraulicColor,
+        PackFlowValveState, PneumaticBleed, PneumaticValve, ReservoirAirPressure,
     },
     simulation::{
-        InitContext, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
     },
 };
 
@@ -112,7 +113,7 @@ pub struct A380Pneumatic {
 
     cross_bleed_valves: [CrossBleedValve; 3],
 
-    fadec: FullAuthorityDigitalEngineControl,
+    fadecs: [FullAuthorityDigitalEngineControl; 4],
     engine_starter_valve_controllers: [EngineStarterValveController; 4],
 
     apu_compression_chamber: CompressionChamber,
@@ -152,12 +153,22 @@ impl A380Pneumatic {
                 CrossBleedValve::new(context.get_identifier("PNEU_XBLEED_VALVE_C_OPEN".to_owned())),
                 CrossBleedValve::new(context.get_identifier("PNEU_XBLEED_VALVE_R_OPEN".to_owned())),
             ],
-            fadec: FullAuthorityDigitalEngineControl::new(context),
+            fadecs: [1, 2, 3, 4].map(|number| {
+                FullAuthorityDigitalEngineControl::new(
+                    context,
+                    number,
+                    [
+                        ElectricalBusType::DirectCurrentEssential,
+                        ElectricalBusType::DirectCurrent(if number <= 2 { 1 } else { 2 }),
+                    ],
+                    ThermodynamicTemperature::new::<degree_celsius>(700.),
+                )
+            }),
             engine_starter_valve_controllers: [
-                EngineStarterValveController::new(1),
-                EngineStarterValveController::new(2),
-                EngineStarterValveController::new(3),
-                EngineStarterValveController::new(4),
+                EngineStarterValveController::new(),
+                EngineStarterValveController::new(),
+                EngineStarterValveController::new(),
+                EngineStarterValveController::new(),
             ],
             apu_compression_chamber: CompressionChamber::new(Volume::new::<cubic_meter>(5.)),
             apu_bleed_air_valve: DefaultValve::new_closed(),
@@ -199,12 +210,16 @@ impl A380Pneumatic {
     pub(crate) fn update(
         &mut self,
         context: &UpdateContext,
-        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2); 4],
+        engines: [&impl Engine; 4],
         pneumatic_overhead_panel: &A380PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         apu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
         pack_flow_valve_signals: &impl PackFlowControllers,
     ) {
+        for (fadec, engine) in self.fadecs.iter_mut().zip(engines) {
+            fadec.update(context, engine);
+        }
+
         self.physics_updater.update(context);
 
         for cur_time_step in self.physics_updater {
@@ -222,7 +237,7 @@ impl A380Pneumatic {
     pub(crate) fn update_physics(
         &mut self,
         context: &UpdateContext,
-        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2); 4],
+        engines: [&impl Engine; 4],
         overhead_panel: &A380PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         apu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
@@ -245,8 +260,12 @@ impl A380Pneumatic {
                 .update_open_amount(&self.core_processing_input_output_module_a.units[0])
         }
 
-        for controller in self.engine_starter_valve_controllers.iter_mut() {
-            controller.update(&self.fadec);
+        for (controller, fadec) in self
+            .engine_starter_valve_controllers
+            .iter_mut()
+            .zip(&self.fadecs)
+        {
+            controller.update(fadec);
         }
 
         for (index, (engine_system, cpiom_unit)) in self
@@ -363,10 +382,18 @@ impl PneumaticBleed for A380Pneumatic {
 }
 impl EngineStartState for A380Pneumatic {
     fn engine_state(&self, engine_number: usize) -> EngineState {
-        self.fadec.engine_state(engine_number)
+        self.fadecs[engine_number - 1].engine_state()
     }
     fn engine_mode_selector(&self) -> EngineModeSelector {
-        self.fadec.engine_mode_selector()
+        self.fadecs[0].engine_mode_selector()
+    }
+}
+impl EngineStartControl for A380Pneumatic {
+    fn fuel_is_commanded(&self, engine_number: usize) -> bool {
+        self.fadecs[engine_number - 1].fuel_is_commanded()
+    }
+    fn ignition_is_commanded(&self, engine_number: usize) -> bool {
+        self.fadecs[engine_number - 1].ignition_is_commanded()
     }
 }
 impl PackFlowValveState for A380Pneumatic {
@@ -398,7 +425,7 @@ impl PackFlowValveState for A380Pneumatic {
 }
 impl SimulationElement for A380Pneumatic {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        self.fadec.accept(visitor);
+        accept_iterable!(self.fadecs, visitor);
 
         accept_iterable!(self.cross_bleed_valves, visitor);
         accept_iterable!(self.engine_systems, visitor);
@@ -436,27 +463,26 @@ impl ReservoirAirPressure for A380Pneumatic {
 }
 
 struct EngineStarterValveController {
-    number: usize,
-    engine_state: EngineState,
+    is_commanded_open: bool,
 }
 impl ControllerSignal<EngineStarterValveSignal> for EngineStarterValveController {
     fn signal(&self) -> Option<EngineStarterValveSignal> {
-        match self.engine_state {
-            EngineState::Starting => Some(EngineStarterValveSignal::new_open()),
-            _ => Some(EngineStarterValveSignal::new_closed()),
+        if self.is_commanded_open {
+            Some(EngineStarterValveSignal::new_open())
+        } else {
+            Some(EngineStarterValveSignal::new_closed())
         }
     }
 }
 impl EngineStarterValveController {
-    fn new(number: usize) -> Self {
+    fn new() -> Self {
         Self {
-            number,
-            engine_state: EngineState::Off,
+            is_commanded_open: false,
         }
     }
 
     fn update(&mut self, fadec: &FullAuthorityDigitalEngineControl) {
-        self.engine_state = fadec.engine_state(self.number);
+        self.is_commanded_open = fadec.starter_valve_is_commanded_open();
     }
 }
 
@@ -1129,82 +1155,6 @@ impl SimulationElement for A380PneumaticOverheadPanel {
     }
 }
 
-/// We use this simply as an interface to engine parameter simvars. It should probably not be part of the pneumatic system.
-pub struct FullAuthorityDigitalEngineControl {
-    engine_1_state_id: VariableIdentifier,
-    engine_2_state_id: VariableIdentifier,
-    engine_3_state_id: VariableIdentifier,
-    engine_4_state_id: VariableIdentifier,
-
-    engine_1_state: EngineState,
-    engine_2_state: EngineState,
-    engine_3_state: EngineState,
-    engine_4_state: EngineState,
-
-    engine_mode_selector1_id: VariableIdentifier,
-    engine_mode_selector1_position: EngineModeSelector,
-}
-impl FullAuthorityDigitalEngineControl {
-    fn new(context: &mut InitContext) -> Self {
-        Self {
-            engine_1_state_id: context.get_identifier("ENGINE_STATE:1".to_owned()),
-            engine_2_state_id: context.get_identifier("ENGINE_STATE:2".to_owned()),
-            engine_3_state_id: context.get_identifier("ENGINE_STATE:3".to_owned()),
-            engine_4_state_id: context.get_identifier("ENGINE_STATE:4".to_owned()),
-            engine_1_state: EngineState::Off,
-            engine_2_state: EngineState::Off,
-            engine_3_state: EngineState::Off,
-            engine_4_state: EngineState::Off,
-            engine_mode_selector1_id: context
-                .get_identifier("TURB ENG IGNITION SWITCH EX1:1".to_owned()),
-            engine_mode_selector1_position: EngineModeSelector::Norm,
-        }
-    }
-
-    fn engine_state(&self, number: usize) -> EngineState {
-        match number {
-            1 => self.engine_1_state,
-            2 => self.engine_2_state,
-            3 => self.engine_3_state,
-            4 => self.engine_4_state,
-            _ => panic!("Invalid engine number"),
-        }
-    }
-
-    fn engine_mode_selector(&self) -> EngineModeSelector {
-        self.engine_mode_selector1_position
-    }
-}
-impl SimulationElement for FullAuthorityDigitalEngineControl {
-    fn read(&mut self, reader: &mut SimulatorReader) {
-        self.engine_1_state = reader.read_discrete_or_fallback(
-            &self.engine_1_state_id,
-            "EngineState1",
-            EngineState::Off,
-        );
-        self.engine_2_state = reader.read_discrete_or_fallback(
-            &self.engine_2_state_id,
-            "EngineState2",
-            EngineState::Off,
-        );
-        self.engine_3_state = reader.read_discrete_or_fallback(
-            &self.engine_3_state_id,
-            "EngineState3",
-            EngineState::Off,
-        );
-        self.engine_4_state = reader.read_discrete_or_fallback(
-            &self.engine_4_state_id,
-            "EngineState4",
-            EngineState::Off,
-        );
-        self.engine_mode_selector1_position = reader.read_discrete_or_fallback(
-            &self.engine_mode_selector1_id,
-            "EngineModeSelector",
-            EngineModeSelector::Norm,
-        );
-    }
-}
-
 /// A struct to hold all the pack related components
 pub struct PackComplex {
     pack_number: usize,
@@ -2315,7 +2265,7 @@ mod tests {
         }
 
         fn engine_state(&self, number: usize) -> EngineState {
-            self.query(|a| a.pneumatic.fadec.engine_state(number))
+            self.query(|a| a.pneumatic.engine_state(number))
         }
 
         fn cross_bleed_valves_are_open(&self) -> bool {
@@ -202,6 +202,10 @@ impl A380StructuralFlex {
         self.surface_vibrations
             .update(context, self.wing_flex.ground_weight_ratio());
     }
+
+    pub fn engines_displacements(&self) -> [Length; 4] {
+        self.engines_flex_physics.displacements()
+    }
 }
 impl SimulationElement for A380StructuralFlex {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -82,6 +82,9 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .with_auxiliary_power_unit(Variable::named("OVHD_APU_START_PB_IS_AVAILABLE"), 8, 21)?
     .with_engine_anti_ice(4)?
     .with_wing_anti_ice()?
+    .with_engine_fuel_cut_off(4)?
+    .with_engine_fire(4)?
+    .with_variable_trace_recording(Variable::named("SYSTEMS_TRACE_RECORDING"))
     .with_fuel_pumps(1..=21)?
     .with_failures([
         (21_000, FailureType::RapidDecompression),
@@ -151,6 +154,10 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         (24_023, FailureType::Generator(4)),
         (24_030, FailureType::ApuGenerator(1)),
         (24_031, FailureType::ApuGenerator(2)),
+        (24_040, FailureType::BatteryOverheat(1)),
+        (24_041, FailureType::BatteryOverheat(2)),
+        (24_042, FailureType::BatteryOverheat(3)),
+        (24_043, FailureType::BatteryOverheat(4)),
         (
             24_100,
             FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(1)),
@@ -277,6 +284,22 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             26_018,
             FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Mlg),
         ),
+        (28_000, FailureType::FuelPump(1)),
+        (28_001, FailureType::FuelPump(2)),
+        (28_002, FailureType::FuelPump(3)),
+        (28_003, FailureType::FuelPump(4)),
+        (28_004, FailureType::FuelPump(5)),
+        (28_005, FailureType::FuelPump(6)),
+        (28_006, FailureType::FuelPump(7)),
+        (28_007, FailureType::FuelPump(8)),
+        (28_008, FailureType::FuelPump(9)),
+        (28_009, FailureType::FuelPump(10)),
+        (28_010, FailureType::FuelPump(11)),
+        (28_011, FailureType::FuelPump(12)),
+        (28_012, FailureType::FuelValveJammed(1)),
+        (28_013, FailureType::FuelValveJammed(2)),
+        (28_014, FailureType::FuelValveJammed(3)),
+        (28_015, FailureType::FuelValveJammed(4)),
         (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
         (29_001, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
         (29_002, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
@@ -416,6 +439,38 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             32_025,
             FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
         ),
+        (32_200, FailureType::TyreBurst(1)),
+        (32_201, FailureType::TyreBurst(2)),
+        (32_202, FailureType::TyreBurst(3)),
+        (32_203, FailureType::TyreBurst(4)),
+        (32_204, FailureType::TyreBurst(5)),
+        (32_205, FailureType::TyreBurst(6)),
+        (32_206, FailureType::TyreBurst(7)),
+        (32_207, FailureType::TyreBurst(8)),
+        (32_208, FailureType::TyreBurst(9)),
+        (32_209, FailureType::TyreBurst(10)),
+        (32_210, FailureType::TyreBurst(11)),
+        (32_211, FailureType::TyreBurst(12)),
+        (32_212, FailureType::TyreBurst(13)),
+        (32_213, FailureType::TyreBurst(14)),
+        (32_214, FailureType::TyreBurst(15)),
+        (32_215, FailureType::TyreBurst(16)),
+        (32_250, FailureType::TyreDeflation(1)),
+        (32_251, FailureType::TyreDeflation(2)),
+        (32_252, FailureType::TyreDeflation(3)),
+        (32_253, FailureType::TyreDeflation(4)),
+        (32_254, FailureType::TyreDeflation(5)),
+        (32_255, FailureType::TyreDeflation(6)),
+        (32_256, FailureType::TyreDeflation(7)),
+        (32_257, FailureType::TyreDeflation(8)),
+        (32_258, FailureType::TyreDeflation(9)),
+        (32_259, FailureType::TyreDeflation(10)),
+        (32_260, FailureType::TyreDeflation(11)),
+        (32_261, FailureType::TyreDeflation(12)),
+        (32_262, FailureType::TyreDeflation(13)),
+        (32_263, FailureType::TyreDeflation(14)),
+        (32_264, FailureType::TyreDeflation(15)),
+        (32_265, FailureType::TyreDeflation(16)),
         (34_000, FailureType::RadioAltimeter(1)),
         (34_001, FailureType::RadioAltimeter(2)),
         (34_002, FailureType::RadioAltimeter(3)),
@@ -425,6 +480,26 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         (34_020, FailureType::RadioAntennaDirectCoupling(1)),
         (34_021, FailureType::RadioAntennaDirectCoupling(2)),
         (34_022, FailureType::RadioAntennaDirectCoupling(3)),
+        (72_000, FailureType::EngineFlameout(1)),
+        (72_001, FailureType::EngineFlameout(2)),
+        (72_002, FailureType::EngineFlameout(3)),
+        (72_003, FailureType::EngineFlameout(4)),
+        (72_010, FailureType::EngineSeizure(1)),
+        (72_011, FailureType::EngineSeizure(2)),
+        (72_012, FailureType::EngineSeizure(3)),
+        (72_013, FailureType::EngineSeizure(4)),
+        (72_020, FailureType::EngineOilLeak(1)),
+        (72_021, FailureType::EngineOilLeak(2)),
+        (72_022, FailureType::EngineOilLeak(3)),
+        (72_023, FailureType::EngineOilLeak(4)),
+        (72_030, FailureType::EngineEgtOverlimit(1)),
+        (72_031, FailureType::EngineEgtOverlimit(2)),
+        (72_032, FailureType::EngineEgtOverlimit(3)),
+        (72_033, FailureType::EngineEgtOverlimit(4)),
+        (72_040, FailureType::EngineFanDamage(1)),
+        (72_041, FailureType::EngineFanDamage(2)),
+        (72_042, FailureType::EngineFanDamage(3)),
+        (72_043, FailureType::EngineFanDamage(4)),
     ])
     .provides_aircraft_variable("ACCELERATION BODY X", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Y", "feet per second squared", 0)?
@@ -465,6 +540,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "gallons", 10)?
     .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "gallons", 11)?
     .provides_aircraft_variable("FUELSYSTEM LINE FUEL FLOW", "gallons per hour", 141)?
+    .provides_aircraft_variable_range("FUELSYSTEM VALVE SWITCH", "Bool", 1..=4)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 0)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 1)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 2)?
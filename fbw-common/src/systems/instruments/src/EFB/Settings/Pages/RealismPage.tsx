@@ -29,6 +29,7 @@ export const RealismPage = () => {
   const [, setAdirsAlignTimeSimVar] = useSimVar('L:A32NX_CONFIG_ADIRS_IR_ALIGN_TIME', 'Enum', Number.MAX_SAFE_INTEGER);
   const [dmcSelfTestTime, setDmcSelfTestTime] = usePersistentProperty('CONFIG_SELF_TEST_TIME', '12');
   const [boardingRate, setBoardingRate] = usePersistentProperty('CONFIG_BOARDING_RATE', 'REAL');
+  const [fuelType, setFuelType] = usePersistentProperty('CONFIG_FUEL_TYPE', '0');
   const [mcduInput, setMcduInput] = usePersistentBooleanProperty('MCDU_KB_INPUT', false);
   const [mcduTimeout, setMcduTimeout] = usePersistentProperty('CONFIG_MCDU_KB_TIMEOUT', '60');
   const [pauseAtTod, setPauseAtTod] = usePersistentBooleanProperty('PAUSE_AT_TOD', false);
@@ -62,6 +63,13 @@ export const RealismPage = () => {
     { name: t('Settings.Real'), setting: 'REAL' },
   ];
 
+  const fuelTypeButtons: ButtonType[] = [
+    { name: t('Settings.Realism.FuelTypeJetA1'), setting: '0' },
+    { name: t('Settings.Realism.FuelTypeJetA'), setting: '1' },
+    { name: t('Settings.Realism.FuelTypeJp8'), setting: '2' },
+    { name: t('Settings.Realism.FuelTypeJetB'), setting: '3' },
+  ];
+
   return (
     <SettingsPage name={t('Settings.Realism.Title')}>
       <SettingItem name={t('Settings.Realism.AdirsAlignTime')}>
@@ -109,6 +117,20 @@ export const RealismPage = () => {
         </SelectGroup>
       </SettingItem>
 
+      <SettingItem name={t('Settings.Realism.FuelType')}>
+        <SelectGroup>
+          {fuelTypeButtons.map((button) => (
+            <SelectItem
+              key={button.name}
+              onSelect={() => setFuelType(button.setting)}
+              selected={fuelType === button.setting}
+            >
+              {button.name}
+            </SelectItem>
+          ))}
+        </SelectGroup>
+      </SettingItem>
+
       <SettingItem name={t('Settings.Realism.AutofillChecklists')} unrealistic>
         <Toggle value={!!autoFillChecklists} onToggle={(value) => setAutoFillChecklists(value ? 1 : 0)} />
       </SettingItem>
@@ -183,6 +183,12 @@ export const globalSyncedSettings: SyncedSettingDefinition[] = [
     localVarName: 'L:A32NX_EFB_REFUEL_RATE_SETTING',
     defaultValue: '0',
   },
+  {
+    type: 'legacy',
+    configKey: 'CONFIG_FUEL_TYPE',
+    localVarName: 'L:A32NX_EFB_FUEL_TYPE',
+    defaultValue: '0',
+  },
   {
     type: 'legacy',
     configKey: 'CONFIG_BOARDING_RATE',
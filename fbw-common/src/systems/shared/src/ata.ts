@@ -104,6 +104,7 @@ export const AtaChaptersDescription = Object.freeze({
   32: 'The landing gear components are responsible for supporting and steering the aircraft on the ground, and make it possible to retract and store the landing gear in flight. Includes the functioning and maintenance aspects of the landing gear doors.',
   34: 'The navigation systems provide data about the position, speed, heading, and altitude of the aircraft. Failures in a system such as the ADIRS can cause a loss of data sent to instrumentation.',
   46: 'Information systems provide means of communication between Airline Operational Control (AOC), Air Traffic Control (ATC), and various applications around the organization of on-board information (e.g. via the OIS)',
+  72: 'The engines provide thrust and drive the accessory gearbox, which powers the generators and hydraulic pumps, and supply bleed air to the pneumatic system. Failures of an engine affect all the systems it drives.',
 });
 
 export type AtaChapterNumber = keyof typeof AtaChaptersTitle;
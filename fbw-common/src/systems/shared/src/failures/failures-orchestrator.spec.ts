@@ -1,5 +1,5 @@
 import { describe, test, expect, vitest } from 'vitest';
-import { FailuresOrchestrator } from '.';
+import { FailuresOrchestrator, ScheduledFailureTrigger } from '.';
 import { flushPromises } from './test-functions';
 
 // mock enough of JS GenericDataListener to ensure the right calls are made for JS interop
@@ -12,24 +12,32 @@ vitest.mock('../GenericDataListenerSync', () => ({
   }),
 }));
 
-let sendRequestForFailures = undefined;
+const commBusListeners = {};
+const sendRequestForFailures = () => commBusListeners['FBW_FAILURE_REQUEST']();
+const sendScheduledFailuresUpdate = (data: string) => commBusListeners['FBW_FAILURE_SCHEDULED_UPDATE'](data);
 
 vitest.mock('../ViewListenerUtils', () => ({
   ViewListenerUtils: {
     getListener: () =>
       Promise.resolve({
-        on: (topic, requestFailuresCallback) => (sendRequestForFailures = requestFailuresCallback),
+        on: (topic, callback) => (commBusListeners[topic] = callback),
       }),
   },
 }));
 
 // mock enough of COMM BUS to ensure the right calls are made for WASM interop
 const failuresUpdateReceiver = vitest.fn();
+const failuresScheduleReceiver = vitest.fn();
+const failuresCancelScheduleReceiver = vitest.fn();
 (global as any).RegisterGenericDataListener = vitest.fn();
 (global as any).Coherent = {
   call: (event, data0, data1) => {
     if (event === 'COMM_BUS_WASM_CALLBACK' && data0 === 'FBW_FAILURE_UPDATE') {
       failuresUpdateReceiver(data1);
+    } else if (event === 'COMM_BUS_WASM_CALLBACK' && data0 === 'FBW_FAILURE_SCHEDULE') {
+      failuresScheduleReceiver(data1);
+    } else if (event === 'COMM_BUS_WASM_CALLBACK' && data0 === 'FBW_FAILURE_CANCEL_SCHEDULE') {
+      failuresCancelScheduleReceiver(data1);
     }
   },
 };
@@ -136,6 +144,81 @@ describe('FailuresOrchestrator', () => {
       expect(genericDataListenerSend.mock.lastCall[1]).toEqual([123]);
     });
   });
+
+  describe('schedules failures over commbus', () => {
+    test('sends scheduled failures', async () => {
+      const o = await orchestrator();
+      failuresScheduleReceiver.mockReset();
+
+      o.schedule(identifier, ScheduledFailureTrigger.AltitudeAbove, 10000, 30);
+
+      expect(failuresScheduleReceiver).toHaveBeenCalledTimes(1);
+      expect(JSON.parse(failuresScheduleReceiver.mock.lastCall[0])).toEqual({
+        id: 123,
+        trigger: 'altitudeAbove',
+        value: 10000,
+        intermittent: 30,
+      });
+    });
+
+    test('sends cancelled schedules', async () => {
+      const o = await orchestrator();
+      failuresCancelScheduleReceiver.mockReset();
+
+      o.cancelSchedule(identifier);
+
+      expect(failuresCancelScheduleReceiver).toHaveBeenCalledTimes(1);
+      expect(failuresCancelScheduleReceiver.mock.lastCall[0]).toBe('123');
+    });
+
+    test('does not activate scheduled failures', async () => {
+      const o = await orchestrator();
+
+      o.schedule(identifier, ScheduledFailureTrigger.After, 0);
+
+      expect(o.isActive(identifier)).toBe(false);
+    });
+  });
+
+  describe('receives scheduled failure activations over commbus', () => {
+    test('indicates a failure activated by the scheduler is active', async () => {
+      const o = await orchestrator();
+
+      sendScheduledFailuresUpdate('[123]');
+
+      expect(o.isActive(identifier)).toBe(true);
+      expect(o.getActiveFailures()).toEqual(new Set([123]));
+
+      sendScheduledFailuresUpdate('[]');
+
+      expect(o.isActive(identifier)).toBe(false);
+    });
+
+    test('sends failures activated by the scheduler to JS only', async () => {
+      const o = await orchestrator();
+      o.update();
+      failuresUpdateReceiver.mockReset();
+      genericDataListenerSend.mockReset();
+
+      sendScheduledFailuresUpdate('[123]');
+      o.update();
+
+      expect(failuresUpdateReceiver).not.toHaveBeenCalled();
+      expect(genericDataListenerSend).toHaveBeenCalledTimes(1);
+      expect(genericDataListenerSend.mock.lastCall[1]).toEqual([123]);
+    });
+
+    test('cancels a failure activated by the scheduler when deactivated', async () => {
+      const o = await orchestrator();
+      sendScheduledFailuresUpdate('[123]');
+      failuresCancelScheduleReceiver.mockReset();
+
+      await deactivateFailure(o);
+
+      expect(failuresCancelScheduleReceiver).toHaveBeenCalledTimes(1);
+      expect(failuresCancelScheduleReceiver.mock.lastCall[0]).toBe('123');
+    });
+  });
 });
 
 const identifier = 123;
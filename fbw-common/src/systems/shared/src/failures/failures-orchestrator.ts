@@ -14,6 +14,26 @@ export interface Failure {
 
 export type FailureDefinition = [AtaChapterNumber, number, string];
 
+/**
+ * The condition under which a scheduled failure activates within the systems simulation.
+ */
+export enum ScheduledFailureTrigger {
+  /** Activates once the given number of seconds passed since scheduling. */
+  After = 'after',
+  /** Activates above the given pressure altitude in feet. */
+  AltitudeAbove = 'altitudeAbove',
+  /** Activates below the given pressure altitude in feet. */
+  AltitudeBelow = 'altitudeBelow',
+  /** Activates above the given indicated airspeed in knots. */
+  IndicatedAirspeedAbove = 'indicatedAirspeedAbove',
+  /** Activates below the given indicated airspeed in knots. */
+  IndicatedAirspeedBelow = 'indicatedAirspeedBelow',
+  /** Activates when entering the given FWC flight phase. */
+  FlightPhase = 'flightPhase',
+  /** Activates at random, on average once per the given number of seconds. */
+  MeanTimeBetweenFailures = 'meanTimeBetweenFailures',
+}
+
 /**
  * Orchestrates the activation and deactivation of failures.
  *
@@ -31,8 +51,13 @@ export class FailuresOrchestrator {
 
   private activeFailures = new Set<number>();
 
+  /** The failures activated by the failure scheduler of the systems simulation. */
+  private scheduledActiveFailures = new Set<number>();
+
   private needSendFailures = true;
 
+  private needSendFailuresToJs = false;
+
   constructor(failures: FailureDefinition[]) {
     failures.forEach((failure) => {
       this.failures.push({
@@ -44,6 +69,7 @@ export class FailuresOrchestrator {
 
     ViewListenerUtils.getListener('JS_LISTENER_COMM_BUS').then((commBusListener) => {
       commBusListener.on('FBW_FAILURE_REQUEST', () => (this.needSendFailures = true));
+      commBusListener.on('FBW_FAILURE_SCHEDULED_UPDATE', (data: string) => this.onScheduledFailuresUpdate(data));
       // better send in case we missed a request from a wasm consumer
       this.needSendFailures = true;
     });
@@ -63,12 +89,21 @@ export class FailuresOrchestrator {
     }
   }
 
+  private onScheduledFailuresUpdate(data: string): void {
+    this.scheduledActiveFailures = new Set<number>(JSON.parse(data));
+    // The systems simulation already knows of its scheduled failures, only the JS consumers need them
+    this.needSendFailuresToJs = true;
+  }
+
   update() {
     if (this.needSendFailures) {
       this.needSendFailures = false;
-      const failures = Array.from(this.activeFailures);
-      this.sendFailuresToWasm(failures);
-      this.sendFailuresToJs(failures);
+      this.needSendFailuresToJs = false;
+      this.sendFailuresToWasm(Array.from(this.activeFailures));
+      this.sendFailuresToJs(Array.from(this.getActiveFailures()));
+    } else if (this.needSendFailuresToJs) {
+      this.needSendFailuresToJs = false;
+      this.sendFailuresToJs(Array.from(this.getActiveFailures()));
     }
   }
 
@@ -81,18 +116,46 @@ export class FailuresOrchestrator {
   }
 
   /**
-   * Deactivates the failure with the given identifier.
+   * Deactivates the failure with the given identifier. A failure activated by the failure scheduler is cancelled.
    */
   async deactivate(identifier: number): Promise<void> {
     this.activeFailures.delete(identifier);
     this.needSendFailures = true;
+
+    if (this.scheduledActiveFailures.has(identifier)) {
+      this.cancelSchedule(identifier);
+    }
+  }
+
+  /**
+   * Schedules the failure with the given identifier in the systems simulation. It activates once the trigger fires.
+   * Only failures simulated by the systems simulation can be scheduled. Once the systems simulation activates the
+   * failure, it is reported by {@link isActive} and sent to the JS consumers.
+   * @param identifier The identifier of the failure.
+   * @param trigger The condition under which the failure activates.
+   * @param value The value of the trigger, in the unit documented by the trigger.
+   * @param intermittentSeconds When given, the failure clears by itself once it has been active for this duration.
+   */
+  schedule(identifier: number, trigger: ScheduledFailureTrigger, value: number, intermittentSeconds?: number): void {
+    Coherent.call(
+      'COMM_BUS_WASM_CALLBACK',
+      'FBW_FAILURE_SCHEDULE',
+      JSON.stringify({ id: identifier, trigger, value, intermittent: intermittentSeconds }),
+    );
+  }
+
+  /**
+   * Cancels all scheduled failures with the given identifier. An active scheduled failure clears.
+   */
+  cancelSchedule(identifier: number): void {
+    Coherent.call('COMM_BUS_WASM_CALLBACK', 'FBW_FAILURE_CANCEL_SCHEDULE', JSON.stringify(identifier));
   }
 
   /**
    * Determines whether or not the failure with the given identifier is active.
    */
   isActive(identifier: number): boolean {
-    return this.activeFailures.has(identifier);
+    return this.activeFailures.has(identifier) || this.scheduledActiveFailures.has(identifier);
   }
 
   getAllFailures(): Readonly<Readonly<Failure>[]> {
@@ -100,6 +163,6 @@ export class FailuresOrchestrator {
   }
 
   getActiveFailures(): Set<number> {
-    return new Set(this.activeFailures);
+    return new Set([...this.activeFailures, ...this.scheduledActiveFailures]);
   }
 }
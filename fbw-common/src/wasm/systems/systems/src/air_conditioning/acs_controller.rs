@@ -30,7 +30,7 @@ use uom::si::{
     velocity::knot,
 };
 
-#[derive(Eq, PartialEq, Clone, Copy, Hash)]
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Hash)]
 pub enum AcscId {
     Acsc1(Channel),
     Acsc2(Channel),
@@ -3,8 +3,8 @@ use crate::{
     failures::{Failure, FailureType},
     shared::{AverageExt, CabinSimulation},
     simulation::{
-        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
-        VariableIdentifier, Write,
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, StateReader,
+        StateWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
 use num_traits::Pow;
@@ -384,6 +384,22 @@ impl<C: PressurizationConstants, const ZONES: usize> SimulationElement
 
         visitor.visit(self);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_initialised);
+        state.write(self.filtered_exterior_pressure);
+        state.write(self.internal_air.pressure());
+        state.write(self.internal_air.temperature());
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_initialised = state.read();
+        self.filtered_exterior_pressure = state.read();
+        self.previous_exterior_pressure =
+            BoundedVecDeque::from_iter([self.filtered_exterior_pressure; 20], 20);
+        self.internal_air.set_pressure(state.read());
+        self.internal_air.set_temperature(state.read());
+    }
 }
 
 pub struct CabinZone<C> {
@@ -475,6 +491,16 @@ impl<C: PressurizationConstants> SimulationElement for CabinZone<C> {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.zone_identifier, self.zone_air_temperature());
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.zone_air.internal_air.pressure());
+        state.write(self.zone_air.internal_air.temperature());
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.zone_air.internal_air.set_pressure(state.read());
+        self.zone_air.internal_air.set_temperature(state.read());
+    }
 }
 
 struct ZoneAir {
@@ -77,6 +77,10 @@ impl Turbine for ShutdownAps3200Turbine {
     fn bleed_air_pressure(&self) -> Pressure {
         Pressure::new::<psi>(14.7)
     }
+
+    fn restore_egt(&mut self, egt: ThermodynamicTemperature) {
+        self.egt = egt;
+    }
 }
 
 struct Starting {
@@ -231,9 +231,11 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
     }
 
     fn update_fuel_used(&mut self, context: &UpdateContext) {
-        self.fuel_used += self.fuel_flow
-            * MassConcentration::new::<kilogram_per_liter>(Self::JET_A_1_DENSITY)
-            * context.delta_as_time();
+        self.fuel_used += self.fuel_flow() * context.delta_as_time();
+    }
+
+    pub(super) fn fuel_flow(&self) -> MassRate {
+        self.fuel_flow * MassConcentration::new::<kilogram_per_liter>(Self::JET_A_1_DENSITY)
     }
 
     pub(super) fn update_fuel_used_reset(
@@ -12,7 +12,8 @@ use crate::{
         LgciuWeightOnWheels,
     },
     simulation::{
-        SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext, Write,
+        SimulationElement, SimulationElementVisitor, SimulatorWriter, StateReader, StateWriter,
+        UpdateContext, Write,
     },
 };
 use std::time::Duration;
@@ -185,6 +186,11 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         self.ecb.update_fuel_used_reset(engines, lgciu);
     }
 
+    /// The fuel flow the APU draws from the fuel system.
+    pub fn fuel_flow(&self) -> MassRate {
+        self.ecb.fuel_flow()
+    }
+
     fn is_available(&self) -> bool {
         self.ecb.is_available()
     }
@@ -277,6 +283,16 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize> Simulat
             self.air_intake_flap.open_amount(),
         );
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write_option(self.turbine.as_ref().map(|turbine| turbine.egt()));
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        if let (Some(turbine), Some(egt)) = (self.turbine.as_mut(), state.read_option()) {
+            turbine.restore_egt(egt);
+        }
+    }
 }
 
 pub trait Turbine {
@@ -294,6 +310,10 @@ pub trait Turbine {
     fn egt(&self) -> ThermodynamicTemperature;
     fn state(&self) -> TurbineState;
     fn bleed_air_pressure(&self) -> Pressure;
+
+    /// Restores the EGT of a previously saved simulation state. Only a shut down turbine
+    /// accepts the EGT, as the other states derive it from their own progress.
+    fn restore_egt(&mut self, _egt: ThermodynamicTemperature) {}
 }
 
 #[derive(PartialEq, Eq)]
@@ -1208,6 +1228,10 @@ mod tests {
         fn apu_fuel_used(&mut self) -> Arinc429Word<Mass> {
             self.read_arinc429_by_name("APU_FUEL_USED")
         }
+
+        fn apu_fuel_flow(&self) -> MassRate {
+            self.query(|a| a.apu.fuel_flow())
+        }
     }
     impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize> TestBed
         for AuxiliaryPowerUnitTestBed<T, U, C, N>
@@ -1231,7 +1255,7 @@ mod tests {
         use ntest::assert_about_eq;
 
         use super::*;
-        use uom::si::{mass::kilogram, power::watt};
+        use uom::si::{mass::kilogram, mass_rate::kilogram_per_second, power::watt};
 
         const APPROXIMATE_STARTUP_TIME: u64 = 49;
 
@@ -3199,6 +3223,33 @@ mod tests {
             );
         }
 
+        #[rstest]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn fuel_flow_is_the_fuel_line_flow<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let test_bed = bed_with
+                .running_apu()
+                .and()
+                .apu_fuel_line_flowing(true, C::FUEL_LINE_ID)
+                .run(Duration::from_millis(1));
+
+            // 33 gallons per hour of Jet A-1
+            assert!((test_bed.apu_fuel_flow().get::<kilogram_per_second>() - 0.0279).abs() < 1e-4);
+
+            let test_bed = test_bed
+                .apu_fuel_line_flowing(false, C::FUEL_LINE_ID)
+                .run(Duration::from_millis(1));
+
+            assert_eq!(test_bed.apu_fuel_flow(), MassRate::default());
+        }
+
         #[rstest]
         #[case::aps3200(test_bed_aps3200())]
         #[case::pw980(test_bed_pw980())]
@@ -85,6 +85,10 @@ impl Turbine for ShutdownPw980Turbine {
     fn bleed_air_pressure(&self) -> Pressure {
         Pressure::new::<psi>(14.7)
     }
+
+    fn restore_egt(&mut self, egt: ThermodynamicTemperature) {
+        self.egt = egt;
+    }
 }
 
 struct Starting {
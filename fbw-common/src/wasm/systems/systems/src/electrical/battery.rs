@@ -1,31 +1,60 @@
 use uom::si::{
     electric_charge::ampere_hour, electric_current::ampere, electric_potential::volt,
-    electrical_resistance::ohm, f64::*, time::second,
+    electrical_resistance::ohm, f64::*, thermodynamic_temperature::degree_celsius, time::second,
 };
 
 use crate::{
+    failures::{Failure, FailureType},
     shared::{ConsumePower, PowerConsumptionReport},
-    simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, StateReader,
+        StateWriter, UpdateContext, VariableIdentifier, Write,
+    },
 };
 
 use super::{
     ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
     ElectricalStateWriter, ElectricitySource, Potential, PotentialOrigin, ProvideCurrent,
-    ProvidePotential,
+    ProvidePotential, ProvideTemperature,
 };
 
+/// A nickel-cadmium battery. Its temperature follows the temperature of its surroundings and rises
+/// with the heat dissipated by its internal resistance. A cold battery has less capacity available
+/// and a higher internal resistance, thus its potential sags under load. A battery in thermal runaway
+/// has shorted cells. It no longer stores charge and heats up quickly while being charged.
 pub struct Battery {
     number: usize,
     identifier: ElectricalElementIdentifier,
     writer: ElectricalStateWriter,
+    temperature_id: VariableIdentifier,
+    temperature_normal_id: VariableIdentifier,
     charge: ElectricCharge,
     input_potential: ElectricPotential,
     output_potential: ElectricPotential,
     current: ElectricCurrent,
+    temperature: Option<ThermodynamicTemperature>,
+    surrounding_temperature: Option<ThermodynamicTemperature>,
+    overheat_failure: Failure,
 }
 impl Battery {
     const RATED_CAPACITY_AMPERE_HOURS: f64 = 23.;
 
+    const HEAT_CAPACITY_JOULE_PER_KELVIN: f64 = 24_000.;
+    const HEAT_TRANSFER_COEFFICIENT_WATT_PER_KELVIN: f64 = 2.5;
+    const INTERNAL_RESISTANCE_OHM: f64 = 0.011;
+
+    // Below freezing, the electrolyte gets sluggish. Capacity is lost and the internal
+    // resistance increases for every degree the battery is colder.
+    const COLD_THRESHOLD_DEGREE_CELSIUS: f64 = 0.;
+    const COLD_CAPACITY_LOSS_PER_DEGREE: f64 = 0.015;
+    const MINIMUM_CAPACITY_RATIO: f64 = 0.4;
+    const COLD_RESISTANCE_INCREASE_PER_DEGREE: f64 = 0.05;
+
+    const THERMAL_RUNAWAY_POTENTIAL_DROP_VOLT: f64 = 2.6;
+    const THERMAL_RUNAWAY_HEAT_WATT: f64 = 1500.;
+
+    const MAXIMUM_NORMAL_TEMPERATURE_DEGREE_CELSIUS: f64 = 55.;
+
     pub fn full(context: &mut InitContext, number: usize) -> Battery {
         Battery::new(
             context,
@@ -51,13 +80,25 @@ impl Battery {
             number,
             identifier: context.next_electrical_identifier(),
             writer: ElectricalStateWriter::new(context, &format!("BAT_{}", number)),
+            temperature_id: context.get_identifier(format!("ELEC_BAT_{}_TEMPERATURE", number)),
+            temperature_normal_id: context
+                .get_identifier(format!("ELEC_BAT_{}_TEMPERATURE_NORMAL", number)),
             charge,
             input_potential: ElectricPotential::new::<volt>(0.),
             output_potential: Battery::calculate_output_potential_for_charge(charge),
             current: ElectricCurrent::new::<ampere>(0.),
+            temperature: None,
+            surrounding_temperature: None,
+            overheat_failure: Failure::new(FailureType::BatteryOverheat(number)),
         }
     }
 
+    /// Sets the temperature of the compartment the battery is installed in.
+    /// Without it, the battery exchanges heat with the ambient air.
+    pub fn update_surrounding_temperature(&mut self, temperature: ThermodynamicTemperature) {
+        self.surrounding_temperature = Some(temperature);
+    }
+
     pub fn needs_charging(&self) -> bool {
         self.charge <= ElectricCharge::new::<ampere_hour>(Battery::RATED_CAPACITY_AMPERE_HOURS - 3.)
     }
@@ -95,6 +136,78 @@ impl Battery {
         self.set_charge(ElectricCharge::new::<ampere_hour>(0.))
     }
 
+    fn degrees_below_cold_threshold(&self) -> f64 {
+        self.temperature.map_or(0., |temperature| {
+            (Battery::COLD_THRESHOLD_DEGREE_CELSIUS - temperature.get::<degree_celsius>()).max(0.)
+        })
+    }
+
+    fn available_charge(&self) -> ElectricCharge {
+        let capacity_ratio = (1.
+            - Battery::COLD_CAPACITY_LOSS_PER_DEGREE * self.degrees_below_cold_threshold())
+        .max(Battery::MINIMUM_CAPACITY_RATIO);
+        let unavailable_charge = ElectricCharge::new::<ampere_hour>(
+            Battery::RATED_CAPACITY_AMPERE_HOURS * (1. - capacity_ratio),
+        );
+
+        (self.charge - unavailable_charge).max(ElectricCharge::new::<ampere_hour>(0.))
+    }
+
+    fn internal_resistance(&self) -> ElectricalResistance {
+        ElectricalResistance::new::<ohm>(
+            Battery::INTERNAL_RESISTANCE_OHM
+                * (1.
+                    + Battery::COLD_RESISTANCE_INCREASE_PER_DEGREE
+                        * self.degrees_below_cold_threshold()),
+        )
+    }
+
+    fn calculate_output_potential(&self) -> ElectricPotential {
+        let potential = Battery::calculate_output_potential_for_charge(self.available_charge());
+        if potential <= ElectricPotential::new::<volt>(0.) {
+            return potential;
+        }
+
+        // The charge curve already accounts for the nominal internal resistance,
+        // thus only the increase of a cold battery sags the potential further.
+        let discharge_current = (-self.current).max(ElectricCurrent::new::<ampere>(0.));
+        let cold_resistance_increase = self.internal_resistance()
+            - ElectricalResistance::new::<ohm>(Battery::INTERNAL_RESISTANCE_OHM);
+        let thermal_runaway_drop =
+            ElectricPotential::new::<volt>(if self.overheat_failure.is_active() {
+                Battery::THERMAL_RUNAWAY_POTENTIAL_DROP_VOLT
+            } else {
+                0.
+            });
+
+        (potential - discharge_current * cold_resistance_increase - thermal_runaway_drop)
+            .max(ElectricPotential::new::<volt>(0.))
+    }
+
+    fn update_temperature(&mut self, context: &UpdateContext) {
+        let surrounding_temperature = self
+            .surrounding_temperature
+            .unwrap_or_else(|| context.ambient_temperature())
+            .get::<degree_celsius>();
+        let temperature = self
+            .temperature
+            .map_or(surrounding_temperature, |t| t.get::<degree_celsius>());
+
+        let current = self.current.get::<ampere>();
+        let mut heat_watt = current.powi(2) * self.internal_resistance().get::<ohm>();
+        if self.overheat_failure.is_active() && current > 0. {
+            heat_watt += Battery::THERMAL_RUNAWAY_HEAT_WATT;
+        }
+        let heat_loss_watt = Battery::HEAT_TRANSFER_COEFFICIENT_WATT_PER_KELVIN
+            * (temperature - surrounding_temperature);
+
+        self.temperature = Some(ThermodynamicTemperature::new::<degree_celsius>(
+            temperature
+                + (heat_watt - heat_loss_watt) * context.delta_as_secs_f64()
+                    / Battery::HEAT_CAPACITY_JOULE_PER_KELVIN,
+        ));
+    }
+
     fn calculate_output_potential_for_charge(charge: ElectricCharge) -> ElectricPotential {
         // There are four distinct charges, being:
         // 1. No charge, giving no potential.
@@ -153,6 +266,18 @@ impl ProvidePotential for Battery {
             .contains(&ProvidePotential::potential(self))
     }
 }
+impl ProvideTemperature for Battery {
+    fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+            .or(self.surrounding_temperature)
+            .unwrap_or_else(|| ThermodynamicTemperature::new::<degree_celsius>(15.))
+    }
+
+    fn temperature_normal(&self) -> bool {
+        self.temperature().get::<degree_celsius>()
+            <= Battery::MAXIMUM_NORMAL_TEMPERATURE_DEGREE_CELSIUS
+    }
+}
 impl ElectricalElement for Battery {
     fn input_identifier(&self) -> ElectricalElementIdentifier {
         self.identifier
@@ -176,8 +301,16 @@ impl ElectricitySource for Battery {
     }
 }
 impl SimulationElement for Battery {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.overheat_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         self.writer.write_direct(self, writer);
+        writer.write(&self.temperature_id, self.temperature());
+        writer.write(&self.temperature_normal_id, self.temperature_normal());
     }
 
     fn consume_power<T: ConsumePower>(&mut self, context: &UpdateContext, consumption: &mut T) {
@@ -190,8 +323,12 @@ impl SimulationElement for Battery {
             let power = self.input_potential * self.current;
             consumption.consume_from_input(self, power);
 
-            let time = Time::new::<second>(context.delta_as_secs_f64());
-            self.charge += ((self.input_potential * self.current) * time) / self.input_potential;
+            // A battery in thermal runaway turns the charging energy into heat instead.
+            if !self.overheat_failure.is_active() {
+                let time = Time::new::<second>(context.delta_as_secs_f64());
+                self.charge +=
+                    ((self.input_potential * self.current) * time) / self.input_potential;
+            }
         }
     }
 
@@ -215,7 +352,18 @@ impl SimulationElement for Battery {
             }
         }
 
-        self.output_potential = Battery::calculate_output_potential_for_charge(self.charge);
+        self.update_temperature(context);
+        self.output_potential = self.calculate_output_potential();
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.charge);
+        state.write_option(self.temperature);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.set_charge(state.read());
+        self.temperature = state.read_option();
     }
 }
 
@@ -334,6 +482,18 @@ mod tests {
             fn potential(&mut self, number: usize) -> ElectricPotential {
                 self.read_by_name(&format!("ELEC_BAT_{}_POTENTIAL", number))
             }
+
+            fn temperature(&mut self, number: usize) -> ThermodynamicTemperature {
+                self.read_by_name(&format!("ELEC_BAT_{}_TEMPERATURE", number))
+            }
+
+            fn temperature_is_normal(&mut self, number: usize) -> bool {
+                self.read_by_name(&format!("ELEC_BAT_{}_TEMPERATURE_NORMAL", number))
+            }
+
+            fn run_for(&mut self, duration: Duration) {
+                self.test_bed.run_multiple_frames(duration);
+            }
         }
         impl TestBed for BatteryTestBed {
             type Aircraft = TestAircraft;
@@ -382,6 +542,13 @@ mod tests {
                 self.electricity_source.set_potential(potential);
             }
 
+            fn set_battery_1_surrounding_temperature(
+                &mut self,
+                temperature: ThermodynamicTemperature,
+            ) {
+                self.battery_1.update_surrounding_temperature(temperature);
+            }
+
             fn close_battery_2_contactor(&mut self) {
                 self.battery_2_contactor.close_when(true);
             }
@@ -458,6 +625,35 @@ mod tests {
             assert!(test_bed.potential_is_normal(1));
         }
 
+        #[test]
+        fn restores_charge_from_snapshot() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.run();
+            let snapshot = test_bed.snapshot();
+
+            let mut empty_test_bed = BatteryTestBed::with_empty_batteries();
+            assert_eq!(empty_test_bed.restore(&snapshot), Ok(()));
+            empty_test_bed.run();
+
+            assert_eq!(empty_test_bed.potential(1), test_bed.potential(1));
+        }
+
+        #[test]
+        fn restores_temperature_from_snapshot() {
+            let mut test_bed = BatteryTestBed::with_half_charged_batteries();
+            test_bed.fail(FailureType::BatteryOverheat(1));
+            test_bed.command(|a| a.supply_input_potential(ElectricPotential::new::<volt>(28.)));
+            test_bed.run_for(Duration::from_secs(20 * 60));
+            let snapshot = test_bed.snapshot();
+
+            let mut other_test_bed = BatteryTestBed::with_half_charged_batteries();
+            assert_eq!(other_test_bed.restore(&snapshot), Ok(()));
+            other_test_bed.run_with_delta(Duration::from_secs(0));
+
+            assert_gt!(other_test_bed.temperature(1).get::<degree_celsius>(), 60.);
+            assert!(!other_test_bed.temperature_is_normal(1));
+        }
+
         #[test]
         fn when_empty_has_no_potential() {
             let mut test_bed = BatteryTestBed::with_empty_batteries();
@@ -706,5 +902,119 @@ mod tests {
                     < ElectricCharge::new::<ampere_hour>(0.001)
             );
         }
+
+        #[test]
+        fn starts_at_ambient_temperature() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-20.));
+
+            test_bed.run();
+
+            assert!((test_bed.temperature(1).get::<degree_celsius>() + 20.).abs() < 0.01);
+            assert!(test_bed.temperature_is_normal(1));
+        }
+
+        #[test]
+        fn follows_surrounding_temperature() {
+            let mut test_bed = BatteryTestBed::with_half_charged_batteries();
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-20.));
+            test_bed.run();
+
+            test_bed.command(|a| {
+                a.set_battery_1_surrounding_temperature(ThermodynamicTemperature::new::<
+                    degree_celsius,
+                >(20.))
+            });
+            test_bed.run_for(Duration::from_secs(5 * 3600));
+
+            assert_gt!(test_bed.temperature(1).get::<degree_celsius>(), 10.);
+            assert_lt!(test_bed.temperature(1).get::<degree_celsius>(), 20.);
+        }
+
+        #[test]
+        fn heats_up_when_discharging_quickly() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.run();
+            let temperature_prior_to_run = test_bed.temperature(1);
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(2800.)));
+            test_bed.run_for(Duration::from_secs(120));
+
+            assert_gt!(test_bed.temperature(1), temperature_prior_to_run);
+        }
+
+        #[test]
+        fn cold_battery_has_lower_potential() {
+            let mut warm_test_bed = BatteryTestBed::with_full_batteries();
+            warm_test_bed.run();
+            warm_test_bed.run();
+
+            let mut cold_test_bed = BatteryTestBed::with_full_batteries();
+            cold_test_bed
+                .set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-30.));
+            cold_test_bed.run();
+            cold_test_bed.run();
+
+            assert_lt!(cold_test_bed.potential(1), warm_test_bed.potential(1));
+        }
+
+        fn potential_sag_under_load(
+            ambient_temperature: ThermodynamicTemperature,
+        ) -> ElectricPotential {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.set_ambient_temperature(ambient_temperature);
+            test_bed.run();
+            let unloaded_potential = test_bed.potential(1);
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(5000.)));
+            test_bed.run();
+            test_bed.run();
+
+            unloaded_potential - test_bed.potential(1)
+        }
+
+        #[test]
+        fn cold_battery_potential_sags_more_under_load() {
+            assert_gt!(
+                potential_sag_under_load(ThermodynamicTemperature::new::<degree_celsius>(-30.)),
+                potential_sag_under_load(ThermodynamicTemperature::new::<degree_celsius>(0.))
+                    + ElectricPotential::new::<volt>(1.)
+            );
+        }
+
+        #[test]
+        fn thermal_runaway_lowers_potential() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.run();
+            let potential_prior_to_failure = test_bed.potential(1);
+
+            test_bed.fail(FailureType::BatteryOverheat(1));
+            test_bed.run();
+            test_bed.run();
+
+            assert_lt!(test_bed.potential(1), potential_prior_to_failure);
+        }
+
+        #[test]
+        fn thermal_runaway_overheats_battery_while_charging() {
+            let mut test_bed = BatteryTestBed::with_half_charged_batteries();
+            test_bed.fail(FailureType::BatteryOverheat(1));
+
+            test_bed.command(|a| a.supply_input_potential(ElectricPotential::new::<volt>(28.)));
+            test_bed.run_for(Duration::from_secs(20 * 60));
+
+            assert_gt!(test_bed.temperature(1).get::<degree_celsius>(), 60.);
+            assert!(!test_bed.temperature_is_normal(1));
+        }
+
+        #[test]
+        fn thermal_runaway_does_not_heat_battery_when_not_charging() {
+            let mut test_bed = BatteryTestBed::with_half_charged_batteries();
+            test_bed.fail(FailureType::BatteryOverheat(1));
+
+            test_bed.run_for(Duration::from_secs(20 * 60));
+
+            assert!(test_bed.temperature_is_normal(1));
+        }
     }
 }
@@ -1,15 +1,20 @@
 use super::{
     AlternatingCurrentElectricalSystem, BatteryPushButtons, ElectricalElement, Electricity,
-    ElectricitySource, EmergencyElectrical, ProvideCurrent, ProvidePotential,
+    ElectricitySource, EmergencyElectrical, ProvideCurrent, ProvidePotential, ProvideTemperature,
 };
 use crate::shared::AdirsDiscreteOutputs;
-use crate::simulation::{InitContext, VariableIdentifier};
+use crate::simulation::{InitContext, StateReader, StateWriter, VariableIdentifier};
 use crate::{
     shared::{ApuAvailable, ApuMaster, ApuStart, DelayedTrueLogicGate, LgciuWeightOnWheels},
     simulation::{SimulationElement, SimulatorWriter, UpdateContext, Write},
 };
 use std::time::Duration;
-use uom::si::{electric_current::ampere, electric_potential::volt, f64::*};
+use uom::si::{
+    electric_current::ampere, electric_potential::volt, f64::*,
+    thermodynamic_temperature::degree_celsius,
+};
+
+const BATTERY_OVERHEAT_TEMPERATURE_DEGREE_CELSIUS: f64 = 60.;
 
 enum State {
     Off(Off),
@@ -32,7 +37,7 @@ impl State {
         battery_number: usize,
         emergency_elec: &EmergencyElectrical,
         emergency_generator: &impl ElectricitySource,
-        battery: &(impl ProvidePotential + ProvideCurrent),
+        battery: &(impl ProvidePotential + ProvideCurrent + ProvideTemperature),
         battery_bus: &impl ElectricalElement,
         lgciu1: &impl LgciuWeightOnWheels,
         battery_push_buttons: &impl BatteryPushButtons,
@@ -77,6 +82,10 @@ impl State {
     fn should_close_contactor(&self) -> bool {
         matches!(self, State::Closed(_))
     }
+
+    fn battery_overheat_detected(&self) -> bool {
+        matches!(self, State::Open(observer) if observer.open_due_to_overheat_protection)
+    }
 }
 
 pub struct BatteryChargeLimiter {
@@ -104,7 +113,7 @@ impl BatteryChargeLimiter {
         electricity: &Electricity,
         emergency_elec: &EmergencyElectrical,
         emergency_generator: &impl ElectricitySource,
-        battery: &(impl ProvidePotential + ProvideCurrent),
+        battery: &(impl ProvidePotential + ProvideCurrent + ProvideTemperature),
         battery_bus: &impl ElectricalElement,
         lgciu1: &impl LgciuWeightOnWheels,
         battery_push_buttons: &impl BatteryPushButtons,
@@ -137,6 +146,12 @@ impl BatteryChargeLimiter {
     pub fn should_close_contactor(&self) -> bool {
         self.observer.as_ref().unwrap().should_close_contactor()
     }
+
+    /// Indicates the battery contactor was opened because the battery overheated,
+    /// e.g. due to a thermal runaway.
+    pub fn battery_overheat_detected(&self) -> bool {
+        self.observer.as_ref().unwrap().battery_overheat_detected()
+    }
 }
 impl SimulationElement for BatteryChargeLimiter {
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -145,6 +160,21 @@ impl SimulationElement for BatteryChargeLimiter {
             self.arrow.should_show_when_contactor_closed(),
         );
     }
+
+    /// Only the latched overheat protection is stored, the other states are
+    /// derived again from the restored battery and aircraft state.
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.battery_overheat_detected());
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        let overheat_detected: bool = state.read();
+        if overheat_detected {
+            self.observer = Some(State::Open(Open::due_to_overheat_protection()));
+        } else if self.battery_overheat_detected() {
+            self.observer = Some(State::Open(Open::for_initial_bcl_state()));
+        }
+    }
 }
 
 /// The BCL is not powered when the BAT push button is in the OFF
@@ -187,6 +217,7 @@ struct Open {
     begin_charging_cycle_delay: DelayedTrueLogicGate,
     open_due_to_discharge_protection: bool,
     open_due_to_exceeding_emergency_elec_closing_time_allowance: bool,
+    open_due_to_overheat_protection: bool,
 }
 impl Open {
     const CHARGE_BATTERY_BELOW_VOLTAGE: f64 = 26.5;
@@ -197,6 +228,7 @@ impl Open {
     fn new(
         open_due_to_discharge_protection: bool,
         open_due_to_exceeding_emergency_elec_closing_time_allowance: bool,
+        open_due_to_overheat_protection: bool,
     ) -> Self {
         Self {
             begin_charging_cycle_delay: DelayedTrueLogicGate::new(Duration::from_millis(
@@ -204,35 +236,44 @@ impl Open {
             )),
             open_due_to_discharge_protection,
             open_due_to_exceeding_emergency_elec_closing_time_allowance,
+            open_due_to_overheat_protection,
         }
     }
 
     fn for_initial_bcl_state() -> Self {
-        Self::new(false, false)
+        Self::new(false, false, false)
     }
 
     fn from_closed() -> Self {
-        Self::new(false, false)
+        Self::new(false, false, false)
     }
 
     fn due_to_discharge_protection() -> Self {
-        Self::new(true, false)
+        Self::new(true, false, false)
     }
 
     fn due_to_exceeding_emergency_elec_closing_time_allowance() -> Self {
-        Self::new(false, true)
+        Self::new(false, true, false)
+    }
+
+    fn due_to_overheat_protection() -> Self {
+        Self::new(false, false, true)
     }
 
     fn update_state(
         &mut self,
         context: &UpdateContext,
         electricity: &Electricity,
-        battery: &impl ProvidePotential,
+        battery: &(impl ProvidePotential + ProvideTemperature),
         battery_bus: &impl ElectricalElement,
         apu_overhead: &impl ApuMaster,
     ) {
         self.update_begin_charging_cycle_delay(context, electricity, battery, battery_bus);
 
+        if battery_is_overheating(battery) {
+            self.open_due_to_overheat_protection = true;
+        }
+
         if self.open_due_to_exceeding_emergency_elec_closing_time_allowance
             && !apu_overhead.master_sw_is_on()
         {
@@ -259,6 +300,7 @@ impl Open {
                 lgciu1,
             )
             && !self.open_due_to_discharge_protection
+            && !self.open_due_to_overheat_protection
             && (self.should_get_ready_for_apu_start(apu, apu_overhead)
                 || on_ground_at_low_speed_with_unpowered_ac_buses(
                     electricity,
@@ -318,7 +360,7 @@ impl Open {
         battery_number: usize,
         emergency_elec: &EmergencyElectrical,
         emergency_generator: &impl ElectricitySource,
-        battery: &impl ProvidePotential,
+        battery: &(impl ProvidePotential + ProvideTemperature),
         battery_bus: &impl ElectricalElement,
         lgciu1: &impl LgciuWeightOnWheels,
         battery_push_buttons: &impl BatteryPushButtons,
@@ -488,7 +530,7 @@ impl Closed {
         electricity: &Electricity,
         battery_number: usize,
         emergency_elec: &EmergencyElectrical,
-        battery: &(impl ProvidePotential + ProvideCurrent),
+        battery: &(impl ProvidePotential + ProvideCurrent + ProvideTemperature),
         lgciu1: &impl LgciuWeightOnWheels,
         battery_push_buttons: &impl BatteryPushButtons,
         apu: &impl ApuAvailable,
@@ -500,6 +542,8 @@ impl Closed {
 
         if !battery_push_buttons.bat_is_auto(battery_number) {
             State::Off(Off::new())
+        } else if battery_is_overheating(battery) {
+            State::Open(Open::due_to_overheat_protection())
         } else if self.should_open_due_to_discharge_protection(lgciu1) {
             State::Open(Open::due_to_discharge_protection())
         } else if self
@@ -522,6 +566,13 @@ impl Closed {
     }
 }
 
+fn battery_is_overheating(battery: &impl ProvideTemperature) -> bool {
+    battery.temperature()
+        > ThermodynamicTemperature::new::<degree_celsius>(
+            BATTERY_OVERHEAT_TEMPERATURE_DEGREE_CELSIUS,
+        )
+}
+
 fn on_ground_at_low_speed_with_unpowered_ac_buses(
     electricity: &Electricity,
     ac_electrical_system: &impl AlternatingCurrentElectricalSystem,
@@ -587,6 +638,7 @@ mod tests {
                 ElectricalElementIdentifier, ElectricalElementIdentifierProvider, Electricity,
                 Potential, PotentialOrigin,
             },
+            failures::FailureType,
             simulation::{
                 test::{ReadByName, SimulationTestBed, TestBed},
                 Aircraft, InitContext, SimulationElementVisitor,
@@ -781,6 +833,26 @@ mod tests {
                 self.query(|a| a.battery_contactor_is_closed())
             }
 
+            fn battery_overheat_detected(&self) -> bool {
+                self.query(|a| a.battery_overheat_detected())
+            }
+
+            fn battery_in_thermal_runaway(mut self) -> Self {
+                self.fail(FailureType::BatteryOverheat(1));
+                self
+            }
+
+            fn wait_for_battery_overheat(mut self) -> Self {
+                for _ in 0..180 {
+                    self = self.run(Duration::from_secs(10));
+                    if !self.battery_contactor_is_closed() {
+                        break;
+                    }
+                }
+
+                self
+            }
+
             fn apu_master_sw_pb_on(mut self) -> Self {
                 self.command(|a| a.set_apu_master_sw_pb_on());
                 self
@@ -1107,6 +1179,10 @@ mod tests {
                 self.battery_contactor.is_closed()
             }
 
+            fn battery_overheat_detected(&self) -> bool {
+                self.battery_charge_limiter.battery_overheat_detected()
+            }
+
             fn set_battery_push_button_auto(&mut self) {
                 self.battery_push_button_auto = true;
             }
@@ -1857,5 +1933,68 @@ mod tests {
 
             assert!(test_bed.battery_contactor_is_closed(),);
         }
+
+        #[test]
+        fn contactor_opens_when_the_battery_overheats() {
+            let test_bed = test_bed_with()
+                .wait_for_closed_contactor(true)
+                .battery_in_thermal_runaway()
+                .wait_for_battery_overheat();
+
+            assert!(!test_bed.battery_contactor_is_closed());
+            assert!(test_bed.battery_overheat_detected());
+        }
+
+        #[test]
+        fn restores_overheat_protection_from_snapshot() {
+            let mut test_bed = test_bed_with()
+                .wait_for_closed_contactor(true)
+                .battery_in_thermal_runaway()
+                .wait_for_battery_overheat();
+            let snapshot = test_bed.snapshot();
+
+            let mut other_test_bed = test_bed_with().wait_for_closed_contactor(true);
+            assert_eq!(other_test_bed.restore(&snapshot), Ok(()));
+            other_test_bed = other_test_bed.run(Duration::from_secs(0));
+
+            assert!(!other_test_bed.battery_contactor_is_closed());
+            assert!(other_test_bed.battery_overheat_detected());
+        }
+
+        #[test]
+        fn battery_overheat_is_not_detected_without_thermal_runaway() {
+            let test_bed = test_bed_with()
+                .wait_for_closed_contactor(true)
+                .run(Duration::from_secs(30 * 60));
+
+            assert!(test_bed.battery_contactor_is_closed());
+            assert!(!test_bed.battery_overheat_detected());
+        }
+
+        #[test]
+        fn overheat_protection_keeps_the_contactor_open_for_charging() {
+            let test_bed = test_bed_with()
+                .wait_for_closed_contactor(true)
+                .battery_in_thermal_runaway()
+                .wait_for_battery_overheat()
+                .wait_for_closed_contactor(false)
+                .run(Duration::from_secs(60));
+
+            assert!(!test_bed.battery_contactor_is_closed());
+        }
+
+        #[test]
+        fn overheat_protection_reopens_the_contactor_when_cycling_the_battery_push_button_while_hot(
+        ) {
+            let test_bed = test_bed_with()
+                .wait_for_closed_contactor(true)
+                .battery_in_thermal_runaway()
+                .wait_for_battery_overheat()
+                .cycle_battery_push_button()
+                .wait_for_bcl_startup();
+
+            assert!(!test_bed.battery_contactor_is_closed());
+            assert!(test_bed.battery_overheat_detected());
+        }
     }
 }
@@ -481,6 +481,14 @@ mod tests {
         fn gearbox_speed(&self) -> AngularVelocity {
             AngularVelocity::new::<revolution_per_minute>(self.corrected_n2.get::<ratio>() * 16000.)
         }
+
+        fn exhaust_gas_temperature(&self) -> ThermodynamicTemperature {
+            ThermodynamicTemperature::new::<degree_celsius>(15.)
+        }
+
+        fn fan_is_damaged(&self) -> bool {
+            false
+        }
     }
 
     struct TestOverhead {
@@ -1143,4 +1151,102 @@ mod tests {
             }
         }
     }
+
+    #[cfg(test)]
+    mod engine_failure_tests {
+        use super::*;
+        use crate::{
+            electrical::Electricity,
+            engine::leap_engine::LeapEngine,
+            shared::EngineStartControl,
+            simulation::{
+                test::{SimulationTestBed, TestBed, WriteByName},
+                Aircraft, InitContext,
+            },
+        };
+
+        use uom::si::power::kilowatt;
+
+        struct TestStartControl;
+        impl EngineStartControl for TestStartControl {
+            fn fuel_is_commanded(&self, _: usize) -> bool {
+                true
+            }
+
+            fn ignition_is_commanded(&self, _: usize) -> bool {
+                false
+            }
+        }
+
+        struct TestAircraft {
+            engine: LeapEngine,
+            engine_gen: IntegratedDriveGenerator,
+        }
+        impl TestAircraft {
+            fn new(context: &mut InitContext) -> Self {
+                Self {
+                    engine: LeapEngine::new(context, 1),
+                    engine_gen: IntegratedDriveGenerator::new(
+                        context,
+                        1,
+                        Power::new::<kilowatt>(90.),
+                        390.0..=410.0,
+                    ),
+                }
+            }
+        }
+        impl Aircraft for TestAircraft {
+            fn update_before_power_distribution(
+                &mut self,
+                context: &UpdateContext,
+                _: &mut Electricity,
+            ) {
+                self.engine.update(context, &TestStartControl);
+                self.engine_gen.update(
+                    context,
+                    &self.engine,
+                    &TestOverhead::new(true, false),
+                    &TestFireOverhead::new(false),
+                );
+            }
+        }
+        impl SimulationElement for TestAircraft {
+            fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+                self.engine.accept(visitor);
+                self.engine_gen.accept(visitor);
+
+                visitor.visit(self);
+            }
+        }
+
+        fn test_bed_with_running_engine() -> SimulationTestBed<TestAircraft> {
+            let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+            test_bed.write_by_name("ENGINE_N2:1", Ratio::new::<percent>(80.));
+            test_bed.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<percent>(80.));
+            test_bed.run_with_delta(Duration::from_secs(1));
+
+            test_bed
+        }
+
+        #[test]
+        fn idg_output_is_lost_on_engine_flameout() {
+            let mut test_bed = test_bed_with_running_engine();
+            assert!(test_bed.query(|a| a.engine_gen.output_within_normal_parameters()));
+
+            test_bed.fail(FailureType::EngineFlameout(1));
+            test_bed.run_with_delta(Duration::from_secs(5));
+
+            assert!(!test_bed.query(|a| a.engine_gen.output_within_normal_parameters()));
+        }
+
+        #[test]
+        fn idg_output_is_lost_on_engine_seizure() {
+            let mut test_bed = test_bed_with_running_engine();
+
+            test_bed.fail(FailureType::EngineSeizure(1));
+            test_bed.run_with_delta(Duration::from_secs(5));
+
+            assert!(!test_bed.query(|a| a.engine_gen.output_within_normal_parameters()));
+        }
+    }
 }
@@ -9,6 +9,7 @@ mod engine_generator;
 mod external_power_source;
 mod ram_air_turbine;
 mod static_inverter;
+mod topology;
 mod transformer_rectifier;
 
 use std::{
@@ -41,6 +42,10 @@ pub use engine_generator::{
 pub use external_power_source::ExternalPowerSource;
 use rustc_hash::{FxHashMap, FxHashSet};
 pub use static_inverter::StaticInverter;
+pub use topology::{
+    ElectricalTopology, ElectricalTopologyDescription, ElectricalTopologyElements, TopologyBus,
+    TopologyContactor, TopologyError, TopologyNodeKind,
+};
 pub use transformer_rectifier::TransformerRectifier;
 use uom::si::{electric_potential::volt, f64::*, power::watt, velocity::knot};
 
@@ -276,6 +281,11 @@ pub trait ProvideLoad {
     fn load_normal(&self) -> bool;
 }
 
+pub trait ProvideTemperature {
+    fn temperature(&self) -> ThermodynamicTemperature;
+    fn temperature_normal(&self) -> bool;
+}
+
 /// Determines if and for how long the aircraft is in an emergency electrical situation.
 pub struct EmergencyElectrical {
     is_active_for_duration: Duration,
@@ -410,8 +420,8 @@ impl Electricity {
     /// ```
     pub fn flow(
         &mut self,
-        from_output: &impl ElectricalElement,
-        to_input: &impl ElectricalElement,
+        from_output: &(impl ElectricalElement + ?Sized),
+        to_input: &(impl ElectricalElement + ?Sized),
     ) {
         if from_output.is_conductive() && to_input.is_conductive() {
             self.potential
@@ -444,7 +454,7 @@ impl Electricity {
     /// electricity.supplied_by(&generator);
     /// electricity.flow(&generator, &contactor);
     /// ```
-    pub fn supplied_by(&mut self, source: &impl ElectricitySource) {
+    pub fn supplied_by(&mut self, source: &(impl ElectricitySource + ?Sized)) {
         let output_identifier = source.output_identifier();
         self.potential.supplied_by(
             output_identifier,
@@ -478,7 +488,7 @@ impl Electricity {
     /// electricity.transform_in(&tr);
     /// electricity.flow(&tr, &dc_bus);
     /// ```
-    pub fn transform_in(&mut self, transformer: &impl ElectricityTransformer) {
+    pub fn transform_in(&mut self, transformer: &(impl ElectricityTransformer + ?Sized)) {
         let output_identifier = transformer.output_identifier();
         let transformed_potential = match self.potential.get(transformer.input_identifier()) {
             Some(input_potential) => transformer
@@ -0,0 +1,995 @@
+//! Declarative description of the wiring of an electrical system.
+//!
+//! An [`ElectricalTopologyDescription`] lists the buses, contactors, sources and transformers of
+//! an electrical system and how they are connected. From it, an [`ElectricalTopology`] creates
+//! the buses and contactors and replays the wiring onto [`Electricity`] every tick. Potential
+//! propagation and load sharing between the origins of a network remain the responsibility of
+//! [`Electricity`]; the topology only takes care of supplying, flowing and transforming in an
+//! order which makes the result independent of the order of the description.
+//!
+//! Sources and transformers are owned by the aircraft, as they come with their own behaviour.
+//! So are external elements, which connect the topology to the parts of the electrical system
+//! it doesn't describe. They are looked up by name through [`ElectricalTopologyElements`]. The
+//! logic deciding when contactors close also remains with the aircraft, which addresses buses and
+//! contactors through the handles returned when adding them to the description.
+//!
+//! The description can be validated in tests and exported as a Graphviz diagram.
+
+use super::{
+    Contactor, ElectricalBus, ElectricalElement, Electricity, ElectricitySource,
+    ElectricityTransformer,
+};
+use crate::{
+    shared::ElectricalBusType,
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor},
+};
+use rustc_hash::FxHashMap;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TopologyNodeKind {
+    Bus(ElectricalBusType),
+    Contactor,
+    Source,
+    Transformer,
+    External,
+}
+
+/// Identifies a bus of the [`ElectricalTopology`] created from the description it was added to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TopologyBus(usize);
+
+/// Identifies a contactor of the [`ElectricalTopology`] created from the description it was
+/// added to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TopologyContactor(usize);
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TopologyError {
+    DuplicateNode(String),
+    UnknownNode(String),
+    UnconnectedNode(String),
+    TransformerWithoutInput(String),
+    TransformerWithoutOutput(String),
+    BusWithoutSource(String),
+}
+
+/// Describes the elements of an electrical system and how they are connected.
+///
+/// A connection flows electricity from the output of the first element to the input of the
+/// second element. The direction is only relevant for transformers: connecting an element to a
+/// transformer feeds its input, while connecting a transformer to an element feeds from its
+/// output.
+#[derive(Clone, Debug, Default)]
+pub struct ElectricalTopologyDescription {
+    nodes: Vec<(String, TopologyNodeKind)>,
+    connections: Vec<(String, String)>,
+}
+impl ElectricalTopologyDescription {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_bus(mut self, name: &str, bus_type: ElectricalBusType) -> Self {
+        self.add_bus(name, bus_type);
+        self
+    }
+
+    pub fn add_bus(&mut self, name: &str, bus_type: ElectricalBusType) -> TopologyBus {
+        TopologyBus(self.add_node(name, TopologyNodeKind::Bus(bus_type)))
+    }
+
+    /// Adds a contactor. The name is used as the contactor's identifier in the simulator.
+    pub fn with_contactor(mut self, name: &str) -> Self {
+        self.add_contactor(name);
+        self
+    }
+
+    /// Adds a contactor. The name is used as the contactor's identifier in the simulator.
+    pub fn add_contactor(&mut self, name: &str) -> TopologyContactor {
+        TopologyContactor(self.add_node(name, TopologyNodeKind::Contactor))
+    }
+
+    pub fn with_source(self, name: &str) -> Self {
+        self.with_node(name, TopologyNodeKind::Source)
+    }
+
+    pub fn with_transformer(self, name: &str) -> Self {
+        self.with_node(name, TopologyNodeKind::Transformer)
+    }
+
+    /// Adds an element which isn't part of the topology, such as a bus of another part of the
+    /// electrical system. Potential flows through it, it is thus considered as a possible
+    /// supply of the buses it connects to.
+    pub fn with_external(self, name: &str) -> Self {
+        self.with_node(name, TopologyNodeKind::External)
+    }
+
+    pub fn with_connection(mut self, from: &str, to: &str) -> Self {
+        self.add_connection(from, to);
+        self
+    }
+
+    pub fn add_connection(&mut self, from: &str, to: &str) {
+        self.connections.push((from.to_owned(), to.to_owned()));
+    }
+
+    fn with_node(mut self, name: &str, kind: TopologyNodeKind) -> Self {
+        self.add_node(name, kind);
+        self
+    }
+
+    fn add_node(&mut self, name: &str, kind: TopologyNodeKind) -> usize {
+        self.nodes.push((name.to_owned(), kind));
+        self.nodes.len() - 1
+    }
+
+    pub fn validate(&self) -> Result<(), Vec<TopologyError>> {
+        let mut errors = Vec::new();
+
+        let mut indices: FxHashMap<&str, usize> = FxHashMap::default();
+        for (index, (name, _)) in self.nodes.iter().enumerate() {
+            if indices.insert(name.as_str(), index).is_some() {
+                errors.push(TopologyError::DuplicateNode(name.clone()));
+            }
+        }
+
+        for (from, to) in &self.connections {
+            for name in [from, to] {
+                if !indices.contains_key(name.as_str()) {
+                    errors.push(TopologyError::UnknownNode(name.clone()));
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let ports = TopologyPorts::new(self);
+        for (index, (name, kind)) in self.nodes.iter().enumerate() {
+            let is_fed = self.connections.iter().any(|(_, to)| to == name);
+            let feeds = self.connections.iter().any(|(from, _)| from == name);
+
+            match kind {
+                TopologyNodeKind::Transformer => {
+                    if !is_fed {
+                        errors.push(TopologyError::TransformerWithoutInput(name.clone()));
+                    }
+                    if !feeds {
+                        errors.push(TopologyError::TransformerWithoutOutput(name.clone()));
+                    }
+                }
+                _ if !is_fed && !feeds => {
+                    errors.push(TopologyError::UnconnectedNode(name.clone()));
+                }
+                TopologyNodeKind::Bus(_) if !ports.can_be_powered(index) => {
+                    errors.push(TopologyError::BusWithoutSource(name.clone()));
+                }
+                _ => {}
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Returns the topology in the Graphviz DOT language.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph electrical {\n");
+        for (name, kind) in &self.nodes {
+            let shape = match kind {
+                TopologyNodeKind::Bus(_) => "box",
+                TopologyNodeKind::Contactor => "diamond",
+                TopologyNodeKind::Source => "circle",
+                TopologyNodeKind::Transformer => "trapezium",
+                TopologyNodeKind::External => "ellipse",
+            };
+            writeln!(dot, "    \"{}\" [shape={}];", name, shape).unwrap();
+        }
+        for (from, to) in &self.connections {
+            writeln!(dot, "    \"{}\" -> \"{}\";", from, to).unwrap();
+        }
+        dot.push('}');
+
+        dot
+    }
+
+    fn index_of(&self, name: &str) -> usize {
+        self.nodes
+            .iter()
+            .position(|(node_name, _)| node_name == name)
+            .unwrap_or_else(|| panic!("Unknown electrical topology node {}.", name))
+    }
+}
+
+/// Groups the elements of a description into the networks they form when all contactors
+/// are closed. Transformers have a separate input and output port, as they do not conduct
+/// potential from one to the other.
+struct TopologyPorts {
+    parents: Vec<usize>,
+    input_ports: Vec<usize>,
+    output_ports: Vec<usize>,
+    source_indices: Vec<usize>,
+    transformer_indices: Vec<usize>,
+    external_indices: Vec<usize>,
+}
+impl TopologyPorts {
+    fn new(description: &ElectricalTopologyDescription) -> Self {
+        let mut input_ports = Vec::new();
+        let mut output_ports = Vec::new();
+        let mut port_count = 0;
+        for (_, kind) in &description.nodes {
+            input_ports.push(port_count);
+            if *kind == TopologyNodeKind::Transformer {
+                port_count += 1;
+            }
+            output_ports.push(port_count);
+            port_count += 1;
+        }
+
+        let mut ports = Self {
+            parents: (0..port_count).collect(),
+            input_ports,
+            output_ports,
+            source_indices: Self::indices_of(description, TopologyNodeKind::Source),
+            transformer_indices: Self::indices_of(description, TopologyNodeKind::Transformer),
+            external_indices: Self::indices_of(description, TopologyNodeKind::External),
+        };
+
+        for (from, to) in &description.connections {
+            let from_port = ports.output_ports[description.index_of(from)];
+            let to_port = ports.input_ports[description.index_of(to)];
+            ports.union(from_port, to_port);
+        }
+
+        ports
+    }
+
+    fn indices_of(
+        description: &ElectricalTopologyDescription,
+        kind: TopologyNodeKind,
+    ) -> Vec<usize> {
+        description
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, node_kind))| *node_kind == kind)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    fn find(&self, mut port: usize) -> usize {
+        while self.parents[port] != port {
+            port = self.parents[port];
+        }
+
+        port
+    }
+
+    fn union(&mut self, left: usize, right: usize) {
+        let left = self.find(left);
+        let right = self.find(right);
+        self.parents[right] = left;
+    }
+
+    fn input_network(&self, index: usize) -> usize {
+        self.find(self.input_ports[index])
+    }
+
+    fn output_network(&self, index: usize) -> usize {
+        self.find(self.output_ports[index])
+    }
+
+    fn can_be_powered(&self, index: usize) -> bool {
+        let mut powered_networks: Vec<usize> = self
+            .source_indices
+            .iter()
+            .chain(&self.external_indices)
+            .map(|&supply| self.output_network(supply))
+            .collect();
+
+        loop {
+            let newly_powered: Vec<usize> = self
+                .transformer_indices
+                .iter()
+                .filter(|&&transformer| {
+                    powered_networks.contains(&self.input_network(transformer))
+                        && !powered_networks.contains(&self.output_network(transformer))
+                })
+                .map(|&transformer| self.output_network(transformer))
+                .collect();
+
+            if newly_powered.is_empty() {
+                break;
+            }
+            powered_networks.extend(newly_powered);
+        }
+
+        powered_networks.contains(&self.input_network(index))
+    }
+
+    /// Orders transformers such that a transformer feeding the network another transformer
+    /// takes its input from is transformed first. Transformers feeding each other in a loop
+    /// remain in the order of the description.
+    fn transformer_order(&self) -> Vec<usize> {
+        let mut remaining = self.transformer_indices.clone();
+        let mut order = Vec::new();
+
+        while !remaining.is_empty() {
+            let ready = remaining.iter().position(|&transformer| {
+                !remaining.iter().any(|&other| {
+                    other != transformer
+                        && self.output_network(other) == self.input_network(transformer)
+                })
+            });
+
+            order.push(remaining.remove(ready.unwrap_or(0)));
+        }
+
+        order
+    }
+}
+
+/// Provides the sources, transformers and external elements of an [`ElectricalTopology`], which
+/// are owned by the aircraft.
+pub trait ElectricalTopologyElements {
+    fn source(&self, name: &str) -> Option<&dyn ElectricitySource>;
+    fn transformer(&self, name: &str) -> Option<&dyn ElectricityTransformer>;
+    fn external(&self, name: &str) -> Option<&dyn ElectricalElement>;
+}
+
+enum TopologyNode {
+    Bus(ElectricalBus),
+    Contactor(Contactor),
+    Source,
+    Transformer,
+    External,
+}
+
+pub struct ElectricalTopology {
+    names: Vec<String>,
+    nodes: Vec<TopologyNode>,
+    connections: Vec<(usize, usize)>,
+    source_indices: Vec<usize>,
+    transformer_order: Vec<usize>,
+}
+impl ElectricalTopology {
+    pub fn new(context: &mut InitContext, description: &ElectricalTopologyDescription) -> Self {
+        if let Err(errors) = description.validate() {
+            panic!("Invalid electrical topology: {:?}", errors);
+        }
+
+        let ports = TopologyPorts::new(description);
+
+        Self {
+            names: description
+                .nodes
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect(),
+            nodes: description
+                .nodes
+                .iter()
+                .map(|(name, kind)| match kind {
+                    TopologyNodeKind::Bus(bus_type) => {
+                        TopologyNode::Bus(ElectricalBus::new(context, *bus_type))
+                    }
+                    TopologyNodeKind::Contactor => {
+                        TopologyNode::Contactor(Contactor::new(context, name))
+                    }
+                    TopologyNodeKind::Source => TopologyNode::Source,
+                    TopologyNodeKind::Transformer => TopologyNode::Transformer,
+                    TopologyNodeKind::External => TopologyNode::External,
+                })
+                .collect(),
+            connections: description
+                .connections
+                .iter()
+                .map(|(from, to)| (description.index_of(from), description.index_of(to)))
+                .collect(),
+            source_indices: ports.source_indices.clone(),
+            transformer_order: ports.transformer_order(),
+        }
+    }
+
+    pub fn bus(&self, bus: TopologyBus) -> &ElectricalBus {
+        match &self.nodes[bus.0] {
+            TopologyNode::Bus(bus) => bus,
+            _ => panic!("{:?} doesn't belong to this electrical topology.", bus),
+        }
+    }
+
+    pub fn contactor(&self, contactor: TopologyContactor) -> &Contactor {
+        match &self.nodes[contactor.0] {
+            TopologyNode::Contactor(contactor) => contactor,
+            _ => panic!(
+                "{:?} doesn't belong to this electrical topology.",
+                contactor
+            ),
+        }
+    }
+
+    pub fn close_contactor_when(&mut self, contactor: TopologyContactor, should_be_closed: bool) {
+        match &mut self.nodes[contactor.0] {
+            TopologyNode::Contactor(contactor) => contactor.close_when(should_be_closed),
+            _ => panic!(
+                "{:?} doesn't belong to this electrical topology.",
+                contactor
+            ),
+        }
+    }
+
+    /// Supplies the potential of all sources, flows it through all connections and
+    /// transforms it within all transformers.
+    pub fn distribute(
+        &self,
+        electricity: &mut Electricity,
+        elements: &impl ElectricalTopologyElements,
+    ) {
+        for &index in &self.source_indices {
+            electricity.supplied_by(self.source(index, elements));
+        }
+
+        for &(from, to) in &self.connections {
+            electricity.flow(self.element(from, elements), self.element(to, elements));
+        }
+
+        for &index in &self.transformer_order {
+            electricity.transform_in(self.transformer(index, elements));
+        }
+    }
+
+    fn element<'a>(
+        &'a self,
+        index: usize,
+        elements: &'a impl ElectricalTopologyElements,
+    ) -> &'a dyn ElectricalElement {
+        match &self.nodes[index] {
+            TopologyNode::Bus(bus) => bus,
+            TopologyNode::Contactor(contactor) => contactor,
+            TopologyNode::Source => self.source(index, elements),
+            TopologyNode::Transformer => self.transformer(index, elements),
+            TopologyNode::External => elements.external(&self.names[index]).unwrap_or_else(|| {
+                panic!(
+                    "No external electrical element named {}.",
+                    self.names[index]
+                )
+            }),
+        }
+    }
+
+    fn source<'a>(
+        &self,
+        index: usize,
+        elements: &'a impl ElectricalTopologyElements,
+    ) -> &'a dyn ElectricitySource {
+        elements
+            .source(&self.names[index])
+            .unwrap_or_else(|| panic!("No electricity source named {}.", self.names[index]))
+    }
+
+    fn transformer<'a>(
+        &self,
+        index: usize,
+        elements: &'a impl ElectricalTopologyElements,
+    ) -> &'a dyn ElectricityTransformer {
+        elements
+            .transformer(&self.names[index])
+            .unwrap_or_else(|| panic!("No electricity transformer named {}.", self.names[index]))
+    }
+}
+impl SimulationElement for ElectricalTopology {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        for node in self.nodes.iter_mut() {
+            match node {
+                TopologyNode::Bus(bus) => bus.accept(visitor),
+                TopologyNode::Contactor(contactor) => contactor.accept(visitor),
+                TopologyNode::Source | TopologyNode::Transformer | TopologyNode::External => {}
+            }
+        }
+
+        visitor.visit(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{
+            consumption::PowerConsumer, test::TestElectricitySource, PotentialOrigin,
+            StaticInverter, TransformerRectifier,
+        },
+        shared::PowerConsumptionReport,
+        simulation::{
+            test::{ElementCtorFn, ReadByName, SimulationTestBed, TestBed},
+            Aircraft, UpdateContext,
+        },
+    };
+    use uom::si::{f64::*, power::watt};
+
+    struct TestContactors {
+        gen_1_line: TopologyContactor,
+        gen_2_line: TopologyContactor,
+        bus_tie: TopologyContactor,
+        dc_bat_tie: TopologyContactor,
+    }
+
+    fn description() -> ElectricalTopologyDescription {
+        description_with_contactors().0
+    }
+
+    fn description_with_contactors() -> (ElectricalTopologyDescription, TestContactors) {
+        let mut description = ElectricalTopologyDescription::new()
+            .with_source("GEN_1")
+            .with_source("GEN_2")
+            .with_external("DC_BAT");
+        let contactors = TestContactors {
+            gen_1_line: description.add_contactor("GEN_1_LINE"),
+            gen_2_line: description.add_contactor("GEN_2_LINE"),
+            bus_tie: description.add_contactor("BUS_TIE"),
+            dc_bat_tie: description.add_contactor("DC_BAT_TIE"),
+        };
+
+        let description = description
+            .with_bus("AC_1", ElectricalBusType::AlternatingCurrent(1))
+            .with_bus("AC_2", ElectricalBusType::AlternatingCurrent(2))
+            .with_transformer("STAT_INV")
+            .with_transformer("TR_1")
+            .with_bus("DC_1", ElectricalBusType::DirectCurrent(1))
+            .with_bus("AC_ESS", ElectricalBusType::AlternatingCurrentEssential)
+            .with_connection("GEN_1", "GEN_1_LINE")
+            .with_connection("GEN_1_LINE", "AC_1")
+            .with_connection("GEN_2", "GEN_2_LINE")
+            .with_connection("GEN_2_LINE", "AC_2")
+            .with_connection("AC_1", "BUS_TIE")
+            .with_connection("BUS_TIE", "AC_2")
+            .with_connection("DC_1", "STAT_INV")
+            .with_connection("STAT_INV", "AC_ESS")
+            .with_connection("AC_1", "TR_1")
+            .with_connection("TR_1", "DC_1")
+            .with_connection("DC_BAT", "DC_BAT_TIE")
+            .with_connection("DC_BAT_TIE", "DC_1");
+
+        (description, contactors)
+    }
+
+    struct TopologyTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl TopologyTestBed {
+        fn new() -> Self {
+            Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            }
+        }
+
+        fn and(self) -> Self {
+            self
+        }
+
+        fn powered_generator(mut self, number: usize) -> Self {
+            self.command(|a| a.power_generator(number));
+            self
+        }
+
+        fn powered_battery(mut self) -> Self {
+            self.command(|a| a.battery.power());
+            self
+        }
+
+        fn closed_contactor(mut self, contactor: fn(&TestContactors) -> TopologyContactor) -> Self {
+            self.command(|a| a.close_contactor(contactor));
+            self
+        }
+
+        fn demand_on_ac_1(mut self, power: Power) -> Self {
+            self.command(|a| a.ac_1_consumer.demand(power));
+            self
+        }
+
+        fn bus_is_powered(&mut self, bus_type: ElectricalBusType) -> bool {
+            self.read_by_name(&format!("ELEC_{}_BUS_IS_POWERED", bus_type))
+        }
+
+        fn generator_consumption(&self, number: usize) -> Power {
+            self.query(|a| a.generator_consumption[number - 1])
+        }
+    }
+    impl TestBed for TopologyTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> TopologyTestBed {
+        TopologyTestBed::new()
+    }
+
+    struct TestAircraft {
+        topology: ElectricalTopology,
+        contactors: TestContactors,
+        generators: [TestElectricitySource; 2],
+        transformer_rectifier: TransformerRectifier,
+        static_inverter: StaticInverter,
+        battery: TestElectricitySource,
+        dc_bat_bus: ElectricalBus,
+        contactors_to_close: Vec<TopologyContactor>,
+        ac_1_consumer: PowerConsumer,
+        generator_consumption: [Power; 2],
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            let (description, contactors) = description_with_contactors();
+            Self {
+                topology: ElectricalTopology::new(context, &description),
+                contactors,
+                generators: [
+                    TestElectricitySource::unpowered(context, PotentialOrigin::EngineGenerator(1)),
+                    TestElectricitySource::unpowered(context, PotentialOrigin::EngineGenerator(2)),
+                ],
+                transformer_rectifier: TransformerRectifier::new(context, 1),
+                static_inverter: StaticInverter::new(context),
+                battery: TestElectricitySource::unpowered(context, PotentialOrigin::Battery(1)),
+                dc_bat_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentBattery),
+                contactors_to_close: Vec::new(),
+                ac_1_consumer: PowerConsumer::from(ElectricalBusType::AlternatingCurrent(1)),
+                generator_consumption: [Power::new::<watt>(0.); 2],
+            }
+        }
+
+        fn power_generator(&mut self, number: usize) {
+            self.generators[number - 1].power();
+        }
+
+        fn close_contactor(&mut self, contactor: fn(&TestContactors) -> TopologyContactor) {
+            self.contactors_to_close.push(contactor(&self.contactors));
+        }
+    }
+    impl ElectricalTopologyElements for TestAircraft {
+        fn source(&self, name: &str) -> Option<&dyn ElectricitySource> {
+            match name {
+                "GEN_1" => Some(&self.generators[0]),
+                "GEN_2" => Some(&self.generators[1]),
+                _ => None,
+            }
+        }
+
+        fn transformer(&self, name: &str) -> Option<&dyn ElectricityTransformer> {
+            match name {
+                "TR_1" => Some(&self.transformer_rectifier),
+                "STAT_INV" => Some(&self.static_inverter),
+                _ => None,
+            }
+        }
+
+        fn external(&self, name: &str) -> Option<&dyn ElectricalElement> {
+            match name {
+                "DC_BAT" => Some(&self.dc_bat_bus),
+                _ => None,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            for contactor in [
+                self.contactors.gen_1_line,
+                self.contactors.gen_2_line,
+                self.contactors.bus_tie,
+                self.contactors.dc_bat_tie,
+            ] {
+                let should_be_closed = self.contactors_to_close.contains(&contactor);
+                self.topology
+                    .close_contactor_when(contactor, should_be_closed);
+            }
+
+            electricity.supplied_by(&self.battery);
+            electricity.flow(&self.battery, &self.dc_bat_bus);
+
+            self.topology.distribute(electricity, self);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.topology.accept(visitor);
+            self.transformer_rectifier.accept(visitor);
+            self.static_inverter.accept(visitor);
+            self.dc_bat_bus.accept(visitor);
+            self.ac_1_consumer.accept(visitor);
+
+            visitor.visit(self);
+        }
+
+        fn process_power_consumption_report<T: PowerConsumptionReport>(
+            &mut self,
+            _: &UpdateContext,
+            report: &T,
+        ) {
+            self.generator_consumption = [
+                report.total_consumption_of(PotentialOrigin::EngineGenerator(1)),
+                report.total_consumption_of(PotentialOrigin::EngineGenerator(2)),
+            ];
+        }
+    }
+
+    #[test]
+    fn valid_description_passes_validation() {
+        assert_eq!(description().validate(), Ok(()));
+    }
+
+    #[test]
+    fn duplicate_node_is_invalid() {
+        let description = description().with_bus("AC_1", ElectricalBusType::AlternatingCurrent(1));
+
+        assert_eq!(
+            description.validate(),
+            Err(vec![TopologyError::DuplicateNode("AC_1".to_owned())])
+        );
+    }
+
+    #[test]
+    fn connection_to_unknown_node_is_invalid() {
+        let description = description().with_connection("AC_2", "TR_2");
+
+        assert_eq!(
+            description.validate(),
+            Err(vec![TopologyError::UnknownNode("TR_2".to_owned())])
+        );
+    }
+
+    #[test]
+    fn unconnected_node_is_invalid() {
+        let description = description().with_contactor("UNUSED");
+
+        assert_eq!(
+            description.validate(),
+            Err(vec![TopologyError::UnconnectedNode("UNUSED".to_owned())])
+        );
+    }
+
+    #[test]
+    fn transformer_without_input_or_output_is_invalid() {
+        let description = description()
+            .with_transformer("TR_2")
+            .with_connection("AC_2", "TR_2")
+            .with_transformer("TR_3")
+            .with_bus("DC_2", ElectricalBusType::DirectCurrent(2))
+            .with_connection("TR_3", "DC_2");
+
+        assert_eq!(
+            description.validate(),
+            Err(vec![
+                TopologyError::TransformerWithoutOutput("TR_2".to_owned()),
+                TopologyError::TransformerWithoutInput("TR_3".to_owned()),
+                TopologyError::BusWithoutSource("DC_2".to_owned()),
+            ])
+        );
+    }
+
+    #[test]
+    fn bus_which_cannot_be_powered_is_invalid() {
+        let description = description()
+            .with_bus("DC_2", ElectricalBusType::DirectCurrent(2))
+            .with_contactor("DC_TIE")
+            .with_connection("DC_2", "DC_TIE");
+
+        assert_eq!(
+            description.validate(),
+            Err(vec![TopologyError::BusWithoutSource("DC_2".to_owned())])
+        );
+    }
+
+    #[test]
+    fn bus_powered_through_transformer_chain_is_valid() {
+        let description = ElectricalTopologyDescription::new()
+            .with_transformer("STAT_INV")
+            .with_bus("AC_ESS", ElectricalBusType::AlternatingCurrentEssential)
+            .with_transformer("TR_1")
+            .with_bus("DC_1", ElectricalBusType::DirectCurrent(1))
+            .with_source("GEN_1")
+            .with_connection("STAT_INV", "AC_ESS")
+            .with_connection("DC_1", "STAT_INV")
+            .with_connection("TR_1", "DC_1")
+            .with_connection("GEN_1", "TR_1");
+
+        assert_eq!(description.validate(), Ok(()));
+    }
+
+    #[test]
+    fn bus_fed_by_external_element_is_valid() {
+        let description = ElectricalTopologyDescription::new()
+            .with_external("DC_BAT")
+            .with_contactor("DC_ESS_TIE")
+            .with_bus("DC_ESS", ElectricalBusType::DirectCurrentEssential)
+            .with_connection("DC_BAT", "DC_ESS_TIE")
+            .with_connection("DC_ESS_TIE", "DC_ESS");
+
+        assert_eq!(description.validate(), Ok(()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn creating_an_invalid_topology_panics() {
+        let _ = SimulationTestBed::from(ElementCtorFn(|context| {
+            ElectricalTopology::new(
+                context,
+                &description().with_bus("DC_2", ElectricalBusType::DirectCurrent(2)),
+            )
+        }));
+    }
+
+    #[test]
+    fn exports_graphviz_diagram() {
+        let dot = ElectricalTopologyDescription::new()
+            .with_source("GEN_1")
+            .with_contactor("GEN_1_LINE")
+            .with_bus("AC_1", ElectricalBusType::AlternatingCurrent(1))
+            .with_connection("GEN_1", "GEN_1_LINE")
+            .with_connection("GEN_1_LINE", "AC_1")
+            .to_dot();
+
+        assert_eq!(
+            dot,
+            "digraph electrical {\n    \"GEN_1\" [shape=circle];\n    \"GEN_1_LINE\" [shape=diamond];\n    \"AC_1\" [shape=box];\n    \"GEN_1\" -> \"GEN_1_LINE\";\n    \"GEN_1_LINE\" -> \"AC_1\";\n}"
+        );
+    }
+
+    #[test]
+    fn buses_are_unpowered_when_no_source_is_powered() {
+        let mut test_bed = test_bed()
+            .closed_contactor(|c| c.gen_1_line)
+            .and()
+            .closed_contactor(|c| c.bus_tie);
+
+        test_bed.run();
+
+        assert!(!test_bed.bus_is_powered(ElectricalBusType::AlternatingCurrent(1)));
+        assert!(!test_bed.bus_is_powered(ElectricalBusType::AlternatingCurrent(2)));
+    }
+
+    #[test]
+    fn potential_flows_through_closed_contactors() {
+        let mut test_bed = test_bed()
+            .powered_generator(1)
+            .closed_contactor(|c| c.gen_1_line)
+            .and()
+            .closed_contactor(|c| c.bus_tie);
+
+        test_bed.run();
+
+        assert!(test_bed.bus_is_powered(ElectricalBusType::AlternatingCurrent(1)));
+        assert!(test_bed.bus_is_powered(ElectricalBusType::AlternatingCurrent(2)));
+    }
+
+    #[test]
+    fn potential_does_not_flow_through_open_contactors() {
+        let mut test_bed = test_bed()
+            .powered_generator(1)
+            .and()
+            .closed_contactor(|c| c.gen_1_line);
+
+        test_bed.run();
+
+        assert!(test_bed.bus_is_powered(ElectricalBusType::AlternatingCurrent(1)));
+        assert!(!test_bed.bus_is_powered(ElectricalBusType::AlternatingCurrent(2)));
+    }
+
+    #[test]
+    fn contactor_state_is_exposed() {
+        let mut test_bed = test_bed().closed_contactor(|c| c.bus_tie);
+
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.topology.contactor(a.contactors.bus_tie).is_closed()));
+        assert!(test_bed.query(|a| a.topology.contactor(a.contactors.gen_1_line).is_open()));
+    }
+
+    #[test]
+    fn potential_is_transformed_in_dependency_order() {
+        let mut test_bed = test_bed()
+            .powered_generator(1)
+            .and()
+            .closed_contactor(|c| c.gen_1_line);
+
+        // The static inverter only establishes its output potential after its first update.
+        test_bed.run();
+        test_bed.run();
+
+        assert!(test_bed.bus_is_powered(ElectricalBusType::DirectCurrent(1)));
+        assert!(test_bed.bus_is_powered(ElectricalBusType::AlternatingCurrentEssential));
+    }
+
+    #[test]
+    fn transformers_are_unpowered_without_input() {
+        let mut test_bed = test_bed().powered_generator(1);
+
+        test_bed.run();
+
+        assert!(!test_bed.bus_is_powered(ElectricalBusType::DirectCurrent(1)));
+        assert!(!test_bed.bus_is_powered(ElectricalBusType::AlternatingCurrentEssential));
+    }
+
+    #[test]
+    fn potential_flows_from_external_element_into_the_topology() {
+        let mut test_bed = test_bed()
+            .powered_battery()
+            .and()
+            .closed_contactor(|c| c.dc_bat_tie);
+
+        test_bed.run();
+
+        assert!(test_bed.bus_is_powered(ElectricalBusType::DirectCurrent(1)));
+        assert!(!test_bed.bus_is_powered(ElectricalBusType::AlternatingCurrent(1)));
+    }
+
+    #[test]
+    fn potential_flows_from_the_topology_into_external_element() {
+        let mut test_bed = test_bed()
+            .powered_generator(1)
+            .closed_contactor(|c| c.gen_1_line)
+            .and()
+            .closed_contactor(|c| c.dc_bat_tie);
+
+        test_bed.run();
+
+        assert!(test_bed.bus_is_powered(ElectricalBusType::DirectCurrentBattery));
+    }
+
+    #[test]
+    fn external_element_is_exported_as_ellipse() {
+        let dot = ElectricalTopologyDescription::new()
+            .with_external("DC_BAT")
+            .with_bus("DC_1", ElectricalBusType::DirectCurrent(1))
+            .with_connection("DC_BAT", "DC_1")
+            .to_dot();
+
+        assert!(dot.contains("\"DC_BAT\" [shape=ellipse];"));
+    }
+
+    #[test]
+    fn load_is_shared_between_sources_powering_the_same_network() {
+        let mut test_bed = test_bed()
+            .powered_generator(1)
+            .powered_generator(2)
+            .closed_contactor(|c| c.gen_1_line)
+            .closed_contactor(|c| c.gen_2_line)
+            .closed_contactor(|c| c.bus_tie)
+            .and()
+            .demand_on_ac_1(Power::new::<watt>(1000.));
+
+        test_bed.run();
+
+        assert_eq!(test_bed.generator_consumption(1), Power::new::<watt>(500.));
+        assert_eq!(test_bed.generator_consumption(2), Power::new::<watt>(500.));
+    }
+
+    #[test]
+    fn load_is_taken_by_the_only_source_powering_the_network() {
+        let mut test_bed = test_bed()
+            .powered_generator(1)
+            .powered_generator(2)
+            .closed_contactor(|c| c.gen_1_line)
+            .and()
+            .closed_contactor(|c| c.gen_2_line)
+            .demand_on_ac_1(Power::new::<watt>(1000.));
+
+        test_bed.run();
+
+        assert_eq!(test_bed.generator_consumption(1), Power::new::<watt>(1000.));
+        assert_eq!(test_bed.generator_consumption(2), Power::new::<watt>(0.));
+    }
+}
@@ -0,0 +1,754 @@
+//! A model of the core of a turbofan engine, overlaid on the engine of the simulator.
+//!
+//! The engine of the simulator runs the spools, from the start up to the thrust. While healthy,
+//! the core follows the high pressure spool speed reported by the simulator and models what the
+//! simulator lacks: the EGT and the oil temperature, pressure and quantity.
+//!
+//! The core only runs its own spool dynamics when it flamed out or seized. It then no longer
+//! follows the engine of the simulator, but runs down and windmills in the airflow, or stops when
+//! seized. The core signals the fuel cut off, such that the engine of the simulator runs down as
+//! well while the failure is active.
+//!
+//! A fire in the nacelle heats the oil of the core.
+
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{interpolation, FireDetectionZone},
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, StateReader,
+        StateWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+use uom::si::{
+    f64::*,
+    pressure::psi,
+    ratio::percent,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+    velocity::knot,
+    volume::quart_liquid,
+};
+
+pub struct EngineCoreProperties {
+    pub idle_speed_percent: f64,
+    pub light_off_speed_percent: f64,
+    /// The low pressure (fan) spool speed by high pressure spool speed, both in percent.
+    pub fan_speed_map: ([f64; 5], [f64; 5]),
+    pub idle_egt_rise_degree_celsius: f64,
+    pub max_egt_rise_degree_celsius: f64,
+    pub start_egt_rise_degree_celsius: f64,
+    pub oil_quantity_quart: f64,
+}
+
+pub struct EngineCore {
+    fuel_cut_off_id: VariableIdentifier,
+    on_fire_id: VariableIdentifier,
+
+    properties: EngineCoreProperties,
+
+    speed: f64,
+    is_burning: bool,
+    exhaust_gas_temperature: ThermodynamicTemperature,
+    oil_temperature: ThermodynamicTemperature,
+    oil_consumed_quart: f64,
+    oil_leaked_quart: f64,
+
+    flameout: Failure,
+    seizure: Failure,
+    oil_leak: Failure,
+    egt_overlimit: Failure,
+    fan_damage: Failure,
+    fire: Failure,
+}
+impl EngineCore {
+    // Aerodynamic and bearing drag slowing down a failed core
+    const QUADRATIC_DRAG_COEFFICIENT: f64 = 0.002;
+    const LINEAR_DRAG_COEFFICIENT: f64 = 0.05;
+
+    // Acceleration provided by the airflow through a failed core at the reference airspeed, such
+    // that the spool windmills at about 24 percent
+    const WINDMILLING_ACCELERATION_PERCENT_PER_S: f64 = 2.35;
+    const WINDMILLING_REFERENCE_AIRSPEED_KNOT: f64 = 300.;
+    const SEIZED_DECELERATION_PERCENT_PER_S: f64 = 25.;
+
+    const EGT_HEATING_TIME_CONSTANT_S: f64 = 3.;
+    const EGT_COOLING_TIME_CONSTANT_S: f64 = 30.;
+    const OIL_RUNNING_TEMPERATURE_DEGREE_CELSIUS: f64 = 95.;
+    const OIL_TEMPERATURE_TIME_CONSTANT_S: f64 = 300.;
+    const OIL_PRESSURE_PSI_PER_PERCENT: f64 = 0.72;
+    const OIL_PRESSURE_RELIEF_PSI: f64 = 90.;
+    const OIL_NOMINAL_TEMPERATURE_DEGREE_CELSIUS: f64 = 90.;
+    const OIL_VISCOSITY_PRESSURE_FACTOR_PER_DEGREE: f64 = 0.004;
+    // Oil held in the sumps and lines while the engine runs at full speed
+    const OIL_GULPING_QUART: f64 = 3.;
+    const OIL_CONSUMPTION_QUART_PER_HOUR: f64 = 0.2;
+    const OIL_LEAK_QUART_PER_MINUTE: f64 = 1.;
+    // Below this tank quantity the oil pump cavitates and the pressure drops
+    const OIL_STARVATION_QUART: f64 = 3.;
+    const OIL_STARVATION_TEMPERATURE_RISE_DEGREE_CELSIUS: f64 = 60.;
+    const FIRE_OIL_TEMPERATURE_RISE_DEGREE_CELSIUS: f64 = 80.;
+
+    const EGT_OVERLIMIT_RISE_DEGREE_CELSIUS: f64 = 200.;
+    const FAN_DAMAGE_EGT_RISE_DEGREE_CELSIUS: f64 = 40.;
+
+    const INITIAL_TEMPERATURE_DEGREE_CELSIUS: f64 = 15.;
+
+    pub fn new(context: &mut InitContext, number: usize, properties: EngineCoreProperties) -> Self {
+        let initial_temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            Self::INITIAL_TEMPERATURE_DEGREE_CELSIUS,
+        );
+        Self {
+            fuel_cut_off_id: context.get_identifier(format!("ENGINE_{}_FUEL_CUT_OFF", number)),
+            on_fire_id: context.get_identifier(format!("ENGINE_{}_ON_FIRE", number)),
+
+            properties,
+
+            speed: 0.,
+            is_burning: false,
+            exhaust_gas_temperature: initial_temperature,
+            oil_temperature: initial_temperature,
+            oil_consumed_quart: 0.,
+            oil_leaked_quart: 0.,
+
+            flameout: Failure::new(FailureType::EngineFlameout(number)),
+            seizure: Failure::new(FailureType::EngineSeizure(number)),
+            oil_leak: Failure::new(FailureType::EngineOilLeak(number)),
+            egt_overlimit: Failure::new(FailureType::EngineEgtOverlimit(number)),
+            fan_damage: Failure::new(FailureType::EngineFanDamage(number)),
+            fire: Failure::new(FailureType::SetOnFire(FireDetectionZone::Engine(number))),
+        }
+    }
+
+    /// Updates the core with the high pressure spool speed reported by the simulator.
+    pub fn update(&mut self, context: &UpdateContext, fuel_is_on: bool, reported_speed: Ratio) {
+        if self.has_failed() {
+            self.is_burning = false;
+            self.update_failed_speed(context);
+        } else {
+            self.speed = reported_speed.get::<percent>();
+            // The simulator doesn't report the light off, thus the fuel is assumed to burn once
+            // the starter drove the spool to the light off speed
+            self.is_burning = self.speed >= self.properties.idle_speed_percent
+                || (fuel_is_on && self.speed >= self.properties.light_off_speed_percent);
+        }
+
+        self.update_exhaust_gas_temperature(context);
+        self.update_oil(context);
+    }
+
+    fn update_failed_speed(&mut self, context: &UpdateContext) {
+        if self.seizure.is_active() {
+            self.speed = (self.speed
+                - Self::SEIZED_DECELERATION_PERCENT_PER_S * context.delta_as_secs_f64())
+            .max(0.);
+            return;
+        }
+
+        let drag = Self::QUADRATIC_DRAG_COEFFICIENT * self.speed.powi(2)
+            + Self::LINEAR_DRAG_COEFFICIENT * self.speed;
+        let windmilling = Self::WINDMILLING_ACCELERATION_PERCENT_PER_S
+            * (context.true_airspeed().get::<knot>().max(0.)
+                / Self::WINDMILLING_REFERENCE_AIRSPEED_KNOT)
+                .powi(2);
+
+        self.speed = (self.speed + (windmilling - drag) * context.delta_as_secs_f64()).max(0.);
+    }
+
+    fn update_exhaust_gas_temperature(&mut self, context: &UpdateContext) {
+        let ambient = context.ambient_temperature().get::<kelvin>();
+        let idle = self.properties.idle_speed_percent;
+        let light_off = self.properties.light_off_speed_percent;
+
+        let target = if self.is_burning {
+            // The lower air flow during the start lets the EGT peak above the idle EGT
+            let start_rise = self.properties.start_egt_rise_degree_celsius
+                * (1. - (self.speed - light_off) / (idle - light_off)).clamp(0., 1.);
+            let running_rise = (self.properties.max_egt_rise_degree_celsius
+                - self.properties.idle_egt_rise_degree_celsius)
+                * ((self.speed - idle) / (100. - idle)).clamp(0., 1.).powi(2);
+
+            let failure_rise = if self.egt_overlimit.is_active() {
+                Self::EGT_OVERLIMIT_RISE_DEGREE_CELSIUS
+            } else {
+                0.
+            } + if self.fan_damage.is_active() {
+                Self::FAN_DAMAGE_EGT_RISE_DEGREE_CELSIUS
+            } else {
+                0.
+            };
+
+            ambient
+                + self.properties.idle_egt_rise_degree_celsius
+                + start_rise
+                + running_rise
+                + failure_rise
+        } else {
+            ambient
+        };
+
+        let current = self.exhaust_gas_temperature.get::<kelvin>();
+        let time_constant = if target > current {
+            Self::EGT_HEATING_TIME_CONSTANT_S
+        } else {
+            Self::EGT_COOLING_TIME_CONSTANT_S
+        };
+        self.exhaust_gas_temperature = ThermodynamicTemperature::new::<kelvin>(Self::first_order(
+            context,
+            current,
+            target,
+            time_constant,
+        ));
+    }
+
+    fn update_oil(&mut self, context: &UpdateContext) {
+        let ambient = context.ambient_temperature().get::<kelvin>();
+        let running_temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            Self::OIL_RUNNING_TEMPERATURE_DEGREE_CELSIUS,
+        )
+        .get::<kelvin>();
+        let speed_ratio = (self.speed / 100.).clamp(0., 1.);
+        let target = ambient
+            + (running_temperature - ambient
+                + (1. - self.oil_supply_factor(self.speed))
+                    * Self::OIL_STARVATION_TEMPERATURE_RISE_DEGREE_CELSIUS)
+                * speed_ratio
+            + if self.fire.is_active() {
+                Self::FIRE_OIL_TEMPERATURE_RISE_DEGREE_CELSIUS
+            } else {
+                0.
+            };
+
+        self.oil_temperature = ThermodynamicTemperature::new::<kelvin>(Self::first_order(
+            context,
+            self.oil_temperature.get::<kelvin>(),
+            target,
+            Self::OIL_TEMPERATURE_TIME_CONSTANT_S,
+        ));
+
+        if self.is_burning {
+            self.oil_consumed_quart +=
+                Self::OIL_CONSUMPTION_QUART_PER_HOUR * context.delta_as_secs_f64() / 3600.;
+        }
+
+        // The leak flow follows the pressure in the oil lines
+        if self.oil_leak.is_active() {
+            self.oil_leaked_quart +=
+                Self::OIL_LEAK_QUART_PER_MINUTE * speed_ratio * context.delta_as_secs_f64() / 60.;
+        }
+    }
+
+    /// The ratio of the oil flow the pump can still deliver with the oil left in the tank.
+    fn oil_supply_factor(&self, speed: f64) -> f64 {
+        (self
+            .oil_quantity(Ratio::new::<percent>(speed))
+            .get::<quart_liquid>()
+            / Self::OIL_STARVATION_QUART)
+            .clamp(0., 1.)
+    }
+
+    fn first_order(context: &UpdateContext, current: f64, target: f64, time_constant: f64) -> f64 {
+        current + (target - current) * (1. - (-context.delta_as_secs_f64() / time_constant).exp())
+    }
+
+    /// The high pressure spool speed of the core.
+    pub fn speed(&self) -> Ratio {
+        Ratio::new::<percent>(self.speed)
+    }
+
+    /// The fan speed resulting from the given high pressure spool speed.
+    pub fn fan_speed(&self, speed: Ratio) -> Ratio {
+        let (speeds, fan_speeds) = &self.properties.fan_speed_map;
+        Ratio::new::<percent>(interpolation(speeds, fan_speeds, speed.get::<percent>()))
+    }
+
+    /// Whether the core flamed out or seized. A failed core no longer follows the engine of the
+    /// simulator.
+    pub fn has_failed(&self) -> bool {
+        self.flameout.is_active() || self.seizure.is_active()
+    }
+
+    /// The spool speed reported by the simulator, unless the core failed and runs down on its own.
+    pub fn overlaid_on_simulator(&self, simulator_speed: Ratio, core_speed: Ratio) -> Ratio {
+        if self.has_failed() {
+            core_speed
+        } else {
+            simulator_speed
+        }
+    }
+
+    pub fn fan_is_damaged(&self) -> bool {
+        self.fan_damage.is_active()
+    }
+
+    pub fn is_on_fire(&self) -> bool {
+        self.fire.is_active()
+    }
+
+    pub fn exhaust_gas_temperature(&self) -> ThermodynamicTemperature {
+        self.exhaust_gas_temperature
+    }
+
+    pub fn oil_temperature(&self) -> ThermodynamicTemperature {
+        self.oil_temperature
+    }
+
+    /// The oil pressure delivered by the gearbox driven oil pump at the given high pressure spool
+    /// speed. Cold oil is more viscous, thus it raises the pressure.
+    pub fn oil_pressure(&self, speed: Ratio) -> Pressure {
+        let viscosity_factor = 1.
+            + Self::OIL_VISCOSITY_PRESSURE_FACTOR_PER_DEGREE
+                * (Self::OIL_NOMINAL_TEMPERATURE_DEGREE_CELSIUS
+                    - self.oil_temperature.get::<degree_celsius>())
+                .clamp(0., 50.);
+
+        Pressure::new::<psi>(
+            (Self::OIL_PRESSURE_PSI_PER_PERCENT * speed.get::<percent>().max(0.))
+                .min(Self::OIL_PRESSURE_RELIEF_PSI)
+                * viscosity_factor
+                * self.oil_supply_factor(speed.get::<percent>()),
+        )
+    }
+
+    /// The oil quantity in the tank at the given high pressure spool speed. While running, part of
+    /// the oil is held in the sumps and lines.
+    pub fn oil_quantity(&self, speed: Ratio) -> Volume {
+        Volume::new::<quart_liquid>(
+            (self.properties.oil_quantity_quart
+                - self.oil_consumed_quart
+                - self.oil_leaked_quart
+                - Self::OIL_GULPING_QUART * (speed.get::<percent>() / 100.).clamp(0., 1.))
+            .max(0.),
+        )
+    }
+}
+impl SimulationElement for EngineCore {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.flameout.accept(visitor);
+        self.seizure.accept(visitor);
+        self.oil_leak.accept(visitor);
+        self.egt_overlimit.accept(visitor);
+        self.fan_damage.accept(visitor);
+        self.fire.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.fuel_cut_off_id, self.has_failed());
+        writer.write(&self.on_fire_id, self.is_on_fire());
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.speed);
+        state.write(self.exhaust_gas_temperature);
+        state.write(self.oil_temperature);
+        state.write(self.oil_consumed_quart);
+        state.write(self.oil_leaked_quart);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.speed = state.read();
+        self.exhaust_gas_temperature = state.read();
+        self.oil_temperature = state.read();
+        self.oil_consumed_quart = state.read();
+        self.oil_leaked_quart = state.read();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed},
+        Aircraft, InitContext, SimulationElement,
+    };
+    use std::time::Duration;
+
+    struct TestAircraft {
+        core: EngineCore,
+        fuel_is_on: bool,
+        reported_speed: Ratio,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                core: EngineCore::new(
+                    context,
+                    1,
+                    EngineCoreProperties {
+                        idle_speed_percent: 58.,
+                        light_off_speed_percent: 16.,
+                        fan_speed_map: ([0., 20., 58., 80., 100.], [0., 3., 19., 55., 100.]),
+                        idle_egt_rise_degree_celsius: 480.,
+                        max_egt_rise_degree_celsius: 900.,
+                        start_egt_rise_degree_celsius: 220.,
+                        oil_quantity_quart: 17.,
+                    },
+                ),
+                fuel_is_on: false,
+                reported_speed: Ratio::default(),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.core
+                .update(context, self.fuel_is_on, self.reported_speed);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.core.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct EngineCoreTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl EngineCoreTestBed {
+        fn new() -> Self {
+            Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            }
+        }
+
+        fn fuel(mut self, is_on: bool) -> Self {
+            self.command(|a| a.fuel_is_on = is_on);
+            self
+        }
+
+        fn reported_speed(mut self, speed_percent: f64) -> Self {
+            self.command(|a| a.reported_speed = Ratio::new::<percent>(speed_percent));
+            self
+        }
+
+        fn running_at(self, speed_percent: f64) -> Self {
+            self.fuel(true)
+                .reported_speed(speed_percent)
+                .run_for(Duration::from_secs(60))
+        }
+
+        fn failed(mut self, failure_type: FailureType) -> Self {
+            self.fail(failure_type);
+            self
+        }
+
+        fn in_flight_at(mut self, true_airspeed_knot: f64) -> Self {
+            self.set_on_ground(false);
+            self.set_true_airspeed(Velocity::new::<knot>(true_airspeed_knot));
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            self.test_bed.run_multiple_frames(duration);
+            self
+        }
+
+        fn speed(&self) -> f64 {
+            self.query(|a| a.core.speed().get::<percent>())
+        }
+
+        fn is_burning(&self) -> bool {
+            self.query(|a| a.core.is_burning)
+        }
+
+        fn egt(&self) -> f64 {
+            self.query(|a| a.core.exhaust_gas_temperature().get::<degree_celsius>())
+        }
+
+        fn oil_pressure(&self) -> f64 {
+            self.query(|a| a.core.oil_pressure(a.core.speed()).get::<psi>())
+        }
+
+        fn oil_quantity(&self) -> f64 {
+            self.query(|a| a.core.oil_quantity(a.core.speed()).get::<quart_liquid>())
+        }
+
+        fn fuel_is_cut_off(&mut self) -> bool {
+            self.read_by_name("ENGINE_1_FUEL_CUT_OFF")
+        }
+
+        fn is_signalled_on_fire(&mut self) -> bool {
+            self.read_by_name("ENGINE_1_ON_FIRE")
+        }
+    }
+    impl TestBed for EngineCoreTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    #[test]
+    fn core_follows_engine_of_simulator() {
+        let mut test_bed = EngineCoreTestBed::new()
+            .reported_speed(80.)
+            .run_for(Duration::from_millis(100));
+
+        assert!(test_bed.is_burning());
+        assert!((test_bed.speed() - 80.).abs() < f64::EPSILON);
+
+        test_bed = test_bed
+            .reported_speed(20.)
+            .run_for(Duration::from_millis(100));
+
+        assert!(!test_bed.is_burning());
+        assert!((test_bed.speed() - 20.).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn fuel_burns_during_start_above_light_off_speed() {
+        let mut test_bed = EngineCoreTestBed::new()
+            .reported_speed(20.)
+            .run_for(Duration::from_millis(100));
+        assert!(!test_bed.is_burning());
+
+        test_bed = test_bed.fuel(true).run_for(Duration::from_millis(100));
+        assert!(test_bed.is_burning());
+
+        test_bed = test_bed
+            .reported_speed(10.)
+            .run_for(Duration::from_millis(100));
+        assert!(!test_bed.is_burning());
+    }
+
+    #[test]
+    fn egt_peaks_during_start_above_idle_egt() {
+        let mut test_bed = EngineCoreTestBed::new().fuel(true);
+
+        let mut peak_egt: f64 = 0.;
+        for speed in 0..=58 {
+            test_bed = test_bed
+                .reported_speed(speed as f64)
+                .run_for(Duration::from_millis(500));
+            peak_egt = peak_egt.max(test_bed.egt());
+        }
+        test_bed = test_bed.run_for(Duration::from_secs(60));
+
+        assert!(peak_egt > test_bed.egt() + 20.);
+        assert!(test_bed.egt() > 400.);
+    }
+
+    #[test]
+    fn egt_cools_down_when_fuel_is_cut() {
+        let test_bed = test_bed_running_at(70.)
+            .fuel(false)
+            .reported_speed(0.)
+            .run_for(Duration::from_secs(300));
+
+        assert!(!test_bed.is_burning());
+        assert!(test_bed.egt() < 20.);
+    }
+
+    #[test]
+    fn cold_oil_raises_oil_pressure() {
+        let mut test_bed = EngineCoreTestBed::new();
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-20.));
+        test_bed = test_bed.run_for(Duration::from_secs(3600));
+
+        let cold_pressure = test_bed.query(|a| a.core.oil_pressure(Ratio::new::<percent>(58.)));
+
+        test_bed = test_bed
+            .fuel(true)
+            .reported_speed(80.)
+            .run_for(Duration::from_secs(3600));
+
+        let warm_pressure = test_bed.query(|a| a.core.oil_pressure(Ratio::new::<percent>(58.)));
+
+        assert!(cold_pressure > warm_pressure);
+    }
+
+    #[test]
+    fn flamed_out_core_runs_down_despite_simulator_engine_running() {
+        let test_bed = test_bed_running_at(80.)
+            .failed(FailureType::EngineFlameout(1))
+            .run_for(Duration::from_secs(120));
+
+        assert!(!test_bed.is_burning());
+        assert!(test_bed.speed() < 1.);
+        assert!(test_bed.query(|a| a.core.has_failed()));
+    }
+
+    #[test]
+    fn flamed_out_core_windmills_in_flight() {
+        let test_bed = test_bed_running_at(80.)
+            .in_flight_at(300.)
+            .failed(FailureType::EngineFlameout(1))
+            .run_for(Duration::from_secs(120));
+
+        assert!(!test_bed.is_burning());
+        assert!((test_bed.speed() - 24.).abs() < 1.5);
+    }
+
+    #[test]
+    fn core_follows_simulator_again_once_flameout_is_cleared() {
+        let mut test_bed = test_bed_running_at(80.)
+            .failed(FailureType::EngineFlameout(1))
+            .run_for(Duration::from_secs(120));
+        test_bed.unfail(FailureType::EngineFlameout(1));
+
+        test_bed = test_bed.run_for(Duration::from_millis(100));
+
+        assert!(test_bed.is_burning());
+        assert!((test_bed.speed() - 80.).abs() < f64::EPSILON);
+        assert!(!test_bed.query(|a| a.core.has_failed()));
+    }
+
+    #[test]
+    fn failed_core_overlays_simulator_speed() {
+        let mut test_bed = test_bed_running_at(80.);
+        let simulator_speed = Ratio::new::<percent>(80.);
+        let core_speed = Ratio::new::<percent>(10.);
+
+        assert_eq!(
+            test_bed.query(|a| a.core.overlaid_on_simulator(simulator_speed, core_speed)),
+            simulator_speed
+        );
+
+        test_bed = test_bed
+            .failed(FailureType::EngineFlameout(1))
+            .run_for(Duration::from_millis(100));
+
+        assert_eq!(
+            test_bed.query(|a| a.core.overlaid_on_simulator(simulator_speed, core_speed)),
+            core_speed
+        );
+    }
+
+    #[test]
+    fn failed_core_cuts_fuel_of_simulator_engine_while_failure_is_active() {
+        let mut test_bed = test_bed_running_at(80.).run_for(Duration::from_millis(100));
+        assert!(!test_bed.fuel_is_cut_off());
+
+        test_bed = test_bed
+            .failed(FailureType::EngineFlameout(1))
+            .run_for(Duration::from_millis(100));
+        assert!(test_bed.fuel_is_cut_off());
+
+        test_bed.unfail(FailureType::EngineFlameout(1));
+        test_bed = test_bed
+            .failed(FailureType::EngineSeizure(1))
+            .run_for(Duration::from_millis(100));
+        assert!(test_bed.fuel_is_cut_off());
+
+        test_bed.unfail(FailureType::EngineSeizure(1));
+        test_bed = test_bed.run_for(Duration::from_millis(100));
+        assert!(!test_bed.fuel_is_cut_off());
+    }
+
+    #[test]
+    fn seized_core_stops_and_does_not_windmill() {
+        let mut test_bed = test_bed_running_at(80.)
+            .failed(FailureType::EngineSeizure(1))
+            .run_for(Duration::from_secs(5));
+
+        assert!(test_bed.speed() < 0.1);
+
+        test_bed = test_bed.in_flight_at(300.).run_for(Duration::from_secs(30));
+
+        assert!(test_bed.speed() < 0.1);
+        assert!(!test_bed.is_burning());
+    }
+
+    #[test]
+    fn oil_leak_empties_tank_and_drops_oil_pressure() {
+        let mut test_bed = test_bed_running_at(80.).run_for(Duration::from_secs(3600));
+        let initial_pressure = test_bed.oil_pressure();
+        let initial_quantity = test_bed.oil_quantity();
+
+        test_bed = test_bed
+            .failed(FailureType::EngineOilLeak(1))
+            .run_for(Duration::from_secs(5 * 60));
+
+        assert!(test_bed.oil_quantity() < initial_quantity - 3.);
+        assert!((test_bed.oil_pressure() - initial_pressure).abs() < 0.1);
+
+        test_bed = test_bed.run_for(Duration::from_secs(20 * 60));
+
+        assert!(test_bed.oil_quantity() < 0.1);
+        assert!(test_bed.oil_pressure() < 0.1);
+    }
+
+    #[test]
+    fn egt_overlimit_raises_egt() {
+        let mut test_bed = test_bed_running_at(80.);
+        let initial_egt = test_bed.egt();
+
+        test_bed = test_bed
+            .failed(FailureType::EngineEgtOverlimit(1))
+            .run_for(Duration::from_secs(30));
+
+        assert!((test_bed.egt() - initial_egt - 200.).abs() < 1.);
+    }
+
+    #[test]
+    fn fire_heats_oil_and_is_reported() {
+        let mut test_bed = test_bed_running_at(80.).run_for(Duration::from_secs(3600));
+        let initial_oil_temperature =
+            test_bed.query(|a| a.core.oil_temperature().get::<degree_celsius>());
+        assert!(!test_bed.is_signalled_on_fire());
+
+        test_bed = test_bed
+            .failed(FailureType::SetOnFire(FireDetectionZone::Engine(1)))
+            .run_for(Duration::from_secs(3600));
+
+        assert!(
+            test_bed.query(|a| a.core.oil_temperature().get::<degree_celsius>())
+                > initial_oil_temperature + 70.
+        );
+        assert!(test_bed.is_signalled_on_fire());
+        assert!(!test_bed.query(|a| a.core.has_failed()));
+    }
+
+    #[test]
+    fn fan_damage_is_reported() {
+        let test_bed = test_bed_running_at(80.)
+            .failed(FailureType::EngineFanDamage(1))
+            .run_for(Duration::from_millis(100));
+
+        assert!(test_bed.query(|a| a.core.fan_is_damaged()));
+        assert!(!test_bed.query(|a| a.core.has_failed()));
+    }
+
+    #[test]
+    fn failure_of_another_engine_does_not_affect_core() {
+        let test_bed = test_bed_running_at(80.)
+            .failed(FailureType::EngineFlameout(2))
+            .run_for(Duration::from_secs(10));
+
+        assert!(test_bed.is_burning());
+        assert!((test_bed.speed() - 80.).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn restores_speed_egt_and_oil_from_snapshot() {
+        let mut test_bed = test_bed_running_at(80.)
+            .failed(FailureType::EngineFlameout(1))
+            .failed(FailureType::EngineOilLeak(1))
+            .run_for(Duration::from_secs(5));
+        let snapshot = test_bed.snapshot();
+
+        let mut other_test_bed = EngineCoreTestBed::new();
+        assert_eq!(other_test_bed.restore(&snapshot), Ok(()));
+
+        assert!(other_test_bed.speed() > 0.);
+        assert_eq!(other_test_bed.speed(), test_bed.speed());
+        assert_eq!(other_test_bed.egt(), test_bed.egt());
+        assert_eq!(
+            other_test_bed.query(|a| a.core.oil_temperature()),
+            test_bed.query(|a| a.core.oil_temperature())
+        );
+        assert_eq!(other_test_bed.oil_quantity(), test_bed.oil_quantity());
+        assert!(other_test_bed.oil_quantity() < 17.);
+    }
+
+    fn test_bed_running_at(speed_percent: f64) -> EngineCoreTestBed {
+        EngineCoreTestBed::new().running_at(speed_percent)
+    }
+}
@@ -0,0 +1,833 @@
+//! Start sequencing of the Full Authority Digital Engine Control (FADEC).
+//!
+//! The FADEC opens the starter valve, selects the ignitors and commands the fuel during the
+//! engine start. In automatic start on ground, it aborts the start when the engine doesn't light
+//! up, hangs or overheats, and dry cranks the engine to purge the fuel before closing the starter
+//! valve. In manual start and in flight, it only reports such faults and leaves the decision to
+//! the crew.
+//!
+//! The FADEC is powered by two aircraft buses, one per channel, until the engine driven
+//! alternator takes over.
+//!
+//! The engine itself is still run by the simulator FADEC, which reads the abort of the start
+//! and cuts the fuel to the engine it runs.
+
+use std::time::Duration;
+
+use uom::si::{f64::*, ratio::percent, thermodynamic_temperature::degree_celsius};
+
+use super::Engine;
+use crate::{
+    pneumatic::{EngineModeSelector, EngineState},
+    shared::{ElectricalBusType, ElectricalBuses},
+    simulation::{
+        InitContext, Read, Reader, SimulationElement, SimulatorReader, SimulatorWriter,
+        UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EngineStartPhase {
+    Off,
+    Cranking,
+    Ignition,
+    Acceleration,
+    Running,
+    DryCrank,
+    Aborted,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EngineStartFault {
+    HotStart = 1,
+    HungStart = 2,
+    NoLightUp = 3,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ignitor {
+    A,
+    B,
+}
+impl Ignitor {
+    fn other(self) -> Self {
+        match self {
+            Ignitor::A => Ignitor::B,
+            Ignitor::B => Ignitor::A,
+        }
+    }
+}
+
+pub struct FullAuthorityDigitalEngineControl {
+    engine_state_id: VariableIdentifier,
+    engine_state: EngineState,
+    engine_master_id: VariableIdentifier,
+    engine_master_is_on: bool,
+    manual_start_id: VariableIdentifier,
+    manual_start_is_on: bool,
+    mode_selector_id: VariableIdentifier,
+    mode_selector: EngineModeSelector,
+
+    ignitor_a_active_id: VariableIdentifier,
+    ignitor_b_active_id: VariableIdentifier,
+    start_fault_id: VariableIdentifier,
+    start_aborted_id: VariableIdentifier,
+
+    powered_by: [ElectricalBusType; 2],
+    is_powered: bool,
+    is_self_powered: bool,
+
+    start_egt_limit: ThermodynamicTemperature,
+
+    phase: EngineStartPhase,
+    phase_duration: Duration,
+    is_automatic_start: bool,
+    uses_both_ignitors: bool,
+    next_ignitor: Ignitor,
+    ignition_egt: ThermodynamicTemperature,
+    hung_start_reference_n2: Ratio,
+    hung_start_window: Duration,
+    fault: Option<EngineStartFault>,
+    n2: Ratio,
+}
+impl FullAuthorityDigitalEngineControl {
+    const IGNITION_N2_PERCENT: f64 = 16.;
+    const STARTER_CUT_OUT_N2_PERCENT: f64 = 50.;
+    // Above this speed the engine driven alternator powers the FADEC
+    const SELF_POWERED_N2_PERCENT: f64 = 12.;
+
+    const LIGHT_UP_EGT_RISE_DEGREE_CELSIUS: f64 = 50.;
+    const GROUND_LIGHT_UP_TIME: Duration = Duration::from_secs(15);
+    const FLIGHT_LIGHT_UP_TIME: Duration = Duration::from_secs(30);
+
+    const HUNG_START_WINDOW: Duration = Duration::from_secs(10);
+    const HUNG_START_MINIMUM_N2_INCREASE_PERCENT: f64 = 1.;
+
+    const DRY_CRANK_TIME: Duration = Duration::from_secs(30);
+
+    pub fn new(
+        context: &mut InitContext,
+        number: usize,
+        powered_by: [ElectricalBusType; 2],
+        start_egt_limit: ThermodynamicTemperature,
+    ) -> Self {
+        Self {
+            engine_state_id: context.get_identifier(format!("ENGINE_STATE:{}", number)),
+            engine_state: EngineState::Off,
+            engine_master_id: context.get_identifier(format!("FUELSYSTEM VALVE SWITCH:{}", number)),
+            engine_master_is_on: false,
+            manual_start_id: context.get_identifier(format!("ENGMANSTART{}_TOGGLE", number)),
+            manual_start_is_on: false,
+            mode_selector_id: context.get_identifier("TURB ENG IGNITION SWITCH EX1:1".to_owned()),
+            mode_selector: EngineModeSelector::Norm,
+
+            ignitor_a_active_id: context
+                .get_identifier(format!("FADEC_{}_IGNITOR_A_ACTIVE", number)),
+            ignitor_b_active_id: context
+                .get_identifier(format!("FADEC_{}_IGNITOR_B_ACTIVE", number)),
+            start_fault_id: context.get_identifier(format!("FADEC_{}_START_FAULT", number)),
+            start_aborted_id: context.get_identifier(format!("FADEC_{}_START_ABORTED", number)),
+
+            powered_by,
+            is_powered: false,
+            is_self_powered: false,
+
+            start_egt_limit,
+
+            phase: EngineStartPhase::Off,
+            phase_duration: Duration::ZERO,
+            is_automatic_start: true,
+            uses_both_ignitors: false,
+            next_ignitor: Ignitor::A,
+            ignition_egt: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            hung_start_reference_n2: Ratio::default(),
+            hung_start_window: Duration::ZERO,
+            fault: None,
+            n2: Ratio::default(),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, engine: &impl Engine) {
+        let n2 = engine.uncorrected_n2();
+        self.n2 = n2;
+        self.is_self_powered = n2.get::<percent>() >= Self::SELF_POWERED_N2_PERCENT;
+        self.phase_duration += context.delta();
+
+        if !self.is_powered {
+            self.change_phase(EngineStartPhase::Off);
+            return;
+        }
+
+        let manual_start =
+            self.mode_selector != EngineModeSelector::Norm && self.manual_start_is_on;
+        let automatic_start = (self.mode_selector == EngineModeSelector::Ignition
+            && self.engine_master_is_on)
+            || matches!(
+                self.engine_state,
+                EngineState::Starting | EngineState::Restarting
+            );
+        let fuel_is_selected = self.engine_master_is_on || self.engine_state.is_fuel_on();
+        let start_is_requested = automatic_start || manual_start;
+
+        match self.phase {
+            EngineStartPhase::Off => {
+                // The fault is reported until the crew selects the fuel off
+                if !fuel_is_selected && !start_is_requested {
+                    self.fault = None;
+                }
+
+                if fuel_is_selected && engine.is_above_minimum_idle() {
+                    self.change_phase(EngineStartPhase::Running);
+                } else if start_is_requested
+                    && n2.get::<percent>() < Self::STARTER_CUT_OUT_N2_PERCENT
+                {
+                    self.is_automatic_start = !manual_start;
+                    self.fault = None;
+                    self.change_phase(EngineStartPhase::Cranking);
+                }
+            }
+            EngineStartPhase::Cranking => {
+                if !start_is_requested {
+                    self.change_phase(EngineStartPhase::Off);
+                } else if fuel_is_selected
+                    && self.mode_selector == EngineModeSelector::Ignition
+                    && n2.get::<percent>() >= Self::IGNITION_N2_PERCENT
+                {
+                    self.ignition_egt = engine.exhaust_gas_temperature();
+                    // In manual start and in flight both ignitors are used
+                    self.uses_both_ignitors = !self.is_automatic_start || !context.is_on_ground();
+                    self.change_phase(EngineStartPhase::Ignition);
+                }
+            }
+            EngineStartPhase::Ignition => {
+                let light_up_time = if context.is_on_ground() {
+                    Self::GROUND_LIGHT_UP_TIME
+                } else {
+                    Self::FLIGHT_LIGHT_UP_TIME
+                };
+
+                if !fuel_is_selected {
+                    self.change_phase(EngineStartPhase::Off);
+                } else if engine.exhaust_gas_temperature().get::<degree_celsius>()
+                    - self.ignition_egt.get::<degree_celsius>()
+                    > Self::LIGHT_UP_EGT_RISE_DEGREE_CELSIUS
+                {
+                    self.hung_start_reference_n2 = n2;
+                    self.hung_start_window = Duration::ZERO;
+                    self.change_phase(EngineStartPhase::Acceleration);
+                } else if self.phase_duration > light_up_time {
+                    self.detect_fault(context, EngineStartFault::NoLightUp);
+                }
+            }
+            EngineStartPhase::Acceleration => {
+                self.hung_start_window += context.delta();
+
+                if !fuel_is_selected {
+                    self.change_phase(EngineStartPhase::Off);
+                } else if engine.is_above_minimum_idle() {
+                    self.next_ignitor = self.next_ignitor.other();
+                    self.change_phase(EngineStartPhase::Running);
+                } else if engine.exhaust_gas_temperature() > self.start_egt_limit {
+                    self.detect_fault(context, EngineStartFault::HotStart);
+                } else if self.hung_start_window >= Self::HUNG_START_WINDOW {
+                    if (n2 - self.hung_start_reference_n2).get::<percent>()
+                        < Self::HUNG_START_MINIMUM_N2_INCREASE_PERCENT
+                    {
+                        self.detect_fault(context, EngineStartFault::HungStart);
+                    }
+                    self.hung_start_reference_n2 = n2;
+                    self.hung_start_window = Duration::ZERO;
+                }
+            }
+            EngineStartPhase::Running => {
+                if !fuel_is_selected {
+                    self.change_phase(EngineStartPhase::Off);
+                }
+            }
+            EngineStartPhase::DryCrank => {
+                if self.phase_duration >= Self::DRY_CRANK_TIME {
+                    self.change_phase(EngineStartPhase::Aborted);
+                }
+            }
+            EngineStartPhase::Aborted => {
+                if !start_is_requested {
+                    self.change_phase(EngineStartPhase::Off);
+                }
+            }
+        }
+    }
+
+    fn detect_fault(&mut self, context: &UpdateContext, fault: EngineStartFault) {
+        if self.fault.is_none() {
+            self.fault = Some(fault);
+        }
+
+        if self.is_automatic_start && context.is_on_ground() {
+            self.next_ignitor = self.next_ignitor.other();
+            self.change_phase(EngineStartPhase::DryCrank);
+        }
+    }
+
+    fn change_phase(&mut self, phase: EngineStartPhase) {
+        if self.phase != phase {
+            self.phase = phase;
+            self.phase_duration = Duration::ZERO;
+        }
+    }
+
+    pub fn engine_state(&self) -> EngineState {
+        self.engine_state
+    }
+
+    pub fn engine_mode_selector(&self) -> EngineModeSelector {
+        self.mode_selector
+    }
+
+    pub fn is_powered(&self) -> bool {
+        self.is_powered
+    }
+
+    pub fn start_fault(&self) -> Option<EngineStartFault> {
+        self.fault
+    }
+
+    /// Whether the start was aborted automatically, in which case the fuel is cut off.
+    pub fn start_is_aborted(&self) -> bool {
+        matches!(
+            self.phase,
+            EngineStartPhase::DryCrank | EngineStartPhase::Aborted
+        )
+    }
+
+    pub fn starter_valve_is_commanded_open(&self) -> bool {
+        match self.phase {
+            EngineStartPhase::Cranking | EngineStartPhase::Ignition => true,
+            EngineStartPhase::Acceleration | EngineStartPhase::DryCrank => {
+                self.n2.get::<percent>() < Self::STARTER_CUT_OUT_N2_PERCENT
+            }
+            _ => false,
+        }
+    }
+
+    pub fn fuel_is_commanded(&self) -> bool {
+        matches!(
+            self.phase,
+            EngineStartPhase::Ignition | EngineStartPhase::Acceleration | EngineStartPhase::Running
+        )
+    }
+
+    pub fn ignitor_is_active(&self, ignitor: Ignitor) -> bool {
+        match self.phase {
+            EngineStartPhase::Ignition | EngineStartPhase::Acceleration => {
+                self.uses_both_ignitors || ignitor == self.next_ignitor
+            }
+            // Continuous ignition
+            EngineStartPhase::Running => self.mode_selector == EngineModeSelector::Ignition,
+            _ => false,
+        }
+    }
+
+    pub fn ignition_is_commanded(&self) -> bool {
+        self.ignitor_is_active(Ignitor::A) || self.ignitor_is_active(Ignitor::B)
+    }
+}
+impl SimulationElement for FullAuthorityDigitalEngineControl {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.engine_state = reader.read_discrete_or_fallback(
+            &self.engine_state_id,
+            "EngineState",
+            EngineState::Off,
+        );
+        self.engine_master_is_on = reader.read(&self.engine_master_id);
+        self.manual_start_is_on = reader.read(&self.manual_start_id);
+        self.mode_selector = reader.read_discrete_or_fallback(
+            &self.mode_selector_id,
+            "EngineModeSelector",
+            EngineModeSelector::Norm,
+        );
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.ignitor_a_active_id,
+            self.ignitor_is_active(Ignitor::A),
+        );
+        writer.write(
+            &self.ignitor_b_active_id,
+            self.ignitor_is_active(Ignitor::B),
+        );
+        writer.write(
+            &self.start_fault_id,
+            self.fault.map_or(0., |fault| fault as u8 as f64),
+        );
+        writer.write(&self.start_aborted_id, self.start_is_aborted());
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered =
+            self.is_self_powered || self.powered_by.iter().any(|&bus| buses.is_powered(bus));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2, PotentialOrigin},
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft, SimulationElementVisitor,
+        },
+    };
+    use uom::si::{angular_velocity::revolution_per_minute, mass::kilogram};
+
+    struct TestEngine {
+        n2: Ratio,
+        egt: ThermodynamicTemperature,
+    }
+    impl TestEngine {
+        fn new() -> Self {
+            Self {
+                n2: Ratio::default(),
+                egt: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            }
+        }
+    }
+    impl EngineCorrectedN1 for TestEngine {
+        fn corrected_n1(&self) -> Ratio {
+            Ratio::default()
+        }
+    }
+    impl EngineCorrectedN2 for TestEngine {
+        fn corrected_n2(&self) -> Ratio {
+            self.n2
+        }
+    }
+    impl EngineUncorrectedN2 for TestEngine {
+        fn uncorrected_n2(&self) -> Ratio {
+            self.n2
+        }
+    }
+    impl Engine for TestEngine {
+        fn hydraulic_pump_output_speed(&self) -> AngularVelocity {
+            AngularVelocity::new::<revolution_per_minute>(0.)
+        }
+
+        fn oil_pressure_is_low(&self) -> bool {
+            false
+        }
+
+        fn is_above_minimum_idle(&self) -> bool {
+            self.n2.get::<percent>() >= 58.
+        }
+
+        fn net_thrust(&self) -> Mass {
+            Mass::new::<kilogram>(0.)
+        }
+
+        fn gearbox_speed(&self) -> AngularVelocity {
+            AngularVelocity::new::<revolution_per_minute>(0.)
+        }
+
+        fn exhaust_gas_temperature(&self) -> ThermodynamicTemperature {
+            self.egt
+        }
+
+        fn fan_is_damaged(&self) -> bool {
+            false
+        }
+    }
+
+    struct TestAircraft {
+        fadec: FullAuthorityDigitalEngineControl,
+        engine: TestEngine,
+        powered_source: TestElectricitySource,
+        dc_ess_bus: ElectricalBus,
+        dc_1_bus: ElectricalBus,
+        is_powered: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                fadec: FullAuthorityDigitalEngineControl::new(
+                    context,
+                    1,
+                    [
+                        ElectricalBusType::DirectCurrentEssential,
+                        ElectricalBusType::DirectCurrent(1),
+                    ],
+                    ThermodynamicTemperature::new::<degree_celsius>(750.),
+                ),
+                engine: TestEngine::new(),
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                dc_ess_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentEssential),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                is_powered: true,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.powered_source);
+            if self.is_powered {
+                electricity.flow(&self.powered_source, &self.dc_ess_bus);
+                electricity.flow(&self.powered_source, &self.dc_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.fadec.update(context, &self.engine);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.fadec.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct FadecTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl FadecTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_on_ground(true);
+
+            test_bed
+        }
+
+        fn mode_selector(mut self, mode: EngineModeSelector) -> Self {
+            self.write_by_name("TURB ENG IGNITION SWITCH EX1:1", mode);
+            self
+        }
+
+        fn master(mut self, is_on: bool) -> Self {
+            self.write_by_name("FUELSYSTEM VALVE SWITCH:1", is_on);
+            self
+        }
+
+        fn manual_start(mut self, is_on: bool) -> Self {
+            self.write_by_name("ENGMANSTART1_TOGGLE", is_on);
+            self
+        }
+
+        fn engine_state(mut self, state: EngineState) -> Self {
+            self.write_by_name("ENGINE_STATE:1", state);
+            self
+        }
+
+        fn in_flight(mut self) -> Self {
+            self.set_on_ground(false);
+            self
+        }
+
+        fn unpowered(mut self) -> Self {
+            self.command(|a| a.is_powered = false);
+            self
+        }
+
+        fn n2(mut self, percent_value: f64) -> Self {
+            self.command(|a| a.engine.n2 = Ratio::new::<percent>(percent_value));
+            self
+        }
+
+        fn egt(mut self, degree_celsius_value: f64) -> Self {
+            self.command(|a| {
+                a.engine.egt = ThermodynamicTemperature::new::<degree_celsius>(degree_celsius_value)
+            });
+            self
+        }
+
+        fn auto_start(self) -> Self {
+            self.mode_selector(EngineModeSelector::Ignition)
+                .master(true)
+        }
+
+        fn light_up(self) -> Self {
+            self.n2(20.).and_run().egt(400.).and_run()
+        }
+
+        fn and_run(mut self) -> Self {
+            self.run_with_delta(Duration::from_millis(100));
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            self.test_bed.run_multiple_frames(duration);
+            self
+        }
+
+        fn starter_valve_is_commanded_open(&self) -> bool {
+            self.query(|a| a.fadec.starter_valve_is_commanded_open())
+        }
+
+        fn fuel_is_commanded(&self) -> bool {
+            self.query(|a| a.fadec.fuel_is_commanded())
+        }
+
+        fn ignitor_is_active(&self, ignitor: Ignitor) -> bool {
+            self.query(|a| a.fadec.ignitor_is_active(ignitor))
+        }
+
+        fn start_fault(&self) -> Option<EngineStartFault> {
+            self.query(|a| a.fadec.start_fault())
+        }
+
+        fn start_is_aborted(&self) -> bool {
+            self.query(|a| a.fadec.start_is_aborted())
+        }
+    }
+    impl TestBed for FadecTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> FadecTestBed {
+        FadecTestBed::new()
+    }
+
+    #[test]
+    fn auto_start_opens_starter_valve_without_fuel_below_ignition_speed() {
+        let test_bed = test_bed().auto_start().n2(10.).and_run();
+
+        assert!(test_bed.starter_valve_is_commanded_open());
+        assert!(!test_bed.fuel_is_commanded());
+        assert!(!test_bed.ignitor_is_active(Ignitor::A));
+    }
+
+    #[test]
+    fn auto_start_commands_fuel_and_one_ignitor_at_ignition_speed() {
+        let test_bed = test_bed().auto_start().and_run().n2(16.).and_run();
+
+        assert!(test_bed.fuel_is_commanded());
+        assert!(test_bed.ignitor_is_active(Ignitor::A));
+        assert!(!test_bed.ignitor_is_active(Ignitor::B));
+    }
+
+    #[test]
+    fn starter_valve_closes_at_cut_out_speed() {
+        let test_bed = test_bed()
+            .auto_start()
+            .and_run()
+            .light_up()
+            .n2(50.)
+            .and_run();
+
+        assert!(!test_bed.starter_valve_is_commanded_open());
+        assert!(test_bed.fuel_is_commanded());
+    }
+
+    #[test]
+    fn start_completes_at_idle_and_alternates_ignitor() {
+        let mut test_bed = test_bed()
+            .auto_start()
+            .and_run()
+            .light_up()
+            .n2(60.)
+            .and_run();
+
+        assert!(!test_bed.starter_valve_is_commanded_open());
+        assert!(test_bed.fuel_is_commanded());
+        assert!(test_bed.start_fault().is_none());
+
+        test_bed = test_bed
+            .mode_selector(EngineModeSelector::Norm)
+            .master(false)
+            .n2(0.)
+            .egt(15.)
+            .and_run()
+            .auto_start()
+            .and_run()
+            .n2(16.)
+            .and_run();
+
+        assert!(!test_bed.ignitor_is_active(Ignitor::A));
+        assert!(test_bed.ignitor_is_active(Ignitor::B));
+    }
+
+    #[test]
+    fn no_light_up_aborts_start_and_dry_cranks_on_ground() {
+        let mut test_bed = test_bed()
+            .auto_start()
+            .and_run()
+            .n2(20.)
+            .and_run()
+            .run_for(Duration::from_secs(16));
+
+        assert_eq!(test_bed.start_fault(), Some(EngineStartFault::NoLightUp));
+        assert!(!test_bed.fuel_is_commanded());
+        assert!(!test_bed.ignitor_is_active(Ignitor::A));
+        assert!(test_bed.starter_valve_is_commanded_open());
+
+        test_bed = test_bed.run_for(Duration::from_secs(31));
+        assert!(!test_bed.starter_valve_is_commanded_open());
+        assert!(!test_bed.fuel_is_commanded());
+    }
+
+    #[test]
+    fn hot_start_aborts_start_on_ground() {
+        let test_bed = test_bed()
+            .auto_start()
+            .and_run()
+            .light_up()
+            .egt(760.)
+            .and_run();
+
+        assert_eq!(test_bed.start_fault(), Some(EngineStartFault::HotStart));
+        assert!(!test_bed.fuel_is_commanded());
+        assert!(test_bed.starter_valve_is_commanded_open());
+    }
+
+    #[test]
+    fn dry_crank_closes_starter_valve_at_cut_out_speed() {
+        let test_bed = test_bed()
+            .auto_start()
+            .and_run()
+            .light_up()
+            .egt(760.)
+            .and_run()
+            .n2(51.)
+            .and_run();
+
+        assert!(test_bed.start_is_aborted());
+        assert!(!test_bed.starter_valve_is_commanded_open());
+    }
+
+    #[test]
+    fn start_fault_is_cleared_when_the_master_is_set_off() {
+        let test_bed = test_bed()
+            .auto_start()
+            .and_run()
+            .n2(20.)
+            .and_run()
+            .run_for(Duration::from_secs(47));
+
+        assert_eq!(test_bed.start_fault(), Some(EngineStartFault::NoLightUp));
+        assert!(test_bed.start_is_aborted());
+
+        let test_bed = test_bed.master(false).and_run().and_run();
+
+        assert!(test_bed.start_fault().is_none());
+        assert!(!test_bed.start_is_aborted());
+    }
+
+    #[test]
+    fn hung_start_aborts_start_on_ground() {
+        let test_bed = test_bed()
+            .auto_start()
+            .and_run()
+            .light_up()
+            .n2(30.)
+            .run_for(Duration::from_secs(21));
+
+        assert_eq!(test_bed.start_fault(), Some(EngineStartFault::HungStart));
+        assert!(!test_bed.fuel_is_commanded());
+    }
+
+    #[test]
+    fn fault_in_flight_does_not_abort_start() {
+        let test_bed = test_bed()
+            .in_flight()
+            .auto_start()
+            .and_run()
+            .light_up()
+            .egt(760.)
+            .and_run();
+
+        assert_eq!(test_bed.start_fault(), Some(EngineStartFault::HotStart));
+        assert!(test_bed.fuel_is_commanded());
+        assert!(test_bed.ignitor_is_active(Ignitor::A));
+        assert!(test_bed.ignitor_is_active(Ignitor::B));
+    }
+
+    #[test]
+    fn manual_start_uses_both_ignitors_and_does_not_abort() {
+        let mut test_bed = test_bed()
+            .mode_selector(EngineModeSelector::Ignition)
+            .manual_start(true)
+            .n2(20.)
+            .and_run();
+
+        assert!(test_bed.starter_valve_is_commanded_open());
+        assert!(!test_bed.fuel_is_commanded());
+
+        test_bed = test_bed.master(true).and_run();
+
+        assert!(test_bed.fuel_is_commanded());
+        assert!(test_bed.ignitor_is_active(Ignitor::A));
+        assert!(test_bed.ignitor_is_active(Ignitor::B));
+
+        test_bed = test_bed.run_for(Duration::from_secs(16));
+
+        assert_eq!(test_bed.start_fault(), Some(EngineStartFault::NoLightUp));
+        assert!(test_bed.fuel_is_commanded());
+    }
+
+    #[test]
+    fn crank_mode_motors_engine_without_fuel() {
+        let test_bed = test_bed()
+            .mode_selector(EngineModeSelector::Crank)
+            .manual_start(true)
+            .n2(20.)
+            .and_run();
+
+        assert!(test_bed.starter_valve_is_commanded_open());
+        assert!(!test_bed.fuel_is_commanded());
+        assert!(!test_bed.ignitor_is_active(Ignitor::A));
+        assert!(!test_bed.ignitor_is_active(Ignitor::B));
+    }
+
+    #[test]
+    fn simulator_engine_start_opens_starter_valve() {
+        let mut test_bed = test_bed().engine_state(EngineState::Starting).and_run();
+
+        assert!(test_bed.starter_valve_is_commanded_open());
+
+        test_bed = test_bed.engine_state(EngineState::On).and_run();
+
+        assert!(!test_bed.starter_valve_is_commanded_open());
+    }
+
+    #[test]
+    fn unpowered_fadec_does_not_start_engine() {
+        let test_bed = test_bed().unpowered().auto_start().and_run().and_run();
+
+        assert!(!test_bed.starter_valve_is_commanded_open());
+        assert!(!test_bed.fuel_is_commanded());
+    }
+
+    #[test]
+    fn fadec_writes_ignitor_and_fault_variables() {
+        let mut test_bed = test_bed().auto_start().and_run().n2(16.).and_run();
+
+        let ignitor_a_is_active: bool = test_bed.read_by_name("FADEC_1_IGNITOR_A_ACTIVE");
+        let ignitor_b_is_active: bool = test_bed.read_by_name("FADEC_1_IGNITOR_B_ACTIVE");
+        assert!(ignitor_a_is_active);
+        assert!(!ignitor_b_is_active);
+
+        test_bed = test_bed.run_for(Duration::from_secs(16));
+        let start_fault: f64 = test_bed.read_by_name("FADEC_1_START_FAULT");
+        let start_is_aborted: bool = test_bed.read_by_name("FADEC_1_START_ABORTED");
+        assert_eq!(start_fault, EngineStartFault::NoLightUp as u8 as f64);
+        assert!(start_is_aborted);
+    }
+}
@@ -1,14 +1,24 @@
-use uom::si::{angular_velocity::revolution_per_minute, f64::*, pressure::psi, ratio::percent};
+use uom::si::{
+    angular_velocity::revolution_per_minute, f64::*, mass_rate::kilogram_per_hour, pressure::psi,
+    ratio::percent, thermodynamic_temperature::kelvin,
+};
 
 use crate::{
-    shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2},
-    simulation::{Read, SimulationElement, SimulatorReader, UpdateContext},
+    shared::{EngineCorrectedN1, EngineCorrectedN2, EngineStartControl, EngineUncorrectedN2},
+    simulation::{
+        Read, SimulationElement, SimulationElementVisitor, SimulatorReader, UpdateContext,
+    },
 };
 
-use super::Engine;
+use super::{
+    engine_core::{EngineCore, EngineCoreProperties},
+    Engine,
+};
 use crate::simulation::{InitContext, VariableIdentifier};
 
 pub struct LeapEngine {
+    number: usize,
+
     corrected_n1_id: VariableIdentifier,
     corrected_n2_id: VariableIdentifier,
     thrust_id: VariableIdentifier,
@@ -19,6 +29,12 @@ pub struct LeapEngine {
     uncorrected_n2_id: VariableIdentifier,
     uncorrected_n2: Ratio,
 
+    fadec_fuel_flow_id: VariableIdentifier,
+    fadec_fuel_flow: MassRate,
+
+    core: EngineCore,
+    temperature_correction: f64,
+
     n2_speed: AngularVelocity,
     hydraulic_pump_output_speed: AngularVelocity,
     oil_pressure: Pressure,
@@ -37,8 +53,12 @@ impl LeapEngine {
 
     const LOW_OIL_PRESSURE_THRESHOLD_PSI: f64 = 18.;
 
+    const STANDARD_TEMPERATURE_KELVIN: f64 = 288.15;
+
     pub fn new(context: &mut InitContext, number: usize) -> LeapEngine {
         LeapEngine {
+            number,
+
             corrected_n1_id: context.get_identifier(format!("TURB ENG CORRECTED N1:{}", number)),
             corrected_n2_id: context.get_identifier(format!("TURB ENG CORRECTED N2:{}", number)),
             uncorrected_n2_id: context.get_identifier(format!("ENGINE_N2:{}", number)),
@@ -48,6 +68,25 @@ impl LeapEngine {
             corrected_n2: Ratio::new::<percent>(0.),
 
             uncorrected_n2: Ratio::new::<percent>(0.),
+
+            fadec_fuel_flow_id: context.get_identifier(format!("ENGINE_FF:{}", number)),
+            fadec_fuel_flow: MassRate::default(),
+
+            core: EngineCore::new(
+                context,
+                number,
+                EngineCoreProperties {
+                    idle_speed_percent: 58.,
+                    light_off_speed_percent: 16.,
+                    fan_speed_map: ([0., 20., 58., 80., 100.], [0., 3., 19., 55., 100.]),
+                    idle_egt_rise_degree_celsius: 480.,
+                    max_egt_rise_degree_celsius: 900.,
+                    start_egt_rise_degree_celsius: 220.,
+                    oil_quantity_quart: 17.,
+                },
+            ),
+            temperature_correction: 1.,
+
             n2_speed: AngularVelocity::new::<revolution_per_minute>(0.),
             hydraulic_pump_output_speed: AngularVelocity::new::<revolution_per_minute>(0.),
             oil_pressure: Pressure::new::<psi>(0.),
@@ -56,40 +95,86 @@ impl LeapEngine {
         }
     }
 
-    pub fn update(&mut self, _: &UpdateContext) {}
+    pub fn update(&mut self, context: &UpdateContext, start_control: &impl EngineStartControl) {
+        self.core.update(
+            context,
+            start_control.fuel_is_commanded(self.number),
+            self.uncorrected_n2,
+        );
+        self.temperature_correction = (context.ambient_temperature().get::<kelvin>()
+            / Self::STANDARD_TEMPERATURE_KELVIN)
+            .sqrt();
+
+        self.update_parameters();
+    }
 
     fn update_parameters(&mut self) {
+        let n2 = self.uncorrected_n2();
         self.n2_speed = AngularVelocity::new::<revolution_per_minute>(
-            self.uncorrected_n2.get::<percent>() * Self::LEAP_1A26_MAX_N2_RPM / 100.,
+            n2.get::<percent>() * Self::LEAP_1A26_MAX_N2_RPM / 100.,
         );
         self.hydraulic_pump_output_speed = self.n2_speed * Self::PUMP_N2_GEAR_RATIO;
+        self.oil_pressure = self.core.oil_pressure(n2);
+    }
 
-        // Ultra stupid model just to have 18psi crossing at 25% N2
-        self.oil_pressure = Pressure::new::<psi>(18. / 25. * self.uncorrected_n2.get::<percent>());
+    /// The fuel flow computed by the FADEC, or none while the core failed and the fuel is cut off.
+    pub fn fuel_flow(&self) -> MassRate {
+        if self.core.has_failed() {
+            MassRate::default()
+        } else {
+            self.fadec_fuel_flow
+        }
+    }
+
+    pub fn oil_pressure(&self) -> Pressure {
+        self.oil_pressure
+    }
+
+    pub fn oil_temperature(&self) -> ThermodynamicTemperature {
+        self.core.oil_temperature()
+    }
+
+    pub fn oil_quantity(&self) -> Volume {
+        self.core.oil_quantity(self.uncorrected_n2())
     }
 }
 impl SimulationElement for LeapEngine {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.core.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.corrected_n1 = reader.read(&self.corrected_n1_id);
         self.corrected_n2 = reader.read(&self.corrected_n2_id);
         self.uncorrected_n2 = reader.read(&self.uncorrected_n2_id);
         self.net_thrust = reader.read(&self.thrust_id);
+        self.fadec_fuel_flow =
+            MassRate::new::<kilogram_per_hour>(reader.read(&self.fadec_fuel_flow_id));
         self.update_parameters();
     }
 }
 impl EngineCorrectedN1 for LeapEngine {
     fn corrected_n1(&self) -> Ratio {
-        self.corrected_n1
+        self.core.overlaid_on_simulator(
+            self.corrected_n1,
+            self.core.fan_speed(self.core.speed()) / self.temperature_correction,
+        )
     }
 }
 impl EngineCorrectedN2 for LeapEngine {
     fn corrected_n2(&self) -> Ratio {
-        self.corrected_n2
+        self.core.overlaid_on_simulator(
+            self.corrected_n2,
+            self.core.speed() / self.temperature_correction,
+        )
     }
 }
 impl EngineUncorrectedN2 for LeapEngine {
     fn uncorrected_n2(&self) -> Ratio {
-        self.uncorrected_n2
+        self.core
+            .overlaid_on_simulator(self.uncorrected_n2, self.core.speed())
     }
 }
 impl Engine for LeapEngine {
@@ -102,15 +187,27 @@ impl Engine for LeapEngine {
     }
 
     fn is_above_minimum_idle(&self) -> bool {
-        self.uncorrected_n2
+        self.uncorrected_n2()
             >= Ratio::new::<percent>(LeapEngine::MIN_IDLE_N2_UNCORRECTED_THRESHOLD_PERCENT)
     }
 
     fn net_thrust(&self) -> Mass {
-        self.net_thrust
+        if self.core.has_failed() {
+            Mass::default()
+        } else {
+            self.net_thrust
+        }
     }
 
     fn gearbox_speed(&self) -> AngularVelocity {
         self.n2_speed
     }
+
+    fn exhaust_gas_temperature(&self) -> ThermodynamicTemperature {
+        self.core.exhaust_gas_temperature()
+    }
+
+    fn fan_is_damaged(&self) -> bool {
+        self.core.fan_is_damaged()
+    }
 }
@@ -7,10 +7,13 @@ use crate::{
     simulation::{SimulationElement, SimulationElementVisitor},
 };
 
+pub mod engine_core;
+pub mod fadec;
 pub mod leap_engine;
 pub mod reverser;
 pub mod reverser_thrust;
 pub mod trent_engine;
+pub mod vibration_monitoring;
 
 pub trait Engine: EngineCorrectedN2 + EngineUncorrectedN2 + EngineCorrectedN1 {
     fn hydraulic_pump_output_speed(&self) -> AngularVelocity;
@@ -18,6 +21,8 @@ pub trait Engine: EngineCorrectedN2 + EngineUncorrectedN2 + EngineCorrectedN1 {
     fn is_above_minimum_idle(&self) -> bool;
     fn net_thrust(&self) -> Mass;
     fn gearbox_speed(&self) -> AngularVelocity;
+    fn exhaust_gas_temperature(&self) -> ThermodynamicTemperature;
+    fn fan_is_damaged(&self) -> bool;
 }
 
 use std::convert::TryInto;
@@ -1,14 +1,27 @@
-use uom::si::{angular_velocity::revolution_per_minute, f64::*, pressure::psi, ratio::percent};
+use uom::si::{
+    angular_velocity::revolution_per_minute, f64::*, pressure::psi, ratio::percent,
+    thermodynamic_temperature::kelvin,
+};
 
 use crate::{
-    shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2},
-    simulation::{Read, SimulationElement, SimulatorReader, UpdateContext},
+    shared::{
+        interpolation, EngineCorrectedN1, EngineCorrectedN2, EngineStartControl,
+        EngineUncorrectedN2,
+    },
+    simulation::{
+        Read, SimulationElement, SimulationElementVisitor, SimulatorReader, UpdateContext,
+    },
 };
 
-use super::Engine;
+use super::{
+    engine_core::{EngineCore, EngineCoreProperties},
+    Engine,
+};
 use crate::simulation::{InitContext, VariableIdentifier};
 
 pub struct TrentEngine {
+    number: usize,
+
     thrust_id: VariableIdentifier,
 
     corrected_n1_id: VariableIdentifier,
@@ -21,6 +34,10 @@ pub struct TrentEngine {
     uncorrected_n3_id: VariableIdentifier,
     uncorrected_n3: Ratio,
 
+    // The core models the high pressure spool (N3)
+    core: EngineCore,
+    temperature_correction: f64,
+
     n3_speed: AngularVelocity,
     hydraulic_pump_output_speed: AngularVelocity,
     oil_pressure: Pressure,
@@ -42,8 +59,16 @@ impl TrentEngine {
 
     const LOW_OIL_PRESSURE_THRESHOLD_PSI: f64 = 18.;
 
+    const STANDARD_TEMPERATURE_KELVIN: f64 = 288.15;
+
+    // Intermediate pressure spool speed (N2) by high pressure spool speed (N3), both in percent
+    const N3_TO_N2_SPEEDS: [f64; 5] = [0., 20., 60., 80., 100.];
+    const N2_SPEEDS: [f64; 5] = [0., 10., 62., 82., 100.];
+
     pub fn new(context: &mut InitContext, number: usize) -> TrentEngine {
         TrentEngine {
+            number,
+
             thrust_id: context.get_identifier(format!("TURB ENG JET THRUST:{}", number)),
 
             corrected_n1_id: context.get_identifier(format!("TURB ENG CORRECTED N1:{}", number)),
@@ -55,6 +80,21 @@ impl TrentEngine {
             uncorrected_n3_id: context.get_identifier(format!("ENGINE_N3:{}", number)),
             uncorrected_n3: Ratio::new::<percent>(0.),
 
+            core: EngineCore::new(
+                context,
+                number,
+                EngineCoreProperties {
+                    idle_speed_percent: 60.,
+                    light_off_speed_percent: 15.,
+                    fan_speed_map: ([0., 20., 60., 80., 100.], [0., 2., 18., 55., 100.]),
+                    idle_egt_rise_degree_celsius: 450.,
+                    max_egt_rise_degree_celsius: 850.,
+                    start_egt_rise_degree_celsius: 200.,
+                    oil_quantity_quart: 22.,
+                },
+            ),
+            temperature_correction: 1.,
+
             n3_speed: AngularVelocity::default(),
             hydraulic_pump_output_speed: AngularVelocity::new::<revolution_per_minute>(0.),
             oil_pressure: Pressure::new::<psi>(0.),
@@ -63,19 +103,60 @@ impl TrentEngine {
         }
     }
 
-    pub fn update(&mut self, _: &UpdateContext) {}
+    pub fn update(&mut self, context: &UpdateContext, start_control: &impl EngineStartControl) {
+        self.core.update(
+            context,
+            start_control.fuel_is_commanded(self.number),
+            self.uncorrected_n3,
+        );
+        self.temperature_correction = (context.ambient_temperature().get::<kelvin>()
+            / Self::STANDARD_TEMPERATURE_KELVIN)
+            .sqrt();
+
+        self.update_parameters();
+    }
 
     fn update_parameters(&mut self) {
+        let n3 = self.uncorrected_n3();
         self.n3_speed = AngularVelocity::new::<revolution_per_minute>(
-            self.uncorrected_n3.get::<percent>() * Self::TRENT_900_100_PCT_N3_RPM / 100.,
+            n3.get::<percent>() * Self::TRENT_900_100_PCT_N3_RPM / 100.,
         );
         self.hydraulic_pump_output_speed = self.n3_speed * Self::PUMP_N3_GEAR_RATIO;
+        self.oil_pressure = self.core.oil_pressure(n3);
+    }
+
+    fn uncorrected_n3(&self) -> Ratio {
+        self.core
+            .overlaid_on_simulator(self.uncorrected_n3, self.core.speed())
+    }
 
-        // Ultra stupid model just to have 18psi crossing at 25% N2
-        self.oil_pressure = Pressure::new::<psi>(18. / 25. * self.uncorrected_n2.get::<percent>());
+    fn core_n2(&self) -> Ratio {
+        Ratio::new::<percent>(interpolation(
+            &Self::N3_TO_N2_SPEEDS,
+            &Self::N2_SPEEDS,
+            self.core.speed().get::<percent>(),
+        ))
+    }
+
+    pub fn oil_pressure(&self) -> Pressure {
+        self.oil_pressure
+    }
+
+    pub fn oil_temperature(&self) -> ThermodynamicTemperature {
+        self.core.oil_temperature()
+    }
+
+    pub fn oil_quantity(&self) -> Volume {
+        self.core.oil_quantity(self.uncorrected_n3())
     }
 }
 impl SimulationElement for TrentEngine {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.core.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.corrected_n1 = reader.read(&self.corrected_n1_id);
         self.corrected_n2 = reader.read(&self.corrected_n2_id);
@@ -88,17 +169,24 @@ impl SimulationElement for TrentEngine {
 }
 impl EngineCorrectedN1 for TrentEngine {
     fn corrected_n1(&self) -> Ratio {
-        self.corrected_n1
+        self.core.overlaid_on_simulator(
+            self.corrected_n1,
+            self.core.fan_speed(self.core.speed()) / self.temperature_correction,
+        )
     }
 }
 impl EngineCorrectedN2 for TrentEngine {
     fn corrected_n2(&self) -> Ratio {
-        self.corrected_n2
+        self.core.overlaid_on_simulator(
+            self.corrected_n2,
+            self.core_n2() / self.temperature_correction,
+        )
     }
 }
 impl EngineUncorrectedN2 for TrentEngine {
     fn uncorrected_n2(&self) -> Ratio {
-        self.uncorrected_n2
+        self.core
+            .overlaid_on_simulator(self.uncorrected_n2, self.core_n2())
     }
 }
 impl Engine for TrentEngine {
@@ -111,15 +199,27 @@ impl Engine for TrentEngine {
     }
 
     fn is_above_minimum_idle(&self) -> bool {
-        self.uncorrected_n2
+        self.uncorrected_n2()
             >= Ratio::new::<percent>(TrentEngine::MIN_IDLE_N2_UNCORRECTED_THRESHOLD_PERCENT)
     }
 
     fn net_thrust(&self) -> Mass {
-        self.net_thrust
+        if self.core.has_failed() {
+            Mass::default()
+        } else {
+            self.net_thrust
+        }
     }
 
     fn gearbox_speed(&self) -> AngularVelocity {
         self.n3_speed
     }
+
+    fn exhaust_gas_temperature(&self) -> ThermodynamicTemperature {
+        self.core.exhaust_gas_temperature()
+    }
+
+    fn fan_is_damaged(&self) -> bool {
+        self.core.fan_is_damaged()
+    }
 }
@@ -0,0 +1,487 @@
+//! Engine Vibration Monitoring Unit (EVMU).
+//!
+//! An accelerometer on each engine picks up the vibrations of the engine. The EVMU filters them
+//! at the rotation frequency of the low (N1) and high (N2) pressure spools and sends the resulting
+//! vibration levels, in units from 0 to 10, on Arinc 429 words. The residual imbalance of a spool
+//! grows with its speed, and a damaged fan raises the N1 vibration sharply. The accelerometer also
+//! picks up the movement of the engine on its pylon, e.g. in turbulence or at touchdown.
+//!
+//! Above the advisory threshold of the engine type, the ECAM engine page pulses the indication.
+
+use uom::si::{f64::*, length::meter, ratio::percent};
+
+use super::Engine;
+use crate::{
+    shared::{arinc429::SignStatus, ElectricalBusType, ElectricalBuses},
+    simulation::{
+        InitContext, SimulationElement, SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+struct EngineVibrationChannel {
+    n1_vibration_id: VariableIdentifier,
+    n2_vibration_id: VariableIdentifier,
+    n1_advisory_id: VariableIdentifier,
+    n2_advisory_id: VariableIdentifier,
+
+    n1_vibration: f64,
+    n2_vibration: f64,
+}
+impl EngineVibrationChannel {
+    // Vibration caused by the residual imbalance of the spools at 100 percent speed
+    const N1_RESIDUAL_UNITS: f64 = 1.;
+    const N2_RESIDUAL_UNITS: f64 = 1.2;
+
+    // Vibration caused by a damaged fan at 100 percent speed. The N2 channel picks up part of it.
+    const N1_FAN_DAMAGE_UNITS: f64 = 8.;
+    const N2_FAN_DAMAGE_UNITS: f64 = 1.5;
+
+    const STRUCTURE_UNITS_PER_METER: f64 = 150.;
+    const N2_STRUCTURE_RATIO: f64 = 0.5;
+
+    const MAX_UNITS: f64 = 10.;
+    const FILTER_TIME_CONSTANT_S: f64 = 1.;
+
+    fn new(context: &mut InitContext, number: usize) -> Self {
+        Self {
+            n1_vibration_id: context.get_identifier(format!("EVMU_ENG_{}_N1_VIBRATION", number)),
+            n2_vibration_id: context.get_identifier(format!("EVMU_ENG_{}_N2_VIBRATION", number)),
+            n1_advisory_id: context
+                .get_identifier(format!("EVMU_ENG_{}_N1_VIBRATION_ADVISORY", number)),
+            n2_advisory_id: context
+                .get_identifier(format!("EVMU_ENG_{}_N2_VIBRATION_ADVISORY", number)),
+
+            n1_vibration: 0.,
+            n2_vibration: 0.,
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, engine: &impl Engine, displacement: Length) {
+        let n1 = (engine.corrected_n1().get::<percent>() / 100.).max(0.);
+        let n2 = (engine.uncorrected_n2().get::<percent>() / 100.).max(0.);
+        let structure = Self::STRUCTURE_UNITS_PER_METER * displacement.get::<meter>().abs();
+
+        let (n1_damage, n2_damage) = if engine.fan_is_damaged() {
+            (Self::N1_FAN_DAMAGE_UNITS, Self::N2_FAN_DAMAGE_UNITS)
+        } else {
+            (0., 0.)
+        };
+
+        let n1_target = (Self::N1_RESIDUAL_UNITS + n1_damage) * n1.powi(2) + structure;
+        let n2_target = (Self::N2_RESIDUAL_UNITS + n2_damage) * n2.powi(2)
+            + Self::N2_STRUCTURE_RATIO * structure;
+
+        let alpha = 1. - (-context.delta_as_secs_f64() / Self::FILTER_TIME_CONSTANT_S).exp();
+        self.n1_vibration += (n1_target.min(Self::MAX_UNITS) - self.n1_vibration) * alpha;
+        self.n2_vibration += (n2_target.min(Self::MAX_UNITS) - self.n2_vibration) * alpha;
+    }
+}
+
+pub struct EngineVibrationMonitoringUnit<const N: usize> {
+    channels: [EngineVibrationChannel; N],
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+
+    n1_advisory_units: f64,
+    n2_advisory_units: f64,
+}
+impl<const N: usize> EngineVibrationMonitoringUnit<N> {
+    pub fn new(
+        context: &mut InitContext,
+        powered_by: ElectricalBusType,
+        n1_advisory_units: f64,
+        n2_advisory_units: f64,
+    ) -> Self {
+        Self {
+            channels: std::array::from_fn(|index| EngineVibrationChannel::new(context, index + 1)),
+
+            powered_by,
+            is_powered: false,
+
+            n1_advisory_units,
+            n2_advisory_units,
+        }
+    }
+
+    /// Updates the vibration levels from the engines and the displacement of each engine on its
+    /// pylon.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        engines: [&impl Engine; N],
+        engine_displacements: [Length; N],
+    ) {
+        for ((channel, engine), displacement) in self
+            .channels
+            .iter_mut()
+            .zip(engines)
+            .zip(engine_displacements)
+        {
+            channel.update(context, engine, displacement);
+        }
+    }
+
+    pub fn n1_vibration(&self, engine_number: usize) -> f64 {
+        self.channels[engine_number - 1].n1_vibration
+    }
+
+    pub fn n2_vibration(&self, engine_number: usize) -> f64 {
+        self.channels[engine_number - 1].n2_vibration
+    }
+
+    pub fn n1_vibration_is_above_advisory(&self, engine_number: usize) -> bool {
+        self.n1_vibration(engine_number) >= self.n1_advisory_units
+    }
+
+    pub fn n2_vibration_is_above_advisory(&self, engine_number: usize) -> bool {
+        self.n2_vibration(engine_number) >= self.n2_advisory_units
+    }
+}
+impl<const N: usize> SimulationElement for EngineVibrationMonitoringUnit<N> {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        let ssm = if self.is_powered {
+            SignStatus::NormalOperation
+        } else {
+            SignStatus::FailureWarning
+        };
+
+        for (index, channel) in self.channels.iter().enumerate() {
+            writer.write_arinc429(&channel.n1_vibration_id, channel.n1_vibration, ssm);
+            writer.write_arinc429(&channel.n2_vibration_id, channel.n2_vibration, ssm);
+            writer.write_arinc429(
+                &channel.n1_advisory_id,
+                self.n1_vibration_is_above_advisory(index + 1),
+                ssm,
+            );
+            writer.write_arinc429(
+                &channel.n2_advisory_id,
+                self.n2_vibration_is_above_advisory(index + 1),
+                ssm,
+            );
+        }
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::{
+            arinc429::Arinc429Word, EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2,
+            PotentialOrigin,
+        },
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed},
+            Aircraft, SimulationElementVisitor,
+        },
+    };
+    use std::time::Duration;
+    use uom::si::{
+        angular_velocity::revolution_per_minute, length::millimeter, mass::kilogram,
+        thermodynamic_temperature::degree_celsius,
+    };
+
+    struct TestEngine {
+        n1: Ratio,
+        n2: Ratio,
+        fan_is_damaged: bool,
+    }
+    impl TestEngine {
+        fn new() -> Self {
+            Self {
+                n1: Ratio::default(),
+                n2: Ratio::default(),
+                fan_is_damaged: false,
+            }
+        }
+    }
+    impl EngineCorrectedN1 for TestEngine {
+        fn corrected_n1(&self) -> Ratio {
+            self.n1
+        }
+    }
+    impl EngineCorrectedN2 for TestEngine {
+        fn corrected_n2(&self) -> Ratio {
+            self.n2
+        }
+    }
+    impl EngineUncorrectedN2 for TestEngine {
+        fn uncorrected_n2(&self) -> Ratio {
+            self.n2
+        }
+    }
+    impl Engine for TestEngine {
+        fn hydraulic_pump_output_speed(&self) -> AngularVelocity {
+            AngularVelocity::new::<revolution_per_minute>(0.)
+        }
+
+        fn oil_pressure_is_low(&self) -> bool {
+            false
+        }
+
+        fn is_above_minimum_idle(&self) -> bool {
+            self.n2.get::<percent>() >= 58.
+        }
+
+        fn net_thrust(&self) -> Mass {
+            Mass::new::<kilogram>(0.)
+        }
+
+        fn gearbox_speed(&self) -> AngularVelocity {
+            AngularVelocity::new::<revolution_per_minute>(0.)
+        }
+
+        fn exhaust_gas_temperature(&self) -> ThermodynamicTemperature {
+            ThermodynamicTemperature::new::<degree_celsius>(15.)
+        }
+
+        fn fan_is_damaged(&self) -> bool {
+            self.fan_is_damaged
+        }
+    }
+
+    struct TestAircraft {
+        evmu: EngineVibrationMonitoringUnit<2>,
+        engines: [TestEngine; 2],
+        engine_displacements: [Length; 2],
+        powered_source: TestElectricitySource,
+        ac_1_bus: ElectricalBus,
+        is_powered: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                evmu: EngineVibrationMonitoringUnit::new(
+                    context,
+                    ElectricalBusType::AlternatingCurrent(1),
+                    6.,
+                    4.3,
+                ),
+                engines: [TestEngine::new(), TestEngine::new()],
+                engine_displacements: [Length::default(); 2],
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                ac_1_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                is_powered: true,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.powered_source);
+            if self.is_powered {
+                electricity.flow(&self.powered_source, &self.ac_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.evmu.update(
+                context,
+                [&self.engines[0], &self.engines[1]],
+                self.engine_displacements,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.evmu.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct EvmuTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl EvmuTestBed {
+        fn new() -> Self {
+            Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            }
+        }
+
+        fn engine_1_at(mut self, n1_percent: f64, n2_percent: f64) -> Self {
+            self.command(|a| {
+                a.engines[0].n1 = Ratio::new::<percent>(n1_percent);
+                a.engines[0].n2 = Ratio::new::<percent>(n2_percent);
+            });
+            self
+        }
+
+        fn engine_1_fan_damaged(mut self) -> Self {
+            self.command(|a| a.engines[0].fan_is_damaged = true);
+            self
+        }
+
+        fn engine_1_displaced_by(mut self, displacement_mm: f64) -> Self {
+            self.command(|a| {
+                a.engine_displacements[0] = Length::new::<millimeter>(displacement_mm)
+            });
+            self
+        }
+
+        fn unpowered(mut self) -> Self {
+            self.command(|a| a.is_powered = false);
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            self.test_bed.run_multiple_frames(duration);
+            self
+        }
+
+        fn n1_vibration(&self, engine_number: usize) -> f64 {
+            self.query(|a| a.evmu.n1_vibration(engine_number))
+        }
+
+        fn n2_vibration(&self, engine_number: usize) -> f64 {
+            self.query(|a| a.evmu.n2_vibration(engine_number))
+        }
+
+        fn n1_advisory(&self, engine_number: usize) -> bool {
+            self.query(|a| a.evmu.n1_vibration_is_above_advisory(engine_number))
+        }
+
+        fn n2_advisory(&self, engine_number: usize) -> bool {
+            self.query(|a| a.evmu.n2_vibration_is_above_advisory(engine_number))
+        }
+    }
+    impl TestBed for EvmuTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> EvmuTestBed {
+        EvmuTestBed::new()
+    }
+
+    #[test]
+    fn no_vibration_with_engines_stopped() {
+        let test_bed = test_bed().run_for(Duration::from_secs(5));
+
+        assert!(test_bed.n1_vibration(1) < 0.01);
+        assert!(test_bed.n2_vibration(1) < 0.01);
+    }
+
+    #[test]
+    fn vibration_increases_with_spool_speed() {
+        let mut test_bed = test_bed()
+            .engine_1_at(20., 60.)
+            .run_for(Duration::from_secs(10));
+        let idle_n1_vibration = test_bed.n1_vibration(1);
+        let idle_n2_vibration = test_bed.n2_vibration(1);
+
+        test_bed = test_bed
+            .engine_1_at(90., 98.)
+            .run_for(Duration::from_secs(10));
+
+        assert!(test_bed.n1_vibration(1) > idle_n1_vibration + 0.5);
+        assert!(test_bed.n2_vibration(1) > idle_n2_vibration + 0.5);
+        assert!(!test_bed.n1_advisory(1));
+        assert!(!test_bed.n2_advisory(1));
+        assert!(test_bed.n1_vibration(2) < 0.01);
+    }
+
+    #[test]
+    fn vibration_is_filtered() {
+        let test_bed = test_bed()
+            .engine_1_at(90., 98.)
+            .run_for(Duration::from_millis(300));
+
+        assert!(test_bed.n1_vibration(1) < 0.4);
+    }
+
+    #[test]
+    fn damaged_fan_raises_n1_vibration_above_advisory_at_high_power() {
+        let mut test_bed = test_bed()
+            .engine_1_fan_damaged()
+            .engine_1_at(20., 60.)
+            .run_for(Duration::from_secs(10));
+
+        assert!(!test_bed.n1_advisory(1));
+
+        test_bed = test_bed
+            .engine_1_at(90., 98.)
+            .run_for(Duration::from_secs(10));
+
+        assert!(test_bed.n1_advisory(1));
+        assert!(!test_bed.n2_advisory(1));
+        assert!(test_bed.n2_vibration(1) > 2.);
+    }
+
+    #[test]
+    fn engine_movement_on_pylon_raises_vibration() {
+        let test_bed = test_bed()
+            .engine_1_at(80., 95.)
+            .engine_1_displaced_by(10.)
+            .run_for(Duration::from_secs(10));
+
+        assert!(test_bed.n1_vibration(1) > 2.);
+        assert!(test_bed.n2_vibration(1) > 1.5);
+    }
+
+    #[test]
+    fn vibration_is_limited_to_ten_units() {
+        let test_bed = test_bed()
+            .engine_1_fan_damaged()
+            .engine_1_at(100., 100.)
+            .engine_1_displaced_by(50.)
+            .run_for(Duration::from_secs(20));
+
+        assert!(test_bed.n1_vibration(1) <= 10.);
+        assert!(test_bed.n1_vibration(1) > 9.9);
+    }
+
+    #[test]
+    fn writes_vibration_in_normal_operation_when_powered() {
+        let mut test_bed = test_bed()
+            .engine_1_fan_damaged()
+            .engine_1_at(90., 98.)
+            .run_for(Duration::from_secs(10));
+
+        let n1_vibration: Arinc429Word<f64> =
+            test_bed.read_arinc429_by_name("EVMU_ENG_1_N1_VIBRATION");
+        let n1_advisory: Arinc429Word<bool> =
+            test_bed.read_arinc429_by_name("EVMU_ENG_1_N1_VIBRATION_ADVISORY");
+
+        assert!(n1_vibration.is_normal_operation());
+        assert!((n1_vibration.value() - test_bed.n1_vibration(1)).abs() < 0.01);
+        assert!(n1_advisory.is_normal_operation());
+        assert!(n1_advisory.value());
+    }
+
+    #[test]
+    fn writes_failure_warning_when_unpowered() {
+        let mut test_bed = test_bed()
+            .unpowered()
+            .engine_1_at(90., 98.)
+            .run_for(Duration::from_secs(1));
+
+        let n1_vibration: Arinc429Word<f64> =
+            test_bed.read_arinc429_by_name("EVMU_ENG_1_N1_VIBRATION");
+        let n2_vibration: Arinc429Word<f64> =
+            test_bed.read_arinc429_by_name("EVMU_ENG_2_N2_VIBRATION");
+
+        assert!(n1_vibration.is_failure_warning());
+        assert!(n2_vibration.is_failure_warning());
+    }
+}
@@ -10,7 +10,10 @@ use crate::shared::{
 use crate::simulation::SimulationElement;
 use rustc_hash::FxHashSet;
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+mod scheduler;
+pub use scheduler::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum FailureType {
     // ATA21
     Acsc(AcscId),
@@ -43,10 +46,14 @@ pub enum FailureType {
     ApuGenerator(usize),
     TransformerRectifier(usize),
     StaticInverter,
+    BatteryOverheat(usize),
     ElectricalBus(ElectricalBusType),
     // ATA26
     SetOnFire(FireDetectionZone),
     FireDetectionLoop(FireDetectionLoopID, FireDetectionZone),
+    // ATA28
+    FuelPump(usize),
+    FuelValveJammed(usize),
     // ATA29
     ReservoirLeak(HydraulicColor),
     ReservoirAirLeak(HydraulicColor),
@@ -60,11 +67,19 @@ pub enum FailureType {
     GearActuatorJammed(GearActuatorId),
     BrakeHydraulicLeak(HydraulicColor),
     BrakeAccumulatorGasLeak,
+    TyreBurst(usize),
+    TyreDeflation(usize),
     // ATA34
     RadioAltimeter(usize),
     RadioAntennaInterrupted(usize),
     RadioAntennaDirectCoupling(usize),
     EnhancedGroundProximityWarningSystemComputer,
+    // ATA72
+    EngineFlameout(usize),
+    EngineSeizure(usize),
+    EngineOilLeak(usize),
+    EngineEgtOverlimit(usize),
+    EngineFanDamage(usize),
 }
 
 pub struct Failure {
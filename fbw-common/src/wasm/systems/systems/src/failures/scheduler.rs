@@ -0,0 +1,571 @@
+use super::FailureType;
+use crate::{
+    shared::{random_from_range, FwcFlightPhase, InternationalStandardAtmosphere},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulatorReader, StateReader, StateWriter,
+        UpdateContext, VariableIdentifier,
+    },
+};
+use num_traits::FromPrimitive;
+use rustc_hash::{FxHashSet, FxHasher};
+use std::{
+    hash::{Hash, Hasher},
+    time::Duration,
+};
+use uom::si::f64::*;
+
+/// The condition under which a scheduled failure activates.
+///
+/// Conditions activate the failure when they become met, thus a condition which remains met
+/// doesn't activate an intermittent failure again after it cleared.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FailureTrigger {
+    /// Activates once the given amount of simulated time passed since scheduling.
+    After(Duration),
+    /// Activates above the given pressure altitude.
+    AltitudeAbove(Length),
+    /// Activates below the given pressure altitude.
+    AltitudeBelow(Length),
+    IndicatedAirspeedAbove(Velocity),
+    IndicatedAirspeedBelow(Velocity),
+    FlightPhase(FwcFlightPhase),
+    /// Activates at random, on average once per the given duration.
+    MeanTimeBetweenFailures(Duration),
+}
+
+struct FailureConditions {
+    elapsed: Duration,
+    altitude: Length,
+    indicated_airspeed: Velocity,
+    flight_phase: Option<FwcFlightPhase>,
+}
+
+impl FailureTrigger {
+    fn is_met(&self, conditions: &FailureConditions, scheduled_at: Duration) -> bool {
+        match *self {
+            FailureTrigger::After(duration) => conditions.elapsed >= scheduled_at + duration,
+            FailureTrigger::AltitudeAbove(altitude) => conditions.altitude > altitude,
+            FailureTrigger::AltitudeBelow(altitude) => conditions.altitude < altitude,
+            FailureTrigger::IndicatedAirspeedAbove(speed) => conditions.indicated_airspeed > speed,
+            FailureTrigger::IndicatedAirspeedBelow(speed) => conditions.indicated_airspeed < speed,
+            FailureTrigger::FlightPhase(phase) => conditions.flight_phase == Some(phase),
+            FailureTrigger::MeanTimeBetweenFailures(_) => false,
+        }
+    }
+}
+
+/// A failure which activates when its trigger fires.
+pub struct ScheduledFailure {
+    failure_type: FailureType,
+    trigger: FailureTrigger,
+    clears_after: Option<Duration>,
+}
+impl ScheduledFailure {
+    pub fn new(failure_type: FailureType, trigger: FailureTrigger) -> Self {
+        Self {
+            failure_type,
+            trigger,
+            clears_after: None,
+        }
+    }
+
+    /// Makes the failure intermittent: it clears by itself once it has been active for
+    /// the given duration, after which the trigger can activate it again.
+    pub fn intermittent(mut self, active_duration: Duration) -> Self {
+        self.clears_after = Some(active_duration);
+        self
+    }
+
+    pub fn failure_type(&self) -> FailureType {
+        self.failure_type
+    }
+}
+
+struct ArmedFailure {
+    failure: ScheduledFailure,
+    scheduled_at: Duration,
+    trigger_was_met: bool,
+    active_since: Option<Duration>,
+}
+impl ArmedFailure {
+    fn new(failure: ScheduledFailure, scheduled_at: Duration) -> Self {
+        Self {
+            failure,
+            scheduled_at,
+            trigger_was_met: false,
+            active_since: None,
+        }
+    }
+
+    fn update(&mut self, conditions: &FailureConditions, delta: Duration) {
+        if let (Some(active_since), Some(clears_after)) =
+            (self.active_since, self.failure.clears_after)
+        {
+            if conditions.elapsed >= active_since + clears_after {
+                self.active_since = None;
+            }
+        }
+
+        let fires = match self.failure.trigger {
+            FailureTrigger::MeanTimeBetweenFailures(mtbf) => {
+                let probability = 1. - (-delta.as_secs_f64() / mtbf.as_secs_f64()).exp();
+                random_from_range(0., 1.) < probability
+            }
+            trigger => {
+                let is_met = trigger.is_met(conditions, self.scheduled_at);
+                let fires = is_met && !self.trigger_was_met;
+                self.trigger_was_met = is_met;
+
+                fires
+            }
+        };
+
+        if fires && self.active_since.is_none() {
+            self.active_since = Some(conditions.elapsed);
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.active_since.is_some()
+    }
+
+    /// Identifies the failure type within a snapshot, such that the progress of a scheduled
+    /// failure isn't restored into another one.
+    fn snapshot_key(&self) -> u32 {
+        let mut hasher = FxHasher::default();
+        self.failure.failure_type.hash(&mut hasher);
+
+        hasher.finish() as u32
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FailureEventKind {
+    Activated,
+    Cleared,
+}
+
+/// An entry of the activation history of the [`FailureScheduler`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FailureEvent {
+    /// The simulated time since the start of the simulation.
+    elapsed: Duration,
+    failure_type: FailureType,
+    kind: FailureEventKind,
+}
+impl FailureEvent {
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn failure_type(&self) -> FailureType {
+        self.failure_type
+    }
+
+    pub fn kind(&self) -> FailureEventKind {
+        self.kind
+    }
+}
+
+/// Activates and clears failures based on time, the state of the aircraft or at random.
+///
+/// The failures activated by the scheduler are combined with the failures activated
+/// through [`Simulation::update_active_failures`], and reach the aircraft through
+/// [`SimulationElement::receive_failure`].
+///
+/// Snapshots capture the progress of the scheduled failures, but not the schedule itself. They
+/// can thus only be restored while the same failures are scheduled. The history isn't captured.
+///
+/// [`Simulation::update_active_failures`]: crate::simulation::Simulation::update_active_failures
+pub struct FailureScheduler {
+    fwc_flight_phase_id: VariableIdentifier,
+    flight_phase: Option<FwcFlightPhase>,
+
+    elapsed: Duration,
+    armed: Vec<ArmedFailure>,
+    active_failures: FxHashSet<FailureType>,
+    history: Vec<FailureEvent>,
+}
+impl FailureScheduler {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            fwc_flight_phase_id: context.get_identifier("FWC_FLIGHT_PHASE".to_owned()),
+            flight_phase: None,
+
+            elapsed: Duration::ZERO,
+            armed: vec![],
+            active_failures: FxHashSet::default(),
+            history: vec![],
+        }
+    }
+
+    pub fn schedule(&mut self, failure: ScheduledFailure) {
+        self.armed.push(ArmedFailure::new(failure, self.elapsed));
+    }
+
+    /// Removes all scheduled failures of the given type. When such a failure is active,
+    /// it clears with the next update.
+    pub fn cancel(&mut self, failure_type: FailureType) {
+        self.armed
+            .retain(|armed| armed.failure.failure_type != failure_type);
+    }
+
+    pub fn active_failures(&self) -> &FxHashSet<FailureType> {
+        &self.active_failures
+    }
+
+    pub fn history(&self) -> &[FailureEvent] {
+        &self.history
+    }
+
+    fn armed_active_failures(&self) -> FxHashSet<FailureType> {
+        self.armed
+            .iter()
+            .filter(|armed| armed.is_active())
+            .map(|armed| armed.failure.failure_type)
+            .collect()
+    }
+
+    /// Updates the scheduled failures and returns whether the set of active failures changed.
+    pub fn update(&mut self, context: &UpdateContext) -> bool {
+        self.elapsed += context.delta();
+
+        let conditions = FailureConditions {
+            elapsed: self.elapsed,
+            altitude: InternationalStandardAtmosphere::altitude_from_pressure(
+                context.ambient_pressure(),
+            ),
+            indicated_airspeed: context.indicated_airspeed(),
+            flight_phase: self.flight_phase,
+        };
+
+        for armed in &mut self.armed {
+            armed.update(&conditions, context.delta());
+        }
+
+        let active_failures = self.armed_active_failures();
+        if active_failures == self.active_failures {
+            return false;
+        }
+
+        let elapsed = self.elapsed;
+        let event = |failure_type: &FailureType, kind| FailureEvent {
+            elapsed,
+            failure_type: *failure_type,
+            kind,
+        };
+        self.history.extend(
+            active_failures
+                .difference(&self.active_failures)
+                .map(|failure_type| event(failure_type, FailureEventKind::Activated)),
+        );
+        self.history.extend(
+            self.active_failures
+                .difference(&active_failures)
+                .map(|failure_type| event(failure_type, FailureEventKind::Cleared)),
+        );
+        self.active_failures = active_failures;
+
+        true
+    }
+}
+impl SimulationElement for FailureScheduler {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        let flight_phase: f64 = reader.read(&self.fwc_flight_phase_id);
+        self.flight_phase = FromPrimitive::from_f64(flight_phase);
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.elapsed);
+        for armed in &self.armed {
+            state.write(armed.snapshot_key());
+            state.write(armed.scheduled_at);
+            state.write(armed.trigger_was_met);
+            state.write_option(armed.active_since);
+        }
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.elapsed = state.read();
+        for armed in &mut self.armed {
+            let key: u32 = state.read();
+            let scheduled_at = state.read();
+            let trigger_was_met = state.read();
+            let active_since = state.read_option();
+
+            if key == armed.snapshot_key() {
+                armed.scheduled_at = scheduled_at;
+                armed.trigger_was_met = trigger_was_met;
+                armed.active_since = active_since;
+            }
+        }
+        self.active_failures = self.armed_active_failures();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        failures::Failure,
+        simulation::{
+            test::{SimulationTestBed, TestBed, WriteByName},
+            Aircraft, SimulationElementVisitor,
+        },
+    };
+    use uom::si::{length::foot, velocity::knot};
+
+    struct TestAircraft {
+        failure: Failure,
+        other_failure: Failure,
+    }
+    impl TestAircraft {
+        fn new() -> Self {
+            Self {
+                failure: Failure::new(FailureType::TransformerRectifier(1)),
+                other_failure: Failure::new(FailureType::TransformerRectifier(2)),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {}
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.failure.accept(visitor);
+            self.other_failure.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    const FAILURE: FailureType = FailureType::TransformerRectifier(1);
+    const OTHER_FAILURE: FailureType = FailureType::TransformerRectifier(2);
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new_with_seed(1, |_| TestAircraft::new())
+    }
+
+    fn is_failed(test_bed: &SimulationTestBed<TestAircraft>) -> bool {
+        test_bed.query(|a| a.failure.is_active())
+    }
+
+    #[test]
+    fn activates_after_time() {
+        let mut test_bed = test_bed();
+        test_bed.schedule_failure(ScheduledFailure::new(
+            FAILURE,
+            FailureTrigger::After(Duration::from_secs(3)),
+        ));
+
+        test_bed.run_iterations_with_delta(2, Duration::from_secs(1));
+        assert!(!is_failed(&test_bed));
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert!(is_failed(&test_bed));
+    }
+
+    #[test]
+    fn activates_when_climbing_above_altitude() {
+        let mut test_bed = test_bed();
+        test_bed.set_pressure_altitude(Length::new::<foot>(5000.));
+        test_bed.schedule_failure(ScheduledFailure::new(
+            FAILURE,
+            FailureTrigger::AltitudeAbove(Length::new::<foot>(10000.)),
+        ));
+
+        test_bed.run();
+        assert!(!is_failed(&test_bed));
+
+        test_bed.set_pressure_altitude(Length::new::<foot>(10500.));
+        test_bed.run();
+        assert!(is_failed(&test_bed));
+    }
+
+    #[test]
+    fn activates_when_slowing_below_airspeed() {
+        let mut test_bed = test_bed();
+        test_bed.schedule_failure(ScheduledFailure::new(
+            FAILURE,
+            FailureTrigger::IndicatedAirspeedBelow(Velocity::new::<knot>(200.)),
+        ));
+
+        test_bed.run();
+        assert!(!is_failed(&test_bed));
+
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(180.));
+        test_bed.run();
+        assert!(is_failed(&test_bed));
+    }
+
+    #[test]
+    fn activates_in_flight_phase() {
+        let mut test_bed = test_bed();
+        test_bed.schedule_failure(ScheduledFailure::new(
+            FAILURE,
+            FailureTrigger::FlightPhase(FwcFlightPhase::LiftOff),
+        ));
+
+        test_bed.write_by_name("FWC_FLIGHT_PHASE", 4);
+        test_bed.run();
+        assert!(!is_failed(&test_bed));
+
+        test_bed.write_by_name("FWC_FLIGHT_PHASE", 5);
+        test_bed.run();
+        assert!(is_failed(&test_bed));
+    }
+
+    #[test]
+    fn activates_at_random_with_mean_time_between_failures() {
+        let mut test_bed = test_bed();
+        test_bed.schedule_failure(ScheduledFailure::new(
+            FAILURE,
+            FailureTrigger::MeanTimeBetweenFailures(Duration::from_secs(60)),
+        ));
+
+        let mut iterations = 0;
+        while !is_failed(&test_bed) && iterations < 3600 {
+            test_bed.run();
+            iterations += 1;
+        }
+
+        assert!(is_failed(&test_bed));
+    }
+
+    #[test]
+    fn intermittent_failure_clears_by_itself() {
+        let mut test_bed = test_bed();
+        test_bed.schedule_failure(
+            ScheduledFailure::new(FAILURE, FailureTrigger::After(Duration::from_secs(1)))
+                .intermittent(Duration::from_secs(2)),
+        );
+
+        test_bed.run();
+        assert!(is_failed(&test_bed));
+
+        test_bed.run();
+        assert!(is_failed(&test_bed));
+
+        test_bed.run();
+        assert!(!is_failed(&test_bed));
+    }
+
+    #[test]
+    fn intermittent_failure_activates_again_when_condition_is_met_again() {
+        let mut test_bed = test_bed();
+        test_bed.schedule_failure(
+            ScheduledFailure::new(
+                FAILURE,
+                FailureTrigger::AltitudeAbove(Length::new::<foot>(1000.)),
+            )
+            .intermittent(Duration::from_secs(1)),
+        );
+
+        test_bed.run();
+        test_bed.run();
+        assert!(!is_failed(&test_bed));
+
+        test_bed.set_pressure_altitude(Length::new::<foot>(500.));
+        test_bed.run();
+        test_bed.set_pressure_altitude(Length::new::<foot>(1500.));
+        test_bed.run();
+        assert!(is_failed(&test_bed));
+    }
+
+    #[test]
+    fn cancelled_failure_clears() {
+        let mut test_bed = test_bed();
+        test_bed.schedule_failure(ScheduledFailure::new(
+            FAILURE,
+            FailureTrigger::After(Duration::ZERO),
+        ));
+        test_bed.run();
+
+        test_bed.cancel_scheduled_failure(FAILURE);
+        test_bed.run();
+
+        assert!(!is_failed(&test_bed));
+    }
+
+    #[test]
+    fn does_not_clear_failures_activated_otherwise() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FAILURE);
+        test_bed.schedule_failure(
+            ScheduledFailure::new(FAILURE, FailureTrigger::After(Duration::ZERO))
+                .intermittent(Duration::from_secs(1)),
+        );
+
+        test_bed.run_iterations_with_delta(3, Duration::from_secs(1));
+
+        assert!(is_failed(&test_bed));
+    }
+
+    #[test]
+    fn restoring_a_snapshot_restores_the_progress_of_scheduled_failures() {
+        let mut test_bed = test_bed();
+        test_bed.schedule_failure(ScheduledFailure::new(
+            FAILURE,
+            FailureTrigger::After(Duration::from_secs(3)),
+        ));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        let snapshot = test_bed.snapshot();
+
+        test_bed.run_iterations_with_delta(2, Duration::from_secs(1));
+        assert!(is_failed(&test_bed));
+
+        assert_eq!(test_bed.restore(&snapshot), Ok(()));
+        assert!(!is_failed(&test_bed));
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert!(!is_failed(&test_bed));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert!(is_failed(&test_bed));
+    }
+
+    #[test]
+    fn snapshot_with_other_scheduled_failures_is_rejected() {
+        let mut test_bed = test_bed();
+        let snapshot = test_bed.snapshot();
+
+        test_bed.schedule_failure(ScheduledFailure::new(
+            FAILURE,
+            FailureTrigger::After(Duration::from_secs(3)),
+        ));
+
+        assert!(test_bed.restore(&snapshot).is_err());
+    }
+
+    #[test]
+    fn logs_activation_history() {
+        let mut test_bed = test_bed();
+        test_bed.schedule_failure(
+            ScheduledFailure::new(FAILURE, FailureTrigger::After(Duration::from_secs(1)))
+                .intermittent(Duration::from_secs(1)),
+        );
+        test_bed.schedule_failure(ScheduledFailure::new(
+            OTHER_FAILURE,
+            FailureTrigger::After(Duration::from_secs(2)),
+        ));
+
+        test_bed.run_iterations_with_delta(3, Duration::from_secs(1));
+
+        let history: Vec<_> = test_bed
+            .failure_history()
+            .iter()
+            .map(|event| {
+                (
+                    event.elapsed().as_secs(),
+                    event.failure_type(),
+                    event.kind(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            history,
+            [
+                (1, FAILURE, FailureEventKind::Activated),
+                (2, OTHER_FAILURE, FailureEventKind::Activated),
+                (2, FAILURE, FailureEventKind::Cleared),
+            ]
+        );
+    }
+}
@@ -6,8 +6,15 @@ use crate::{
     },
 };
 use nalgebra::Vector3;
+use network::{FuelNetwork, FuelNetworkController};
 use num_traits::Zero;
-use uom::si::{electric_current::ampere, f64::*, mass::kilogram};
+use temperature::{FuelTemperature, FuelType};
+use uom::si::{
+    electric_current::ampere, f64::*, mass::kilogram, thermodynamic_temperature::degree_celsius,
+};
+
+pub mod network;
+pub mod temperature;
 
 pub const FUEL_GALLONS_TO_KG: f64 = 3.039075693483925;
 
@@ -61,6 +68,7 @@ impl FuelInfo<'_> {
             context,
             self.fuel_tank_id,
             Vector3::new(self.position.0, self.position.1, self.position.2),
+            Mass::new::<kilogram>(self.total_capacity_gallons * FUEL_GALLONS_TO_KG),
             write,
         )
     }
@@ -70,15 +78,25 @@ impl FuelInfo<'_> {
 pub struct FuelTank {
     fuel_id: VariableIdentifier,
     location: Vector3<f64>,
+    capacity: Mass,
     quantity: Mass,
+    temperature: FuelTemperature,
     write: bool,
 }
 impl FuelTank {
-    pub fn new(context: &mut InitContext, id: &str, location: Vector3<f64>, write: bool) -> Self {
+    pub fn new(
+        context: &mut InitContext,
+        id: &str,
+        location: Vector3<f64>,
+        capacity: Mass,
+        write: bool,
+    ) -> Self {
         FuelTank {
             fuel_id: context.get_identifier(id.to_owned()),
             location,
+            capacity,
             quantity: Mass::default(),
+            temperature: FuelTemperature::new(capacity),
             write,
         }
     }
@@ -94,8 +112,37 @@ impl FuelTank {
     pub fn set_quantity(&mut self, quantity: Mass) {
         self.quantity = quantity;
     }
+
+    pub fn capacity(&self) -> Mass {
+        self.capacity
+    }
+
+    pub fn free_capacity(&self) -> Mass {
+        (self.capacity - self.quantity).max(Mass::default())
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature.temperature()
+    }
+
+    /// Updates the fuel temperature, where fuel added to the tank since the last update enters
+    /// with the given inflow temperature.
+    pub fn update_temperature(
+        &mut self,
+        context: &UpdateContext,
+        inflow_temperature: ThermodynamicTemperature,
+    ) {
+        self.temperature
+            .update(context, self.quantity, self.capacity, inflow_temperature);
+    }
 }
 impl SimulationElement for FuelTank {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.temperature.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         let volume: f64 = reader.read(&self.fuel_id);
         self.quantity = Mass::new::<kilogram>(volume * FUEL_GALLONS_TO_KG);
@@ -118,6 +165,8 @@ impl SimulationElement for FuelTank {
 pub struct FuelSystem<const N: usize, const PUMP_COUNT: usize> {
     unlimited_fuel_id: VariableIdentifier,
     unlimited_fuel: bool,
+    fuel_type_id: VariableIdentifier,
+    fuel_type: FuelType,
 
     total_fuel_quantity_id: VariableIdentifier,
     total_fuel_volume_id: VariableIdentifier,
@@ -126,6 +175,8 @@ pub struct FuelSystem<const N: usize, const PUMP_COUNT: usize> {
     fuel_pumps: [FuelPump; PUMP_COUNT],
 }
 impl<const N: usize, const PUMP_COUNT: usize> FuelSystem<N, PUMP_COUNT> {
+    const LOW_TEMPERATURE_MARGIN_DEGREE_CELSIUS: f64 = 3.;
+
     pub fn new(
         context: &mut InitContext,
         fuel_tanks: [FuelTank; N],
@@ -134,6 +185,8 @@ impl<const N: usize, const PUMP_COUNT: usize> FuelSystem<N, PUMP_COUNT> {
         FuelSystem {
             unlimited_fuel_id: context.get_identifier("UNLIMITED FUEL".to_owned()),
             unlimited_fuel: false,
+            fuel_type_id: context.get_identifier("EFB_FUEL_TYPE".to_owned()),
+            fuel_type: FuelType::JetA1,
             total_fuel_quantity_id: context.get_identifier("TOTAL_FUEL_QUANTITY".to_owned()),
             total_fuel_volume_id: context.get_identifier("TOTAL_FUEL_VOLUME".to_owned()),
             fuel_tanks,
@@ -176,9 +229,78 @@ impl<const N: usize, const PUMP_COUNT: usize> FuelSystem<N, PUMP_COUNT> {
         self.fuel_tanks[t].quantity()
     }
 
+    pub fn tank_free_capacity(&self, t: usize) -> Mass {
+        self.fuel_tanks[t].free_capacity()
+    }
+
+    pub fn tank_temperature(&self, t: usize) -> ThermodynamicTemperature {
+        self.fuel_tanks[t].temperature()
+    }
+
+    pub fn fuel_type(&self) -> FuelType {
+        self.fuel_type
+    }
+
+    /// Indicates the fuel of the tank approaches the freezing point of the loaded fuel type.
+    pub fn tank_temperature_is_low(&self, t: usize) -> bool {
+        self.tank_temperature(t)
+            < ThermodynamicTemperature::new::<degree_celsius>(
+                self.fuel_type.freezing_point().get::<degree_celsius>()
+                    + Self::LOW_TEMPERATURE_MARGIN_DEGREE_CELSIUS,
+            )
+    }
+
+    /// Updates the fuel temperature of all tanks. On ground, fuel is added at the ambient
+    /// temperature. In flight, fuel only moves between tanks, thus it enters a tank at the
+    /// average fuel temperature.
+    pub fn update_temperatures(&mut self, context: &UpdateContext) {
+        let inflow_temperature = if context.is_on_ground() {
+            context.ambient_temperature()
+        } else {
+            self.average_temperature()
+                .unwrap_or_else(|| context.ambient_temperature())
+        };
+
+        for tank in &mut self.fuel_tanks {
+            tank.update_temperature(context, inflow_temperature);
+        }
+    }
+
+    fn average_temperature(&self) -> Option<ThermodynamicTemperature> {
+        let total_mass_kg = self.total_load().get::<kilogram>();
+        (total_mass_kg > 0.).then(|| {
+            ThermodynamicTemperature::new::<degree_celsius>(
+                self.fuel_tanks
+                    .iter()
+                    .map(|t| {
+                        t.quantity().get::<kilogram>() * t.temperature().get::<degree_celsius>()
+                    })
+                    .sum::<f64>()
+                    / total_mass_kg,
+            )
+        })
+    }
+
     pub fn is_fuel_pump_running(&self, i: usize) -> bool {
         self.fuel_pumps[i].is_running()
     }
+
+    /// Moves fuel between the tanks and burns the fuel demanded by the consumers of the network.
+    pub fn update_network(
+        &mut self,
+        context: &UpdateContext,
+        network: &mut FuelNetwork,
+        controller: &impl FuelNetworkController,
+        fuel_demands: &[MassRate],
+    ) {
+        network.update(
+            context,
+            controller,
+            &mut self.fuel_tanks,
+            self.unlimited_fuel,
+            fuel_demands,
+        );
+    }
 }
 impl<const N: usize, const PUMP_COUNT: usize> SimulationElement for FuelSystem<N, PUMP_COUNT> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -198,6 +320,8 @@ impl<const N: usize, const PUMP_COUNT: usize> SimulationElement for FuelSystem<N
 
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.unlimited_fuel = reader.read(&self.unlimited_fuel_id);
+        self.fuel_type =
+            reader.read_discrete_or_fallback(&self.fuel_type_id, "FuelType", FuelType::JetA1);
     }
 }
 
@@ -0,0 +1,994 @@
+//! A fuel network moving fuel between the tanks of a [`super::FuelSystem`] and the consumers
+//! of the aircraft, such as engines and the APU.
+//!
+//! Fuel is delivered through feed lines. A feed line is pressurised by the electric pumps feeding
+//! into it, of which the pumps with the highest pressure deliver first. Feed lines connected by an
+//! open valve (e.g. a crossfeed valve) act as a single feed line. Without any pump pressure, a
+//! consumer can only draw fuel from its suction tank. Transfer valves let the fuel of a
+//! pressurised feed line flow into a tank, and gravity transfer valves let fuel flow from one tank
+//! into another without any pump.
+
+use super::FuelTank;
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{low_pass_filter::LowPassFilter, ConsumePower, ElectricalBusType, ElectricalBuses},
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+use std::time::Duration;
+use uom::si::{
+    electric_current::ampere, f64::*, mass::kilogram, mass_rate::kilogram_per_second,
+    pressure::psi, ratio::ratio,
+};
+
+pub trait FuelNetworkController {
+    fn pump_should_run(&self, pump: usize) -> bool;
+    fn valve_should_open(&self, valve: usize) -> bool;
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ElectricFuelPumpProperties {
+    /// The tank the pump is located in.
+    pub tank: usize,
+    /// The feed line the pump delivers into.
+    pub feed_line: usize,
+    pub powered_by: ElectricalBusType,
+    pub consumption_current_ampere: f64,
+    pub nominal_pressure_psi: f64,
+    pub max_flow_kg_per_s: f64,
+}
+
+pub struct ElectricFuelPump {
+    pressure_id: VariableIdentifier,
+    low_pressure_id: VariableIdentifier,
+
+    properties: ElectricFuelPumpProperties,
+    is_powered: bool,
+    available_potential: ElectricPotential,
+    is_running: bool,
+    pressure: LowPassFilter<Pressure>,
+    failure: Failure,
+}
+impl ElectricFuelPump {
+    const SPOOL_TIME_CONSTANT: Duration = Duration::from_millis(500);
+    const LOW_PRESSURE_THRESHOLD_PSI: f64 = 6.;
+
+    pub fn new(
+        context: &mut InitContext,
+        id: &str,
+        failure_type: FailureType,
+        properties: ElectricFuelPumpProperties,
+    ) -> Self {
+        Self {
+            pressure_id: context.get_identifier(format!("FUEL_PUMP_{id}_PRESSURE")),
+            low_pressure_id: context.get_identifier(format!("FUEL_PUMP_{id}_LOW_PRESSURE")),
+            properties,
+            is_powered: false,
+            available_potential: ElectricPotential::default(),
+            is_running: false,
+            pressure: LowPassFilter::new(Self::SPOOL_TIME_CONSTANT),
+            failure: Failure::new(failure_type),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, should_run: bool, tank_has_fuel: bool) {
+        self.is_running = should_run && self.is_powered && !self.failure.is_active();
+
+        // A pump running dry cannot build up any pressure
+        let target_pressure = if self.is_running && tank_has_fuel {
+            Pressure::new::<psi>(self.properties.nominal_pressure_psi)
+        } else {
+            Pressure::default()
+        };
+        self.pressure.update(context.delta(), target_pressure);
+    }
+
+    pub fn tank(&self) -> usize {
+        self.properties.tank
+    }
+
+    pub fn feed_line(&self) -> usize {
+        self.properties.feed_line
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.is_running
+    }
+
+    pub fn pressure(&self) -> Pressure {
+        self.pressure.output()
+    }
+
+    pub fn is_pressurised(&self) -> bool {
+        self.pressure().get::<psi>() >= Self::LOW_PRESSURE_THRESHOLD_PSI
+    }
+
+    /// The flow the pump can deliver at its current pressure.
+    fn flow_capacity_kg_per_s(&self) -> f64 {
+        let pressure_ratio =
+            (self.pressure().get::<psi>() / self.properties.nominal_pressure_psi).clamp(0., 1.);
+
+        self.properties.max_flow_kg_per_s * pressure_ratio
+    }
+}
+impl SimulationElement for ElectricFuelPump {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.pressure_id, self.pressure());
+        writer.write(&self.low_pressure_id, !self.is_pressurised());
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.properties.powered_by);
+        self.available_potential = buses.potential_of(self.properties.powered_by).raw();
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, power: &mut T) {
+        let consumed_power = if self.is_running {
+            self.available_potential
+                * ElectricCurrent::new::<ampere>(self.properties.consumption_current_ampere)
+        } else {
+            Power::default()
+        };
+        power.consume_from_bus(self.properties.powered_by, consumed_power);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FuelValveConnection {
+    /// Connects two feed lines, e.g. a crossfeed valve.
+    FeedLines(usize, usize),
+    /// Lets the fuel of a pressurised feed line flow into a tank, e.g. a transfer valve.
+    FeedLineToTank { feed_line: usize, tank: usize },
+    /// Lets fuel flow from one tank into another by gravity.
+    Gravity { from_tank: usize, to_tank: usize },
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FuelValveProperties {
+    pub connection: FuelValveConnection,
+    pub powered_by: ElectricalBusType,
+    pub travel_time_s: f64,
+    /// The flow through the fully open valve. Unused for valves connecting feed lines.
+    pub max_flow_kg_per_s: f64,
+}
+
+/// A motor driven valve, which stays in its position when unpowered or jammed.
+pub struct FuelValve {
+    open_amount_id: VariableIdentifier,
+
+    properties: FuelValveProperties,
+    is_powered: bool,
+    open_amount: Ratio,
+    flow: MassRate,
+    jammed_failure: Failure,
+}
+impl FuelValve {
+    pub fn new(
+        context: &mut InitContext,
+        id: &str,
+        failure_type: FailureType,
+        properties: FuelValveProperties,
+    ) -> Self {
+        Self {
+            open_amount_id: context.get_identifier(format!("FUEL_VALVE_{id}_OPEN_AMOUNT")),
+            properties,
+            is_powered: false,
+            open_amount: Ratio::default(),
+            flow: MassRate::default(),
+            jammed_failure: Failure::new(failure_type),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, should_open: bool) {
+        if !self.is_powered || self.jammed_failure.is_active() {
+            return;
+        }
+
+        let target = if should_open { 1. } else { 0. };
+        let max_travel = context.delta_as_secs_f64() / self.properties.travel_time_s.max(1e-3);
+        let open_amount = self.open_amount.get::<ratio>();
+        self.open_amount = Ratio::new::<ratio>(
+            open_amount + (target - open_amount).clamp(-max_travel, max_travel),
+        );
+    }
+
+    pub fn connection(&self) -> FuelValveConnection {
+        self.properties.connection
+    }
+
+    pub fn open_amount(&self) -> Ratio {
+        self.open_amount
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open_amount.get::<ratio>() > 0.
+    }
+
+    pub fn is_fully_open(&self) -> bool {
+        self.open_amount.get::<ratio>() >= 1.
+    }
+
+    /// The fuel flowing through the valve into a tank.
+    pub fn flow(&self) -> MassRate {
+        self.flow
+    }
+
+    fn max_flow_kg_per_s(&self) -> f64 {
+        self.properties.max_flow_kg_per_s * self.open_amount.get::<ratio>()
+    }
+}
+impl SimulationElement for FuelValve {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.jammed_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.open_amount_id, self.open_amount);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.properties.powered_by);
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FuelConsumer {
+    pub feed_line: usize,
+    /// The tank of which the consumer can draw fuel by suction when its feed line isn't
+    /// pressurised, e.g. the engine driven fuel pump drawing fuel through a bypass.
+    pub suction_tank: Option<usize>,
+}
+
+pub struct FuelNetwork {
+    feed_line_count: usize,
+    pumps: Vec<ElectricFuelPump>,
+    valves: Vec<FuelValve>,
+    consumers: Vec<FuelConsumer>,
+
+    feed_line_pressures: Vec<Pressure>,
+    consumer_fuel_flows: Vec<MassRate>,
+    consumer_starved: Vec<bool>,
+}
+impl FuelNetwork {
+    // Pumps within this margin of the highest pressure of a feed line deliver together
+    const PRESSURE_PRIORITY_MARGIN_PSI: f64 = 3.;
+    const STARVATION_RATIO: f64 = 0.99;
+
+    pub fn new(
+        feed_line_count: usize,
+        pumps: Vec<ElectricFuelPump>,
+        valves: Vec<FuelValve>,
+        consumers: Vec<FuelConsumer>,
+    ) -> Self {
+        let consumer_count = consumers.len();
+        Self {
+            feed_line_count,
+            pumps,
+            valves,
+            consumers,
+            feed_line_pressures: vec![Pressure::default(); feed_line_count],
+            consumer_fuel_flows: vec![MassRate::default(); consumer_count],
+            consumer_starved: vec![false; consumer_count],
+        }
+    }
+
+    pub(super) fn update(
+        &mut self,
+        context: &UpdateContext,
+        controller: &impl FuelNetworkController,
+        tanks: &mut [FuelTank],
+        unlimited_fuel: bool,
+        fuel_demands: &[MassRate],
+    ) {
+        for (i, pump) in self.pumps.iter_mut().enumerate() {
+            let tank_has_fuel = unlimited_fuel || tanks[pump.tank()].quantity() > Mass::default();
+            pump.update(context, controller.pump_should_run(i), tank_has_fuel);
+        }
+        for (i, valve) in self.valves.iter_mut().enumerate() {
+            valve.update(context, controller.valve_should_open(i));
+            valve.flow = MassRate::default();
+        }
+
+        let delta = context.delta_as_secs_f64();
+        if delta <= 0. {
+            return;
+        }
+
+        let mut flows = TankFlows::new(tanks, delta, unlimited_fuel);
+        let groups = self.feed_line_groups();
+        self.update_feed_line_pressures(&groups);
+
+        for group in 0..self.feed_line_count {
+            if groups.contains(&group) {
+                self.deliver_feed_line_group(group, &groups, &mut flows, fuel_demands);
+            }
+        }
+        self.transfer_by_gravity(&mut flows);
+
+        flows.apply(tanks);
+    }
+
+    /// Assigns every feed line to a group of feed lines connected by open valves, identified
+    /// by the lowest feed line index within the group.
+    fn feed_line_groups(&self) -> Vec<usize> {
+        let mut groups: Vec<usize> = (0..self.feed_line_count).collect();
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for valve in self.valves.iter().filter(|valve| valve.is_open()) {
+                if let FuelValveConnection::FeedLines(a, b) = valve.connection() {
+                    let group = groups[a].min(groups[b]);
+                    if groups[a] != group || groups[b] != group {
+                        groups[a] = group;
+                        groups[b] = group;
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        groups
+    }
+
+    fn update_feed_line_pressures(&mut self, groups: &[usize]) {
+        for line in 0..self.feed_line_count {
+            self.feed_line_pressures[line] = self
+                .pumps
+                .iter()
+                .filter(|pump| groups[pump.feed_line()] == groups[line])
+                .map(|pump| pump.pressure())
+                .fold(Pressure::default(), |acc, p| acc.max(p));
+        }
+    }
+
+    fn deliver_feed_line_group(
+        &mut self,
+        group: usize,
+        groups: &[usize],
+        flows: &mut TankFlows,
+        fuel_demands: &[MassRate],
+    ) {
+        let in_group = |line: usize| groups[line] == group;
+
+        let consumers: Vec<usize> = (0..self.consumers.len())
+            .filter(|&i| in_group(self.consumers[i].feed_line))
+            .collect();
+        let consumer_demand: f64 = consumers
+            .iter()
+            .map(|&i| Self::demand_kg_per_s(fuel_demands, i))
+            .sum();
+
+        let transfers: Vec<(usize, usize, f64)> = self
+            .valves
+            .iter()
+            .enumerate()
+            .filter_map(|(i, valve)| match valve.connection() {
+                FuelValveConnection::FeedLineToTank { feed_line, tank } if in_group(feed_line) => {
+                    Some((i, tank, flows.limit_inflow(tank, valve.max_flow_kg_per_s())))
+                }
+                _ => None,
+            })
+            .collect();
+        let transfer_demand: f64 = transfers.iter().map(|(_, _, flow)| flow).sum();
+
+        let mut pumps: Vec<&ElectricFuelPump> = self
+            .pumps
+            .iter()
+            .filter(|pump| in_group(pump.feed_line()) && pump.is_pressurised())
+            .collect();
+
+        if pumps.is_empty() {
+            for i in consumers {
+                let demand = Self::demand_kg_per_s(fuel_demands, i);
+                let flow = self.consumers[i]
+                    .suction_tank
+                    .map_or(0., |tank| flows.draw(tank, demand, true));
+                self.set_consumer_fuel_flow(i, demand, flow);
+            }
+            return;
+        }
+
+        // The pumps of the highest pressure deliver first, the others only deliver what
+        // these cannot deliver.
+        pumps.sort_by(|a, b| b.pressure().partial_cmp(&a.pressure()).unwrap());
+        let demand = consumer_demand + transfer_demand;
+        let consumed_share = if demand > 0. {
+            consumer_demand / demand
+        } else {
+            0.
+        };
+
+        let mut remaining = demand;
+        let mut tier_start = 0;
+        while tier_start < pumps.len() && remaining > 0. {
+            let tier_pressure = pumps[tier_start].pressure().get::<psi>();
+            let tier_end = pumps[tier_start..]
+                .iter()
+                .position(|pump| {
+                    tier_pressure - pump.pressure().get::<psi>()
+                        > Self::PRESSURE_PRIORITY_MARGIN_PSI
+                })
+                .map_or(pumps.len(), |position| tier_start + position);
+            let tier = &pumps[tier_start..tier_end];
+
+            let capacity: f64 = tier.iter().map(|pump| pump.flow_capacity_kg_per_s()).sum();
+            if capacity > 0. {
+                let delivered_ratio = (remaining / capacity).min(1.);
+                for pump in tier {
+                    remaining -= flows.draw_shared(
+                        pump.tank(),
+                        pump.flow_capacity_kg_per_s() * delivered_ratio,
+                        consumed_share,
+                    );
+                }
+            }
+
+            tier_start = tier_end;
+        }
+
+        let supplied_ratio = if demand > 0. {
+            ((demand - remaining.max(0.)) / demand).clamp(0., 1.)
+        } else {
+            1.
+        };
+        for i in consumers {
+            let demand = Self::demand_kg_per_s(fuel_demands, i);
+            self.set_consumer_fuel_flow(i, demand, demand * supplied_ratio);
+        }
+        for (valve, tank, flow) in transfers {
+            let flow = flow * supplied_ratio;
+            flows.fill(tank, flow);
+            self.valves[valve].flow = MassRate::new::<kilogram_per_second>(flow);
+        }
+    }
+
+    fn transfer_by_gravity(&mut self, flows: &mut TankFlows) {
+        for valve in &mut self.valves {
+            if let FuelValveConnection::Gravity { from_tank, to_tank } = valve.connection() {
+                // The available head decreases as the source tank empties
+                let head_ratio = flows.fill_ratio(from_tank).sqrt();
+                let flow = flows.limit_inflow(to_tank, valve.max_flow_kg_per_s() * head_ratio);
+                let flow = flows.draw(from_tank, flow, false);
+                flows.fill(to_tank, flow);
+                valve.flow = MassRate::new::<kilogram_per_second>(flow);
+            }
+        }
+    }
+
+    fn demand_kg_per_s(fuel_demands: &[MassRate], consumer: usize) -> f64 {
+        fuel_demands
+            .get(consumer)
+            .map_or(0., |demand| demand.get::<kilogram_per_second>().max(0.))
+    }
+
+    fn set_consumer_fuel_flow(&mut self, consumer: usize, demand: f64, flow: f64) {
+        self.consumer_fuel_flows[consumer] = MassRate::new::<kilogram_per_second>(flow);
+        self.consumer_starved[consumer] = demand > 0. && flow < demand * Self::STARVATION_RATIO;
+    }
+
+    pub fn pump(&self, pump: usize) -> &ElectricFuelPump {
+        &self.pumps[pump]
+    }
+
+    pub fn valve(&self, valve: usize) -> &FuelValve {
+        &self.valves[valve]
+    }
+
+    pub fn feed_line_pressure(&self, feed_line: usize) -> Pressure {
+        self.feed_line_pressures[feed_line]
+    }
+
+    /// The fuel flow delivered to the consumer during the last update.
+    pub fn consumer_fuel_flow(&self, consumer: usize) -> MassRate {
+        self.consumer_fuel_flows[consumer]
+    }
+
+    /// Indicates the consumer didn't receive the fuel it demanded during the last update.
+    pub fn consumer_is_starved(&self, consumer: usize) -> bool {
+        self.consumer_starved[consumer]
+    }
+}
+impl SimulationElement for FuelNetwork {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.pumps, visitor);
+        accept_iterable!(self.valves, visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// Accumulates the fuel drawn from and filled into the tanks during one update, such that no
+/// tank delivers more fuel than it contains and no tank is filled beyond its capacity.
+struct TankFlows {
+    delta: f64,
+    unlimited_fuel: bool,
+    quantities: Vec<f64>,
+    capacities: Vec<f64>,
+    changes: Vec<f64>,
+}
+impl TankFlows {
+    fn new(tanks: &[FuelTank], delta: f64, unlimited_fuel: bool) -> Self {
+        Self {
+            delta,
+            unlimited_fuel,
+            quantities: tanks
+                .iter()
+                .map(|tank| tank.quantity().get::<kilogram>())
+                .collect(),
+            capacities: tanks
+                .iter()
+                .map(|tank| tank.capacity().get::<kilogram>())
+                .collect(),
+            changes: vec![0.; tanks.len()],
+        }
+    }
+
+    fn available(&self, tank: usize) -> f64 {
+        (self.quantities[tank] + self.changes[tank]).max(0.)
+    }
+
+    fn fill_ratio(&self, tank: usize) -> f64 {
+        if self.capacities[tank] > 0. {
+            (self.available(tank) / self.capacities[tank]).clamp(0., 1.)
+        } else {
+            0.
+        }
+    }
+
+    fn limit_inflow(&self, tank: usize, flow: f64) -> f64 {
+        let free = (self.capacities[tank] - self.available(tank)).max(0.);
+
+        flow.min(free / self.delta)
+    }
+
+    /// Draws up to the given flow from the tank, returns the flow which could be drawn.
+    fn draw(&mut self, tank: usize, flow: f64, consumed: bool) -> f64 {
+        self.draw_shared(tank, flow, if consumed { 1. } else { 0. })
+    }
+
+    /// Draws up to the given flow from the tank, of which the consumed share is burned by
+    /// consumers rather than transferred. Returns the flow which could be drawn.
+    fn draw_shared(&mut self, tank: usize, flow: f64, consumed_share: f64) -> f64 {
+        let flow = if self.unlimited_fuel {
+            flow
+        } else {
+            flow.min(self.available(tank) / self.delta)
+        };
+
+        // Unlimited fuel only keeps the fuel burned by consumers in the tank
+        let removed = if self.unlimited_fuel {
+            flow * (1. - consumed_share)
+        } else {
+            flow
+        };
+        self.changes[tank] -= removed * self.delta;
+
+        flow
+    }
+
+    fn fill(&mut self, tank: usize, flow: f64) {
+        self.changes[tank] += flow * self.delta;
+    }
+
+    fn apply(&self, tanks: &mut [FuelTank]) {
+        for (tank, change) in tanks.iter_mut().zip(&self.changes) {
+            if *change != 0. {
+                tank.set_quantity(Mass::new::<kilogram>(
+                    (tank.quantity().get::<kilogram>() + change).max(0.),
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        fuel::{FuelSystem, FUEL_GALLONS_TO_KG},
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+    use nalgebra::Vector3;
+    use uom::si::electric_potential::volt;
+
+    const LEFT_TANK: usize = 0;
+    const CENTER_TANK: usize = 1;
+    const RIGHT_TANK: usize = 2;
+    const LEFT_OUTER_TANK: usize = 3;
+
+    const LEFT_PUMP: usize = 0;
+    const CENTER_LEFT_PUMP: usize = 1;
+    const CENTER_RIGHT_PUMP: usize = 2;
+    const RIGHT_PUMP: usize = 3;
+
+    const CROSSFEED_VALVE: usize = 0;
+    const OUTER_TRANSFER_VALVE: usize = 1;
+    const CENTER_TRANSFER_VALVE: usize = 2;
+
+    const TANK_CAPACITY_KG: f64 = 5000.;
+    const ENGINE_FUEL_FLOW_KG_PER_S: f64 = 0.5;
+
+    struct TestFuelController {
+        pumps_running: [bool; 4],
+        valves_open: [bool; 3],
+    }
+    impl FuelNetworkController for TestFuelController {
+        fn pump_should_run(&self, pump: usize) -> bool {
+            self.pumps_running[pump]
+        }
+
+        fn valve_should_open(&self, valve: usize) -> bool {
+            self.valves_open[valve]
+        }
+    }
+
+    struct TestAircraft {
+        powered_source: TestElectricitySource,
+        ac_bus: ElectricalBus,
+        is_powered: bool,
+
+        fuel_system: FuelSystem<4, 0>,
+        network: FuelNetwork,
+        controller: TestFuelController,
+        fuel_demands: [MassRate; 2],
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            let tanks = ["LEFT", "CENTER", "RIGHT", "LEFT_OUTER"].map(|id| {
+                FuelTank::new(
+                    context,
+                    &format!("FUEL_TANK_{id}"),
+                    Vector3::zeros(),
+                    Mass::new::<kilogram>(TANK_CAPACITY_KG),
+                    true,
+                )
+            });
+
+            let pump = |tank, feed_line, nominal_pressure_psi| ElectricFuelPumpProperties {
+                tank,
+                feed_line,
+                powered_by: ElectricalBusType::AlternatingCurrent(1),
+                consumption_current_ampere: 8.,
+                nominal_pressure_psi,
+                max_flow_kg_per_s: 2.,
+            };
+            let valve = |connection| FuelValveProperties {
+                connection,
+                powered_by: ElectricalBusType::AlternatingCurrent(1),
+                travel_time_s: 2.,
+                max_flow_kg_per_s: 1.,
+            };
+
+            Self {
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                ac_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                is_powered: true,
+
+                fuel_system: FuelSystem::new(context, tanks, []),
+                network: FuelNetwork::new(
+                    2,
+                    vec![
+                        ElectricFuelPump::new(
+                            context,
+                            "LEFT",
+                            FailureType::FuelPump(LEFT_PUMP),
+                            pump(LEFT_TANK, 0, 30.),
+                        ),
+                        ElectricFuelPump::new(
+                            context,
+                            "CENTER_LEFT",
+                            FailureType::FuelPump(CENTER_LEFT_PUMP),
+                            pump(CENTER_TANK, 0, 40.),
+                        ),
+                        ElectricFuelPump::new(
+                            context,
+                            "CENTER_RIGHT",
+                            FailureType::FuelPump(CENTER_RIGHT_PUMP),
+                            pump(CENTER_TANK, 1, 40.),
+                        ),
+                        ElectricFuelPump::new(
+                            context,
+                            "RIGHT",
+                            FailureType::FuelPump(RIGHT_PUMP),
+                            pump(RIGHT_TANK, 1, 30.),
+                        ),
+                    ],
+                    vec![
+                        FuelValve::new(
+                            context,
+                            "CROSSFEED",
+                            FailureType::FuelValveJammed(CROSSFEED_VALVE),
+                            valve(FuelValveConnection::FeedLines(0, 1)),
+                        ),
+                        FuelValve::new(
+                            context,
+                            "OUTER_TRANSFER",
+                            FailureType::FuelValveJammed(OUTER_TRANSFER_VALVE),
+                            valve(FuelValveConnection::Gravity {
+                                from_tank: LEFT_OUTER_TANK,
+                                to_tank: LEFT_TANK,
+                            }),
+                        ),
+                        FuelValve::new(
+                            context,
+                            "CENTER_TRANSFER",
+                            FailureType::FuelValveJammed(CENTER_TRANSFER_VALVE),
+                            valve(FuelValveConnection::FeedLineToTank {
+                                feed_line: 1,
+                                tank: CENTER_TANK,
+                            }),
+                        ),
+                    ],
+                    vec![
+                        FuelConsumer {
+                            feed_line: 0,
+                            suction_tank: Some(LEFT_TANK),
+                        },
+                        FuelConsumer {
+                            feed_line: 1,
+                            suction_tank: Some(RIGHT_TANK),
+                        },
+                    ],
+                ),
+                controller: TestFuelController {
+                    pumps_running: [true, false, false, true],
+                    valves_open: [false; 3],
+                },
+                fuel_demands: [MassRate::new::<kilogram_per_second>(ENGINE_FUEL_FLOW_KG_PER_S); 2],
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.powered_source
+                .power_with_potential(ElectricPotential::new::<volt>(115.));
+            electricity.supplied_by(&self.powered_source);
+
+            if self.is_powered {
+                electricity.flow(&self.powered_source, &self.ac_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.fuel_system.update_network(
+                context,
+                &mut self.network,
+                &self.controller,
+                &self.fuel_demands,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.fuel_system.accept(visitor);
+            self.network.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct FuelNetworkTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl FuelNetworkTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            for tank in ["LEFT", "CENTER", "RIGHT", "LEFT_OUTER"] {
+                test_bed.set_tank_quantity(tank, 2000.);
+            }
+
+            test_bed
+        }
+
+        fn set_tank_quantity(&mut self, tank: &str, kg: f64) {
+            self.write_by_name(&format!("FUEL_TANK_{tank}"), kg / FUEL_GALLONS_TO_KG);
+        }
+
+        fn tank_quantity(&mut self, tank: usize) -> f64 {
+            self.query(|a| a.fuel_system.tank_mass(tank).get::<kilogram>())
+        }
+
+        fn set_pump_running(&mut self, pump: usize, running: bool) {
+            self.command(|a| a.controller.pumps_running[pump] = running);
+        }
+
+        fn set_valve_open(&mut self, valve: usize, open: bool) {
+            self.command(|a| a.controller.valves_open[valve] = open);
+        }
+
+        fn unpower(&mut self) {
+            self.command(|a| a.is_powered = false);
+        }
+
+        fn run_for(&mut self, duration: Duration) {
+            self.test_bed.run_multiple_frames(duration);
+        }
+
+        fn consumer_fuel_flow(&self, consumer: usize) -> f64 {
+            self.query(|a| {
+                a.network
+                    .consumer_fuel_flow(consumer)
+                    .get::<kilogram_per_second>()
+            })
+        }
+
+        fn consumer_is_starved(&self, consumer: usize) -> bool {
+            self.query(|a| a.network.consumer_is_starved(consumer))
+        }
+    }
+    impl TestBed for FuelNetworkTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    #[test]
+    fn engines_burn_fuel_of_their_own_feed_tanks() {
+        let mut test_bed = FuelNetworkTestBed::new();
+        test_bed.run_for(Duration::from_secs(100));
+
+        assert!(test_bed.query(|a| a.network.pump(RIGHT_PUMP).is_running()));
+        assert!((test_bed.tank_quantity(LEFT_TANK) - 1950.).abs() < 1.);
+        assert!((test_bed.tank_quantity(RIGHT_TANK) - 1950.).abs() < 1.);
+        assert!((test_bed.tank_quantity(CENTER_TANK) - 2000.).abs() < 0.01);
+        assert!((test_bed.consumer_fuel_flow(0) - ENGINE_FUEL_FLOW_KG_PER_S).abs() < 0.001);
+        assert!(!test_bed.consumer_is_starved(0));
+    }
+
+    #[test]
+    fn pumps_of_higher_pressure_deliver_first() {
+        let mut test_bed = FuelNetworkTestBed::new();
+        test_bed.set_pump_running(CENTER_LEFT_PUMP, true);
+        test_bed.set_pump_running(CENTER_RIGHT_PUMP, true);
+        test_bed.run_for(Duration::from_secs(100));
+
+        assert!((test_bed.tank_quantity(CENTER_TANK) - 1900.).abs() < 2.);
+        assert!(test_bed.tank_quantity(LEFT_TANK) > 1999.);
+        assert!(test_bed.tank_quantity(RIGHT_TANK) > 1999.);
+    }
+
+    #[test]
+    fn pumps_write_pressure() {
+        let mut test_bed = FuelNetworkTestBed::new();
+        test_bed.run_for(Duration::from_secs(5));
+
+        let pressure: Pressure = test_bed.read_by_name("FUEL_PUMP_LEFT_PRESSURE");
+        let low_pressure: bool = test_bed.read_by_name("FUEL_PUMP_LEFT_LOW_PRESSURE");
+        assert!((pressure.get::<psi>() - 30.).abs() < 0.1);
+        assert!(!low_pressure);
+
+        let low_pressure: bool = test_bed.read_by_name("FUEL_PUMP_CENTER_LEFT_LOW_PRESSURE");
+        assert!(low_pressure);
+    }
+
+    #[test]
+    fn unpowered_pumps_do_not_pressurise_and_engines_suction_feed() {
+        let mut test_bed = FuelNetworkTestBed::new();
+        test_bed.unpower();
+        test_bed.run_for(Duration::from_secs(100));
+
+        assert!(!test_bed.query(|a| a.network.pump(LEFT_PUMP).is_pressurised()));
+        assert!(test_bed.query(|a| a.network.feed_line_pressure(0)) < Pressure::new::<psi>(1.));
+        assert!((test_bed.tank_quantity(LEFT_TANK) - 1950.).abs() < 1.);
+        assert!(!test_bed.consumer_is_starved(0));
+    }
+
+    #[test]
+    fn engine_starves_when_its_suction_tank_is_empty_without_pump_pressure() {
+        let mut test_bed = FuelNetworkTestBed::new();
+        test_bed.set_tank_quantity("LEFT", 0.);
+        test_bed.run_for(Duration::from_secs(10));
+
+        assert!(test_bed.consumer_is_starved(0));
+        assert!(test_bed.consumer_fuel_flow(0) < 0.001);
+        assert!(!test_bed.consumer_is_starved(1));
+    }
+
+    #[test]
+    fn crossfeed_feeds_both_engines_from_one_side() {
+        let mut test_bed = FuelNetworkTestBed::new();
+        test_bed.set_pump_running(LEFT_PUMP, false);
+        test_bed.set_valve_open(CROSSFEED_VALVE, true);
+        test_bed.run_for(Duration::from_secs(100));
+
+        assert!(test_bed.query(|a| a.network.valve(CROSSFEED_VALVE).is_fully_open()));
+        assert!(test_bed.tank_quantity(LEFT_TANK) > 1999.);
+        assert!((test_bed.tank_quantity(RIGHT_TANK) - 1900.).abs() < 2.);
+        assert!(!test_bed.consumer_is_starved(0));
+    }
+
+    #[test]
+    fn gravity_transfer_moves_fuel_until_source_tank_is_empty() {
+        let mut test_bed = FuelNetworkTestBed::new();
+        test_bed.set_tank_quantity("LEFT_OUTER", 100.);
+        test_bed.set_valve_open(OUTER_TRANSFER_VALVE, true);
+        test_bed.run_for(Duration::from_secs(2000));
+
+        assert!(test_bed.tank_quantity(LEFT_OUTER_TANK) < 1.);
+        // 100 kg transferred, 1000 kg burned
+        assert!((test_bed.tank_quantity(LEFT_TANK) - 1100.).abs() < 2.);
+    }
+
+    #[test]
+    fn transfer_valve_fills_tank_from_pressurised_feed_line_until_full() {
+        let mut test_bed = FuelNetworkTestBed::new();
+        test_bed.set_tank_quantity("CENTER", TANK_CAPACITY_KG - 50.);
+        test_bed.set_valve_open(CENTER_TRANSFER_VALVE, true);
+        test_bed.run_for(Duration::from_secs(100));
+
+        assert!((test_bed.tank_quantity(CENTER_TANK) - TANK_CAPACITY_KG).abs() < 0.1);
+        // 50 kg transferred, 50 kg burned
+        assert!((test_bed.tank_quantity(RIGHT_TANK) - 1900.).abs() < 2.);
+    }
+
+    #[test]
+    fn valves_do_not_move_without_power() {
+        let mut test_bed = FuelNetworkTestBed::new();
+        test_bed.unpower();
+        test_bed.set_valve_open(CROSSFEED_VALVE, true);
+        test_bed.run_for(Duration::from_secs(10));
+
+        assert!(!test_bed.query(|a| a.network.valve(CROSSFEED_VALVE).is_open()));
+    }
+
+    #[test]
+    fn failed_pump_does_not_run() {
+        let mut test_bed = FuelNetworkTestBed::new();
+        test_bed.fail(FailureType::FuelPump(LEFT_PUMP));
+        test_bed.run_for(Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.network.pump(LEFT_PUMP).is_running()));
+        assert!(!test_bed.query(|a| a.network.pump(LEFT_PUMP).is_pressurised()));
+    }
+
+    #[test]
+    fn jammed_valve_does_not_move() {
+        let mut test_bed = FuelNetworkTestBed::new();
+        test_bed.fail(FailureType::FuelValveJammed(CROSSFEED_VALVE));
+        test_bed.set_valve_open(CROSSFEED_VALVE, true);
+        test_bed.run_for(Duration::from_secs(10));
+
+        assert!(!test_bed.query(|a| a.network.valve(CROSSFEED_VALVE).is_open()));
+    }
+
+    #[test]
+    fn unlimited_fuel_does_not_burn_fuel() {
+        let mut test_bed = FuelNetworkTestBed::new();
+        test_bed.write_by_name("UNLIMITED FUEL", true);
+        test_bed.run_for(Duration::from_secs(100));
+
+        assert!((test_bed.tank_quantity(LEFT_TANK) - 2000.).abs() < 0.01);
+        assert!((test_bed.consumer_fuel_flow(0) - ENGINE_FUEL_FLOW_KG_PER_S).abs() < 0.001);
+    }
+}
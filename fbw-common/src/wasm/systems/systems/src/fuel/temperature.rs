@@ -0,0 +1,339 @@
+//! Fuel temperature of the tanks of a [`super::FuelSystem`].
+//!
+//! The fuel of a tank exchanges heat through the skin of the tank with the boundary layer of the
+//! airflow around the aircraft. The boundary layer is warmer than the ambient air by the ram rise,
+//! and the heat exchange increases with the airspeed. Small and nearly empty tanks thus cold-soak
+//! quicker than large and full ones. Fuel entering a tank mixes with the fuel already in it.
+
+use crate::simulation::{
+    Read, Reader, SimulationElement, StateReader, StateWriter, UpdateContext, Write, Writer,
+};
+use uom::si::{
+    f64::*,
+    mass::kilogram,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+    velocity::meter_per_second,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FuelType {
+    JetA1,
+    JetA,
+    Jp8,
+    JetB,
+}
+try_read_write_enum!(FuelType);
+impl TryFrom<f64> for FuelType {
+    type Error = u8;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        match value as u8 {
+            0 => Ok(FuelType::JetA1),
+            1 => Ok(FuelType::JetA),
+            2 => Ok(FuelType::Jp8),
+            3 => Ok(FuelType::JetB),
+            i => Err(i),
+        }
+    }
+}
+impl FuelType {
+    pub fn freezing_point(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(match self {
+            FuelType::JetA1 => -47.,
+            FuelType::JetA => -40.,
+            FuelType::Jp8 => -47.,
+            FuelType::JetB => -50.,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct FuelTemperature {
+    temperature: Option<ThermodynamicTemperature>,
+    previous_quantity: Mass,
+    exposed_skin_area_square_meter: f64,
+}
+impl FuelTemperature {
+    const FUEL_DENSITY_KG_PER_CUBIC_METER: f64 = 800.;
+    const FUEL_SPECIFIC_HEAT_J_PER_KG_KELVIN: f64 = 2000.;
+    const AIR_SPECIFIC_HEAT_J_PER_KG_KELVIN: f64 = 1005.;
+    const BOUNDARY_LAYER_RECOVERY_FACTOR: f64 = 0.9;
+
+    // Ratio of the skin area of a tank to its volume to the power of 2/3. Wing tanks are flat,
+    // thus the ratio is well above the one of a cube.
+    const TANK_SHAPE_FACTOR: f64 = 8.;
+
+    const STILL_AIR_HEAT_TRANSFER_COEFFICIENT_W_PER_SQUARE_METER_KELVIN: f64 = 10.;
+    const HEAT_TRANSFER_COEFFICIENT_PER_AIRSPEED: f64 = 0.3;
+
+    const MINIMUM_QUANTITY_KG: f64 = 1.;
+
+    pub(super) fn new(capacity: Mass) -> Self {
+        let capacity_cubic_meter =
+            capacity.get::<kilogram>() / Self::FUEL_DENSITY_KG_PER_CUBIC_METER;
+        Self {
+            temperature: None,
+            previous_quantity: Mass::default(),
+            exposed_skin_area_square_meter: Self::TANK_SHAPE_FACTOR
+                * capacity_cubic_meter.powf(2. / 3.),
+        }
+    }
+
+    pub(super) fn update(
+        &mut self,
+        context: &UpdateContext,
+        quantity: Mass,
+        capacity: Mass,
+        inflow_temperature: ThermodynamicTemperature,
+    ) {
+        let boundary_layer_temperature = Self::boundary_layer_temperature(context);
+        let temperature_kelvin = match self.temperature {
+            Some(temperature) => Self::mixed_temperature(
+                temperature,
+                self.previous_quantity,
+                quantity,
+                inflow_temperature,
+            ),
+            None => context.ambient_temperature().get::<kelvin>(),
+        };
+
+        let quantity_kg = quantity.get::<kilogram>().max(Self::MINIMUM_QUANTITY_KG);
+        let fill_ratio = (quantity_kg / capacity.get::<kilogram>()).clamp(0., 1.);
+        // The bottom skin is wetted first, thus the wetted area grows less than the quantity.
+        let wetted_area_square_meter = self.exposed_skin_area_square_meter * fill_ratio.sqrt();
+        let heat_transfer_coefficient =
+            Self::STILL_AIR_HEAT_TRANSFER_COEFFICIENT_W_PER_SQUARE_METER_KELVIN
+                + Self::HEAT_TRANSFER_COEFFICIENT_PER_AIRSPEED
+                    * context.true_airspeed().get::<meter_per_second>().max(0.);
+
+        let time_constant_s = quantity_kg * Self::FUEL_SPECIFIC_HEAT_J_PER_KG_KELVIN
+            / (heat_transfer_coefficient * wetted_area_square_meter);
+        let alpha = 1. - (-context.delta_as_secs_f64() / time_constant_s).exp();
+
+        self.temperature = Some(ThermodynamicTemperature::new::<kelvin>(
+            temperature_kelvin + (boundary_layer_temperature - temperature_kelvin) * alpha,
+        ));
+        self.previous_quantity = quantity;
+    }
+
+    pub(super) fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+            .unwrap_or_else(|| ThermodynamicTemperature::new::<degree_celsius>(15.))
+    }
+
+    fn mixed_temperature(
+        temperature: ThermodynamicTemperature,
+        previous_quantity: Mass,
+        quantity: Mass,
+        inflow_temperature: ThermodynamicTemperature,
+    ) -> f64 {
+        let previous_quantity_kg = previous_quantity.get::<kilogram>();
+        let inflow_kg = quantity.get::<kilogram>() - previous_quantity_kg;
+        if inflow_kg > 0. {
+            (previous_quantity_kg * temperature.get::<kelvin>()
+                + inflow_kg * inflow_temperature.get::<kelvin>())
+                / (previous_quantity_kg + inflow_kg)
+        } else {
+            temperature.get::<kelvin>()
+        }
+    }
+
+    fn boundary_layer_temperature(context: &UpdateContext) -> f64 {
+        let true_airspeed = context.true_airspeed().get::<meter_per_second>();
+        context.ambient_temperature().get::<kelvin>()
+            + Self::BOUNDARY_LAYER_RECOVERY_FACTOR * true_airspeed.powi(2)
+                / (2. * Self::AIR_SPECIFIC_HEAT_J_PER_KG_KELVIN)
+    }
+}
+impl SimulationElement for FuelTemperature {
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write_option(self.temperature);
+        state.write(self.previous_quantity);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.temperature = state.read_option();
+        self.previous_quantity = state.read();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fuel::{FuelSystem, FuelTank, FUEL_GALLONS_TO_KG},
+        simulation::{
+            test::{SimulationTestBed, TestBed, WriteByName},
+            Aircraft, InitContext, SimulationElement, SimulationElementVisitor,
+        },
+    };
+    use nalgebra::Vector3;
+    use std::time::Duration;
+
+    const SMALL_TANK: usize = 0;
+    const LARGE_TANK: usize = 1;
+
+    struct TestAircraft {
+        fuel_system: FuelSystem<2, 0>,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            let tanks = [("SMALL", 1000.), ("LARGE", 20000.)].map(|(id, capacity)| {
+                FuelTank::new(
+                    context,
+                    &format!("FUEL_TANK_{id}"),
+                    Vector3::zeros(),
+                    Mass::new::<kilogram>(capacity),
+                    true,
+                )
+            });
+
+            Self {
+                fuel_system: FuelSystem::new(context, tanks, []),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.fuel_system.update_temperatures(context);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.fuel_system.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct FuelTemperatureTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl FuelTemperatureTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_tank_quantity("SMALL", 900.);
+            test_bed.set_tank_quantity("LARGE", 18000.);
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(20.));
+            test_bed.run_with_delta(Duration::from_secs(1));
+
+            test_bed
+        }
+
+        fn cruise(&mut self) {
+            self.set_on_ground(false);
+            self.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-56.5));
+            self.set_true_airspeed(Velocity::new::<meter_per_second>(250.));
+        }
+
+        fn set_tank_quantity(&mut self, tank: &str, kg: f64) {
+            self.write_by_name(&format!("FUEL_TANK_{tank}"), kg / FUEL_GALLONS_TO_KG);
+        }
+
+        fn set_fuel_type(&mut self, fuel_type: FuelType) {
+            self.write_by_name("EFB_FUEL_TYPE", fuel_type);
+        }
+
+        fn tank_temperature(&self, tank: usize) -> f64 {
+            self.query(|a| a.fuel_system.tank_temperature(tank).get::<degree_celsius>())
+        }
+
+        fn tank_temperature_is_low(&self, tank: usize) -> bool {
+            self.query(|a| a.fuel_system.tank_temperature_is_low(tank))
+        }
+    }
+    impl TestBed for FuelTemperatureTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    #[test]
+    fn fuel_starts_at_ambient_temperature() {
+        let test_bed = FuelTemperatureTestBed::new();
+
+        assert!((test_bed.tank_temperature(SMALL_TANK) - 20.).abs() < 0.1);
+        assert!((test_bed.tank_temperature(LARGE_TANK) - 20.).abs() < 0.1);
+    }
+
+    #[test]
+    fn fuel_cold_soaks_towards_boundary_layer_temperature_in_cruise() {
+        let mut test_bed = FuelTemperatureTestBed::new();
+        test_bed.cruise();
+        test_bed.run_with_delta(Duration::from_secs(30 * 3600));
+
+        // The ram rise at 250 m/s keeps the boundary layer about 28 degrees above the ambient air.
+        assert!((test_bed.tank_temperature(SMALL_TANK) + 28.5).abs() < 0.5);
+        assert!((test_bed.tank_temperature(LARGE_TANK) + 28.5).abs() < 0.5);
+    }
+
+    #[test]
+    fn small_tank_cold_soaks_quicker_than_large_tank() {
+        let mut test_bed = FuelTemperatureTestBed::new();
+        test_bed.cruise();
+        test_bed.run_with_delta(Duration::from_secs(3600));
+
+        assert!(test_bed.tank_temperature(SMALL_TANK) < test_bed.tank_temperature(LARGE_TANK));
+        assert!(test_bed.tank_temperature(LARGE_TANK) < 20.);
+    }
+
+    #[test]
+    fn refuelled_fuel_mixes_at_ambient_temperature() {
+        let mut test_bed = FuelTemperatureTestBed::new();
+        test_bed.set_on_ground(true);
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(0.));
+        test_bed.set_tank_quantity("LARGE", 18000.);
+        test_bed.run_with_delta(Duration::from_millis(10));
+        let temperature = test_bed.tank_temperature(LARGE_TANK);
+
+        test_bed.set_tank_quantity("LARGE", 20000.);
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        let expected = (18000. * temperature) / 20000.;
+        assert!((test_bed.tank_temperature(LARGE_TANK) - expected).abs() < 0.1);
+    }
+
+    #[test]
+    fn restores_tank_temperatures_from_snapshot() {
+        let mut test_bed = FuelTemperatureTestBed::new();
+        test_bed.cruise();
+        test_bed.run_with_delta(Duration::from_secs(3600));
+        let snapshot = test_bed.snapshot();
+
+        let mut other_test_bed = FuelTemperatureTestBed::new();
+        assert_eq!(other_test_bed.restore(&snapshot), Ok(()));
+
+        assert_eq!(
+            other_test_bed.tank_temperature(SMALL_TANK),
+            test_bed.tank_temperature(SMALL_TANK)
+        );
+        assert_eq!(
+            other_test_bed.tank_temperature(LARGE_TANK),
+            test_bed.tank_temperature(LARGE_TANK)
+        );
+        assert!(other_test_bed.tank_temperature(LARGE_TANK) < 20.);
+    }
+
+    #[test]
+    fn low_temperature_depends_on_fuel_type() {
+        let mut test_bed = FuelTemperatureTestBed::new();
+        test_bed.set_on_ground(false);
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-42.));
+        test_bed.run_with_delta(Duration::from_secs(100 * 3600));
+
+        assert!(!test_bed.tank_temperature_is_low(SMALL_TANK));
+
+        test_bed.set_fuel_type(FuelType::JetA);
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert!(test_bed.tank_temperature_is_low(SMALL_TANK));
+    }
+}
@@ -0,0 +1,656 @@
+use crate::simulation::{
+    InitContext, SimulationElement, SimulatorWriter, StateReader, StateWriter, UpdateContext,
+    VariableIdentifier, Write,
+};
+
+use std::time::Duration;
+use uom::si::{
+    angular_velocity::radian_per_second,
+    f64::*,
+    length::meter,
+    mass::kilogram,
+    moment_of_inertia::kilogram_square_meter,
+    pressure::psi,
+    ratio::ratio,
+    torque::newton_meter,
+    velocity::{knot, meter_per_second},
+};
+
+pub struct WheelProperties {
+    radius: Length,
+    moment_of_inertia: MomentOfInertia,
+    max_brake_torque: Torque,
+    max_brake_pressure: Pressure,
+    weight_share: Ratio,
+}
+impl WheelProperties {
+    /// Creates the properties of a group of braked wheels sharing the same anti skid channel
+    /// ## Parameters
+    /// `moment_of_inertia` - rotating inertia of all the wheels of the group, brakes included
+    /// `max_brake_torque` - total brake torque of the group at `max_brake_pressure`
+    /// `weight_share` - ratio of the aircraft weight carried by the group when on ground
+    pub fn new(
+        radius: Length,
+        moment_of_inertia: MomentOfInertia,
+        max_brake_torque: Torque,
+        max_brake_pressure: Pressure,
+        weight_share: Ratio,
+    ) -> Self {
+        Self {
+            radius,
+            moment_of_inertia,
+            max_brake_torque,
+            max_brake_pressure,
+            weight_share,
+        }
+    }
+
+    fn brake_torque(&self, brake_pressure: Pressure) -> Torque {
+        self.max_brake_torque
+            * (brake_pressure.get::<psi>() / self.max_brake_pressure.get::<psi>()).clamp(0., 1.)
+    }
+}
+
+/// Rotational dynamics of a group of braked wheels.
+///
+/// The wheels are spun up by the tyre to runway friction and slowed down by the brakes. The
+/// friction depends on the slip ratio between the wheel rolling speed and the aircraft speed, with
+/// a peak at moderate slip and a lower friction once the wheels are locked.
+pub struct BrakedWheel {
+    slip_ratio_id: VariableIdentifier,
+
+    angular_velocity: AngularVelocity,
+    slip_ratio: Ratio,
+    friction_coefficient: Ratio,
+    maximum_friction_coefficient: Ratio,
+}
+impl BrakedWheel {
+    // Pacejka like friction curve: mu = mu_max * sin(C * atan(B * slip))
+    const FRICTION_CURVE_STIFFNESS: f64 = 10.;
+    const FRICTION_CURVE_SHAPE: f64 = 1.65;
+
+    // Below this speed slip is computed against this speed to avoid dividing by zero
+    const MIN_SPEED_FOR_SLIP_MPS: f64 = 1.;
+
+    const GRAVITY_MPS2: f64 = 9.81;
+
+    // Bearing and brake residual drag slowing down a free spinning wheel in the air
+    const RESIDUAL_DRAG_TORQUE_NM: f64 = 50.;
+
+    pub fn new(context: &mut InitContext, name: &str) -> Self {
+        Self {
+            slip_ratio_id: context.get_identifier(format!("WHEEL_{}_SLIP_RATIO", name)),
+
+            angular_velocity: AngularVelocity::default(),
+            slip_ratio: Ratio::default(),
+            friction_coefficient: Ratio::default(),
+            maximum_friction_coefficient: Ratio::default(),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        properties: &WheelProperties,
+        brake_pressure: Pressure,
+        is_compressed: bool,
+    ) {
+        self.maximum_friction_coefficient = context.runway_condition().friction_coefficient();
+
+        let radius = properties.radius.get::<meter>();
+        let inertia = properties.moment_of_inertia.get::<kilogram_square_meter>();
+        let speed = context.ground_speed().get::<meter_per_second>().abs();
+        let slip_speed = speed.max(Self::MIN_SPEED_FOR_SLIP_MPS);
+
+        let normal_force = if is_compressed {
+            context.total_weight().get::<kilogram>()
+                * properties.weight_share.get::<ratio>()
+                * Self::GRAVITY_MPS2
+        } else {
+            0.
+        };
+        let friction_torque_gain =
+            normal_force * radius * self.maximum_friction_coefficient.get::<ratio>();
+
+        let brake_torque = properties
+            .brake_torque(brake_pressure)
+            .get::<newton_meter>()
+            + Self::RESIDUAL_DRAG_TORQUE_NM;
+
+        let omega = self.angular_velocity.get::<radian_per_second>();
+        let slip = Self::slip(speed, slip_speed, omega, radius);
+
+        // Friction torque derivative against wheel speed is negative on the stable side of the
+        // friction curve, which makes the wheel dynamics stiff at high loads. Solving it with a
+        // linearized implicit step keeps it stable at any frame rate.
+        let friction_torque = friction_torque_gain * Self::friction_shape(slip);
+        let friction_torque_derivative =
+            -friction_torque_gain * Self::friction_shape_derivative(slip) * radius / slip_speed;
+
+        let dt = context.delta_as_secs_f64();
+        let acceleration = (friction_torque - brake_torque) / inertia;
+        let acceleration_derivative = (friction_torque_derivative / inertia).min(0.);
+
+        let max_omega = if is_compressed {
+            speed / radius
+        } else {
+            f64::MAX
+        };
+        let new_omega = (omega + dt * acceleration / (1. - dt * acceleration_derivative))
+            .clamp(0., max_omega.max(0.));
+
+        self.angular_velocity = AngularVelocity::new::<radian_per_second>(new_omega);
+
+        let new_slip = if is_compressed {
+            Self::slip(speed, slip_speed, new_omega, radius)
+        } else {
+            0.
+        };
+        self.slip_ratio = Ratio::new::<ratio>(new_slip);
+        self.friction_coefficient = self.maximum_friction_coefficient
+            * if is_compressed {
+                Self::friction_shape(new_slip)
+            } else {
+                0.
+            };
+    }
+
+    fn slip(speed: f64, slip_speed: f64, omega: f64, radius: f64) -> f64 {
+        ((speed - omega * radius) / slip_speed).clamp(0., 1.)
+    }
+
+    fn friction_shape(slip: f64) -> f64 {
+        (Self::FRICTION_CURVE_SHAPE * (Self::FRICTION_CURVE_STIFFNESS * slip).atan()).sin()
+    }
+
+    fn friction_shape_derivative(slip: f64) -> f64 {
+        let stiff_slip = Self::FRICTION_CURVE_STIFFNESS * slip;
+
+        (Self::FRICTION_CURVE_SHAPE * stiff_slip.atan()).cos()
+            * Self::FRICTION_CURVE_SHAPE
+            * Self::FRICTION_CURVE_STIFFNESS
+            / (1. + stiff_slip.powi(2))
+    }
+
+    /// Slip ratio at which the tyre gets the most friction from the runway
+    pub fn optimal_slip_ratio() -> Ratio {
+        Ratio::new::<ratio>(
+            (std::f64::consts::FRAC_PI_2 / Self::FRICTION_CURVE_SHAPE).tan()
+                / Self::FRICTION_CURVE_STIFFNESS,
+        )
+    }
+
+    pub fn angular_velocity(&self) -> AngularVelocity {
+        self.angular_velocity
+    }
+
+    pub fn rolling_speed(&self, properties: &WheelProperties) -> Velocity {
+        Velocity::new::<meter_per_second>(
+            self.angular_velocity.get::<radian_per_second>() * properties.radius.get::<meter>(),
+        )
+    }
+
+    pub fn slip_ratio(&self) -> Ratio {
+        self.slip_ratio
+    }
+
+    pub fn friction_coefficient(&self) -> Ratio {
+        self.friction_coefficient
+    }
+
+    /// Ratio of the friction the tyres currently get against the best one they could get
+    /// on this surface. Skidding tyres lose a part of their braking capacity.
+    pub fn braking_efficiency(&self) -> Ratio {
+        if self.slip_ratio <= Self::optimal_slip_ratio() {
+            Ratio::new::<ratio>(1.)
+        } else {
+            Ratio::new::<ratio>(Self::friction_shape(self.slip_ratio.get::<ratio>()))
+        }
+    }
+}
+impl SimulationElement for BrakedWheel {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.slip_ratio_id, self.slip_ratio.get::<ratio>());
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.angular_velocity);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.angular_velocity = state.read();
+    }
+}
+
+/// Anti skid channel controlling a pair of braked wheels.
+///
+/// The channel compares the wheel speed to a reference speed of the aircraft and reduces the
+/// brake pressure demand when the wheels start to skid.
+/// - Touchdown protection: brakes are released in flight and after touchdown until the wheels
+///   have spun up, or at most for a few seconds after touchdown.
+/// - Locked wheel protection: brakes are released when the wheel speed falls far below the
+///   reference speed.
+/// - Slip regulation: brake pressure is reduced quickly when slip exceeds the optimal slip ratio
+///   and recovers slowly once the wheel spins up again.
+pub struct AntiSkidController {
+    pressure_ratio: Ratio,
+    is_active: bool,
+    touchdown_protection_active: bool,
+    time_since_touchdown: Duration,
+    was_compressed: bool,
+}
+impl AntiSkidController {
+    const MIN_REFERENCE_SPEED_KNOT: f64 = 20.;
+
+    const TOUCHDOWN_PROTECTION_MAX_DURATION: Duration = Duration::from_secs(5);
+    const WHEEL_SPIN_UP_SPEED_RATIO: f64 = 0.7;
+
+    const LOCKED_WHEEL_SPEED_RATIO: f64 = 0.5;
+
+    // Target slip is slightly under the peak friction to keep margin on the stable side
+    const TARGET_SLIP_MARGIN: f64 = 0.02;
+
+    const PRESSURE_RELEASE_RATE_PER_S: f64 = 5.;
+    const PRESSURE_RECOVERY_RATE_PER_S: f64 = 1.;
+
+    pub fn new() -> Self {
+        Self {
+            pressure_ratio: Ratio::new::<ratio>(1.),
+            is_active: false,
+            touchdown_protection_active: false,
+            time_since_touchdown: Duration::default(),
+            was_compressed: false,
+        }
+    }
+
+    /// ## Parameters
+    /// `reference_speed` - aircraft speed against the ground the wheel speed is compared to
+    /// `wheel_speed` - rolling speed of the controlled wheels
+    /// `is_compressed` - weight is on the controlled wheels
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        reference_speed: Velocity,
+        wheel_speed: Velocity,
+        is_compressed: bool,
+    ) {
+        if is_compressed && !self.was_compressed {
+            self.time_since_touchdown = Duration::default();
+        } else if is_compressed {
+            self.time_since_touchdown += context.delta();
+        }
+        self.was_compressed = is_compressed;
+
+        self.is_active = reference_speed.get::<knot>() > Self::MIN_REFERENCE_SPEED_KNOT;
+        if !self.is_active {
+            self.touchdown_protection_active = false;
+            self.pressure_ratio = Ratio::new::<ratio>(1.);
+            return;
+        }
+
+        let speed_ratio = (wheel_speed / reference_speed).get::<ratio>();
+
+        self.touchdown_protection_active = !is_compressed
+            || (self.touchdown_protection_active
+                && speed_ratio < Self::WHEEL_SPIN_UP_SPEED_RATIO
+                && self.time_since_touchdown < Self::TOUCHDOWN_PROTECTION_MAX_DURATION);
+
+        let slip = 1. - speed_ratio;
+        let target_slip =
+            BrakedWheel::optimal_slip_ratio().get::<ratio>() - Self::TARGET_SLIP_MARGIN;
+
+        let dt = context.delta_as_secs_f64();
+        self.pressure_ratio =
+            if self.touchdown_protection_active || speed_ratio < Self::LOCKED_WHEEL_SPEED_RATIO {
+                Ratio::new::<ratio>(0.)
+            } else if slip > target_slip {
+                Ratio::new::<ratio>(
+                    (self.pressure_ratio.get::<ratio>() - Self::PRESSURE_RELEASE_RATE_PER_S * dt)
+                        .max(0.),
+                )
+            } else {
+                Ratio::new::<ratio>(
+                    (self.pressure_ratio.get::<ratio>() + Self::PRESSURE_RECOVERY_RATE_PER_S * dt)
+                        .min(1.),
+                )
+            };
+    }
+
+    /// Ratio of the pilot or autobrake demand that can be sent to the brakes
+    pub fn pressure_ratio(&self) -> Ratio {
+        self.pressure_ratio
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    pub fn touchdown_protection_active(&self) -> bool {
+        self.touchdown_protection_active
+    }
+}
+impl SimulationElement for AntiSkidController {
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.pressure_ratio);
+        state.write(self.is_active);
+        state.write(self.touchdown_protection_active);
+        state.write(self.time_since_touchdown);
+        state.write(self.was_compressed);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.pressure_ratio = state.read();
+        self.is_active = state.read();
+        self.touchdown_protection_active = state.read();
+        self.time_since_touchdown = state.read();
+        self.was_compressed = state.read();
+    }
+}
+impl Default for AntiSkidController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft, SimulationElementVisitor, SurfaceTypeMsfs,
+    };
+    use uom::si::mass::kilogram;
+
+    struct TestAircraft {
+        wheel: BrakedWheel,
+        properties: WheelProperties,
+        anti_skid: AntiSkidController,
+
+        demand: Ratio,
+        anti_skid_enabled: bool,
+        is_compressed: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                wheel: BrakedWheel::new(context, "TEST"),
+                properties: WheelProperties::new(
+                    Length::new::<meter>(0.58),
+                    MomentOfInertia::new::<kilogram_square_meter>(35.),
+                    Torque::new::<newton_meter>(90000.),
+                    Pressure::new::<psi>(3000.),
+                    Ratio::new::<ratio>(0.46),
+                ),
+                anti_skid: AntiSkidController::new(),
+                demand: Ratio::default(),
+                anti_skid_enabled: true,
+                is_compressed: true,
+            }
+        }
+
+        fn spin_up(&mut self, speed: Velocity) {
+            self.wheel.angular_velocity = AngularVelocity::new::<radian_per_second>(
+                speed.get::<meter_per_second>() / self.properties.radius.get::<meter>(),
+            );
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            let pressure_ratio = if self.anti_skid_enabled {
+                self.anti_skid.pressure_ratio()
+            } else {
+                Ratio::new::<ratio>(1.)
+            };
+
+            self.wheel.update(
+                context,
+                &self.properties,
+                Pressure::new::<psi>(3000.) * (self.demand * pressure_ratio).get::<ratio>(),
+                self.is_compressed,
+            );
+
+            self.anti_skid.update(
+                context,
+                context.ground_speed(),
+                self.wheel.rolling_speed(&self.properties),
+                self.is_compressed,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.wheel.accept(visitor);
+            self.anti_skid.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct AntiSkidTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl AntiSkidTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed
+                .test_bed
+                .write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(60000.));
+            test_bed.test_bed.set_on_ground(true);
+
+            test_bed
+        }
+
+        fn and(self) -> Self {
+            self
+        }
+
+        fn rolling_at(mut self, speed: Velocity) -> Self {
+            self.test_bed.write_by_name("GPS GROUND SPEED", speed);
+            self.test_bed.run_without_delta();
+            self.test_bed.command(|a| a.spin_up(speed));
+            self
+        }
+
+        fn on_surface(mut self, surface: SurfaceTypeMsfs) -> Self {
+            self.test_bed
+                .write_by_name("SURFACE TYPE", surface as u32 as f64);
+            self
+        }
+
+        fn in_heavy_rain(mut self) -> Self {
+            self.test_bed.write_by_name("AMBIENT PRECIP RATE", 5.);
+            self
+        }
+
+        fn in_flight_at(mut self, speed: Velocity) -> Self {
+            self.test_bed.command(|a| a.is_compressed = false);
+            self.test_bed.set_on_ground(false);
+            self.test_bed.write_by_name("GPS GROUND SPEED", speed);
+            self
+        }
+
+        fn touching_down(mut self) -> Self {
+            self.test_bed.command(|a| a.is_compressed = true);
+            self
+        }
+
+        fn brake_demand(mut self, demand: f64) -> Self {
+            self.test_bed
+                .command(|a| a.demand = Ratio::new::<ratio>(demand));
+            self
+        }
+
+        fn anti_skid_off(mut self) -> Self {
+            self.test_bed.command(|a| a.anti_skid_enabled = false);
+            self
+        }
+
+        /// Runs in fixed 10ms steps, as the anti skid loop runs in the hydraulic fixed step loop
+        fn run_for(mut self, duration: Duration) -> Self {
+            for _ in 0..duration.as_millis() / 10 {
+                self.test_bed.run_with_delta(Duration::from_millis(10));
+            }
+            self
+        }
+
+        fn slip_ratio(&mut self) -> f64 {
+            let slip: f64 = self.test_bed.read_by_name("WHEEL_TEST_SLIP_RATIO");
+            slip
+        }
+
+        fn pressure_ratio(&self) -> f64 {
+            self.test_bed
+                .query(|a| a.anti_skid.pressure_ratio().get::<ratio>())
+        }
+
+        fn touchdown_protection_active(&self) -> bool {
+            self.test_bed
+                .query(|a| a.anti_skid.touchdown_protection_active())
+        }
+
+        fn wheel_speed_knot(&self) -> f64 {
+            self.test_bed
+                .query(|a| a.wheel.rolling_speed(&a.properties).get::<knot>())
+        }
+
+        fn braking_efficiency(&self) -> f64 {
+            self.test_bed
+                .query(|a| a.wheel.braking_efficiency().get::<ratio>())
+        }
+    }
+
+    fn test_bed() -> AntiSkidTestBed {
+        AntiSkidTestBed::new()
+    }
+
+    #[test]
+    fn optimal_slip_is_moderate() {
+        let optimal_slip = BrakedWheel::optimal_slip_ratio().get::<ratio>();
+
+        assert!((0.1..0.2).contains(&optimal_slip));
+    }
+
+    #[test]
+    fn free_rolling_wheel_has_no_slip() {
+        let mut test_bed = test_bed()
+            .rolling_at(Velocity::new::<knot>(100.))
+            .and()
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.slip_ratio() < 0.01);
+        assert!((test_bed.wheel_speed_knot() - 100.).abs() < 1.);
+    }
+
+    #[test]
+    fn max_braking_in_rain_without_anti_skid_locks_the_wheel() {
+        let mut test_bed = test_bed()
+            .in_heavy_rain()
+            .rolling_at(Velocity::new::<knot>(100.))
+            .and()
+            .anti_skid_off()
+            .and()
+            .brake_demand(1.)
+            .run_for(Duration::from_secs(2));
+
+        assert!(test_bed.slip_ratio() > 0.99);
+        assert!(test_bed.braking_efficiency() < 0.7);
+    }
+
+    #[test]
+    fn max_braking_in_rain_with_anti_skid_keeps_the_wheel_turning() {
+        let mut test_bed = test_bed()
+            .in_heavy_rain()
+            .rolling_at(Velocity::new::<knot>(100.))
+            .and()
+            .brake_demand(1.)
+            .run_for(Duration::from_secs(5));
+
+        assert!(test_bed.wheel_speed_knot() > 50.);
+        assert!(test_bed.slip_ratio() < 0.5);
+        assert!(test_bed.pressure_ratio() < 1.);
+    }
+
+    #[test]
+    fn max_braking_on_ice_with_anti_skid_keeps_the_wheel_turning() {
+        let test_bed = test_bed()
+            .on_surface(SurfaceTypeMsfs::Ice)
+            .rolling_at(Velocity::new::<knot>(100.))
+            .and()
+            .brake_demand(1.)
+            .run_for(Duration::from_secs(5));
+
+        assert!(test_bed.wheel_speed_knot() > 50.);
+        assert!(test_bed.pressure_ratio() < 0.5);
+    }
+
+    #[test]
+    fn light_braking_is_not_limited_by_anti_skid() {
+        let test_bed = test_bed()
+            .rolling_at(Velocity::new::<knot>(100.))
+            .and()
+            .brake_demand(0.1)
+            .run_for(Duration::from_secs(5));
+
+        assert!((test_bed.pressure_ratio() - 1.).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn anti_skid_inactive_at_low_speed() {
+        let test_bed = test_bed()
+            .rolling_at(Velocity::new::<knot>(10.))
+            .and()
+            .brake_demand(1.)
+            .run_for(Duration::from_secs(2));
+
+        assert!((test_bed.pressure_ratio() - 1.).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn brakes_released_in_flight() {
+        let test_bed = test_bed()
+            .in_flight_at(Velocity::new::<knot>(140.))
+            .and()
+            .brake_demand(1.)
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.touchdown_protection_active());
+        assert!(test_bed.pressure_ratio() < f64::EPSILON);
+    }
+
+    #[test]
+    fn brakes_applied_after_touchdown_once_wheels_spun_up() {
+        let test_bed = test_bed()
+            .in_flight_at(Velocity::new::<knot>(140.))
+            .and()
+            .brake_demand(1.)
+            .run_for(Duration::from_secs(1))
+            .touching_down()
+            .run_for(Duration::from_secs(2));
+
+        assert!(!test_bed.touchdown_protection_active());
+        assert!(test_bed.wheel_speed_knot() > 100.);
+        assert!(test_bed.pressure_ratio() > 0.);
+    }
+
+    #[test]
+    fn touchdown_protection_restored_from_snapshot() {
+        let mut test_bed = test_bed()
+            .in_flight_at(Velocity::new::<knot>(140.))
+            .and()
+            .brake_demand(1.)
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.touchdown_protection_active());
+        let snapshot = test_bed.test_bed.snapshot();
+
+        let mut test_bed = test_bed.touching_down().run_for(Duration::from_secs(2));
+        assert!(!test_bed.touchdown_protection_active());
+
+        assert_eq!(test_bed.test_bed.restore(&snapshot), Ok(()));
+
+        assert!(test_bed.touchdown_protection_active());
+        assert!(test_bed.pressure_ratio() < f64::EPSILON);
+    }
+}
@@ -1,8 +1,9 @@
 use crate::{
+    landing_gear::{Tyre, TyreProperties},
     shared::{ConsumePower, ControllerSignal, ElectricalBusType, ElectricalBuses},
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+        SimulatorWriter, StateReader, StateWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
 use std::f64::consts::PI;
@@ -11,7 +12,7 @@ use uom::si::{
     area::square_meter,
     energy::joule,
     f64::{
-        AngularVelocity, Area, Energy, HeatCapacity, Length, Mass, Power, Pressure,
+        AngularVelocity, Area, Energy, HeatCapacity, Length, Mass, Power, Pressure, Ratio,
         SpecificHeatCapacity, TemperatureInterval, ThermodynamicTemperature,
     },
     length::meter,
@@ -29,6 +30,7 @@ pub struct BrakeAssembly<const N: usize> {
     brakes: [Brake; N],
     brake_probes: [BrakeProbe; N],
     brake_fans: Option<[BrakeFan; N]>,
+    tyres: [Tyre; N],
 }
 impl<const N: usize> BrakeAssembly<N> {
     /// Creates a new brake assembly
@@ -42,6 +44,7 @@ impl<const N: usize> BrakeAssembly<N> {
         brake_fan_bus: Option<ElectricalBusType>,
     ) -> Self {
         let brakes = indices.map(|index| Brake::new(context, index));
+        let tyres = indices.map(|index| Tyre::new(context, index));
         let brake_probes = sensors_powered_by.map(BrakeProbe::new);
         let brake_fans = brake_fan_bus.map(|bus| {
             brakes
@@ -57,6 +60,7 @@ impl<const N: usize> BrakeAssembly<N> {
             brakes,
             brake_probes,
             brake_fans,
+            tyres,
         }
     }
 
@@ -64,6 +68,7 @@ impl<const N: usize> BrakeAssembly<N> {
         &mut self,
         context: &UpdateContext,
         brake_properties: &BrakeProperties,
+        tyre_properties: &TyreProperties,
         actuator_pressure: Pressure,
         brake_fan_should_be_on: bool,
         gear_extended_phys: bool,
@@ -102,6 +107,11 @@ impl<const N: usize> BrakeAssembly<N> {
             );
             brake_probe.update(context, brake, brake_fan_is_running)
         }
+
+        let rolling_speed = tyre_properties.rolling_speed(self.wheel_speed);
+        for (tyre, brake) in self.tyres.iter_mut().zip(&self.brakes) {
+            tyre.update(context, tyre_properties, brake.temperature(), rolling_speed);
+        }
     }
 
     pub fn brake_temperature_sensors(
@@ -110,6 +120,19 @@ impl<const N: usize> BrakeAssembly<N> {
         &self.brake_probes
     }
 
+    pub fn tyres(&self) -> &[Tyre; N] {
+        &self.tyres
+    }
+
+    /// Returns the average fraction of the brake force which the tyres can transfer to the ground.
+    pub fn braking_efficiency(&self) -> Ratio {
+        self.tyres
+            .iter()
+            .map(|tyre| tyre.braking_efficiency())
+            .fold(Ratio::default(), |sum, efficiency| sum + efficiency)
+            / N as f64
+    }
+
     pub fn any_brake_fan_running(&self) -> bool {
         self.brake_fans
             .iter()
@@ -122,6 +145,7 @@ impl<const N: usize> SimulationElement for BrakeAssembly<N> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.brakes, visitor);
         accept_iterable!(self.brake_probes, visitor);
+        accept_iterable!(self.tyres, visitor);
         if let Some(brake_fans) = &mut self.brake_fans {
             accept_iterable!(brake_fans, visitor);
         }
@@ -294,6 +318,16 @@ impl SimulationElement for Brake {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.temperature_id, self.temperature);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.temperature);
+        state.write(self.initialized);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.temperature = state.read();
+        self.initialized = state.read();
+    }
 }
 
 #[derive(Debug)]
@@ -39,6 +39,9 @@ pub trait Actuator {
     fn used_volume(&self) -> Volume;
     fn reservoir_return(&self) -> Volume;
     fn reset_volumes(&mut self);
+
+    /// Ratio of the nominal flow the actuator can get with current fluid viscosity
+    fn set_viscous_flow_factor(&mut self, _flow_factor: Ratio) {}
 }
 
 /// Trait linked to anything moving bounded between a minimum and maximum position.
@@ -415,6 +418,8 @@ struct CoreHydraulicForce {
     has_flow_restriction: bool,
     max_working_pressure: Pressure,
 
+    viscous_flow_factor: Ratio,
+
     locks_position_in_closed_mode: bool,
     is_soft_locked: bool,
     soft_lock_velocity: (AngularVelocity, AngularVelocity),
@@ -515,6 +520,8 @@ impl CoreHydraulicForce {
             has_flow_restriction,
             max_working_pressure,
 
+            viscous_flow_factor: Ratio::new::<ratio>(1.),
+
             locks_position_in_closed_mode,
             is_soft_locked: locks_position_in_closed_mode,
             soft_lock_velocity: soft_lock_velocity.unwrap_or_default(),
@@ -849,7 +856,8 @@ impl CoreHydraulicForce {
             1.
         };
 
-        let pressure_corrected_openloop_target = open_loop_flow_target * pressure_correction_factor;
+        let pressure_corrected_openloop_target =
+            open_loop_flow_target * pressure_correction_factor * self.viscous_flow_factor;
         self.pid_controller
             .change_setpoint(pressure_corrected_openloop_target.get::<gallon_per_second>());
 
@@ -1251,6 +1259,10 @@ impl Actuator for LinearActuator {
             eha.reset_volumes();
         }
     }
+
+    fn set_viscous_flow_factor(&mut self, flow_factor: Ratio) {
+        self.core_hydraulics.viscous_flow_factor = flow_factor;
+    }
 }
 impl HydraulicLocking for LinearActuator {
     fn should_soft_lock(&self) -> bool {
@@ -1475,6 +1487,14 @@ impl<const N: usize> HydraulicLinearActuatorAssembly<N> {
         self.rigid_body.apply_global_angle_offset(trim_angle);
     }
 
+    #[cfg(test)]
+    pub fn actuator_electrical_backup_consumed_power(&self, index: usize) -> Power {
+        self.linear_actuators[index]
+            .electro_hydrostatic_backup
+            .as_ref()
+            .map_or(Power::default(), |eha| eha.pump.consumed_power)
+    }
+
     #[cfg(test)]
     pub fn actuator_flow(&self, index: usize) -> VolumeRate {
         self.linear_actuators[index].signed_flow().abs()
@@ -2194,6 +2214,17 @@ mod tests {
             self.controllers[actuator_id].set_elec_backup_refill(is_active);
         }
 
+        fn command_viscous_flow_factor(&mut self, flow_factor: Ratio) {
+            for actuator in &mut self.hydraulic_assembly.linear_actuators {
+                actuator.set_viscous_flow_factor(flow_factor);
+            }
+        }
+
+        fn actuator_backup_power_consumption(&self, actuator_id: usize) -> Power {
+            self.hydraulic_assembly
+                .actuator_electrical_backup_consumed_power(actuator_id)
+        }
+
         fn accumulator_pressure(&self, actuator_id: usize) -> Pressure {
             if let Some(eha) =
                 self.hydraulic_assembly.linear_actuators[actuator_id].electro_hydrostatic_backup
@@ -2980,6 +3011,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn linear_actuator_moves_slower_with_viscous_fluid() {
+        let mut nominal_test_bed = SimulationTestBed::new(|context| {
+            let tested_object = cargo_door_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+        let mut viscous_test_bed = SimulationTestBed::new(|context| {
+            let tested_object = cargo_door_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+        viscous_test_bed.command(|a| a.command_viscous_flow_factor(Ratio::new::<ratio>(0.5)));
+
+        for test_bed in [&mut nominal_test_bed, &mut viscous_test_bed] {
+            test_bed.command(|a| a.command_unlock());
+            test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(0.7), 0));
+            test_bed.command(|a| a.set_pressures([Pressure::new::<psi>(3000.)]));
+
+            test_bed.run_with_delta(Duration::from_secs(2));
+        }
+
+        assert_gt!(
+            viscous_test_bed.query(|a| a.body_position()),
+            Ratio::new::<ratio>(0.)
+        );
+        assert_lt!(
+            viscous_test_bed.query(|a| a.body_position()),
+            nominal_test_bed.query(|a| a.body_position()) * 0.8
+        );
+    }
+
     #[test]
     fn right_main_gear_door_drops_when_unlocked() {
         let mut test_bed = SimulationTestBed::new(|context| {
@@ -4199,6 +4260,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn electro_hydrostatic_backup_power_consumption_is_reported_per_actuator() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = elevator_electro_hydrostatic_assembly(context);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| a.set_pressures([Pressure::new::<psi>(0.), Pressure::new::<psi>(0.)]));
+
+        test_bed.command(|a| a.set_ac_1_power(true));
+
+        test_bed.command(|a| a.command_active_damping_mode(0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(0.8), 1));
+        test_bed.command(|a| a.command_electro_backup(true, 1));
+
+        test_bed.run_with_delta(Duration::from_secs_f64(0.2));
+
+        assert!(
+            test_bed
+                .query(|a| a.actuator_backup_power_consumption(0))
+                .get::<watt>()
+                < 1.
+        );
+        assert!(
+            test_bed
+                .query(|a| a.actuator_backup_power_consumption(1))
+                .get::<watt>()
+                > 150.
+        );
+    }
+
     #[test]
     fn spoiler_electro_hydrostatic_cannot_move_once_accumulator_empty() {
         let mut test_bed = SimulationTestBed::new(|context| {
@@ -15,7 +15,7 @@ use crate::shared::{
 };
 use crate::simulation::{
     InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-    SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    SimulatorWriter, StateReader, StateWriter, UpdateContext, VariableIdentifier, Write,
 };
 use nalgebra::Vector3;
 
@@ -24,15 +24,20 @@ use std::time::Duration;
 use uom::si::{
     angular_velocity::{radian_per_second, revolution_per_minute},
     f64::*,
+    kinematic_viscosity::centistokes,
     length::meter,
+    power::watt,
     pressure::{pascal, psi},
     ratio::ratio,
+    thermal_conductance::watt_per_kelvin,
+    thermodynamic_temperature::degree_celsius,
     torque::{newton_meter, pound_force_inch},
     volume::{cubic_inch, cubic_meter, gallon},
-    volume_rate::{gallon_per_minute, gallon_per_second},
+    volume_rate::{cubic_meter_per_second, gallon_per_minute, gallon_per_second},
 };
 
 pub mod aerodynamic_model;
+pub mod anti_skid;
 pub mod brake;
 pub mod brake_circuit;
 pub mod bypass_pin;
@@ -84,31 +89,37 @@ pub trait PressureSource {
     fn displacement(&self) -> Volume;
 }
 
+/// Hydraulic fluid of the high pressure part of a circuit.
+///
+/// Most of that fluid stagnates in lines and actuators, so its temperature is modeled as a single thermal
+/// mass only exchanging heat with the fluid circulating from and back to the reservoir, and with ambient.
+/// Viscosity, and thus the flow actuators can deliver, depends on that temperature.
 pub struct Fluid {
     current_bulk: Pressure,
-    heat_state: HeatingProperties,
+    temperature: Option<ThermodynamicTemperature>,
 }
 impl Fluid {
-    const HEATING_TIME_CONSTANT_MEAN_S: f64 = 40.;
-    const HEATING_TIME_CONSTANT_STD_S: f64 = 10.;
+    // Phosphate ester fluid properties
+    const DENSITY_KG_PER_CUBIC_METER: f64 = 1000.;
+    const SPECIFIC_HEAT_JOULE_PER_KG_KELVIN: f64 = 1750.;
 
-    const COOLING_TIME_CONSTANT: Duration = Duration::from_secs(60 * 3);
-    const DAMAGE_TIME_CONSTANT: Duration = Duration::from_secs(60 * 3);
+    const TEMPERATURE_BREAKPOINTS_DEGREE_CELSIUS: [f64; 9] =
+        [-55., -40., -20., 0., 20., 40., 60., 80., 100.];
+    const KINEMATIC_VISCOSITY_CENTISTOKES: [f64; 9] =
+        [1200., 330., 95., 38., 17., 9.5, 6.2, 4.5, 3.6];
+
+    // Up to this viscosity pumps and actuators deliver their nominal flow
+    const MAX_VISCOSITY_FOR_NOMINAL_FLOW_CENTISTOKES: f64 = 60.;
+
+    const AMBIENT_HEAT_TRANSFER_COEFFICIENT_WATT_PER_KELVIN: f64 = 15.;
+
+    // Heat exchange between circulating fluid and fluid stagnating in lines and actuators
+    const LINES_HEAT_TRANSFER_COEFFICIENT_WATT_PER_KELVIN: f64 = 150.;
 
     pub fn new(bulk: Pressure) -> Self {
         Self {
             current_bulk: bulk,
-            heat_state: HeatingProperties::new(
-                Duration::from_secs_f64(
-                    random_from_normal_distribution(
-                        Self::HEATING_TIME_CONSTANT_MEAN_S,
-                        Self::HEATING_TIME_CONSTANT_STD_S,
-                    )
-                    .max(10.),
-                ),
-                Self::COOLING_TIME_CONSTANT,
-                Self::DAMAGE_TIME_CONSTANT,
-            ),
+            temperature: None,
         }
     }
 
@@ -116,17 +127,171 @@ impl Fluid {
         self.current_bulk
     }
 
-    fn update(&mut self, context: &UpdateContext, is_heating: bool) {
-        self.heat_state.update(context, is_heating);
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+            .unwrap_or(ThermodynamicTemperature::new::<degree_celsius>(15.))
+    }
+
+    pub fn kinematic_viscosity(&self) -> KinematicViscosity {
+        Self::kinematic_viscosity_at(self.temperature())
+    }
+
+    /// Ratio of the nominal flow that can go through pumps and actuators with current fluid viscosity
+    pub fn viscous_flow_factor(&self) -> Ratio {
+        Self::viscous_flow_factor_at(self.temperature())
+    }
+
+    fn kinematic_viscosity_at(temperature: ThermodynamicTemperature) -> KinematicViscosity {
+        KinematicViscosity::new::<centistokes>(interpolation(
+            &Self::TEMPERATURE_BREAKPOINTS_DEGREE_CELSIUS,
+            &Self::KINEMATIC_VISCOSITY_CENTISTOKES,
+            temperature.get::<degree_celsius>(),
+        ))
+    }
+
+    fn viscous_flow_factor_at(temperature: ThermodynamicTemperature) -> Ratio {
+        Ratio::new::<ratio>(
+            (Self::MAX_VISCOSITY_FOR_NOMINAL_FLOW_CENTISTOKES
+                / Self::kinematic_viscosity_at(temperature).get::<centistokes>())
+            .powf(0.25)
+            .min(1.),
+        )
+    }
+
+    fn heat_capacity_joule_per_kelvin(volume: Volume) -> f64 {
+        volume.get::<cubic_meter>().max(0.)
+            * Self::DENSITY_KG_PER_CUBIC_METER
+            * Self::SPECIFIC_HEAT_JOULE_PER_KG_KELVIN
+    }
+
+    /// Heat transported per kelvin of temperature difference by a fluid flow
+    fn heat_capacity_rate_watt_per_kelvin(flow: VolumeRate) -> f64 {
+        flow.get::<cubic_meter_per_second>().abs()
+            * Self::DENSITY_KG_PER_CUBIC_METER
+            * Self::SPECIFIC_HEAT_JOULE_PER_KG_KELVIN
+    }
+
+    fn circulation_effectiveness(circulation_flow: VolumeRate) -> f64 {
+        let capacity_rate = Self::heat_capacity_rate_watt_per_kelvin(circulation_flow);
+
+        if capacity_rate > 0. {
+            1. - (-Self::LINES_HEAT_TRANSFER_COEFFICIENT_WATT_PER_KELVIN / capacity_rate).exp()
+        } else {
+            0.
+        }
+    }
+
+    /// Temperature of the circulating fluid once back from the circuit, including heat it picked up on its way
+    fn return_temperature(
+        &self,
+        supply_temperature: ThermodynamicTemperature,
+        circulation_flow: VolumeRate,
+        heat_picked_up: Power,
+    ) -> ThermodynamicTemperature {
+        let supply = supply_temperature.get::<degree_celsius>();
+        let capacity_rate = Self::heat_capacity_rate_watt_per_kelvin(circulation_flow);
+
+        let heating = if capacity_rate > 0. {
+            heat_picked_up.get::<watt>() / capacity_rate
+        } else {
+            0.
+        };
+
+        ThermodynamicTemperature::new::<degree_celsius>(
+            supply
+                + Self::circulation_effectiveness(circulation_flow)
+                    * (self.temperature().get::<degree_celsius>() - supply)
+                + heating,
+        )
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        volume: Volume,
+        supply_temperature: ThermodynamicTemperature,
+        circulation_flow: VolumeRate,
+    ) {
+        let ambient_temperature = context.ambient_temperature().get::<degree_celsius>();
+        let temperature = self
+            .temperature
+            .map_or(ambient_temperature, |t| t.get::<degree_celsius>());
+
+        self.temperature = Some(ThermodynamicTemperature::new::<degree_celsius>(
+            lumped_thermal_mass_temperature(
+                context,
+                temperature,
+                Self::heat_capacity_joule_per_kelvin(volume),
+                0.,
+                &[
+                    (
+                        Self::heat_capacity_rate_watt_per_kelvin(circulation_flow)
+                            * Self::circulation_effectiveness(circulation_flow),
+                        supply_temperature.get::<degree_celsius>(),
+                    ),
+                    (
+                        Self::AMBIENT_HEAT_TRANSFER_COEFFICIENT_WATT_PER_KELVIN,
+                        ambient_temperature,
+                    ),
+                ],
+            ),
+        ));
     }
 }
-impl HeatingElement for Fluid {
-    fn is_overheating(&self) -> bool {
-        self.heat_state.is_overheating()
+
+/// Semi implicit temperature integration of a lumped thermal mass receiving a heat power and exchanging
+/// heat with surrounding temperatures through (conductance in W/K, temperature in °C) couples.
+/// Being implicit on exchanges it stays stable whatever the conductance vs thermal mass ratio.
+fn lumped_thermal_mass_temperature(
+    context: &UpdateContext,
+    temperature_celsius: f64,
+    heat_capacity_joule_per_kelvin: f64,
+    heat_watt: f64,
+    exchanges: &[(f64, f64)],
+) -> f64 {
+    if heat_capacity_joule_per_kelvin <= 0. {
+        return temperature_celsius;
+    }
+
+    let step = context.delta_as_secs_f64() / heat_capacity_joule_per_kelvin;
+    let total_conductance: f64 = exchanges.iter().map(|(g, _)| g).sum();
+    let weighted_temperatures: f64 = exchanges.iter().map(|(g, t)| g * t).sum();
+
+    (temperature_celsius + step * (heat_watt + weighted_temperatures))
+        / (1. + step * total_conductance)
+}
+
+/// Fuel cooled heat exchanger located on the return line of a circuit, immersed in a fuel tank
+/// whose temperature is given by the fuel system.
+struct FuelHeatExchanger {
+    conductance: ThermalConductance,
+}
+impl FuelHeatExchanger {
+    fn new(conductance: ThermalConductance) -> Self {
+        Self { conductance }
     }
 
-    fn is_damaged(&self) -> bool {
-        self.heat_state.is_damaged()
+    /// Temperature of the fluid leaving the exchanger, using effectiveness of a counterflow
+    /// exchanger against a fuel tank of infinite capacity
+    fn outlet_temperature(
+        &self,
+        inlet_temperature: ThermodynamicTemperature,
+        flow: VolumeRate,
+        fuel_temperature: ThermodynamicTemperature,
+    ) -> ThermodynamicTemperature {
+        let capacity_rate = Fluid::heat_capacity_rate_watt_per_kelvin(flow);
+
+        if capacity_rate > 0. {
+            let effectiveness =
+                1. - (-self.conductance.get::<watt_per_kelvin>() / capacity_rate).exp();
+            let inlet = inlet_temperature.get::<degree_celsius>();
+
+            ThermodynamicTemperature::new::<degree_celsius>(
+                inlet - effectiveness * (inlet - fuel_temperature.get::<degree_celsius>()),
+            )
+        } else {
+            inlet_temperature
+        }
     }
 }
 
@@ -391,6 +556,8 @@ impl PowerTransferUnit {
 
     const MAX_SPEED_BEFORE_HEATING_UP_RPM: f64 = 2000.;
 
+    const OVERHEATING_CASE_DRAIN_HEAT_WATT: f64 = 40000.;
+
     // We consider that ptu can't overheat if there's enough pressure on both side (it's cooled by hyd fluid)
     const MIN_PRESSURE_ALLOWING_PTU_HEATING_UP_RPM: f64 = 500.;
 
@@ -791,6 +958,20 @@ pub struct Accumulator {
 impl Accumulator {
     const FLOW_DYNAMIC_LOW_PASS: f64 = 0.7;
 
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.current_gas_init_precharge);
+        state.write(self.gas_pressure);
+        state.write(self.gas_volume);
+        state.write(self.fluid_volume);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.current_gas_init_precharge = state.read();
+        self.gas_pressure = state.read();
+        self.gas_volume = state.read();
+        self.fluid_volume = state.read();
+    }
+
     // Gain of the delta pressure to flow relation.
     // Higher gain enables faster flow transient but brings instability.
     const DELTA_PRESSURE_CHARACTERISTICS: f64 = 0.009;
@@ -974,6 +1155,7 @@ pub struct HydraulicCircuit {
 
     fluid: Fluid,
     reservoir: Reservoir,
+    fuel_heat_exchanger: FuelHeatExchanger,
 
     circuit_target_pressure: Pressure,
 }
@@ -989,6 +1171,10 @@ impl HydraulicCircuit {
 
     const FLUID_BULK_MODULUS_PASCAL: f64 = 1450000000.0;
 
+    // Part of pumped hydraulic power lost as heat in pump case drain flow
+    const PUMP_CASE_DRAIN_LOSS_RATIO: f64 = 0.15;
+    const OVERHEATING_PUMP_CASE_DRAIN_HEAT_WATT: f64 = 40000.;
+
     // TODO firevalves are actually powered by a sub-bus (401PP DC ESS)
     const DEFAULT_FIRE_VALVE_POWERING_BUS: ElectricalBusType =
         ElectricalBusType::DirectCurrentEssential;
@@ -1018,6 +1204,7 @@ impl HydraulicCircuit {
         priority_valve: PriorityValve,
         system_accumulator_precharge: Pressure,
         system_accumulator_volume: Volume,
+        fuel_heat_exchanger_conductance: ThermalConductance,
     ) -> Self {
         // FIXME no runtime panics please!
         assert!(number_of_pump_sections > 0);
@@ -1117,6 +1304,7 @@ impl HydraulicCircuit {
             pump_section_routed_to_auxiliary_section: pump_section_to_auxiliary,
             fluid: Fluid::new(Pressure::new::<pascal>(Self::FLUID_BULK_MODULUS_PASCAL)),
             reservoir,
+            fuel_heat_exchanger: FuelHeatExchanger::new(fuel_heat_exchanger_conductance),
             circuit_target_pressure,
         }
     }
@@ -1126,10 +1314,12 @@ impl HydraulicCircuit {
     }
 
     pub fn update_system_actuator_volumes(&mut self, actuator: &mut impl Actuator) {
+        actuator.set_viscous_flow_factor(self.fluid.viscous_flow_factor());
         self.system_section.update_actuator_volumes(actuator);
     }
 
     pub fn update_auxiliary_actuator_volumes(&mut self, actuator: &mut impl Actuator) {
+        actuator.set_viscous_flow_factor(self.fluid.viscous_flow_factor());
         if let Some(auxiliary_section) = self.auxiliary_section.as_mut() {
             auxiliary_section.update_actuator_volumes(actuator);
         } else {
@@ -1146,33 +1336,17 @@ impl HydraulicCircuit {
         ptu: Option<&PowerTransferUnit>,
         controller: &impl HydraulicCircuitController,
         reservoir_pressure: Pressure,
+        fuel_temperature: ThermodynamicTemperature,
     ) {
-        let mut any_pump_is_overheating = false;
-        for pump in main_section_pumps.iter() {
-            if pump.flow().get::<gallon_per_second>() > 0.01 && pump.is_overheating() {
-                any_pump_is_overheating = true;
-            }
-        }
-
-        if let Some(pump) = system_section_pump.as_ref() {
-            if pump.flow().get::<gallon_per_second>() > 0.01 && pump.is_overheating() {
-                any_pump_is_overheating = true;
-            }
-        }
-
-        if let Some(pump) = auxiliary_section_pump.as_ref() {
-            if pump.flow().get::<gallon_per_second>() > 0.01 && pump.is_overheating() {
-                any_pump_is_overheating = true;
-            }
-        }
-
-        let ptu_overheats_fluid = ptu.is_some_and(|p| p.is_overheating() && p.is_rotating());
-
-        self.fluid
-            .update(context, ptu_overheats_fluid || any_pump_is_overheating);
-
-        self.reservoir
-            .update(context, reservoir_pressure, &self.fluid);
+        self.update_fluid_temperatures(
+            context,
+            main_section_pumps,
+            &system_section_pump,
+            &auxiliary_section_pump,
+            ptu,
+            reservoir_pressure,
+            fuel_temperature,
+        );
 
         self.update_shutoff_valves(controller);
         self.update_leak_measurement_valves(context, controller);
@@ -1213,6 +1387,96 @@ impl HydraulicCircuit {
         self.update_final_delta_vol_and_pressure(context);
     }
 
+    fn update_fluid_temperatures(
+        &mut self,
+        context: &UpdateContext,
+        main_section_pumps: &[&mut dyn HeatingPressureSource],
+        system_section_pump: &Option<&mut impl HeatingPressureSource>,
+        auxiliary_section_pump: &Option<&mut impl HeatingPressureSource>,
+        ptu: Option<&PowerTransferUnit>,
+        reservoir_pressure: Pressure,
+        fuel_temperature: ThermodynamicTemperature,
+    ) {
+        let mut case_drain_heat = Power::default();
+        for (pump_index, pump) in main_section_pumps.iter().enumerate() {
+            case_drain_heat +=
+                Self::pump_case_drain_heat(&**pump, self.pump_sections[pump_index].pressure());
+        }
+
+        if let Some(pump) = system_section_pump.as_ref() {
+            case_drain_heat += Self::pump_case_drain_heat(&**pump, self.system_section.pressure());
+        }
+
+        if let Some(pump) = auxiliary_section_pump.as_ref() {
+            case_drain_heat +=
+                Self::pump_case_drain_heat(&**pump, self.auxiliary_section().pressure());
+        }
+
+        if let Some(ptu) = ptu {
+            case_drain_heat += self.system_section.ptu_case_drain_heat(ptu);
+        }
+
+        // A dry pump or ptu keeps its heat, there's no fluid to carry it to the reservoir
+        if self.reservoir.is_empty() {
+            case_drain_heat = Power::default();
+        }
+
+        let mut internal_leakage_heat = self
+            .system_section
+            .internal_leakage_heat(self.circuit_target_pressure);
+        for section in &self.pump_sections {
+            internal_leakage_heat += section.internal_leakage_heat(self.circuit_target_pressure);
+        }
+        if let Some(auxiliary_section) = &self.auxiliary_section {
+            internal_leakage_heat +=
+                auxiliary_section.internal_leakage_heat(self.circuit_target_pressure);
+        }
+
+        // Leakage heat is carried by the leaking fluid to the return line
+        let return_temperature = self.fuel_heat_exchanger.outlet_temperature(
+            self.fluid.return_temperature(
+                self.reservoir.temperature(),
+                self.reservoir.return_flow(),
+                internal_leakage_heat,
+            ),
+            self.reservoir.return_flow(),
+            fuel_temperature,
+        );
+
+        self.reservoir.update(
+            context,
+            reservoir_pressure,
+            return_temperature,
+            case_drain_heat,
+        );
+
+        self.fluid.update(
+            context,
+            self.high_pressure_fluid_volume(),
+            self.reservoir.temperature(),
+            self.reservoir.return_flow(),
+        );
+    }
+
+    fn pump_case_drain_heat(pump: &dyn HeatingPressureSource, pressure: Pressure) -> Power {
+        let mut heat = pump.flow() * pressure * Self::PUMP_CASE_DRAIN_LOSS_RATIO;
+
+        if pump.flow().get::<gallon_per_second>() > 0.01 && pump.is_overheating() {
+            heat += Power::new::<watt>(Self::OVERHEATING_PUMP_CASE_DRAIN_HEAT_WATT);
+        }
+
+        heat
+    }
+
+    fn high_pressure_fluid_volume(&self) -> Volume {
+        self.pump_sections
+            .iter()
+            .chain(std::iter::once(&self.system_section))
+            .chain(self.auxiliary_section.iter())
+            .map(|section| section.current_volume)
+            .sum()
+    }
+
     fn update_delta_vol_from_valves(&mut self) {
         for (pump_index, section) in self.pump_sections.iter_mut().enumerate() {
             section.update_downstream_delta_vol(&self.pump_sections_check_valves[pump_index]);
@@ -1447,6 +1711,10 @@ impl HydraulicCircuit {
         &self.reservoir
     }
 
+    pub fn fluid_temperature(&self) -> ThermodynamicTemperature {
+        self.fluid.temperature()
+    }
+
     pub fn system_section_pressure(&self) -> Pressure {
         self.system_section.pressure()
     }
@@ -1483,6 +1751,14 @@ impl SimulationElement for HydraulicCircuit {
 
         visitor.visit(self);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write_option(self.fluid.temperature);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.fluid.temperature = state.read_option();
+    }
 }
 impl HydraulicPressureSensors for HydraulicCircuit {
     fn pump_section_switch_pressurised(&self, pump_index: usize) -> bool {
@@ -1680,6 +1956,40 @@ impl Section {
         self.reset_actuator_volumes();
     }
 
+    /// Heat generated by fluid leaking internally from high pressure to return. Most of it happens
+    /// in servo valves downstream the leak measurement valve, thus closing it stops that heating.
+    fn internal_leakage_heat(&self, target_pressure: Pressure) -> Power {
+        let leak_flow = self.static_leak_at_max_press
+            * (self.current_pressure - Pressure::new::<psi>(14.7))
+            / target_pressure;
+
+        leak_flow
+            * (self.pressure_downstream_leak_valve() - Pressure::new::<psi>(14.7))
+                .max(Pressure::new::<psi>(0.))
+    }
+
+    /// Heat lost by the ptu side connected to this section, carried by its case drain to the reservoir
+    fn ptu_case_drain_heat(&self, ptu: &PowerTransferUnit) -> Power {
+        let side_flow = if self.connected_to_ptu_left_side {
+            ptu.flow_to_left
+        } else if self.connected_to_ptu_right_side {
+            ptu.flow_to_right
+        } else {
+            return Power::default();
+        }
+        .abs();
+
+        let mut heat =
+            side_flow * self.current_pressure * (1. - ptu.efficiency.get::<ratio>()) / 2.;
+
+        if side_flow.get::<gallon_per_second>() > 0.01 && ptu.is_overheating() && ptu.is_rotating()
+        {
+            heat += Power::new::<watt>(PowerTransferUnit::OVERHEATING_CASE_DRAIN_HEAT_WATT);
+        }
+
+        heat
+    }
+
     fn update_actuator_volumes(&mut self, actuator: &mut impl Actuator) {
         self.total_actuator_consumed_volume += actuator.used_volume();
         self.total_actuator_returned_volume += actuator.reservoir_return();
@@ -1832,6 +2142,24 @@ impl SimulationElement for Section {
             self.pressure_switch_state() == PressureSwitchState::Pressurised,
         );
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.current_volume);
+        state.write(self.current_pressure);
+
+        if let Some(accumulator) = &self.accumulator {
+            accumulator.save_state(state);
+        }
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.current_volume = state.read();
+        self.current_pressure = state.read();
+
+        if let Some(accumulator) = &mut self.accumulator {
+            accumulator.restore_state(state);
+        }
+    }
 }
 impl SectionPressure for Section {
     fn pressure(&self) -> Pressure {
@@ -2222,6 +2550,7 @@ pub struct Reservoir {
     low_level_id: VariableIdentifier,
     low_air_press_id: VariableIdentifier,
     overheating_id: VariableIdentifier,
+    temperature_id: VariableIdentifier,
 
     max_capacity: Volume,
     max_gaugeable: Volume,
@@ -2238,7 +2567,10 @@ pub struct Reservoir {
 
     fluid_physics: FluidPhysics,
 
-    heat_state: HeatingProperties,
+    temperature: Option<ThermodynamicTemperature>,
+    is_overheating: bool,
+    is_damaged_by_heat: bool,
+    damaging_time: DelayedTrueLogicGate,
 
     total_return_flow: VolumeRate,
     total_return_volume: Volume,
@@ -2251,9 +2583,16 @@ impl Reservoir {
     // Part of the fluid lost instead of returning to reservoir
     const RETURN_FAILURE_LEAK_RATIO: f64 = 0.1;
 
-    const HEATING_TIME_CONSTANT_MEAN_S: f64 = 30.;
-    const HEATING_TIME_CONSTANT_STD_S: f64 = 5.;
-    const COOLING_TIME_CONSTANT: Duration = Duration::from_secs(60 * 3);
+    const OVERHEAT_TEMPERATURE_DEGREE_CELSIUS: f64 = 95.;
+    const OVERHEAT_RESET_TEMPERATURE_DEGREE_CELSIUS: f64 = 85.;
+
+    // Above overheat temperature pumps will start to lose suction due to fluid vaporisation
+    const FULL_CAVITATION_TEMPERATURE_DEGREE_CELSIUS: f64 = 140.;
+
+    // Reservoir body still absorbs some heat when nearly empty
+    const STRUCTURE_HEAT_CAPACITY_JOULE_PER_KELVIN: f64 = 4000.;
+    const AMBIENT_HEAT_TRANSFER_COEFFICIENT_WATT_PER_KELVIN: f64 = 10.;
+
     const DAMAGE_TIME_CONSTANT: Duration = Duration::from_secs(60 * 5);
 
     pub fn new(
@@ -2272,6 +2611,8 @@ impl Reservoir {
             low_air_press_id: context
                 .get_identifier(format!("HYD_{}_RESERVOIR_AIR_PRESSURE_IS_LOW", hyd_loop_id)),
             overheating_id: context.get_identifier(format!("HYD_{}_RESERVOIR_OVHT", hyd_loop_id)),
+            temperature_id: context
+                .get_identifier(format!("HYD_{}_RESERVOIR_TEMPERATURE", hyd_loop_id)),
 
             max_capacity,
             max_gaugeable,
@@ -2284,17 +2625,11 @@ impl Reservoir {
             level_switch: LevelSwitch::new(low_level_threshold),
             fluid_physics: FluidPhysics::new(),
 
-            heat_state: HeatingProperties::new(
-                Duration::from_secs_f64(
-                    random_from_normal_distribution(
-                        Self::HEATING_TIME_CONSTANT_MEAN_S,
-                        Self::HEATING_TIME_CONSTANT_STD_S,
-                    )
-                    .max(10.),
-                ),
-                Self::COOLING_TIME_CONSTANT,
-                Self::DAMAGE_TIME_CONSTANT,
-            ),
+            temperature: None,
+            is_overheating: false,
+            is_damaged_by_heat: false,
+            damaging_time: DelayedTrueLogicGate::new(Self::DAMAGE_TIME_CONSTANT),
+
             total_return_flow: VolumeRate::default(),
             total_return_volume: Volume::default(),
         }
@@ -2304,12 +2639,13 @@ impl Reservoir {
         &mut self,
         context: &UpdateContext,
         air_pressure: Pressure,
-        fluid: &impl HeatingElement,
+        return_temperature: ThermodynamicTemperature,
+        case_drain_heat: Power,
     ) {
         self.air_pressure = air_pressure;
 
         self.update_return_flow(context);
-        self.update_heat(context, fluid);
+        self.update_temperature(context, return_temperature, case_drain_heat);
 
         self.fluid_physics.update(context);
 
@@ -2328,10 +2664,44 @@ impl Reservoir {
         self.total_return_volume = Volume::default();
     }
 
-    fn update_heat(&mut self, context: &UpdateContext, fluid: &impl HeatingElement) {
-        let has_fluid_return = self.total_return_flow.get::<gallon_per_second>() > 0.01;
-        self.heat_state
-            .update(context, has_fluid_return && fluid.is_overheating())
+    fn update_temperature(
+        &mut self,
+        context: &UpdateContext,
+        return_temperature: ThermodynamicTemperature,
+        case_drain_heat: Power,
+    ) {
+        let ambient_temperature = context.ambient_temperature().get::<degree_celsius>();
+        let temperature = self
+            .temperature
+            .map_or(ambient_temperature, |t| t.get::<degree_celsius>());
+
+        let temperature = lumped_thermal_mass_temperature(
+            context,
+            temperature,
+            Fluid::heat_capacity_joule_per_kelvin(self.current_level)
+                + Self::STRUCTURE_HEAT_CAPACITY_JOULE_PER_KELVIN,
+            case_drain_heat.get::<watt>(),
+            &[
+                (
+                    Fluid::heat_capacity_rate_watt_per_kelvin(self.total_return_flow),
+                    return_temperature.get::<degree_celsius>(),
+                ),
+                (
+                    Self::AMBIENT_HEAT_TRANSFER_COEFFICIENT_WATT_PER_KELVIN,
+                    ambient_temperature,
+                ),
+            ],
+        );
+        self.temperature = Some(ThermodynamicTemperature::new::<degree_celsius>(temperature));
+
+        if temperature > Self::OVERHEAT_TEMPERATURE_DEGREE_CELSIUS {
+            self.is_overheating = true;
+        } else if temperature < Self::OVERHEAT_RESET_TEMPERATURE_DEGREE_CELSIUS {
+            self.is_overheating = false;
+        }
+
+        self.damaging_time.update(context, self.is_overheating);
+        self.is_damaged_by_heat = self.is_damaged_by_heat || self.damaging_time.output();
     }
 
     fn update_leak_failure(&mut self, context: &UpdateContext) {
@@ -2405,6 +2775,31 @@ impl Reservoir {
         self.air_pressure
     }
 
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+            .unwrap_or(ThermodynamicTemperature::new::<degree_celsius>(15.))
+    }
+
+    fn return_flow(&self) -> VolumeRate {
+        self.total_return_flow
+    }
+
+    /// Ratio of pump suction capacity lost to vaporisation of an overheated fluid
+    fn hot_fluid_cavitation_ratio(&self) -> Ratio {
+        Ratio::new::<ratio>(
+            ((self.temperature().get::<degree_celsius>()
+                - Self::OVERHEAT_TEMPERATURE_DEGREE_CELSIUS)
+                / (Self::FULL_CAVITATION_TEMPERATURE_DEGREE_CELSIUS
+                    - Self::OVERHEAT_TEMPERATURE_DEGREE_CELSIUS))
+                .clamp(0., 1.),
+        )
+    }
+
+    /// Ratio of the nominal flow pumps can draw with current reservoir fluid viscosity
+    fn viscous_flow_factor(&self) -> Ratio {
+        Fluid::viscous_flow_factor_at(self.temperature())
+    }
+
     fn is_empty(&self) -> bool {
         self.fluid_level_reachable_by_pumps() <= Volume::new::<gallon>(0.01)
     }
@@ -2423,6 +2818,7 @@ impl SimulationElement for Reservoir {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.leak_failure.accept(visitor);
         self.return_failure.accept(visitor);
+        self.damaging_time.accept(visitor);
 
         visitor.visit(self);
     }
@@ -2432,6 +2828,21 @@ impl SimulationElement for Reservoir {
         writer.write(&self.low_level_id, self.is_low_level());
         writer.write(&self.low_air_press_id, self.is_low_air_pressure());
         writer.write(&self.overheating_id, self.is_overheating());
+        writer.write(&self.temperature_id, self.temperature());
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.current_level);
+        state.write_option(self.temperature);
+        state.write(self.is_overheating);
+        state.write(self.is_damaged_by_heat);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.current_level = state.read();
+        self.temperature = state.read_option();
+        self.is_overheating = state.read();
+        self.is_damaged_by_heat = state.read();
     }
 }
 impl PressurizeableReservoir for Reservoir {
@@ -2441,11 +2852,11 @@ impl PressurizeableReservoir for Reservoir {
 }
 impl HeatingElement for Reservoir {
     fn is_damaged(&self) -> bool {
-        self.heat_state.is_damaged()
+        self.is_damaged_by_heat
     }
 
     fn is_overheating(&self) -> bool {
-        self.heat_state.is_overheating()
+        self.is_overheating
     }
 }
 
@@ -2531,8 +2942,8 @@ impl Pump {
         self.cavitation_efficiency = if !reservoir.is_empty() {
             self.pump_characteristics.cavitation_efficiency(
                 reservoir.air_pressure(),
-                reservoir.heat_state.overheat_ratio(),
-            )
+                reservoir.hot_fluid_cavitation_ratio(),
+            ) * reservoir.viscous_flow_factor()
         } else {
             Ratio::new::<ratio>(0.)
         };
@@ -3237,7 +3648,7 @@ mod tests {
     use ntest::assert_about_eq;
 
     use crate::simulation::test::{
-        ElementCtorFn, ReadByName, SimulationTestBed, TestBed, WriteByName,
+        ElementCtorFn, ReadByName, SimulationTestBed, TestAircraft, TestBed, WriteByName,
     };
     use crate::simulation::InitContext;
 
@@ -3245,22 +3656,12 @@ mod tests {
 
     use super::*;
 
-    struct TestFluid {
-        is_hot: bool,
+    fn nominal_return_temperature() -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(20.)
     }
-    impl TestFluid {
-        fn overheat() -> Self {
-            Self { is_hot: true }
-        }
 
-        fn nominal() -> Self {
-            Self { is_hot: false }
-        }
-    }
-    impl HeatingElement for TestFluid {
-        fn is_overheating(&self) -> bool {
-            self.is_hot
-        }
+    fn hot_return_temperature() -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(130.)
     }
 
     impl SimulationElement for PriorityValve {}
@@ -3477,7 +3878,12 @@ mod tests {
         }));
 
         test_bed.set_update_after_power_distribution(|reservoir, context| {
-            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::nominal())
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_return_temperature(),
+                Power::default(),
+            )
         });
 
         test_bed.fail(FailureType::ReservoirLeak(HydraulicColor::Green));
@@ -3500,7 +3906,12 @@ mod tests {
         }));
 
         test_bed.set_update_after_power_distribution(|reservoir, context| {
-            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::nominal())
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_return_temperature(),
+                Power::default(),
+            )
         });
 
         test_bed.fail(FailureType::ReservoirLeak(HydraulicColor::Green));
@@ -3523,7 +3934,12 @@ mod tests {
         }));
 
         test_bed.set_update_after_power_distribution(|reservoir, context| {
-            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::nominal())
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_return_temperature(),
+                Power::default(),
+            )
         });
 
         let is_low: bool = test_bed.read_by_name("HYD_GREEN_RESERVOIR_LEVEL_IS_LOW");
@@ -3549,7 +3965,12 @@ mod tests {
         }));
 
         test_bed.set_update_after_power_distribution(|reservoir, context| {
-            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::nominal())
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_return_temperature(),
+                Power::default(),
+            )
         });
 
         test_bed.run_multiple_frames(Duration::from_secs(2));
@@ -3576,7 +3997,12 @@ mod tests {
             )
         }))
         .with_update_after_power_distribution(|el, context| {
-            el.update(context, Pressure::new::<psi>(50.), &TestFluid::nominal())
+            el.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_return_temperature(),
+                Power::default(),
+            )
         });
 
         test_bed.write_by_name("PLANE BANK DEGREES", 180.);
@@ -3620,7 +4046,7 @@ mod tests {
     }
 
     #[test]
-    fn reservoir_receiving_heating_fluid_overheats() {
+    fn reservoir_receiving_hot_return_fluid_overheats() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
             reservoir(
                 context,
@@ -3632,23 +4058,30 @@ mod tests {
         }));
 
         test_bed.set_update_after_power_distribution(|reservoir, context| {
-            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::overheat());
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                hot_return_temperature(),
+                Power::default(),
+            );
 
             reservoir.try_take_volume(Volume::new::<gallon>(0.10));
 
             reservoir.add_return_volume(Volume::new::<gallon>(0.10));
         });
 
-        test_bed.run_multiple_frames(Duration::from_secs_f64(
-            Reservoir::HEATING_TIME_CONSTANT_MEAN_S + 4. * Reservoir::HEATING_TIME_CONSTANT_STD_S,
-        ));
+        test_bed.run_multiple_frames(Duration::from_secs(60));
 
         let is_overheating: bool = test_bed.read_by_name("HYD_GREEN_RESERVOIR_OVHT");
         assert!(is_overheating);
+
+        let temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("HYD_GREEN_RESERVOIR_TEMPERATURE");
+        assert!(temperature.get::<degree_celsius>() > 95.);
     }
 
     #[test]
-    fn reservoir_receiving_zero_flow_of_heating_fluid_do_not_overheat() {
+    fn reservoir_receiving_zero_flow_of_hot_return_fluid_do_not_overheat() {
         let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
             reservoir(
                 context,
@@ -3660,17 +4093,251 @@ mod tests {
         }));
 
         test_bed.set_update_after_power_distribution(|reservoir, context| {
-            reservoir.update(context, Pressure::new::<psi>(50.), &TestFluid::overheat());
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                hot_return_temperature(),
+                Power::default(),
+            );
         });
 
-        test_bed.run_multiple_frames(Duration::from_secs_f64(
-            Reservoir::HEATING_TIME_CONSTANT_MEAN_S + 4. * Reservoir::HEATING_TIME_CONSTANT_STD_S,
-        ));
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        let is_overheating: bool = test_bed.read_by_name("HYD_GREEN_RESERVOIR_OVHT");
+        assert!(!is_overheating);
+    }
+
+    #[test]
+    fn reservoir_receiving_case_drain_heat_overheats_without_return_flow() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+            reservoir(
+                context,
+                HydraulicColor::Green,
+                Volume::new::<gallon>(5.),
+                Volume::new::<gallon>(2.),
+                Volume::new::<gallon>(0.5),
+            )
+        }));
+
+        test_bed.set_update_after_power_distribution(|reservoir, context| {
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_return_temperature(),
+                Power::new::<watt>(HydraulicCircuit::OVERHEATING_PUMP_CASE_DRAIN_HEAT_WATT),
+            );
+        });
+
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        let is_overheating: bool = test_bed.read_by_name("HYD_GREEN_RESERVOIR_OVHT");
+        assert!(is_overheating);
+    }
+
+    #[test]
+    fn reservoir_overheat_resets_only_once_cooled_below_reset_temperature() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+            reservoir(
+                context,
+                HydraulicColor::Green,
+                Volume::new::<gallon>(5.),
+                Volume::new::<gallon>(2.),
+                Volume::new::<gallon>(0.5),
+            )
+        }));
+
+        test_bed.set_update_after_power_distribution(|reservoir, context| {
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                hot_return_temperature(),
+                Power::default(),
+            );
+
+            reservoir.try_take_volume(Volume::new::<gallon>(0.10));
+            reservoir.add_return_volume(Volume::new::<gallon>(0.10));
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+        let is_overheating: bool = test_bed.read_by_name("HYD_GREEN_RESERVOIR_OVHT");
+        assert!(is_overheating);
+
+        test_bed.set_update_after_power_distribution(|reservoir, context| {
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                ThermodynamicTemperature::new::<degree_celsius>(90.),
+                Power::default(),
+            );
 
+            reservoir.try_take_volume(Volume::new::<gallon>(0.10));
+            reservoir.add_return_volume(Volume::new::<gallon>(0.10));
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+        let is_overheating: bool = test_bed.read_by_name("HYD_GREEN_RESERVOIR_OVHT");
+        assert!(is_overheating);
+
+        test_bed.set_update_after_power_distribution(|reservoir, context| {
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_return_temperature(),
+                Power::default(),
+            );
+
+            reservoir.try_take_volume(Volume::new::<gallon>(0.10));
+            reservoir.add_return_volume(Volume::new::<gallon>(0.10));
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(60));
         let is_overheating: bool = test_bed.read_by_name("HYD_GREEN_RESERVOIR_OVHT");
         assert!(!is_overheating);
     }
 
+    #[test]
+    fn reservoir_restores_temperature_and_heat_damage_timer_from_snapshot() {
+        fn overheating_reservoir_test_bed() -> SimulationTestBed<TestAircraft<Reservoir>> {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+                reservoir(
+                    context,
+                    HydraulicColor::Green,
+                    Volume::new::<gallon>(5.),
+                    Volume::new::<gallon>(2.),
+                    Volume::new::<gallon>(0.5),
+                )
+            }));
+
+            test_bed.set_update_after_power_distribution(|reservoir, context| {
+                reservoir.update(
+                    context,
+                    Pressure::new::<psi>(50.),
+                    hot_return_temperature(),
+                    Power::default(),
+                );
+
+                reservoir.try_take_volume(Volume::new::<gallon>(0.10));
+                reservoir.add_return_volume(Volume::new::<gallon>(0.10));
+            });
+
+            test_bed
+        }
+
+        let mut test_bed = overheating_reservoir_test_bed();
+        test_bed.run_multiple_frames(Duration::from_secs(60 * 4));
+        assert!(!test_bed.query_element(|r| r.is_damaged()));
+        let snapshot = test_bed.snapshot();
+
+        let mut restored_test_bed = overheating_reservoir_test_bed();
+        assert_eq!(restored_test_bed.restore(&snapshot), Ok(()));
+
+        assert!(restored_test_bed.query_element(|r| r.is_overheating()));
+        assert_eq!(
+            restored_test_bed.query_element(|r| r.temperature()),
+            test_bed.query_element(|r| r.temperature())
+        );
+
+        restored_test_bed.run_multiple_frames(Duration::from_secs(90));
+        assert!(restored_test_bed.query_element(|r| r.is_damaged()));
+    }
+
+    #[test]
+    fn reservoir_fluid_cools_down_to_ambient_temperature() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+            reservoir(
+                context,
+                HydraulicColor::Green,
+                Volume::new::<gallon>(5.),
+                Volume::new::<gallon>(2.),
+                Volume::new::<gallon>(0.5),
+            )
+        }));
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(30.));
+
+        test_bed.set_update_after_power_distribution(|reservoir, context| {
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_return_temperature(),
+                Power::new::<watt>(HydraulicCircuit::OVERHEATING_PUMP_CASE_DRAIN_HEAT_WATT),
+            );
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(30));
+
+        let temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("HYD_GREEN_RESERVOIR_TEMPERATURE");
+        assert!(temperature.get::<degree_celsius>() > 80.);
+
+        test_bed.set_update_after_power_distribution(|reservoir, context| {
+            reservoir.update(
+                context,
+                Pressure::new::<psi>(50.),
+                nominal_return_temperature(),
+                Power::default(),
+            );
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(60 * 60 * 2));
+
+        let temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("HYD_GREEN_RESERVOIR_TEMPERATURE");
+        assert!((temperature.get::<degree_celsius>() - 30.).abs() < 1.);
+    }
+
+    #[test]
+    fn cold_fluid_reduces_flow_factor() {
+        assert_about_eq!(
+            Fluid::viscous_flow_factor_at(ThermodynamicTemperature::new::<degree_celsius>(40.))
+                .get::<ratio>(),
+            1.
+        );
+        assert_about_eq!(
+            Fluid::viscous_flow_factor_at(ThermodynamicTemperature::new::<degree_celsius>(0.))
+                .get::<ratio>(),
+            1.
+        );
+
+        let flow_factor_at_minus_40 =
+            Fluid::viscous_flow_factor_at(ThermodynamicTemperature::new::<degree_celsius>(-40.));
+        let flow_factor_at_minus_55 =
+            Fluid::viscous_flow_factor_at(ThermodynamicTemperature::new::<degree_celsius>(-55.));
+
+        assert!(flow_factor_at_minus_40.get::<ratio>() < 0.8);
+        assert!(flow_factor_at_minus_55 < flow_factor_at_minus_40);
+        assert!(flow_factor_at_minus_55.get::<ratio>() > 0.3);
+    }
+
+    #[test]
+    fn fluid_viscosity_decreases_with_temperature() {
+        let mut last_viscosity = KinematicViscosity::new::<centistokes>(f64::MAX);
+        for temperature in (-55..=100).step_by(5) {
+            let viscosity = Fluid::kinematic_viscosity_at(ThermodynamicTemperature::new::<
+                degree_celsius,
+            >(temperature as f64));
+
+            assert!(viscosity < last_viscosity);
+            last_viscosity = viscosity;
+        }
+    }
+
+    #[test]
+    fn fuel_heat_exchanger_cools_return_fluid_down_to_fuel_temperature_at_most() {
+        let heat_exchanger =
+            FuelHeatExchanger::new(ThermalConductance::new::<watt_per_kelvin>(60.));
+        let fuel_temperature = ThermodynamicTemperature::new::<degree_celsius>(0.);
+
+        let low_flow_outlet = heat_exchanger.outlet_temperature(
+            hot_return_temperature(),
+            VolumeRate::new::<gallon_per_second>(0.01),
+            fuel_temperature,
+        );
+        let high_flow_outlet = heat_exchanger.outlet_temperature(
+            hot_return_temperature(),
+            VolumeRate::new::<gallon_per_second>(1.),
+            fuel_temperature,
+        );
+
+        assert!(low_flow_outlet >= fuel_temperature);
+        assert!(low_flow_outlet < high_flow_outlet);
+        assert!(high_flow_outlet < hot_return_temperature());
+    }
+
     fn section(
         context: &mut InitContext,
         loop_id: HydraulicColor,
@@ -3765,6 +4432,7 @@ mod tests {
             priority_valve,
             Pressure::new::<psi>(1885.),
             Volume::new::<gallon>(0.264),
+            ThermalConductance::new::<watt_per_kelvin>(60.),
         )
     }
 
@@ -23,6 +23,10 @@ use uom::si::{
 };
 
 use nalgebra::Vector3;
+
+mod tyre;
+pub use tyre::{Tyre, TyrePressureIndicationSystem, TyreProperties};
+
 pub trait GearSystemSensors {
     fn is_wheel_id_up_and_locked(&self, wheel_id: GearWheel, lgciu_id: LgciuId) -> bool;
     fn is_wheel_id_down_and_locked(&self, wheel_id: GearWheel, lgciu_id: LgciuId) -> bool;
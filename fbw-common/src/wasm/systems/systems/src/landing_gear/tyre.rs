@@ -0,0 +1,614 @@
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{arinc429::SignStatus, ElectricalBusType, ElectricalBuses},
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, StateReader,
+        StateWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+use uom::si::{
+    angular_velocity::radian_per_second,
+    energy::joule,
+    f64::*,
+    length::meter,
+    pressure::psi,
+    ratio::ratio,
+    specific_heat_capacity::joule_per_kilogram_kelvin,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+    velocity::meter_per_second,
+};
+
+pub struct TyreProperties {
+    radius: Length,
+    heat_capacity: HeatCapacity,
+    inflation_pressure: Pressure,
+}
+impl TyreProperties {
+    /// Specific heat capacity of the tyre and wheel rim taken together
+    const SPECIFIC_HEAT_CAPACITY: f64 = 1100.; // J/kg*K
+
+    /// Creates the properties of a tyre
+    /// ## Parameters
+    /// `mass` - the mass of the tyre and the wheel it is mounted on
+    /// `inflation_pressure` - the nominal (cold) inflation pressure
+    pub fn new(radius: Length, mass: Mass, inflation_pressure: Pressure) -> Self {
+        Self {
+            radius,
+            heat_capacity: mass
+                * SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+                    Self::SPECIFIC_HEAT_CAPACITY,
+                ),
+            inflation_pressure,
+        }
+    }
+
+    pub fn rolling_speed(&self, wheel_speed: AngularVelocity) -> Velocity {
+        Velocity::new::<meter_per_second>(
+            wheel_speed.get::<radian_per_second>().abs() * self.radius.get::<meter>(),
+        )
+    }
+
+    pub fn inflation_pressure(&self) -> Pressure {
+        self.inflation_pressure
+    }
+}
+
+/// Simulates a tyre and the wheel it is mounted on as a single thermal mass.
+///
+/// The tyre heats up by rolling and by heat soaking from the brake. The pressure of the gas
+/// follows its temperature. When the wheel gets too hot, the fusible plugs melt and deflate the
+/// tyre before it can burst.
+pub struct Tyre {
+    temperature_id: VariableIdentifier,
+    pressure_id: VariableIdentifier,
+
+    temperature: ThermodynamicTemperature,
+    inflation_temperature: ThermodynamicTemperature,
+    gas_amount: Ratio,
+    pressure: Pressure,
+    fusible_plug_melted: bool,
+    braking_efficiency: Ratio,
+    initialized: bool,
+
+    burst_failure: Failure,
+    deflation_failure: Failure,
+}
+impl Tyre {
+    /// Heat rejected into the tyre per m/s of rolling speed, W/(m/s)
+    const ROLLING_HEAT_COEFFICIENT: f64 = 600.;
+    /// Thermal conductance between the brake and the wheel, W/K
+    const BRAKE_HEAT_SOAK_CONDUCTANCE: f64 = 25.;
+    /// Thermal conductance towards the ambient air when not moving, W/K
+    const STATIC_COOLING_CONDUCTANCE: f64 = 25.;
+    /// Additional thermal conductance towards the ambient air per m/s of rolling speed, W/K
+    const ROLLING_COOLING_CONDUCTANCE: f64 = 15.;
+
+    const FUSIBLE_PLUG_MELTING_TEMPERATURE_DEGREE_CELSIUS: f64 = 177.;
+    const FUSIBLE_PLUG_DEFLATION_TIME_CONSTANT_S: f64 = 20.;
+    const SLOW_DEFLATION_TIME_CONSTANT_S: f64 = 300.;
+
+    /// Below this fraction of the inflation pressure the braking efficiency reduces, down to
+    /// the minimum efficiency of a fully deflated tyre.
+    const FULL_BRAKING_EFFICIENCY_PRESSURE_RATIO: f64 = 0.5;
+    const DEFLATED_BRAKING_EFFICIENCY: f64 = 0.5;
+
+    const STANDARD_ATMOSPHERIC_PRESSURE_PSI: f64 = 14.696;
+
+    pub fn new(context: &mut InitContext, number: usize) -> Self {
+        Self {
+            temperature_id: context.get_identifier(format!("TYRE_TEMPERATURE_{number}")),
+            pressure_id: context.get_identifier(format!("TYRE_PRESSURE_{number}")),
+
+            temperature: ThermodynamicTemperature::default(),
+            inflation_temperature: ThermodynamicTemperature::default(),
+            gas_amount: Ratio::new::<ratio>(1.),
+            pressure: Pressure::default(),
+            fusible_plug_melted: false,
+            braking_efficiency: Ratio::new::<ratio>(1.),
+            initialized: false,
+
+            burst_failure: Failure::new(FailureType::TyreBurst(number)),
+            deflation_failure: Failure::new(FailureType::TyreDeflation(number)),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        properties: &TyreProperties,
+        brake_temperature: ThermodynamicTemperature,
+        rolling_speed: Velocity,
+    ) {
+        if !self.initialized {
+            self.temperature = context.ambient_temperature();
+            self.inflation_temperature = context.ambient_temperature();
+            self.initialized = true;
+        }
+
+        self.update_temperature(context, properties, brake_temperature, rolling_speed);
+
+        if self.temperature.get::<degree_celsius>()
+            >= Self::FUSIBLE_PLUG_MELTING_TEMPERATURE_DEGREE_CELSIUS
+        {
+            self.fusible_plug_melted = true;
+        }
+
+        self.update_gas_amount(context, properties);
+        self.pressure = Pressure::new::<psi>(
+            (self.absolute_pressure_psi(properties, self.gas_amount)
+                - Self::STANDARD_ATMOSPHERIC_PRESSURE_PSI)
+                .max(0.),
+        );
+        self.update_braking_efficiency(properties);
+    }
+
+    fn update_temperature(
+        &mut self,
+        context: &UpdateContext,
+        properties: &TyreProperties,
+        brake_temperature: ThermodynamicTemperature,
+        rolling_speed: Velocity,
+    ) {
+        let speed = rolling_speed.get::<meter_per_second>();
+        let rolling_heat = Self::ROLLING_HEAT_COEFFICIENT * speed;
+        let brake_heat = Self::BRAKE_HEAT_SOAK_CONDUCTANCE
+            * (brake_temperature.get::<kelvin>() - self.temperature.get::<kelvin>());
+        let cooling = (Self::STATIC_COOLING_CONDUCTANCE
+            + Self::ROLLING_COOLING_CONDUCTANCE * speed)
+            * (self.temperature.get::<kelvin>() - context.ambient_temperature().get::<kelvin>());
+
+        let energy = Energy::new::<joule>(
+            (rolling_heat + brake_heat - cooling) * context.delta_as_secs_f64(),
+        );
+        self.temperature += energy / properties.heat_capacity;
+    }
+
+    fn update_gas_amount(&mut self, context: &UpdateContext, properties: &TyreProperties) {
+        // The tyre cannot deflate below the ambient pressure.
+        let deflated_gas_amount = Self::STANDARD_ATMOSPHERIC_PRESSURE_PSI
+            / self.absolute_pressure_psi(properties, Ratio::new::<ratio>(1.));
+
+        if self.burst_failure.is_active() {
+            self.gas_amount = Ratio::new::<ratio>(deflated_gas_amount);
+        } else if let Some(time_constant) = self.deflation_time_constant() {
+            let alpha = 1. - (-context.delta_as_secs_f64() / time_constant).exp();
+            self.gas_amount += (Ratio::new::<ratio>(deflated_gas_amount) - self.gas_amount) * alpha;
+        }
+    }
+
+    fn deflation_time_constant(&self) -> Option<f64> {
+        if self.fusible_plug_melted {
+            Some(Self::FUSIBLE_PLUG_DEFLATION_TIME_CONSTANT_S)
+        } else if self.deflation_failure.is_active() {
+            Some(Self::SLOW_DEFLATION_TIME_CONSTANT_S)
+        } else {
+            None
+        }
+    }
+
+    fn update_braking_efficiency(&mut self, properties: &TyreProperties) {
+        self.braking_efficiency = if self.burst_failure.is_active() {
+            Ratio::new::<ratio>(0.)
+        } else {
+            let pressure_ratio = (self.pressure / properties.inflation_pressure).get::<ratio>()
+                / Self::FULL_BRAKING_EFFICIENCY_PRESSURE_RATIO;
+
+            Ratio::new::<ratio>(
+                Self::DEFLATED_BRAKING_EFFICIENCY
+                    + (1. - Self::DEFLATED_BRAKING_EFFICIENCY) * pressure_ratio.clamp(0., 1.),
+            )
+        };
+    }
+
+    fn absolute_pressure_psi(&self, properties: &TyreProperties, gas_amount: Ratio) -> f64 {
+        (properties.inflation_pressure.get::<psi>() + Self::STANDARD_ATMOSPHERIC_PRESSURE_PSI)
+            * gas_amount.get::<ratio>()
+            * (self.temperature.get::<kelvin>() / self.inflation_temperature.get::<kelvin>())
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    /// Returns the pressure of the tyre, measured relative to the ambient pressure.
+    pub fn pressure(&self) -> Pressure {
+        self.pressure
+    }
+
+    pub fn fusible_plug_melted(&self) -> bool {
+        self.fusible_plug_melted
+    }
+
+    pub fn is_burst(&self) -> bool {
+        self.burst_failure.is_active()
+    }
+
+    /// Returns the fraction of the brake force which the tyre can transfer to the ground.
+    pub fn braking_efficiency(&self) -> Ratio {
+        self.braking_efficiency
+    }
+}
+impl SimulationElement for Tyre {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.burst_failure.accept(visitor);
+        self.deflation_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.temperature_id, self.temperature);
+        writer.write(&self.pressure_id, self.pressure);
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.temperature);
+        state.write(self.inflation_temperature);
+        state.write(self.gas_amount);
+        state.write(self.fusible_plug_melted);
+        state.write(self.initialized);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.temperature = state.read();
+        self.inflation_temperature = state.read();
+        self.gas_amount = state.read();
+        self.fusible_plug_melted = state.read();
+        self.initialized = state.read();
+    }
+}
+
+/// Tyre Pressure Indication System (TPIS)
+///
+/// Measures the pressure of each tyre and flags tyres of which the pressure dropped below the
+/// low pressure threshold.
+pub struct TyrePressureIndicationSystem<const N: usize> {
+    pressure_ids: [VariableIdentifier; N],
+    low_pressure_ids: [VariableIdentifier; N],
+
+    pressures: [Pressure; N],
+    reference_pressure: Pressure,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+}
+impl<const N: usize> TyrePressureIndicationSystem<N> {
+    const LOW_PRESSURE_RATIO: f64 = 0.9;
+
+    /// Creates a new TPIS
+    /// ## Parameters
+    /// `tyre_numbers` - the numbers of the monitored tyres, in the order they are given on update
+    /// `reference_pressure` - the pressure against which a low pressure is detected
+    pub fn new(
+        context: &mut InitContext,
+        powered_by: ElectricalBusType,
+        tyre_numbers: [usize; N],
+        reference_pressure: Pressure,
+    ) -> Self {
+        Self {
+            pressure_ids: tyre_numbers
+                .map(|number| context.get_identifier(format!("TPIS_TYRE_PRESSURE_{number}"))),
+            low_pressure_ids: tyre_numbers
+                .map(|number| context.get_identifier(format!("TPIS_TYRE_LOW_PRESSURE_{number}"))),
+
+            pressures: [Pressure::default(); N],
+            reference_pressure,
+
+            powered_by,
+            is_powered: false,
+        }
+    }
+
+    pub fn update<'a>(&mut self, tyres: impl IntoIterator<Item = &'a Tyre>) {
+        for (pressure, tyre) in self.pressures.iter_mut().zip(tyres) {
+            *pressure = tyre.pressure();
+        }
+    }
+
+    fn has_low_pressure(&self, pressure: Pressure) -> bool {
+        pressure < self.reference_pressure * Self::LOW_PRESSURE_RATIO
+    }
+
+    pub fn any_tyre_has_low_pressure(&self) -> bool {
+        self.is_powered
+            && self
+                .pressures
+                .iter()
+                .any(|&pressure| self.has_low_pressure(pressure))
+    }
+}
+impl<const N: usize> SimulationElement for TyrePressureIndicationSystem<N> {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        let ssm = if self.is_powered {
+            SignStatus::NormalOperation
+        } else {
+            SignStatus::FailureWarning
+        };
+
+        for ((pressure_id, low_pressure_id), &pressure) in self
+            .pressure_ids
+            .iter()
+            .zip(&self.low_pressure_ids)
+            .zip(&self.pressures)
+        {
+            writer.write_arinc429(pressure_id, pressure, ssm);
+            writer.write_arinc429(low_pressure_id, self.has_low_pressure(pressure), ssm);
+        }
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::{arinc429::Arinc429Word, PotentialOrigin},
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed},
+            Aircraft,
+        },
+    };
+    use std::time::Duration;
+    use uom::si::{angular_velocity::revolution_per_minute, length::inch, mass::kilogram};
+
+    struct TyreTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl TyreTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+
+            test_bed
+        }
+
+        fn and(self) -> Self {
+            self
+        }
+
+        fn brake_at(mut self, temperature: f64) -> Self {
+            self.command(|a| {
+                a.brake_temperature = ThermodynamicTemperature::new::<degree_celsius>(temperature)
+            });
+            self
+        }
+
+        fn wheel_at(mut self, rpm: f64) -> Self {
+            self.command(|a| a.wheel_speed = AngularVelocity::new::<revolution_per_minute>(rpm));
+            self
+        }
+
+        fn tpis_unpowered(mut self) -> Self {
+            self.command(|a| a.powered_source.unpower());
+            self
+        }
+
+        fn run_for(&mut self, duration: Duration) {
+            self.test_bed.run_multiple_frames(duration);
+        }
+
+        fn temperature(&mut self) -> ThermodynamicTemperature {
+            self.read_by_name("TYRE_TEMPERATURE_1")
+        }
+
+        fn pressure(&mut self) -> Pressure {
+            self.read_by_name("TYRE_PRESSURE_1")
+        }
+
+        fn tpis_pressure(&mut self) -> Arinc429Word<Pressure> {
+            self.read_arinc429_by_name("TPIS_TYRE_PRESSURE_1")
+        }
+
+        fn tpis_low_pressure(&mut self) -> Arinc429Word<bool> {
+            self.read_arinc429_by_name("TPIS_TYRE_LOW_PRESSURE_1")
+        }
+
+        fn fusible_plug_melted(&self) -> bool {
+            self.query(|a| a.tyre.fusible_plug_melted())
+        }
+
+        fn braking_efficiency(&self) -> Ratio {
+            self.query(|a| a.tyre.braking_efficiency())
+        }
+    }
+    impl TestBed for TyreTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> TyreTestBed {
+        TyreTestBed::new()
+    }
+
+    struct TestAircraft {
+        powered_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        tyre_properties: TyreProperties,
+        tyre: Tyre,
+        tpis: TyrePressureIndicationSystem<1>,
+        brake_temperature: ThermodynamicTemperature,
+        wheel_speed: AngularVelocity,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                powered_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                tyre_properties: TyreProperties::new(
+                    Length::new::<inch>(23.),
+                    Mass::new::<kilogram>(80.),
+                    Pressure::new::<psi>(200.),
+                ),
+                tyre: Tyre::new(context, 1),
+                tpis: TyrePressureIndicationSystem::new(
+                    context,
+                    ElectricalBusType::DirectCurrent(1),
+                    [1],
+                    Pressure::new::<psi>(200.),
+                ),
+                brake_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+                wheel_speed: AngularVelocity::default(),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.powered_source);
+            electricity.flow(&self.powered_source, &self.dc_1_bus);
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.tyre.update(
+                context,
+                &self.tyre_properties,
+                self.brake_temperature,
+                self.tyre_properties.rolling_speed(self.wheel_speed),
+            );
+            self.tpis.update([&self.tyre]);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.tyre.accept(visitor);
+            self.tpis.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    #[test]
+    fn tyre_starts_at_ambient_temperature_and_inflation_pressure() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        assert!((test_bed.temperature().get::<degree_celsius>() - 15.).abs() < 0.1);
+        assert!((test_bed.pressure().get::<psi>() - 200.).abs() < 0.1);
+    }
+
+    #[test]
+    fn tyre_heats_up_from_hot_brake() {
+        let mut test_bed = test_bed().brake_at(300.);
+        test_bed.run_for(Duration::from_secs(300));
+
+        assert!(test_bed.temperature().get::<degree_celsius>() > 30.);
+    }
+
+    #[test]
+    fn tyre_heats_up_from_rolling() {
+        let mut test_bed = test_bed().wheel_at(400.);
+        test_bed.run_for(Duration::from_secs(300));
+
+        assert!(test_bed.temperature().get::<degree_celsius>() > 25.);
+    }
+
+    #[test]
+    fn tyre_cools_down_to_ambient() {
+        let mut test_bed = test_bed().brake_at(300.);
+        test_bed.run_for(Duration::from_secs(300));
+
+        test_bed = test_bed.brake_at(15.);
+        test_bed.run_for(Duration::from_secs(3 * 3600));
+
+        assert!((test_bed.temperature().get::<degree_celsius>() - 15.).abs() < 1.);
+    }
+
+    #[test]
+    fn pressure_rises_with_temperature() {
+        let mut test_bed = test_bed().brake_at(300.);
+        test_bed.run_for(Duration::from_secs(300));
+
+        assert!(test_bed.pressure().get::<psi>() > 210.);
+        assert!(!test_bed.fusible_plug_melted());
+    }
+
+    #[test]
+    fn fusible_plug_melts_after_rejected_takeoff_and_deflates_tyre() {
+        let mut test_bed = test_bed().brake_at(800.);
+        test_bed.run_for(Duration::from_secs(20 * 60));
+
+        assert!(test_bed.fusible_plug_melted());
+        assert!(test_bed.pressure().get::<psi>() < 5.);
+        assert!(test_bed.tpis_low_pressure().value());
+    }
+
+    #[test]
+    fn burst_tyre_loses_pressure_and_braking() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        test_bed.fail(FailureType::TyreBurst(1));
+        test_bed.run();
+
+        assert!(test_bed.pressure().get::<psi>() < 1.);
+        assert_eq!(test_bed.braking_efficiency(), Ratio::new::<ratio>(0.));
+    }
+
+    #[test]
+    fn deflating_tyre_slowly_loses_pressure() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        test_bed.fail(FailureType::TyreDeflation(1));
+        test_bed.run_for(Duration::from_secs(30));
+
+        let pressure = test_bed.pressure().get::<psi>();
+        assert!(pressure < 200. && pressure > 150.);
+        assert!(!test_bed.tpis_low_pressure().value());
+
+        test_bed.run_for(Duration::from_secs(600));
+
+        assert!(test_bed.tpis_low_pressure().value());
+    }
+
+    #[test]
+    fn deflated_tyre_reduces_braking_efficiency() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        assert_eq!(test_bed.braking_efficiency(), Ratio::new::<ratio>(1.));
+
+        test_bed.fail(FailureType::TyreDeflation(1));
+        test_bed.run_for(Duration::from_secs(1800));
+
+        let efficiency = test_bed.braking_efficiency().get::<ratio>();
+        assert!((0.5..0.6).contains(&efficiency));
+    }
+
+    #[test]
+    fn tpis_indicates_tyre_pressure() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        assert!(test_bed.tpis_pressure().is_normal_operation());
+        assert!((test_bed.tpis_pressure().value().get::<psi>() - 200.).abs() < 0.1);
+        assert!(!test_bed.tpis_low_pressure().value());
+    }
+
+    #[test]
+    fn unpowered_tpis_indicates_failure_warning() {
+        let mut test_bed = test_bed().and().tpis_unpowered();
+        test_bed.run();
+
+        assert!(test_bed.tpis_pressure().is_failure_warning());
+    }
+}
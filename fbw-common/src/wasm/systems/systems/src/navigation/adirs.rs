@@ -11,7 +11,7 @@ use crate::{
     },
     simulation::{
         Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, UpdateContext, Write, Writer,
+        SimulatorWriter, StateReader, StateWriter, UpdateContext, Write, Writer,
     },
 };
 use bitflags::bitflags;
@@ -2588,6 +2588,22 @@ impl SimulationElement for InertialReference {
         self.align_discrete.write_to(writer);
         self.fault_warn_discrete.write_to(writer);
     }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.is_on);
+        state.write_option(self.remaining_align_duration);
+        state.write_option(self.remaining_attitude_initialisation_duration);
+        state.write(self.quick_realign_remaining_available_time);
+        state.write(self.alignment_failed);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.is_on = state.read();
+        self.remaining_align_duration = state.read_option();
+        self.remaining_attitude_initialisation_duration = state.read_option();
+        self.quick_realign_remaining_available_time = state.read();
+        self.alignment_failed = state.read();
+    }
 }
 
 fn remaining_initialisation_duration(
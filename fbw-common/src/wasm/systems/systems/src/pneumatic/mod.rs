@@ -541,6 +541,14 @@ impl TryFrom<f64> for EngineState {
         }
     }
 }
+impl EngineState {
+    pub fn is_fuel_on(&self) -> bool {
+        matches!(
+            self,
+            EngineState::On | EngineState::Starting | EngineState::Restarting
+        )
+    }
+}
 
 pub struct Precooler {
     heat_transfer_coefficient: f64,
@@ -2,7 +2,7 @@ use crate::{
     apu::ApuGenerator,
     electrical::{ElectricalElement, Potential},
     pneumatic::{EngineModeSelector, EngineState, PneumaticValveSignal},
-    simulation::UpdateContext,
+    simulation::{SimulationElement, StateReader, StateWriter, UpdateContext},
 };
 
 use arinc429::Arinc429Word;
@@ -165,7 +165,7 @@ pub enum LgciuId {
     Lgciu2 = 1,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum ProximityDetectorId {
     UplockGearNose1,
     UplockGearNose2,
@@ -240,6 +240,11 @@ pub trait EngineStartState {
     fn engine_mode_selector(&self) -> EngineModeSelector;
 }
 
+pub trait EngineStartControl {
+    fn fuel_is_commanded(&self, engine_number: usize) -> bool;
+    fn ignition_is_commanded(&self, engine_number: usize) -> bool;
+}
+
 pub trait EngineBleedPushbutton<const N: usize> {
     fn engine_bleed_pushbuttons_are_auto(&self) -> [bool; N];
 }
@@ -301,7 +306,7 @@ impl Display for HydraulicColor {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum AirbusEngineDrivenPumpId {
     Edp1a,
     Edp1b,
@@ -331,7 +336,7 @@ impl Display for AirbusEngineDrivenPumpId {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum AirbusElectricPumpId {
     GreenA,
     GreenB,
@@ -520,8 +525,8 @@ pub enum ContactorSignal {
     Close,
 }
 
-#[derive(FromPrimitive)]
-pub(crate) enum FwcFlightPhase {
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive)]
+pub enum FwcFlightPhase {
     ElecPwr = 1,
     FirstEngineStarted = 2,
     FirstEngineTakeOffPower = 3,
@@ -578,6 +583,17 @@ impl DelayedTrueLogicGate {
         self.expression_result && self.delay <= self.true_duration
     }
 }
+impl SimulationElement for DelayedTrueLogicGate {
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write(self.expression_result);
+        state.write(self.true_duration);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.expression_result = state.read();
+        self.true_duration = state.read();
+    }
+}
 
 /// The delay pulse logic gate delays the true result of a given expression by the given amount of time.
 /// True will be set as output when time delay is over for one update only, then false.
@@ -1111,7 +1127,7 @@ impl Resolution for f64 {
     }
 }
 
-#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum FireDetectionZone {
     Engine(usize),
     Apu,
@@ -1128,7 +1144,7 @@ impl Display for FireDetectionZone {
     }
 }
 
-#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum FireDetectionLoopID {
     A,
     B,
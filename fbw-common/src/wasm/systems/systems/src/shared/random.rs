@@ -1,80 +1,119 @@
-#[cfg(any(target_arch = "wasm32", doc))]
-pub use wasm::*;
-
-#[cfg(any(target_arch = "wasm32", doc))]
-mod wasm {
-    use rand::rngs::SmallRng;
-    use rand::{Rng, SeedableRng};
-    use rand_distr::{Distribution, Normal};
-    use std::mem::MaybeUninit;
-    use std::sync::Once;
-
-    static RAND_INIT: Once = Once::new();
-    static mut RAND: MaybeUninit<SmallRng> = MaybeUninit::uninit();
-
-    pub fn random_number() -> u8 {
-        // SAFETY: WASM is single-threaded, and we're not passing references to `RAND` around.
-        RAND_INIT.call_once(|| unsafe {
-            RAND = MaybeUninit::new(SmallRng::from_os_rng());
-        });
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rand_distr::{Distribution, Normal};
+use std::cell::RefCell;
 
-        // SAFETY: `RAND` was initialized above.
-        unsafe { (*RAND.as_mut_ptr()).random() }
-    }
+thread_local! {
+    static RAND: RefCell<StdRng> = RefCell::new(StdRng::from_os_rng());
+}
 
-    pub fn random_from_range(from: f64, to: f64) -> f64 {
-        // SAFETY: WASM is single-threaded, and we're not passing references to `RAND` around.
-        RAND_INIT.call_once(|| unsafe {
-            RAND = MaybeUninit::new(SmallRng::from_os_rng());
-        });
+/// The source of all random numbers used by a simulation.
+///
+/// A source created from a seed produces the same sequence of numbers on every platform,
+/// such that a simulation using it is reproducible.
+pub struct RandomSource {
+    rng: StdRng,
+}
+impl RandomSource {
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
 
-        // SAFETY: `RAND` was initialized above.
-        unsafe { (*RAND.as_mut_ptr()).random_range(from..to) }
+    pub fn from_entropy() -> Self {
+        Self {
+            rng: StdRng::from_os_rng(),
+        }
     }
 
-    // Generates a random number based on normal distribution
-    pub fn random_from_normal_distribution(mean: f64, std_dev: f64) -> f64 {
-        // SAFETY: WASM is single-threaded, and we're not passing references to `RAND` around.
-        RAND_INIT.call_once(|| unsafe {
-            RAND = MaybeUninit::new(SmallRng::from_os_rng());
-        });
+    /// Makes the free random functions of this module draw from this source while
+    /// executing the given function.
+    pub fn install<T>(&mut self, func: impl FnOnce() -> T) -> T {
+        self.swap_installed();
+        let result = (func)();
+        self.swap_installed();
 
-        let normal = Normal::new(mean, std_dev).unwrap();
-        let limit_offset = 4. * std_dev;
+        result
+    }
 
-        // SAFETY: `RAND` was initialized above.
-        unsafe {
-            normal
-                .sample(&mut *RAND.as_mut_ptr())
-                .max(mean - limit_offset)
-                .min(mean + limit_offset)
-        }
+    /// Exchanges this source with the one used by the free random functions. Calling it a
+    /// second time undoes the exchange.
+    pub(crate) fn swap_installed(&mut self) {
+        RAND.with(|rand| std::mem::swap(&mut *rand.borrow_mut(), &mut self.rng));
     }
 }
+impl Default for RandomSource {
+    fn default() -> Self {
+        Self::from_entropy()
+    }
+}
+
+pub fn random_number() -> u8 {
+    RAND.with(|rand| rand.borrow_mut().random())
+}
+
+pub fn random_from_range(from: f64, to: f64) -> f64 {
+    RAND.with(|rand| rand.borrow_mut().random_range(from..to))
+}
+
+/// Random value from normal distribution. Output limited to -4 / +4 sigma
+pub fn random_from_normal_distribution(mean: f64, std_dev: f64) -> f64 {
+    let normal = Normal::new(mean, std_dev).unwrap();
+    let limit_offset = 4. * std_dev;
+
+    RAND.with(|rand| normal.sample(&mut *rand.borrow_mut()))
+        .max(mean - limit_offset)
+        .min(mean + limit_offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-#[cfg(not(any(target_arch = "wasm32", doc)))]
-pub use not_wasm::*;
+    fn draw() -> (u8, f64, f64) {
+        (
+            random_number(),
+            random_from_range(10., 20.),
+            random_from_normal_distribution(0., 1.),
+        )
+    }
 
-#[cfg(not(any(target_arch = "wasm32", doc)))]
-mod not_wasm {
-    use rand::Rng;
-    use rand_distr::{Distribution, Normal};
+    #[test]
+    fn sources_with_the_same_seed_produce_the_same_numbers() {
+        let first = RandomSource::from_seed(42).install(draw);
+        let second = RandomSource::from_seed(42).install(draw);
 
-    pub fn random_number() -> u8 {
-        rand::rng().random()
+        assert_eq!(first, second);
     }
 
-    pub fn random_from_range(from: f64, to: f64) -> f64 {
-        rand::rng().random_range(from..to)
+    #[test]
+    fn source_continues_its_sequence_when_installed_again() {
+        let mut continued = RandomSource::from_seed(42);
+        continued.install(draw);
+        let second_draw = continued.install(draw);
+
+        let mut uninterrupted = RandomSource::from_seed(42);
+        let uninterrupted_second_draw = uninterrupted.install(|| {
+            draw();
+            draw()
+        });
+
+        assert_eq!(second_draw, uninterrupted_second_draw);
     }
 
-    /// Random value from normal distribution. Output limited to -4 / +4 sigma
-    pub fn random_from_normal_distribution(mean: f64, std_dev: f64) -> f64 {
-        let normal = Normal::new(mean, std_dev).unwrap();
-        let limit_offset = 4. * std_dev;
-        normal
-            .sample(&mut rand::rng())
-            .max(mean - limit_offset)
-            .min(mean + limit_offset)
+    #[test]
+    fn installed_source_does_not_affect_numbers_drawn_outside_of_it() {
+        let mut source = RandomSource::from_seed(42);
+        let expected = RandomSource::from_seed(42).install(|| {
+            draw();
+            draw()
+        });
+
+        source.install(draw);
+        draw();
+        let actual = source.install(draw);
+
+        assert_eq!(actual, expected);
     }
 }
@@ -1,16 +1,25 @@
 use std::time::Duration;
 
+mod profiler;
+mod recorder;
+mod runway_condition;
+mod snapshot;
 mod update_context;
 use crate::electrical::{ElectricalElementIdentifier, ElectricalElementIdentifierProvider};
-use crate::shared::{fallback_on_unexpected_discrete, from_bool, ElectricalBusType};
+use crate::shared::{fallback_on_unexpected_discrete, from_bool, ElectricalBusType, RandomSource};
 use crate::{
     electrical::Electricity,
-    failures::FailureType,
+    failures::{FailureScheduler, FailureType},
     shared::arinc429::{from_arinc429, to_arinc429, Arinc429Word, SignStatus},
     shared::arinc825::{from_arinc825, to_arinc825, Arinc825Word},
     shared::{to_bool, ConsumePower, ElectricalBuses, MachNumber, PowerConsumptionReport},
 };
+use profiler::ProfiledAircraft;
+pub use profiler::{profile_section, ProfileStatistics, TickPhase, TickProfiler};
+pub use recorder::*;
+pub use runway_condition::{RunwayCondition, RunwayConditionCode};
 use rustc_hash::FxHashSet;
+pub use snapshot::*;
 use uom::si::mass_rate::kilogram_per_second;
 use uom::si::{
     acceleration::foot_per_second_squared, angle::degree, angular_velocity::revolution_per_minute,
@@ -329,6 +338,23 @@ pub trait SimulationElement {
 
     /// Receives a failure in order to activate or deactivate it.
     fn receive_failure(&mut self, _active_failures: &FxHashSet<FailureType>) {}
+
+    /// Saves the internal state which cannot be derived from the simulator variables, such
+    /// that it can be restored using [`restore_state`]. The number of written values must not
+    /// depend on the current state of the element.
+    ///
+    /// Elements which don't implement it are not part of a [`SimulationSnapshot`] and keep
+    /// their current state when one is restored. Those which publish simulator variables are
+    /// reported as a diagnostic when a snapshot is restored.
+    ///
+    /// [`restore_state`]: #method.restore_state
+    fn save_state(&self, _state: &mut StateWriter) {}
+
+    /// Restores the internal state previously saved by [`save_state`], reading the values in
+    /// the order in which they were written.
+    ///
+    /// [`save_state`]: #method.save_state
+    fn restore_state(&mut self, _state: &mut StateReader) {}
 }
 
 /// Trait for visitors that visit the aircraft's system simulation to call
@@ -360,20 +386,61 @@ pub struct Simulation<T: Aircraft> {
     aircraft: T,
     electricity: Electricity,
     update_context: UpdateContext,
+    random_source: RandomSource,
+    failure_scheduler: FailureScheduler,
+    active_failures: FxHashSet<FailureType>,
+    profiler: Option<TickProfiler>,
 }
 impl<T: Aircraft> Simulation<T> {
     pub fn new<U: FnOnce(&mut InitContext) -> T>(
         start_state: StartState,
         aircraft_ctor_fn: U,
         registry: &mut impl VariableRegistry,
+    ) -> Self {
+        Self::new_with_random_source(
+            start_state,
+            RandomSource::from_entropy(),
+            aircraft_ctor_fn,
+            registry,
+        )
+    }
+
+    /// Creates a simulation of which all random behaviour is derived from the given seed.
+    /// Given the same seed and the same simulator input, every run of the simulation is equal.
+    pub fn new_with_seed<U: FnOnce(&mut InitContext) -> T>(
+        start_state: StartState,
+        seed: u64,
+        aircraft_ctor_fn: U,
+        registry: &mut impl VariableRegistry,
+    ) -> Self {
+        Self::new_with_random_source(
+            start_state,
+            RandomSource::from_seed(seed),
+            aircraft_ctor_fn,
+            registry,
+        )
+    }
+
+    fn new_with_random_source<U: FnOnce(&mut InitContext) -> T>(
+        start_state: StartState,
+        mut random_source: RandomSource,
+        aircraft_ctor_fn: U,
+        registry: &mut impl VariableRegistry,
     ) -> Self {
         let mut electricity = Electricity::new();
         let mut context = InitContext::new(start_state, &mut electricity, registry);
         let update_context = UpdateContext::new_for_simulation(&mut context);
+        let failure_scheduler = FailureScheduler::new(&mut context);
+        let aircraft = random_source.install(|| (aircraft_ctor_fn)(&mut context));
+
         Self {
-            aircraft: (aircraft_ctor_fn)(&mut context),
+            aircraft,
             electricity,
             update_context,
+            random_source,
+            failure_scheduler,
+            active_failures: FxHashSet::default(),
+            profiler: None,
         }
     }
 
@@ -438,38 +505,156 @@ impl<T: Aircraft> Simulation<T> {
         simulation_time: f64,
         reader_writer: &mut impl SimulatorReaderWriter,
     ) {
+        self.random_source.swap_installed();
         self.electricity.pre_tick();
 
         let mut reader = SimulatorReader::new(reader_writer);
         self.update_context
             .update(&mut reader, delta, simulation_time);
 
+        self.failure_scheduler.read(&mut reader);
+        if self.failure_scheduler.update(&self.update_context) {
+            self.distribute_failures();
+        }
+
+        match self.profiler.take() {
+            Some(profiler) => {
+                let (mut profiler, _) = profiler::install(profiler, || {
+                    Self::tick_aircraft(
+                        &mut ProfiledAircraft::new(&mut self.aircraft),
+                        &mut self.electricity,
+                        &self.update_context,
+                        reader_writer,
+                    )
+                });
+                profiler.finish_tick();
+                self.profiler = Some(profiler);
+            }
+            None => Self::tick_aircraft(
+                &mut self.aircraft,
+                &mut self.electricity,
+                &self.update_context,
+                reader_writer,
+            ),
+        }
+
+        self.random_source.swap_installed();
+    }
+
+    fn tick_aircraft<U: Aircraft>(
+        aircraft: &mut U,
+        electricity: &mut Electricity,
+        context: &UpdateContext,
+        reader_writer: &mut impl SimulatorReaderWriter,
+    ) {
+        profiler::enter_phase(TickPhase::Read);
+        let mut reader = SimulatorReader::new(reader_writer);
         let mut visitor = SimulatorToSimulationVisitor::new(&mut reader);
-        self.aircraft.accept(&mut visitor);
+        aircraft.accept(&mut visitor);
 
-        self.aircraft
-            .update_before_power_distribution(&self.update_context, &mut self.electricity);
+        profiler::enter_phase(TickPhase::UpdateBeforePowerDistribution);
+        aircraft.update_before_power_distribution(context, electricity);
 
-        self.aircraft
-            .distribute_electricity(&self.update_context, &self.electricity);
+        profiler::enter_phase(TickPhase::ReceivePower);
+        aircraft.distribute_electricity(context, electricity);
 
-        self.aircraft
-            .update_after_power_distribution(&self.update_context);
-        self.aircraft
-            .consume_electricity(&self.update_context, &mut self.electricity);
-        self.aircraft
-            .report_electricity_consumption(&self.update_context, &self.electricity);
+        profiler::enter_phase(TickPhase::UpdateAfterPowerDistribution);
+        aircraft.update_after_power_distribution(context);
+
+        profiler::enter_phase(TickPhase::ConsumePower);
+        aircraft.consume_electricity(context, electricity);
 
+        profiler::enter_phase(TickPhase::ProcessPowerConsumptionReport);
+        aircraft.report_electricity_consumption(context, electricity);
+
+        profiler::enter_phase(TickPhase::Write);
         let mut writer = SimulatorWriter::new(reader_writer);
         let mut visitor = SimulationToSimulatorVisitor::new(&mut writer);
-        self.aircraft.accept(&mut visitor);
+        aircraft.accept(&mut visitor);
     }
 
+    /// Starts measuring the time spent per element in each tick. See [`TickProfiler`].
+    pub fn enable_profiling(&mut self) {
+        self.profiler.get_or_insert_with(TickProfiler::new);
+    }
+
+    /// Stops measuring time and returns the measurements made so far.
+    pub fn disable_profiling(&mut self) -> Option<TickProfiler> {
+        self.profiler.take()
+    }
+
+    pub fn profiler(&self) -> Option<&TickProfiler> {
+        self.profiler.as_ref()
+    }
+
+    /// Sets the failures which are active in addition to those activated by the
+    /// [`FailureScheduler`].
     pub fn update_active_failures(&mut self, active_failures: FxHashSet<FailureType>) {
+        self.active_failures = active_failures;
+
+        self.random_source.swap_installed();
+        self.distribute_failures();
+        self.random_source.swap_installed();
+    }
+
+    pub fn failure_scheduler(&self) -> &FailureScheduler {
+        &self.failure_scheduler
+    }
+
+    pub fn failure_scheduler_mut(&mut self) -> &mut FailureScheduler {
+        &mut self.failure_scheduler
+    }
+
+    fn distribute_failures(&mut self) {
+        let active_failures = self
+            .active_failures
+            .union(self.failure_scheduler.active_failures())
+            .copied()
+            .collect();
+
         self.aircraft
             .accept(&mut FailureSimulationElementVisitor::new(active_failures));
     }
 
+    /// Captures the internal state of the aircraft, such that the simulation can later be
+    /// rewound to this moment using [`restore`]. Only elements implementing
+    /// [`SimulationElement::save_state`] are captured, see [`SimulationSnapshot`].
+    ///
+    /// [`restore`]: #method.restore
+    pub fn snapshot(&mut self) -> SimulationSnapshot {
+        let mut visitor = SaveStateVisitor::default();
+        self.aircraft.accept(&mut visitor);
+        visitor.visit(self.update_context.runway_condition_mut());
+        visitor.visit(&mut self.failure_scheduler);
+
+        visitor.into_snapshot()
+    }
+
+    /// Restores the internal state of the aircraft captured by [`snapshot`].
+    /// When the snapshot doesn't match the aircraft, an error is returned and the aircraft
+    /// is left untouched. Elements which publish simulator variables but have no snapshot
+    /// support are reported as a diagnostic.
+    ///
+    /// [`snapshot`]: #method.snapshot
+    pub fn restore(&mut self, snapshot: &SimulationSnapshot) -> Result<(), SnapshotError> {
+        let mut layout = SaveStateVisitor::default();
+        self.aircraft.accept(&mut layout);
+        layout.visit(self.update_context.runway_condition_mut());
+        layout.visit(&mut self.failure_scheduler);
+
+        let mut visitor = RestoreStateVisitor::new(snapshot, &layout)?;
+        self.aircraft.accept(&mut visitor);
+        visitor.visit(self.update_context.runway_condition_mut());
+        visitor.visit(&mut self.failure_scheduler);
+        layout.report_elements_without_snapshot_support();
+
+        self.random_source.swap_installed();
+        self.distribute_failures();
+        self.random_source.swap_installed();
+
+        Ok(())
+    }
+
     fn electricity(&self) -> &Electricity {
         &self.electricity
     }
@@ -994,4 +1179,44 @@ mod tests {
             assert!(!context.has_engines_running());
         }
     }
+
+    mod seeded_simulation {
+        use super::*;
+        use crate::shared::random_number;
+        use test::{SimulationTestBed, TestBed};
+
+        struct RandomAircraft {
+            numbers: Vec<u8>,
+        }
+        impl RandomAircraft {
+            fn new(_: &mut InitContext) -> Self {
+                Self {
+                    numbers: vec![random_number()],
+                }
+            }
+        }
+        impl Aircraft for RandomAircraft {
+            fn update_after_power_distribution(&mut self, _: &UpdateContext) {
+                self.numbers.push(random_number());
+            }
+        }
+        impl SimulationElement for RandomAircraft {}
+
+        fn numbers_of_run(seed: u64) -> Vec<u8> {
+            let mut test_bed = SimulationTestBed::new_with_seed(seed, RandomAircraft::new);
+            test_bed.run_multiple_frames(Duration::from_secs(10));
+
+            test_bed.query(|a| a.numbers.clone())
+        }
+
+        #[test]
+        fn runs_with_the_same_seed_are_equal() {
+            assert_eq!(numbers_of_run(1), numbers_of_run(1));
+        }
+
+        #[test]
+        fn runs_with_different_seeds_differ() {
+            assert_ne!(numbers_of_run(1), numbers_of_run(2));
+        }
+    }
 }
@@ -0,0 +1,454 @@
+use std::{
+    any::type_name,
+    cell::RefCell,
+    fmt::{Display, Formatter},
+    io,
+    time::{Duration, Instant},
+};
+
+use rustc_hash::FxHashMap;
+
+use super::{Aircraft, SimulationElement, SimulationElementVisitor, UpdateContext};
+use crate::electrical::Electricity;
+
+thread_local! {
+    static PROFILER: RefCell<Option<TickProfiler>> = const { RefCell::new(None) };
+}
+
+/// The steps of a [`Simulation`] tick in which time is measured.
+///
+/// [`Simulation`]: super::Simulation
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TickPhase {
+    Read,
+    UpdateBeforePowerDistribution,
+    ReceivePower,
+    UpdateAfterPowerDistribution,
+    ConsumePower,
+    ProcessPowerConsumptionReport,
+    Write,
+}
+impl Display for TickPhase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            TickPhase::Read => "read",
+            TickPhase::UpdateBeforePowerDistribution => "update_before_power_distribution",
+            TickPhase::ReceivePower => "receive_power",
+            TickPhase::UpdateAfterPowerDistribution => "update_after_power_distribution",
+            TickPhase::ConsumePower => "consume_power",
+            TickPhase::ProcessPowerConsumptionReport => "process_power_consumption_report",
+            TickPhase::Write => "write",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// Durations are grouped into buckets which grow by this factor, bounding the error of
+/// percentiles to the same factor while keeping the memory use independent of the tick count.
+const BUCKET_GROWTH_FACTOR: f64 = 1.05;
+
+#[derive(Default)]
+struct DurationHistogram {
+    buckets: Vec<u32>,
+    count: u32,
+    total: Duration,
+    max: Duration,
+}
+impl DurationHistogram {
+    fn add(&mut self, duration: Duration) {
+        let bucket = Self::bucket_of(duration);
+        if bucket >= self.buckets.len() {
+            self.buckets.resize(bucket + 1, 0);
+        }
+
+        self.buckets[bucket] += 1;
+        self.count += 1;
+        self.total += duration;
+        self.max = self.max.max(duration);
+    }
+
+    fn bucket_of(duration: Duration) -> usize {
+        let nanos = duration.as_nanos() as f64;
+        if nanos < 1. {
+            0
+        } else {
+            nanos.log(BUCKET_GROWTH_FACTOR).floor() as usize + 1
+        }
+    }
+
+    fn upper_bound_of(bucket: usize) -> Duration {
+        if bucket == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_nanos(BUCKET_GROWTH_FACTOR.powi(bucket as i32).ceil() as u64)
+        }
+    }
+
+    fn percentile(&self, percentile: f64) -> Duration {
+        let rank = ((self.count as f64) * percentile / 100.).ceil().max(1.) as u32;
+
+        let mut seen = 0;
+        for (bucket, count) in self.buckets.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return Self::upper_bound_of(bucket).min(self.max);
+            }
+        }
+
+        self.max
+    }
+}
+
+/// Aggregated timing of an element or section within a phase of the tick.
+pub struct ProfileStatistics {
+    phase: TickPhase,
+    name: &'static str,
+    ticks: u32,
+    total: Duration,
+    mean: Duration,
+    p99: Duration,
+    max: Duration,
+}
+impl ProfileStatistics {
+    pub fn phase(&self) -> TickPhase {
+        self.phase
+    }
+
+    /// The type name of the element, or the name of the section.
+    pub fn name(&self) -> &str {
+        self.name
+    }
+
+    /// The number of ticks in which time was measured.
+    pub fn ticks(&self) -> u32 {
+        self.ticks
+    }
+
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+
+    pub fn mean(&self) -> Duration {
+        self.mean
+    }
+
+    pub fn p99(&self) -> Duration {
+        self.p99
+    }
+
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+}
+
+/// Measures the wall time spent per element and phase of each [`Simulation`] tick.
+///
+/// The time spent in multiple instances of an element type within a single tick is summed,
+/// such that each tick results in one measurement per element type and phase.
+///
+/// [`Simulation`]: super::Simulation
+#[derive(Default)]
+pub struct TickProfiler {
+    phase: Option<TickPhase>,
+    current_tick: FxHashMap<(TickPhase, &'static str), Duration>,
+    histograms: FxHashMap<(TickPhase, &'static str), DurationHistogram>,
+    ticks: u32,
+}
+impl TickProfiler {
+    const DUMP_HEADER: &'static str = "phase,name,ticks,total_us,mean_us,p99_us,max_us";
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ticks(&self) -> u32 {
+        self.ticks
+    }
+
+    /// The statistics of all measured elements and sections, ordered by phase and then by
+    /// descending total time.
+    pub fn statistics(&self) -> Vec<ProfileStatistics> {
+        let mut statistics: Vec<_> = self
+            .histograms
+            .iter()
+            .map(|(&(phase, name), histogram)| ProfileStatistics {
+                phase,
+                name,
+                ticks: histogram.count,
+                total: histogram.total,
+                mean: histogram.total / histogram.count,
+                p99: histogram.percentile(99.),
+                max: histogram.max,
+            })
+            .collect();
+
+        statistics.sort_by(|a, b| {
+            a.phase
+                .cmp(&b.phase)
+                .then(b.total.cmp(&a.total))
+                .then(a.name.cmp(b.name))
+        });
+
+        statistics
+    }
+
+    /// Writes the statistics as comma separated values, with durations in microseconds.
+    pub fn dump(&self, mut writer: impl io::Write) -> io::Result<()> {
+        writeln!(writer, "{}", Self::DUMP_HEADER)?;
+        for statistic in self.statistics() {
+            writeln!(
+                writer,
+                "{},\"{}\",{},{:.3},{:.3},{:.3},{:.3}",
+                statistic.phase,
+                statistic.name,
+                statistic.ticks,
+                Self::micros(statistic.total),
+                Self::micros(statistic.mean),
+                Self::micros(statistic.p99),
+                Self::micros(statistic.max),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    pub fn reset(&mut self) {
+        self.current_tick.clear();
+        self.histograms.clear();
+        self.ticks = 0;
+    }
+
+    fn micros(duration: Duration) -> f64 {
+        duration.as_secs_f64() * 1_000_000.
+    }
+
+    fn record(&mut self, name: &'static str, duration: Duration) {
+        if let Some(phase) = self.phase {
+            *self.current_tick.entry((phase, name)).or_default() += duration;
+        }
+    }
+
+    pub(super) fn finish_tick(&mut self) {
+        for (key, duration) in self.current_tick.drain() {
+            self.histograms.entry(key).or_default().add(duration);
+        }
+
+        self.phase = None;
+        self.ticks += 1;
+    }
+}
+
+/// Makes the profiler measure the time spent within the given function.
+pub(super) fn install<T>(profiler: TickProfiler, func: impl FnOnce() -> T) -> (TickProfiler, T) {
+    PROFILER.with(|installed| *installed.borrow_mut() = Some(profiler));
+    let result = (func)();
+    let profiler = PROFILER.with(|installed| installed.borrow_mut().take().unwrap());
+
+    (profiler, result)
+}
+
+/// Attributes the time spent hereafter to the given phase.
+pub(super) fn enter_phase(phase: TickPhase) {
+    PROFILER.with(|installed| {
+        if let Some(profiler) = installed.borrow_mut().as_mut() {
+            profiler.phase = Some(phase);
+        }
+    });
+}
+
+fn is_profiling() -> bool {
+    PROFILER.with(|installed| installed.borrow().is_some())
+}
+
+fn record(name: &'static str, duration: Duration) {
+    PROFILER.with(|installed| {
+        if let Some(profiler) = installed.borrow_mut().as_mut() {
+            profiler.record(name, duration);
+        }
+    });
+}
+
+/// Measures the time spent in a section of an aircraft's update functions, when the
+/// simulation is being profiled. Use it to break down the time of the update phases,
+/// which are otherwise only measured for the aircraft as a whole.
+pub fn profile_section<T>(name: &'static str, func: impl FnOnce() -> T) -> T {
+    if !is_profiling() {
+        return (func)();
+    }
+
+    let start = Instant::now();
+    let result = (func)();
+    record(name, start.elapsed());
+
+    result
+}
+
+struct ProfilingVisitor<'a, V> {
+    visitor: &'a mut V,
+}
+impl<V: SimulationElementVisitor> SimulationElementVisitor for ProfilingVisitor<'_, V> {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        let start = Instant::now();
+        self.visitor.visit(visited);
+        record(type_name::<T>(), start.elapsed());
+    }
+}
+
+/// Wraps an aircraft such that the time spent in each visited element is measured.
+///
+/// The electricity functions are those of the [`Aircraft`] trait, as the visits
+/// of an aircraft's own implementation cannot be measured.
+pub(super) struct ProfiledAircraft<'a, T> {
+    aircraft: &'a mut T,
+}
+impl<'a, T: Aircraft> ProfiledAircraft<'a, T> {
+    pub(super) fn new(aircraft: &'a mut T) -> Self {
+        Self { aircraft }
+    }
+}
+impl<T: Aircraft> Aircraft for ProfiledAircraft<'_, T> {
+    fn update_before_power_distribution(
+        &mut self,
+        context: &UpdateContext,
+        electricity: &mut Electricity,
+    ) {
+        profile_section(type_name::<T>(), || {
+            self.aircraft
+                .update_before_power_distribution(context, electricity)
+        });
+    }
+
+    fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+        profile_section(type_name::<T>(), || {
+            self.aircraft.update_after_power_distribution(context)
+        });
+    }
+}
+impl<T: Aircraft> SimulationElement for ProfiledAircraft<'_, T> {
+    fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
+        self.aircraft.accept(&mut ProfilingVisitor { visitor });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::test::{SimulationTestBed, TestBed};
+    use ntest::assert_about_eq;
+    use std::thread;
+
+    #[derive(Default)]
+    struct SlowElement {}
+    impl SimulationElement for SlowElement {
+        fn write(&self, _: &mut crate::simulation::SimulatorWriter) {
+            thread::sleep(Duration::from_millis(2));
+        }
+    }
+
+    #[derive(Default)]
+    struct TestAircraft {
+        slow_elements: [SlowElement; 2],
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
+            profile_section("sleep", || thread::sleep(Duration::from_millis(1)));
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.slow_elements[0].accept(visitor);
+            self.slow_elements[1].accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn profiled_test_bed(ticks: usize) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(|_| TestAircraft::default());
+        test_bed.enable_profiling();
+        for _ in 0..ticks {
+            test_bed.run();
+        }
+
+        test_bed
+    }
+
+    fn statistic<'a>(
+        statistics: &'a [ProfileStatistics],
+        phase: TickPhase,
+        name: &str,
+    ) -> &'a ProfileStatistics {
+        statistics
+            .iter()
+            .find(|statistic| statistic.phase() == phase && statistic.name() == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn measures_each_element_type_once_per_tick() {
+        let test_bed = profiled_test_bed(3);
+        let statistics = test_bed.profiler().unwrap().statistics();
+
+        let slow = statistic(&statistics, TickPhase::Write, type_name::<SlowElement>());
+        assert_eq!(slow.ticks(), 3);
+        assert!(slow.mean() >= Duration::from_millis(4));
+        assert!(slow.max() >= slow.p99());
+        assert!(slow.p99() >= slow.mean() / 2);
+    }
+
+    #[test]
+    fn measures_the_aircraft_update() {
+        let test_bed = profiled_test_bed(1);
+        let statistics = test_bed.profiler().unwrap().statistics();
+
+        let update = statistic(
+            &statistics,
+            TickPhase::UpdateAfterPowerDistribution,
+            type_name::<TestAircraft>(),
+        );
+        let section = statistic(
+            &statistics,
+            TickPhase::UpdateAfterPowerDistribution,
+            "sleep",
+        );
+        assert!(section.total() >= Duration::from_millis(1));
+        assert!(update.total() >= section.total());
+    }
+
+    #[test]
+    fn does_not_measure_when_not_enabled() {
+        let mut test_bed = SimulationTestBed::new(|_| TestAircraft::default());
+        test_bed.run();
+
+        assert!(test_bed.profiler().is_none());
+    }
+
+    #[test]
+    fn dumps_statistics_as_csv() {
+        let test_bed = profiled_test_bed(1);
+        let mut dump = vec![];
+        test_bed.profiler().unwrap().dump(&mut dump).unwrap();
+        let dump = String::from_utf8(dump).unwrap();
+
+        let mut lines = dump.lines();
+        assert_eq!(lines.next(), Some(TickProfiler::DUMP_HEADER));
+        assert!(lines
+            .any(|line| line.starts_with(&format!("write,\"{}\",1,", type_name::<SlowElement>()))));
+    }
+
+    #[test]
+    fn histogram_percentiles_are_within_bucket_growth() {
+        let mut histogram = DurationHistogram::default();
+        for micros in 1..=1000 {
+            histogram.add(Duration::from_micros(micros));
+        }
+
+        assert_about_eq!(
+            histogram.percentile(99.).as_secs_f64() * 1_000_000.,
+            990.,
+            990. * (BUCKET_GROWTH_FACTOR - 1.)
+        );
+        assert_eq!(histogram.percentile(100.), Duration::from_micros(1000));
+    }
+}
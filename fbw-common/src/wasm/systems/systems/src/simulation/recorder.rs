@@ -0,0 +1,518 @@
+use super::{SimulatorReaderWriter, VariableIdentifier};
+use rustc_hash::FxHashMap;
+use std::io::{self, Read, Write};
+
+const BINARY_MAGIC: &[u8; 8] = b"FBWVTRC\0";
+const BINARY_FORMAT_VERSION: u32 = 1;
+const BINARY_VARIABLE_RECORD: u8 = 1;
+const BINARY_TICK_RECORD: u8 = 2;
+/// Variable names are far shorter than this. Longer names indicate a corrupted trace.
+const BINARY_MAX_VARIABLE_NAME_LENGTH: usize = 1024;
+/// Limits the memory preallocated based on the access count of a tick record.
+const BINARY_MAX_PREALLOCATED_ACCESSES: usize = 4096;
+const CSV_HEADER: &str = "time,access,name,value";
+
+/// Resolves the identifiers handed out by a [`VariableRegistry`] back into variable names.
+///
+/// [`VariableRegistry`]: trait.VariableRegistry.html
+pub trait VariableNames {
+    fn variable_name(&self, identifier: &VariableIdentifier) -> Option<&str>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VariableAccess {
+    Read,
+    Write,
+}
+impl VariableAccess {
+    fn to_csv(self) -> &'static str {
+        match self {
+            Self::Read => "R",
+            Self::Write => "W",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VariableTraceFormat {
+    /// One line per access, containing the simulation time, the kind of access, the
+    /// variable name and the value.
+    Csv,
+    /// Each variable name is stored once, after which accesses refer to it by index.
+    /// Use [`BinaryVariableTraceReader`] to read it.
+    ///
+    /// [`BinaryVariableTraceReader`]: struct.BinaryVariableTraceReader.html
+    Binary,
+}
+
+/// Records every variable read from and written to the simulator, by name and value, such that
+/// the input and output of the systems simulation can be compared between builds.
+///
+/// Accesses are buffered while the simulation ticks using a reader writer created by [`wrap`].
+/// They are resolved into names and written to the output by [`finish_tick`].
+///
+/// [`wrap`]: #method.wrap
+/// [`finish_tick`]: #method.finish_tick
+pub struct VariableTraceRecorder<W: Write> {
+    output: W,
+    format: VariableTraceFormat,
+    name_prefixes: Vec<String>,
+    header_written: bool,
+    accesses: Vec<(VariableAccess, VariableIdentifier, f64)>,
+    /// The index into `names` of each variable seen so far, or `None` when it is filtered out.
+    variables: FxHashMap<VariableIdentifier, Option<usize>>,
+    names: Vec<String>,
+}
+impl<W: Write> VariableTraceRecorder<W> {
+    pub fn new(output: W, format: VariableTraceFormat) -> Self {
+        Self {
+            output,
+            format,
+            name_prefixes: vec![],
+            header_written: false,
+            accesses: vec![],
+            variables: FxHashMap::default(),
+            names: vec![],
+        }
+    }
+
+    /// Only records variables of which the name starts with one of the given prefixes.
+    /// Without any prefix, all variables are recorded.
+    pub fn with_name_prefixes<'a>(mut self, prefixes: impl IntoIterator<Item = &'a str>) -> Self {
+        self.name_prefixes
+            .extend(prefixes.into_iter().map(String::from));
+        self
+    }
+
+    /// Wraps the given reader writer such that all accesses going through it are recorded.
+    pub fn wrap<'a, T: SimulatorReaderWriter + ?Sized>(
+        &'a mut self,
+        reader_writer: &'a mut T,
+    ) -> RecordingSimulatorReaderWriter<'a, T, W> {
+        RecordingSimulatorReaderWriter {
+            reader_writer,
+            recorder: self,
+        }
+    }
+
+    fn record(&mut self, access: VariableAccess, identifier: &VariableIdentifier, value: f64) {
+        if !matches!(self.variables.get(identifier), Some(None)) {
+            self.accesses.push((access, *identifier, value));
+        }
+    }
+
+    /// Writes the accesses recorded since the previous tick to the output.
+    pub fn finish_tick(
+        &mut self,
+        simulation_time: f64,
+        names: &impl VariableNames,
+    ) -> io::Result<()> {
+        if !self.header_written {
+            self.write_header()?;
+            self.header_written = true;
+        }
+
+        let mut accesses = std::mem::take(&mut self.accesses);
+        let mut recorded = Vec::with_capacity(accesses.len());
+        for (access, identifier, value) in accesses.drain(..) {
+            if let Some(index) = self.resolve(&identifier, names)? {
+                recorded.push((access, index, value));
+            }
+        }
+        self.accesses = accesses;
+
+        match self.format {
+            VariableTraceFormat::Csv => {
+                for (access, index, value) in recorded {
+                    writeln!(
+                        self.output,
+                        "{},{},{},{}",
+                        simulation_time,
+                        access.to_csv(),
+                        self.names[index],
+                        value
+                    )?;
+                }
+            }
+            VariableTraceFormat::Binary => {
+                self.output.write_all(&[BINARY_TICK_RECORD])?;
+                self.output.write_all(&simulation_time.to_le_bytes())?;
+                self.output
+                    .write_all(&(recorded.len() as u32).to_le_bytes())?;
+                for (access, index, value) in recorded {
+                    self.output
+                        .write_all(&[(access == VariableAccess::Write) as u8])?;
+                    self.output.write_all(&(index as u32).to_le_bytes())?;
+                    self.output.write_all(&value.to_le_bytes())?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        match self.format {
+            VariableTraceFormat::Csv => writeln!(self.output, "{}", CSV_HEADER),
+            VariableTraceFormat::Binary => {
+                self.output.write_all(BINARY_MAGIC)?;
+                self.output.write_all(&BINARY_FORMAT_VERSION.to_le_bytes())
+            }
+        }
+    }
+
+    fn resolve(
+        &mut self,
+        identifier: &VariableIdentifier,
+        names: &impl VariableNames,
+    ) -> io::Result<Option<usize>> {
+        if let Some(index) = self.variables.get(identifier) {
+            return Ok(*index);
+        }
+
+        let name = names.variable_name(identifier).map_or_else(
+            || {
+                format!(
+                    "#{}:{}",
+                    identifier.identifier_type(),
+                    identifier.identifier_index()
+                )
+            },
+            String::from,
+        );
+
+        let index = if self.name_prefixes.is_empty()
+            || self
+                .name_prefixes
+                .iter()
+                .any(|prefix| name.starts_with(prefix.as_str()))
+        {
+            if self.format == VariableTraceFormat::Binary {
+                self.output.write_all(&[BINARY_VARIABLE_RECORD])?;
+                self.output.write_all(&(name.len() as u32).to_le_bytes())?;
+                self.output.write_all(name.as_bytes())?;
+            }
+
+            self.names.push(name);
+            Some(self.names.len() - 1)
+        } else {
+            None
+        };
+
+        self.variables.insert(*identifier, index);
+
+        Ok(index)
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.output.flush()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.output
+    }
+}
+
+/// A [`SimulatorReaderWriter`] which records all accesses into a [`VariableTraceRecorder`]
+/// before passing them on.
+///
+/// [`SimulatorReaderWriter`]: trait.SimulatorReaderWriter.html
+/// [`VariableTraceRecorder`]: struct.VariableTraceRecorder.html
+pub struct RecordingSimulatorReaderWriter<'a, T: SimulatorReaderWriter + ?Sized, W: Write> {
+    reader_writer: &'a mut T,
+    recorder: &'a mut VariableTraceRecorder<W>,
+}
+impl<T: SimulatorReaderWriter + ?Sized, W: Write> SimulatorReaderWriter
+    for RecordingSimulatorReaderWriter<'_, T, W>
+{
+    fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+        let value = self.reader_writer.read(identifier);
+        self.recorder
+            .record(VariableAccess::Read, identifier, value);
+
+        value
+    }
+
+    fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+        self.reader_writer.write(identifier, value);
+        self.recorder
+            .record(VariableAccess::Write, identifier, value);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RecordedAccess {
+    pub access: VariableAccess,
+    /// Index of the variable, to be resolved using [`BinaryVariableTraceReader::variable_name`].
+    ///
+    /// [`BinaryVariableTraceReader::variable_name`]: struct.BinaryVariableTraceReader.html#method.variable_name
+    pub variable: usize,
+    pub value: f64,
+}
+
+/// Reads the ticks of a trace written in the [`VariableTraceFormat::Binary`] format.
+///
+/// [`VariableTraceFormat::Binary`]: enum.VariableTraceFormat.html#variant.Binary
+pub struct BinaryVariableTraceReader<R: Read> {
+    input: R,
+    names: Vec<String>,
+}
+impl<R: Read> BinaryVariableTraceReader<R> {
+    pub fn new(mut input: R) -> io::Result<Self> {
+        let mut magic = [0; 8];
+        input.read_exact(&mut magic)?;
+        if &magic != BINARY_MAGIC {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "not a variable trace",
+            ));
+        }
+
+        let version = read_u32(&mut input)?;
+        if version != BINARY_FORMAT_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unsupported variable trace version {}", version),
+            ));
+        }
+
+        Ok(Self {
+            input,
+            names: vec![],
+        })
+    }
+
+    pub fn variable_name(&self, variable: usize) -> &str {
+        &self.names[variable]
+    }
+
+    /// Reads the next tick, returning its simulation time and accesses, or `None` at the end
+    /// of the trace.
+    pub fn next_tick(&mut self) -> io::Result<Option<(f64, Vec<RecordedAccess>)>> {
+        loop {
+            let mut record_type = [0];
+            if self.input.read(&mut record_type)? == 0 {
+                return Ok(None);
+            }
+
+            match record_type[0] {
+                BINARY_VARIABLE_RECORD => {
+                    let length = read_u32(&mut self.input)? as usize;
+                    if length > BINARY_MAX_VARIABLE_NAME_LENGTH {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("variable name length {} exceeds the maximum", length),
+                        ));
+                    }
+
+                    let mut name = vec![0; length];
+                    self.input.read_exact(&mut name)?;
+                    self.names.push(
+                        String::from_utf8(name)
+                            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?,
+                    );
+                }
+                BINARY_TICK_RECORD => {
+                    let simulation_time = read_f64(&mut self.input)?;
+                    let count = read_u32(&mut self.input)? as usize;
+                    let mut accesses =
+                        Vec::with_capacity(count.min(BINARY_MAX_PREALLOCATED_ACCESSES));
+                    for _ in 0..count {
+                        let mut access = [0];
+                        self.input.read_exact(&mut access)?;
+                        accesses.push(RecordedAccess {
+                            access: if access[0] == 0 {
+                                VariableAccess::Read
+                            } else {
+                                VariableAccess::Write
+                            },
+                            variable: read_u32(&mut self.input)? as usize,
+                            value: read_f64(&mut self.input)?,
+                        });
+                    }
+
+                    return Ok(Some((simulation_time, accesses)));
+                }
+                other => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("unknown variable trace record type {}", other),
+                    ))
+                }
+            }
+        }
+    }
+}
+
+fn read_u32(input: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    input.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_f64(input: &mut impl Read) -> io::Result<f64> {
+    let mut bytes = [0; 8];
+    input.read_exact(&mut bytes)?;
+    Ok(f64::from_le_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct TestVariables {
+        names: FxHashMap<VariableIdentifier, String>,
+        values: FxHashMap<VariableIdentifier, f64>,
+        next_identifier: VariableIdentifier,
+    }
+    impl TestVariables {
+        fn add(&mut self, name: &str, value: f64) -> VariableIdentifier {
+            let identifier = self.next_identifier;
+            self.next_identifier = identifier.next();
+            self.names.insert(identifier, name.to_owned());
+            self.values.insert(identifier, value);
+
+            identifier
+        }
+    }
+    impl VariableNames for TestVariables {
+        fn variable_name(&self, identifier: &VariableIdentifier) -> Option<&str> {
+            self.names.get(identifier).map(String::as_str)
+        }
+    }
+    impl SimulatorReaderWriter for TestVariables {
+        fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+            self.values[identifier]
+        }
+
+        fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+            self.values.insert(*identifier, value);
+        }
+    }
+
+    fn record_two_ticks(recorder: &mut VariableTraceRecorder<Vec<u8>>) {
+        let mut variables = TestVariables::default();
+        let hyd = variables.add("A32NX_HYD_GREEN_PRESSURE", 3000.);
+        let elec = variables.add("A32NX_ELEC_AC_1_BUS_IS_POWERED", 1.);
+
+        let mut reader_writer = recorder.wrap(&mut variables);
+        reader_writer.read(&hyd);
+        reader_writer.write(&elec, 0.);
+        recorder.finish_tick(10.5, &variables).unwrap();
+
+        let mut reader_writer = recorder.wrap(&mut variables);
+        reader_writer.write(&hyd, 2500.);
+        recorder.finish_tick(11., &variables).unwrap();
+    }
+
+    #[test]
+    fn writes_each_access_as_a_csv_line() {
+        let mut recorder = VariableTraceRecorder::new(vec![], VariableTraceFormat::Csv);
+        record_two_ticks(&mut recorder);
+
+        assert_eq!(
+            String::from_utf8(recorder.into_inner()).unwrap(),
+            "time,access,name,value\n\
+             10.5,R,A32NX_HYD_GREEN_PRESSURE,3000\n\
+             10.5,W,A32NX_ELEC_AC_1_BUS_IS_POWERED,0\n\
+             11,W,A32NX_HYD_GREEN_PRESSURE,2500\n"
+        );
+    }
+
+    #[test]
+    fn only_records_variables_matching_a_name_prefix() {
+        let mut recorder = VariableTraceRecorder::new(vec![], VariableTraceFormat::Csv)
+            .with_name_prefixes(["A32NX_HYD_"]);
+        record_two_ticks(&mut recorder);
+
+        assert_eq!(
+            String::from_utf8(recorder.into_inner()).unwrap(),
+            "time,access,name,value\n\
+             10.5,R,A32NX_HYD_GREEN_PRESSURE,3000\n\
+             11,W,A32NX_HYD_GREEN_PRESSURE,2500\n"
+        );
+    }
+
+    #[test]
+    fn unknown_variables_are_named_after_their_identifier() {
+        let mut recorder = VariableTraceRecorder::new(vec![], VariableTraceFormat::Csv);
+        let mut variables = TestVariables::default();
+        let identifier = variables.add("", 1.);
+        variables.names.clear();
+
+        recorder.wrap(&mut variables).read(&identifier);
+        recorder.finish_tick(1., &variables).unwrap();
+
+        assert_eq!(
+            String::from_utf8(recorder.into_inner()).unwrap(),
+            "time,access,name,value\n1,R,#0:0,1\n"
+        );
+    }
+
+    #[test]
+    fn binary_trace_can_be_read_back() {
+        let mut recorder = VariableTraceRecorder::new(vec![], VariableTraceFormat::Binary);
+        record_two_ticks(&mut recorder);
+        let trace = recorder.into_inner();
+        let mut reader = BinaryVariableTraceReader::new(&trace[..]).unwrap();
+
+        let (time, accesses) = reader.next_tick().unwrap().unwrap();
+        assert_eq!(time, 10.5);
+        assert_eq!(
+            accesses,
+            vec![
+                RecordedAccess {
+                    access: VariableAccess::Read,
+                    variable: 0,
+                    value: 3000.
+                },
+                RecordedAccess {
+                    access: VariableAccess::Write,
+                    variable: 1,
+                    value: 0.
+                }
+            ]
+        );
+        assert_eq!(reader.variable_name(0), "A32NX_HYD_GREEN_PRESSURE");
+        assert_eq!(reader.variable_name(1), "A32NX_ELEC_AC_1_BUS_IS_POWERED");
+
+        let (time, accesses) = reader.next_tick().unwrap().unwrap();
+        assert_eq!(time, 11.);
+        assert_eq!(accesses.len(), 1);
+
+        assert!(reader.next_tick().unwrap().is_none());
+    }
+
+    #[test]
+    fn binary_trace_rejects_excessive_variable_name_length() {
+        let mut trace = BINARY_MAGIC.to_vec();
+        trace.extend_from_slice(&BINARY_FORMAT_VERSION.to_le_bytes());
+        trace.push(BINARY_VARIABLE_RECORD);
+        trace.extend_from_slice(&u32::MAX.to_le_bytes());
+        let mut reader = BinaryVariableTraceReader::new(&trace[..]).unwrap();
+
+        assert_eq!(
+            reader.next_tick().unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn binary_trace_is_more_compact_than_csv() {
+        let mut csv = VariableTraceRecorder::new(vec![], VariableTraceFormat::Csv);
+        let mut binary = VariableTraceRecorder::new(vec![], VariableTraceFormat::Binary);
+        for recorder in [&mut csv, &mut binary] {
+            for _ in 0..10 {
+                record_two_ticks(recorder);
+            }
+        }
+
+        assert!(binary.into_inner().len() < csv.into_inner().len());
+    }
+
+    #[test]
+    fn rejects_input_which_is_not_a_binary_trace() {
+        assert!(BinaryVariableTraceReader::new(&b"time,access,name,value\n"[..]).is_err());
+    }
+}
@@ -0,0 +1,604 @@
+use std::time::Duration;
+use uom::si::{
+    f64::*, length::millimeter, ratio::ratio, thermodynamic_temperature::degree_celsius,
+    velocity::knot,
+};
+
+use super::{SimulationElement, StateReader, StateWriter, SurfaceTypeMsfs};
+
+/// Runway condition code as used in the global reporting format (RWYCC)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RunwayConditionCode {
+    Nil = 0,
+    Poor = 1,
+    MediumToPoor = 2,
+    Medium = 3,
+    GoodToMedium = 4,
+    Good = 5,
+    Dry = 6,
+}
+impl RunwayConditionCode {
+    /// Ratio of the dry surface friction still available with this runway condition
+    fn friction_ratio(&self) -> f64 {
+        match self {
+            RunwayConditionCode::Dry => 1.,
+            RunwayConditionCode::Good => 0.6,
+            RunwayConditionCode::GoodToMedium => 0.45,
+            RunwayConditionCode::Medium => 0.35,
+            RunwayConditionCode::MediumToPoor => 0.3,
+            RunwayConditionCode::Poor => 0.15,
+            RunwayConditionCode::Nil => 0.06,
+        }
+    }
+}
+
+/// Estimates the contamination of the surface the aircraft is on from the weather.
+///
+/// Rain builds up a water layer that drains away once it stops raining. Below freezing,
+/// precipitation builds up a snow layer instead, which melts into water once temperature rises
+/// above freezing. Contaminant type and depth are then turned into a runway condition code and a
+/// friction coefficient available to the tyres.
+///
+/// Contaminant only builds up while on ground, on the surface the aircraft sits on. It is cleared
+/// once airborne or when moving onto another surface type, so that precipitation met in flight
+/// or on another surface does not contaminate the one the aircraft is now on.
+#[derive(Clone, Copy, Debug)]
+pub struct RunwayCondition {
+    surface: Option<SurfaceTypeMsfs>,
+    water_depth: Length,
+    snow_depth: Length,
+    code: RunwayConditionCode,
+    friction_coefficient: Ratio,
+}
+impl RunwayCondition {
+    const FREEZING_TEMPERATURE_DEGREE_CELSIUS: f64 = 0.;
+    const COLD_SNOW_TEMPERATURE_DEGREE_CELSIUS: f64 = -15.;
+
+    // Snow layer is roughly ten times deeper than the water it is made of
+    const SNOW_TO_WATER_DEPTH_RATIO: f64 = 10.;
+
+    const WATER_DRAINING_TIME_CONSTANT: Duration = Duration::from_secs(20 * 60);
+    const SNOW_MELTING_RATE_MM_PER_HOUR_PER_DEGREE: f64 = 2.;
+
+    // Runway considered wet as soon as it is raining or a thin water layer is left
+    const MIN_PRECIPITATION_FOR_WET_MM_PER_HOUR: f64 = 0.1;
+    const MIN_WATER_DEPTH_FOR_WET_MM: f64 = 0.1;
+    const MIN_CONTAMINANT_DEPTH_FOR_STANDING_MM: f64 = 3.;
+
+    const MAX_WATER_DEPTH_MM: f64 = 15.;
+    const MAX_SNOW_DEPTH_MM: f64 = 300.;
+
+    const SPEED_FOR_FULL_BRAKING_LIMIT_KNOT: f64 = 20.;
+
+    pub fn new() -> Self {
+        Self {
+            surface: None,
+            water_depth: Length::default(),
+            snow_depth: Length::default(),
+            code: RunwayConditionCode::Dry,
+            friction_coefficient: Ratio::new::<ratio>(Self::dry_friction_coefficient(
+                SurfaceTypeMsfs::Asphalt,
+            )),
+        }
+    }
+
+    /// ## Parameters
+    /// `precipitation_rate` - precipitation height as water per hour
+    pub fn update(
+        &mut self,
+        delta: Duration,
+        is_on_ground: bool,
+        surface: SurfaceTypeMsfs,
+        precipitation_rate: Length,
+        ambient_temperature: ThermodynamicTemperature,
+    ) {
+        let surface_in_contact = is_on_ground.then_some(surface);
+        if self.surface != surface_in_contact {
+            self.surface = surface_in_contact;
+            self.water_depth = Length::default();
+            self.snow_depth = Length::default();
+        }
+
+        if !is_on_ground {
+            self.code = RunwayConditionCode::Dry;
+            self.friction_coefficient =
+                Ratio::new::<ratio>(Self::dry_friction_coefficient(surface));
+            return;
+        }
+
+        let hours = delta.as_secs_f64() / 3600.;
+        let precipitation_mm_per_hour = precipitation_rate.get::<millimeter>().max(0.);
+        let temperature = ambient_temperature.get::<degree_celsius>();
+
+        let mut water_depth_mm = self.water_depth.get::<millimeter>();
+        let mut snow_depth_mm = self.snow_depth.get::<millimeter>();
+
+        if temperature > Self::FREEZING_TEMPERATURE_DEGREE_CELSIUS {
+            water_depth_mm += precipitation_mm_per_hour * hours;
+
+            let melted_snow_mm = (Self::SNOW_MELTING_RATE_MM_PER_HOUR_PER_DEGREE
+                * (temperature - Self::FREEZING_TEMPERATURE_DEGREE_CELSIUS)
+                * hours)
+                .min(snow_depth_mm);
+            snow_depth_mm -= melted_snow_mm;
+            water_depth_mm += melted_snow_mm / Self::SNOW_TO_WATER_DEPTH_RATIO;
+        } else {
+            snow_depth_mm += precipitation_mm_per_hour * hours * Self::SNOW_TO_WATER_DEPTH_RATIO;
+        }
+
+        water_depth_mm *=
+            (-delta.as_secs_f64() / Self::WATER_DRAINING_TIME_CONSTANT.as_secs_f64()).exp();
+
+        self.water_depth =
+            Length::new::<millimeter>(water_depth_mm.clamp(0., Self::MAX_WATER_DEPTH_MM));
+        self.snow_depth =
+            Length::new::<millimeter>(snow_depth_mm.clamp(0., Self::MAX_SNOW_DEPTH_MM));
+
+        self.code = self.condition_code(surface, precipitation_mm_per_hour, temperature);
+        self.friction_coefficient = Ratio::new::<ratio>(
+            Self::dry_friction_coefficient(surface) * self.code.friction_ratio(),
+        );
+    }
+
+    fn condition_code(
+        &self,
+        surface: SurfaceTypeMsfs,
+        precipitation_mm_per_hour: f64,
+        temperature: f64,
+    ) -> RunwayConditionCode {
+        let water_depth_mm = self.water_depth.get::<millimeter>();
+        let snow_depth_mm = self.snow_depth.get::<millimeter>();
+        let is_freezing = temperature <= Self::FREEZING_TEMPERATURE_DEGREE_CELSIUS;
+        let is_wet = precipitation_mm_per_hour > Self::MIN_PRECIPITATION_FOR_WET_MM_PER_HOUR
+            || water_depth_mm > Self::MIN_WATER_DEPTH_FOR_WET_MM;
+
+        match surface {
+            SurfaceTypeMsfs::WaterFsx
+            | SurfaceTypeMsfs::Ocean
+            | SurfaceTypeMsfs::Water
+            | SurfaceTypeMsfs::Pond
+            | SurfaceTypeMsfs::Lake
+            | SurfaceTypeMsfs::River
+            | SurfaceTypeMsfs::WasteWater => RunwayConditionCode::Nil,
+            SurfaceTypeMsfs::Ice if is_wet => RunwayConditionCode::Nil,
+            SurfaceTypeMsfs::Ice => RunwayConditionCode::Poor,
+            _ if is_freezing && water_depth_mm > Self::MIN_WATER_DEPTH_FOR_WET_MM => {
+                // Water left on the runway froze
+                RunwayConditionCode::Poor
+            }
+            _ if snow_depth_mm > Self::MIN_CONTAMINANT_DEPTH_FOR_STANDING_MM && !is_freezing => {
+                // Melting snow turns into slush
+                RunwayConditionCode::MediumToPoor
+            }
+            SurfaceTypeMsfs::Snow if temperature < Self::COLD_SNOW_TEMPERATURE_DEGREE_CELSIUS => {
+                RunwayConditionCode::GoodToMedium
+            }
+            SurfaceTypeMsfs::Snow => RunwayConditionCode::Medium,
+            _ if snow_depth_mm > Self::MIN_CONTAMINANT_DEPTH_FOR_STANDING_MM => {
+                if temperature < Self::COLD_SNOW_TEMPERATURE_DEGREE_CELSIUS {
+                    RunwayConditionCode::GoodToMedium
+                } else {
+                    RunwayConditionCode::Medium
+                }
+            }
+            _ if water_depth_mm > Self::MIN_CONTAMINANT_DEPTH_FOR_STANDING_MM => {
+                RunwayConditionCode::MediumToPoor
+            }
+            _ if is_wet || snow_depth_mm > 0. => RunwayConditionCode::Good,
+            _ => RunwayConditionCode::Dry,
+        }
+    }
+
+    /// Best friction coefficient a tyre can get from the bare, dry surface. Snow, ice and water
+    /// surfaces are considered as a paved surface under their contaminant.
+    fn dry_friction_coefficient(surface: SurfaceTypeMsfs) -> f64 {
+        match surface {
+            SurfaceTypeMsfs::Concrete
+            | SurfaceTypeMsfs::Asphalt
+            | SurfaceTypeMsfs::Tarmac
+            | SurfaceTypeMsfs::Bituminus
+            | SurfaceTypeMsfs::Macadam
+            | SurfaceTypeMsfs::Brick
+            | SurfaceTypeMsfs::Urban
+            | SurfaceTypeMsfs::Paint
+            | SurfaceTypeMsfs::SteelMats
+            | SurfaceTypeMsfs::Planks
+            | SurfaceTypeMsfs::OilTreated
+            | SurfaceTypeMsfs::Snow
+            | SurfaceTypeMsfs::Ice
+            | SurfaceTypeMsfs::WaterFsx
+            | SurfaceTypeMsfs::Ocean
+            | SurfaceTypeMsfs::Water
+            | SurfaceTypeMsfs::Pond
+            | SurfaceTypeMsfs::Lake
+            | SurfaceTypeMsfs::River
+            | SurfaceTypeMsfs::WasteWater => 0.8,
+            SurfaceTypeMsfs::Dirt
+            | SurfaceTypeMsfs::Gravel
+            | SurfaceTypeMsfs::Coral
+            | SurfaceTypeMsfs::Shale
+            | SurfaceTypeMsfs::HardTurf => 0.5,
+            SurfaceTypeMsfs::Grass
+            | SurfaceTypeMsfs::GrassBumpy
+            | SurfaceTypeMsfs::ShortGrass
+            | SurfaceTypeMsfs::LongGrass
+            | SurfaceTypeMsfs::Forest
+            | SurfaceTypeMsfs::Sand
+            | SurfaceTypeMsfs::WrightFlyerTrack => 0.4,
+            SurfaceTypeMsfs::Unknown => 0.6,
+        }
+    }
+
+    pub fn code(&self) -> RunwayConditionCode {
+        self.code
+    }
+
+    /// Best friction coefficient a tyre can get from the surface in its current condition
+    pub fn friction_coefficient(&self) -> Ratio {
+        self.friction_coefficient
+    }
+
+    /// Ratio of the braking capacity of a dry runway which is still available
+    pub fn braking_capacity(&self) -> Ratio {
+        Ratio::new::<ratio>(self.code.friction_ratio())
+    }
+
+    /// Highest brake force ratio the surface can take at this ground speed. Contaminated runways
+    /// cannot provide as much braking as a dry one. The limit fades in with speed so that parked
+    /// aircraft still hold on brakes.
+    pub fn max_braking_force(&self, ground_speed: Velocity) -> Ratio {
+        let speed_ratio =
+            (ground_speed.get::<knot>().abs() / Self::SPEED_FOR_FULL_BRAKING_LIMIT_KNOT).min(1.);
+
+        Ratio::new::<ratio>(1.) - (Ratio::new::<ratio>(1.) - self.braking_capacity()) * speed_ratio
+    }
+
+    pub fn water_depth(&self) -> Length {
+        self.water_depth
+    }
+
+    pub fn snow_depth(&self) -> Length {
+        self.snow_depth
+    }
+}
+impl SimulationElement for RunwayCondition {
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write_option(self.surface.map(|surface| surface as u32));
+        state.write(self.water_depth);
+        state.write(self.snow_depth);
+    }
+
+    fn restore_state(&mut self, state: &mut StateReader) {
+        self.surface = state
+            .read_option::<u32>()
+            .and_then(|surface| SurfaceTypeMsfs::try_from(surface as f64).ok());
+        self.water_depth = state.read();
+        self.snow_depth = state.read();
+    }
+}
+impl Default for RunwayCondition {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(
+        condition: &mut RunwayCondition,
+        duration: Duration,
+        surface: SurfaceTypeMsfs,
+        precipitation_mm_per_hour: f64,
+        temperature_celsius: f64,
+    ) {
+        run_with_ground(
+            condition,
+            duration,
+            true,
+            surface,
+            precipitation_mm_per_hour,
+            temperature_celsius,
+        );
+    }
+
+    fn run_with_ground(
+        condition: &mut RunwayCondition,
+        duration: Duration,
+        is_on_ground: bool,
+        surface: SurfaceTypeMsfs,
+        precipitation_mm_per_hour: f64,
+        temperature_celsius: f64,
+    ) {
+        for _ in 0..duration.as_secs() {
+            condition.update(
+                Duration::from_secs(1),
+                is_on_ground,
+                surface,
+                Length::new::<millimeter>(precipitation_mm_per_hour),
+                ThermodynamicTemperature::new::<degree_celsius>(temperature_celsius),
+            );
+        }
+    }
+
+    #[test]
+    fn dry_runway_without_precipitation() {
+        let mut condition = RunwayCondition::new();
+        run(
+            &mut condition,
+            Duration::from_secs(60),
+            SurfaceTypeMsfs::Asphalt,
+            0.,
+            15.,
+        );
+
+        assert_eq!(condition.code(), RunwayConditionCode::Dry);
+        assert!((condition.friction_coefficient().get::<ratio>() - 0.8).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn runway_wet_as_soon_as_it_rains() {
+        let mut condition = RunwayCondition::new();
+        run(
+            &mut condition,
+            Duration::from_secs(1),
+            SurfaceTypeMsfs::Asphalt,
+            2.,
+            15.,
+        );
+
+        assert_eq!(condition.code(), RunwayConditionCode::Good);
+    }
+
+    #[test]
+    fn heavy_rain_builds_up_standing_water() {
+        let mut condition = RunwayCondition::new();
+        run(
+            &mut condition,
+            Duration::from_secs(30 * 60),
+            SurfaceTypeMsfs::Asphalt,
+            20.,
+            15.,
+        );
+
+        assert!(condition.water_depth() > Length::new::<millimeter>(3.));
+        assert_eq!(condition.code(), RunwayConditionCode::MediumToPoor);
+    }
+
+    #[test]
+    fn standing_water_drains_after_rain() {
+        let mut condition = RunwayCondition::new();
+        run(
+            &mut condition,
+            Duration::from_secs(30 * 60),
+            SurfaceTypeMsfs::Asphalt,
+            20.,
+            15.,
+        );
+        run(
+            &mut condition,
+            Duration::from_secs(3 * 3600),
+            SurfaceTypeMsfs::Asphalt,
+            0.,
+            15.,
+        );
+
+        assert_eq!(condition.code(), RunwayConditionCode::Dry);
+    }
+
+    #[test]
+    fn snow_builds_up_below_freezing() {
+        let mut condition = RunwayCondition::new();
+        run(
+            &mut condition,
+            Duration::from_secs(30 * 60),
+            SurfaceTypeMsfs::Asphalt,
+            2.,
+            -5.,
+        );
+
+        assert!(condition.snow_depth() > Length::new::<millimeter>(3.));
+        assert_eq!(condition.code(), RunwayConditionCode::Medium);
+    }
+
+    #[test]
+    fn cold_snow_gives_better_braking_than_warm_snow() {
+        let mut cold = RunwayCondition::new();
+        run(
+            &mut cold,
+            Duration::from_secs(30 * 60),
+            SurfaceTypeMsfs::Asphalt,
+            2.,
+            -20.,
+        );
+
+        let mut warm = RunwayCondition::new();
+        run(
+            &mut warm,
+            Duration::from_secs(30 * 60),
+            SurfaceTypeMsfs::Asphalt,
+            2.,
+            -5.,
+        );
+
+        assert!(cold.friction_coefficient() > warm.friction_coefficient());
+    }
+
+    #[test]
+    fn snow_melts_into_slush_above_freezing() {
+        let mut condition = RunwayCondition::new();
+        run(
+            &mut condition,
+            Duration::from_secs(30 * 60),
+            SurfaceTypeMsfs::Asphalt,
+            2.,
+            -5.,
+        );
+        run(
+            &mut condition,
+            Duration::from_secs(60),
+            SurfaceTypeMsfs::Asphalt,
+            0.,
+            2.,
+        );
+
+        assert_eq!(condition.code(), RunwayConditionCode::MediumToPoor);
+    }
+
+    #[test]
+    fn water_freezes_into_ice() {
+        let mut condition = RunwayCondition::new();
+        run(
+            &mut condition,
+            Duration::from_secs(10 * 60),
+            SurfaceTypeMsfs::Asphalt,
+            5.,
+            5.,
+        );
+        run(
+            &mut condition,
+            Duration::from_secs(60),
+            SurfaceTypeMsfs::Asphalt,
+            0.,
+            -5.,
+        );
+
+        assert_eq!(condition.code(), RunwayConditionCode::Poor);
+    }
+
+    #[test]
+    fn ice_is_worse_than_dry_asphalt() {
+        let mut condition = RunwayCondition::new();
+        run(
+            &mut condition,
+            Duration::from_secs(1),
+            SurfaceTypeMsfs::Ice,
+            0.,
+            -5.,
+        );
+
+        assert_eq!(condition.code(), RunwayConditionCode::Poor);
+        assert!(condition.friction_coefficient().get::<ratio>() < 0.2);
+    }
+
+    #[test]
+    fn grass_has_less_friction_than_asphalt() {
+        let mut grass = RunwayCondition::new();
+        run(
+            &mut grass,
+            Duration::from_secs(1),
+            SurfaceTypeMsfs::Grass,
+            0.,
+            15.,
+        );
+
+        let mut asphalt = RunwayCondition::new();
+        run(
+            &mut asphalt,
+            Duration::from_secs(1),
+            SurfaceTypeMsfs::Asphalt,
+            0.,
+            15.,
+        );
+
+        assert!(grass.friction_coefficient() < asphalt.friction_coefficient());
+    }
+
+    #[test]
+    fn braking_limit_on_contaminated_runway_fades_in_with_speed() {
+        let mut condition = RunwayCondition::new();
+        run(
+            &mut condition,
+            Duration::from_secs(30 * 60),
+            SurfaceTypeMsfs::Asphalt,
+            20.,
+            15.,
+        );
+
+        assert!(
+            (condition
+                .max_braking_force(Velocity::new::<knot>(0.))
+                .get::<ratio>()
+                - 1.)
+                .abs()
+                < f64::EPSILON
+        );
+        assert!(
+            (condition.max_braking_force(Velocity::new::<knot>(100.))
+                - condition.braking_capacity())
+            .get::<ratio>()
+            .abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn flying_through_precipitation_lands_on_a_dry_runway() {
+        let mut condition = RunwayCondition::new();
+        run_with_ground(
+            &mut condition,
+            Duration::from_secs(30 * 60),
+            false,
+            SurfaceTypeMsfs::Asphalt,
+            20.,
+            -30.,
+        );
+        run(
+            &mut condition,
+            Duration::from_secs(1),
+            SurfaceTypeMsfs::Asphalt,
+            0.,
+            15.,
+        );
+
+        assert_eq!(condition.snow_depth(), Length::default());
+        assert_eq!(condition.water_depth(), Length::default());
+        assert_eq!(condition.code(), RunwayConditionCode::Dry);
+    }
+
+    #[test]
+    fn contamination_is_cleared_when_taking_off() {
+        let mut condition = RunwayCondition::new();
+        run(
+            &mut condition,
+            Duration::from_secs(30 * 60),
+            SurfaceTypeMsfs::Asphalt,
+            20.,
+            15.,
+        );
+        run_with_ground(
+            &mut condition,
+            Duration::from_secs(1),
+            false,
+            SurfaceTypeMsfs::Asphalt,
+            0.,
+            15.,
+        );
+
+        assert_eq!(condition.water_depth(), Length::default());
+        assert_eq!(condition.code(), RunwayConditionCode::Dry);
+    }
+
+    #[test]
+    fn contamination_is_cleared_when_the_surface_changes() {
+        let mut condition = RunwayCondition::new();
+        run(
+            &mut condition,
+            Duration::from_secs(30 * 60),
+            SurfaceTypeMsfs::Asphalt,
+            2.,
+            -5.,
+        );
+        run(
+            &mut condition,
+            Duration::from_secs(1),
+            SurfaceTypeMsfs::Concrete,
+            0.,
+            -5.,
+        );
+
+        assert_eq!(condition.snow_depth(), Length::default());
+        assert_eq!(condition.code(), RunwayConditionCode::Dry);
+    }
+}
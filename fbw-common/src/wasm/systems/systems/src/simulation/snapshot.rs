@@ -0,0 +1,737 @@
+use std::{
+    any::type_name,
+    collections::BTreeSet,
+    fmt::{Display, Formatter},
+    marker::PhantomData,
+    time::Duration,
+};
+use uom::si::{Dimension, Quantity, Units};
+
+use super::{
+    SimulationElement, SimulationElementVisitor, SimulatorReaderWriter, SimulatorWriter,
+    VariableIdentifier,
+};
+use crate::shared::report_diagnostic;
+
+/// The version of the binary snapshot format. Increment it whenever the encoding changes.
+pub const SIMULATION_SNAPSHOT_FORMAT_VERSION: u32 = 2;
+
+const SNAPSHOT_MAGIC: &[u8; 8] = b"FBWSNAP\0";
+
+/// A value which can be stored in a [`SimulationSnapshot`].
+///
+/// Quantities are stored in their base unit, thus restoring them is lossless.
+pub trait StateValue: Copy {
+    fn into_state(self) -> f64;
+    fn from_state(value: f64) -> Self;
+}
+
+impl<D, U> StateValue for Quantity<D, U, f64>
+where
+    D: Dimension + ?Sized,
+    U: Units<f64> + ?Sized,
+{
+    fn into_state(self) -> f64 {
+        self.value
+    }
+
+    fn from_state(value: f64) -> Self {
+        Quantity {
+            dimension: PhantomData,
+            units: PhantomData,
+            value,
+        }
+    }
+}
+
+impl StateValue for f64 {
+    fn into_state(self) -> f64 {
+        self
+    }
+
+    fn from_state(value: f64) -> Self {
+        value
+    }
+}
+
+impl StateValue for bool {
+    fn into_state(self) -> f64 {
+        if self {
+            1.
+        } else {
+            0.
+        }
+    }
+
+    fn from_state(value: f64) -> Self {
+        value != 0.
+    }
+}
+
+impl StateValue for Duration {
+    fn into_state(self) -> f64 {
+        self.as_secs_f64()
+    }
+
+    fn from_state(value: f64) -> Self {
+        Duration::try_from_secs_f64(value).unwrap_or_default()
+    }
+}
+
+macro_rules! state_value_as {
+    ($t: ty) => {
+        impl StateValue for $t {
+            fn into_state(self) -> f64 {
+                self as f64
+            }
+
+            fn from_state(value: f64) -> Self {
+                value as $t
+            }
+        }
+    };
+}
+
+state_value_as!(u8);
+state_value_as!(u32);
+state_value_as!(usize);
+state_value_as!(i32);
+
+/// FNV-1a hash of the type names of an element and of the values it stores. Type names are
+/// only stable within a build, which matches the snapshot restrictions.
+struct LayoutSignature(u64);
+impl LayoutSignature {
+    fn new(element: &str) -> Self {
+        let mut signature = Self(0xcbf2_9ce4_8422_2325);
+        signature.add(element);
+
+        signature
+    }
+
+    fn add(&mut self, name: &str) {
+        for byte in name.bytes().chain(std::iter::once(0)) {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// Collects the internal state of a [`SimulationElement`].
+/// See [`SimulationElement::save_state`].
+pub struct StateWriter {
+    values: Vec<f64>,
+    signature: LayoutSignature,
+}
+impl StateWriter {
+    pub fn write<T: StateValue>(&mut self, value: T) {
+        self.signature.add(type_name::<T>());
+        self.values.push(value.into_state());
+    }
+
+    /// Writes an optional value. The value is always stored, such that the number of stored
+    /// values doesn't depend on the state of the element.
+    pub fn write_option<T: StateValue>(&mut self, value: Option<T>) {
+        self.write(value.is_some());
+        self.signature.add(type_name::<T>());
+        self.values.push(value.map_or(0., StateValue::into_state));
+    }
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+}
+impl Default for StateWriter {
+    fn default() -> Self {
+        Self {
+            values: Vec::new(),
+            signature: LayoutSignature::new(""),
+        }
+    }
+}
+
+/// Provides a [`SimulationElement`] with its previously saved state.
+/// See [`SimulationElement::restore_state`].
+pub struct StateReader<'a> {
+    values: &'a [f64],
+    position: usize,
+}
+impl<'a> StateReader<'a> {
+    fn new(values: &'a [f64]) -> Self {
+        Self {
+            values,
+            position: 0,
+        }
+    }
+
+    /// Reads the next value. Values are read in the same order as they were written.
+    pub fn read<T: StateValue>(&mut self) -> T {
+        let value = self.values.get(self.position).copied().unwrap_or_default();
+        self.position += 1;
+
+        T::from_state(value)
+    }
+
+    pub fn read_option<T: StateValue>(&mut self) -> Option<T> {
+        let is_some: bool = self.read();
+        let value: T = self.read();
+
+        is_some.then_some(value)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SnapshotError {
+    InvalidFormat,
+    UnsupportedVersion(u32),
+    ElementCountMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    ElementStateMismatch {
+        element_index: usize,
+        expected: usize,
+        actual: usize,
+    },
+    ElementLayoutMismatch {
+        element_index: usize,
+        element: &'static str,
+    },
+}
+
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidFormat => write!(f, "The data is not a simulation snapshot"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "Snapshot format version {} is not supported (expected {})",
+                version, SIMULATION_SNAPSHOT_FORMAT_VERSION
+            ),
+            Self::ElementCountMismatch { expected, actual } => write!(
+                f,
+                "The aircraft contains {} elements, while the snapshot contains {}",
+                expected, actual
+            ),
+            Self::ElementStateMismatch {
+                element_index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Element {} stores {} values, while the snapshot contains {}",
+                element_index, expected, actual
+            ),
+            Self::ElementLayoutMismatch {
+                element_index,
+                element,
+            } => write!(
+                f,
+                "Element {} ({}) stores values of other types than the snapshot contains",
+                element_index, element
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+/// The internal state of the [`SimulationElement`]s of an aircraft at a given moment.
+///
+/// A snapshot is not a complete image of the aircraft. Only elements implementing
+/// [`SimulationElement::save_state`] are captured. Restoring a snapshot leaves all other
+/// elements, such as most controllers and their timers, in the state they are in at that moment.
+/// Elements which publish simulator variables without saving any state are reported as a
+/// diagnostic each time a snapshot is restored.
+/// The elements currently covered are:
+/// - battery charge and temperature, and the battery charge limiter overheat protection;
+/// - hydraulic section volume and pressure, accumulator gas and fluid, reservoir level, temperature
+///   and heat damage, and fluid temperature;
+/// - brake temperature, braked wheel speed and tyre temperature, inflation and plug state, and
+///   anti skid touchdown protection and pressure modulation;
+/// - engine core speed, EGT and oil state, and APU EGT;
+/// - fuel tank temperature;
+/// - cabin and cabin zone air pressure and temperature;
+/// - inertial reference alignment;
+/// - runway water and snow depth, and the progress of scheduled failures, which are not part of
+///   the aircraft and are stored last.
+///
+/// The state of each element is stored in the order in which elements are visited, together with
+/// a signature of the element type and of the types of the values it stores. A snapshot can
+/// therefore only be restored into an aircraft of the same type and build. Restoring into
+/// a different aircraft is detected and rejected before any element is modified.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SimulationSnapshot {
+    elements: Vec<ElementLayout>,
+    values: Vec<f64>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ElementLayout {
+    value_count: u32,
+    signature: u64,
+}
+
+impl SimulationSnapshot {
+    pub fn element_count(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn value_count(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Encodes the snapshot in the versioned binary format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            SNAPSHOT_MAGIC.len() + 8 + self.elements.len() * 12 + self.values.len() * 8,
+        );
+        bytes.extend_from_slice(SNAPSHOT_MAGIC);
+        bytes.extend_from_slice(&SIMULATION_SNAPSHOT_FORMAT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&(self.elements.len() as u32).to_le_bytes());
+        for element in &self.elements {
+            bytes.extend_from_slice(&element.value_count.to_le_bytes());
+            bytes.extend_from_slice(&element.signature.to_le_bytes());
+        }
+        for value in &self.values {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        bytes
+    }
+
+    /// Decodes a snapshot previously encoded by [`SimulationSnapshot::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        let mut decoder = Decoder { bytes };
+        if decoder.take(SNAPSHOT_MAGIC.len())? != SNAPSHOT_MAGIC {
+            return Err(SnapshotError::InvalidFormat);
+        }
+
+        let version = decoder.u32()?;
+        if version != SIMULATION_SNAPSHOT_FORMAT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+
+        let element_count = decoder.u32()? as usize;
+        let elements = (0..element_count)
+            .map(|_| {
+                Ok(ElementLayout {
+                    value_count: decoder.u32()?,
+                    signature: decoder.u64()?,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let value_count: usize = elements.iter().map(|e| e.value_count as usize).sum();
+        let values = (0..value_count)
+            .map(|_| decoder.f64())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if decoder.bytes.is_empty() {
+            Ok(Self { elements, values })
+        } else {
+            Err(SnapshotError::InvalidFormat)
+        }
+    }
+
+    fn verify_layout(&self, layout: &SaveStateVisitor) -> Result<(), SnapshotError> {
+        if self.elements.len() != layout.elements.len() {
+            return Err(SnapshotError::ElementCountMismatch {
+                expected: layout.elements.len(),
+                actual: self.elements.len(),
+            });
+        }
+
+        match layout
+            .elements
+            .iter()
+            .zip(self.elements.iter())
+            .position(|(expected, actual)| expected != actual)
+        {
+            Some(element_index) => {
+                let expected = layout.elements[element_index];
+                let actual = self.elements[element_index];
+                if expected.value_count != actual.value_count {
+                    Err(SnapshotError::ElementStateMismatch {
+                        element_index,
+                        expected: expected.value_count as usize,
+                        actual: actual.value_count as usize,
+                    })
+                } else {
+                    Err(SnapshotError::ElementLayoutMismatch {
+                        element_index,
+                        element: layout.element_names[element_index],
+                    })
+                }
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+}
+impl<'a> Decoder<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], SnapshotError> {
+        if self.bytes.len() < length {
+            return Err(SnapshotError::InvalidFormat);
+        }
+
+        let (taken, remaining) = self.bytes.split_at(length);
+        self.bytes = remaining;
+
+        Ok(taken)
+    }
+
+    fn u32(&mut self) -> Result<u32, SnapshotError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, SnapshotError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn f64(&mut self) -> Result<f64, SnapshotError> {
+        Ok(f64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+}
+
+/// Counts the simulator variables written by an element, without writing them anywhere.
+#[derive(Default)]
+struct WrittenVariableCounter {
+    count: usize,
+}
+impl SimulatorReaderWriter for WrittenVariableCounter {
+    fn read(&mut self, _identifier: &VariableIdentifier) -> f64 {
+        0.
+    }
+
+    fn write(&mut self, _identifier: &VariableIdentifier, _value: f64) {
+        self.count += 1;
+    }
+}
+
+/// Visits aircraft components in order to save their internal state.
+#[derive(Default)]
+pub(super) struct SaveStateVisitor {
+    writer: StateWriter,
+    elements: Vec<ElementLayout>,
+    element_names: Vec<&'static str>,
+    elements_without_snapshot_support: BTreeSet<&'static str>,
+}
+impl SaveStateVisitor {
+    pub(super) fn into_snapshot(self) -> SimulationSnapshot {
+        SimulationSnapshot {
+            elements: self.elements,
+            values: self.writer.values,
+        }
+    }
+
+    /// Reports the elements which publish simulator variables, and thus take part in the
+    /// simulation, but don't save any state. They keep their current state when a snapshot
+    /// is restored.
+    pub(super) fn report_elements_without_snapshot_support(&self) {
+        if !self.elements_without_snapshot_support.is_empty() {
+            report_diagnostic(&format!(
+                "Restored a simulation snapshot. These elements have no snapshot support and keep their current state: {}.",
+                self.elements_without_snapshot_support
+                    .iter()
+                    .copied()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+    }
+}
+impl SimulationElementVisitor for SaveStateVisitor {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        let element = type_name::<T>();
+        let previous_len = self.writer.len();
+        self.writer.signature = LayoutSignature::new(element);
+        visited.save_state(&mut self.writer);
+
+        let value_count = (self.writer.len() - previous_len) as u32;
+        self.elements.push(ElementLayout {
+            value_count,
+            signature: self.writer.signature.0,
+        });
+        self.element_names.push(element);
+
+        if value_count == 0 {
+            let mut counter = WrittenVariableCounter::default();
+            visited.write(&mut SimulatorWriter::new(&mut counter));
+            if counter.count > 0 {
+                self.elements_without_snapshot_support.insert(element);
+            }
+        }
+    }
+}
+
+/// Visits aircraft components in order to restore their internal state.
+/// The snapshot must have been verified against the aircraft beforehand.
+pub(super) struct RestoreStateVisitor<'a> {
+    snapshot: &'a SimulationSnapshot,
+    element_index: usize,
+    position: usize,
+}
+impl<'a> RestoreStateVisitor<'a> {
+    pub(super) fn new(
+        snapshot: &'a SimulationSnapshot,
+        layout: &SaveStateVisitor,
+    ) -> Result<Self, SnapshotError> {
+        snapshot.verify_layout(layout)?;
+
+        Ok(Self {
+            snapshot,
+            element_index: 0,
+            position: 0,
+        })
+    }
+}
+impl SimulationElementVisitor for RestoreStateVisitor<'_> {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        let count = self.snapshot.elements[self.element_index].value_count as usize;
+        if count > 0 {
+            let mut reader =
+                StateReader::new(&self.snapshot.values[self.position..self.position + count]);
+            visited.restore_state(&mut reader);
+        }
+
+        self.element_index += 1;
+        self.position += count;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{SimulationTestBed, TestBed, WriteByName},
+        Aircraft, UpdateContext, Write,
+    };
+    use uom::si::{
+        f64::*, length::millimeter, pressure::psi, thermodynamic_temperature::degree_celsius,
+        volume::gallon,
+    };
+
+    #[derive(Default)]
+    struct StatefulElement {
+        volume: Volume,
+        pressure: Pressure,
+        is_on: bool,
+        remaining: Option<Duration>,
+    }
+    impl SimulationElement for StatefulElement {
+        fn save_state(&self, state: &mut StateWriter) {
+            state.write(self.volume);
+            state.write(self.pressure);
+            state.write(self.is_on);
+            state.write_option(self.remaining);
+        }
+
+        fn restore_state(&mut self, state: &mut StateReader) {
+            self.volume = state.read();
+            self.pressure = state.read();
+            self.is_on = state.read();
+            self.remaining = state.read_option();
+        }
+    }
+
+    #[derive(Default)]
+    struct TestAircraft {
+        first: StatefulElement,
+        second: StatefulElement,
+        has_extra_state: bool,
+        extra_state_is_flag: bool,
+        runway_water_depth: Length,
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.runway_water_depth = context.runway_condition().water_depth();
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.first.accept(visitor);
+            self.second.accept(visitor);
+
+            visitor.visit(self);
+        }
+
+        fn save_state(&self, state: &mut StateWriter) {
+            if self.has_extra_state && self.extra_state_is_flag {
+                state.write(true);
+            } else if self.has_extra_state {
+                state.write(1.);
+            }
+        }
+    }
+
+    fn new_test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(|_| TestAircraft::default())
+    }
+
+    fn modify(aircraft: &mut TestAircraft) {
+        aircraft.first.volume = Volume::new::<gallon>(3.3);
+        aircraft.first.pressure = Pressure::new::<psi>(3000.);
+        aircraft.first.is_on = true;
+        aircraft.second.remaining = Some(Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn restores_saved_state() {
+        let mut test_bed = new_test_bed();
+        test_bed.command(modify);
+        let snapshot = test_bed.snapshot();
+
+        let mut other_test_bed = new_test_bed();
+        assert_eq!(other_test_bed.restore(&snapshot), Ok(()));
+
+        other_test_bed.query(|a| {
+            assert_eq!(a.first.volume, Volume::new::<gallon>(3.3));
+            assert_eq!(a.first.pressure, Pressure::new::<psi>(3000.));
+            assert!(a.first.is_on);
+            assert_eq!(a.first.remaining, None);
+            assert_eq!(a.second.remaining, Some(Duration::from_millis(1500)));
+        });
+    }
+
+    #[test]
+    fn restores_runway_contamination() {
+        let mut test_bed = new_test_bed();
+        test_bed.set_on_ground(true);
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+        test_bed.write_by_name("AMBIENT PRECIP RATE", 20.);
+        test_bed.run_with_delta(Duration::from_secs(30 * 60));
+        let water_depth = test_bed.query(|a| a.runway_water_depth);
+        assert!(water_depth > Length::new::<millimeter>(1.));
+        let snapshot = test_bed.snapshot();
+
+        let mut other_test_bed = new_test_bed();
+        assert_eq!(other_test_bed.restore(&snapshot), Ok(()));
+        other_test_bed.set_on_ground(true);
+        other_test_bed.run_with_delta(Duration::from_secs(0));
+
+        assert_eq!(other_test_bed.query(|a| a.runway_water_depth), water_depth);
+    }
+
+    #[test]
+    fn survives_binary_encoding() {
+        let mut test_bed = new_test_bed();
+        test_bed.command(modify);
+        let snapshot = test_bed.snapshot();
+
+        assert_eq!(
+            SimulationSnapshot::from_bytes(&snapshot.to_bytes()),
+            Ok(snapshot)
+        );
+    }
+
+    #[test]
+    fn rejects_snapshot_of_different_layout_without_modifying_elements() {
+        let mut test_bed = new_test_bed();
+        test_bed.command(modify);
+        let snapshot = test_bed.snapshot();
+
+        let mut other_test_bed = new_test_bed();
+        other_test_bed.command(|a| a.has_extra_state = true);
+
+        assert_eq!(
+            other_test_bed.restore(&snapshot),
+            Err(SnapshotError::ElementStateMismatch {
+                element_index: 2,
+                expected: 1,
+                actual: 0
+            })
+        );
+        assert!(!other_test_bed.query(|a| a.first.is_on));
+    }
+
+    #[test]
+    fn rejects_snapshot_storing_other_value_types_without_modifying_elements() {
+        let mut test_bed = new_test_bed();
+        test_bed.command(|a| {
+            modify(a);
+            a.has_extra_state = true;
+        });
+        let snapshot = test_bed.snapshot();
+
+        let mut other_test_bed = new_test_bed();
+        other_test_bed.command(|a| {
+            a.has_extra_state = true;
+            a.extra_state_is_flag = true;
+        });
+
+        assert_eq!(
+            other_test_bed.restore(&snapshot),
+            Err(SnapshotError::ElementLayoutMismatch {
+                element_index: 2,
+                element: type_name::<TestAircraft>()
+            })
+        );
+        assert!(!other_test_bed.query(|a| a.first.is_on));
+    }
+
+    struct PublishingElement {
+        is_on: bool,
+    }
+    impl SimulationElement for PublishingElement {
+        fn write(&self, writer: &mut SimulatorWriter) {
+            writer.write(&VariableIdentifier::default(), self.is_on);
+        }
+    }
+
+    #[test]
+    fn lists_publishing_elements_without_snapshot_support() {
+        let mut visitor = SaveStateVisitor::default();
+        visitor.visit(&mut StatefulElement::default());
+        visitor.visit(&mut PublishingElement { is_on: true });
+        visitor.visit(&mut TestAircraft::default());
+
+        assert_eq!(
+            visitor
+                .elements_without_snapshot_support
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![type_name::<PublishingElement>()]
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_version() {
+        let mut bytes = SimulationSnapshot::default().to_bytes();
+        bytes[SNAPSHOT_MAGIC.len()] = 0;
+
+        assert_eq!(
+            SimulationSnapshot::from_bytes(&bytes),
+            Err(SnapshotError::UnsupportedVersion(0))
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_data() {
+        let mut test_bed = new_test_bed();
+        let bytes = test_bed.snapshot().to_bytes();
+
+        assert_eq!(
+            SimulationSnapshot::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(SnapshotError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn restores_invalid_duration_as_zero() {
+        for value in [f64::INFINITY, f64::NEG_INFINITY, f64::NAN, -1.] {
+            let values = [value];
+            let mut reader = StateReader::new(&values);
+            assert_eq!(reader.read::<Duration>(), Duration::ZERO);
+        }
+    }
+
+    #[test]
+    fn rejects_other_data() {
+        assert_eq!(
+            SimulationSnapshot::from_bytes(b"SIMULATION TIME,A\n"),
+            Err(SnapshotError::InvalidFormat)
+        );
+    }
+}
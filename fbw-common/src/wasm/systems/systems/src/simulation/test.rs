@@ -1,4 +1,3 @@
-use rand::Rng;
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::{cell::Ref, time::Duration};
 use uom::si::{
@@ -14,14 +13,14 @@ use uom::si::{
 
 use crate::{
     electrical::{Electricity, Potential},
-    failures::FailureType,
-    shared::InternationalStandardAtmosphere,
+    failures::{FailureEvent, FailureType, ScheduledFailure},
+    shared::{random_from_range, InternationalStandardAtmosphere},
 };
 
 use super::{
     Aircraft, Read, Reader, Simulation, SimulationElement, SimulationElementVisitor,
-    SimulationToSimulatorVisitor, SimulatorReaderWriter, SimulatorWriter, UpdateContext, Write,
-    Writer,
+    SimulationSnapshot, SimulationToSimulatorVisitor, SimulatorReaderWriter, SimulatorWriter,
+    SnapshotError, TickProfiler, UpdateContext, Write, Writer,
 };
 use crate::landing_gear::LandingGear;
 use crate::shared::arinc429::{from_arinc429, to_arinc429, Arinc429Word, SignStatus};
@@ -62,6 +61,26 @@ pub trait TestBed {
         self.test_bed_mut().unfail(failure_type);
     }
 
+    fn schedule_failure(&mut self, failure: ScheduledFailure) {
+        self.test_bed_mut().schedule_failure(failure);
+    }
+
+    fn cancel_scheduled_failure(&mut self, failure_type: FailureType) {
+        self.test_bed_mut().cancel_scheduled_failure(failure_type);
+    }
+
+    fn failure_history(&self) -> &[FailureEvent] {
+        self.test_bed().failure_history()
+    }
+
+    fn snapshot(&mut self) -> SimulationSnapshot {
+        self.test_bed_mut().snapshot()
+    }
+
+    fn restore(&mut self, snapshot: &SimulationSnapshot) -> Result<(), SnapshotError> {
+        self.test_bed_mut().restore(snapshot)
+    }
+
     fn command<V: FnOnce(&mut Self::Aircraft)>(&mut self, func: V) {
         self.test_bed_mut().command(func);
     }
@@ -252,9 +271,34 @@ impl<T: Aircraft> SimulationTestBed<T> {
         aircraft_ctor_fn: U,
     ) -> Self {
         let mut variable_registry = TestVariableRegistry::default();
+        let simulation = Simulation::new(start_state, aircraft_ctor_fn, &mut variable_registry);
+
+        Self::new_with_simulation(simulation, variable_registry)
+    }
+
+    /// Creates an instance of which all random behaviour, including the frame deltas of
+    /// [`run_multiple_frames`], is derived from the given seed.
+    ///
+    /// [`run_multiple_frames`]: #method.run_multiple_frames
+    pub fn new_with_seed<U: FnOnce(&mut InitContext) -> T>(seed: u64, aircraft_ctor_fn: U) -> Self {
+        let mut variable_registry = TestVariableRegistry::default();
+        let simulation = Simulation::new_with_seed(
+            Default::default(),
+            seed,
+            aircraft_ctor_fn,
+            &mut variable_registry,
+        );
+
+        Self::new_with_simulation(simulation, variable_registry)
+    }
+
+    fn new_with_simulation(
+        simulation: Simulation<T>,
+        variable_registry: TestVariableRegistry,
+    ) -> Self {
         let mut test_bed = Self {
             reader_writer: TestReaderWriter::new(),
-            simulation: Simulation::new(start_state, aircraft_ctor_fn, &mut variable_registry),
+            simulation,
             variable_registry,
             failures: FxHashSet::default(),
         };
@@ -301,12 +345,14 @@ impl<T: Aircraft> SimulationTestBed<T> {
     /// [`Aircraft`]: ../trait.Aircraft.html
     /// [`Simulation`]: ../struct.Simulation.html
     pub fn run_multiple_frames(&mut self, delta: Duration) {
-        let mut rng = rand::rng();
-
         let mut executed_duration = Duration::from_secs(0);
         while executed_duration < delta {
             // Randomly set delta for 12 to 200ms, giving a simulated 83 to 5 fps refresh
-            let current_delta = Duration::from_millis(rng.random_range(12..200));
+            let current_delta = Duration::from_millis(
+                self.simulation
+                    .random_source
+                    .install(|| random_from_range(12., 200.)) as u64,
+            );
 
             if executed_duration + current_delta > delta {
                 self.simulation.tick(
@@ -338,6 +384,34 @@ impl<T: Aircraft> SimulationTestBed<T> {
             .update_active_failures(self.failures.clone());
     }
 
+    fn schedule_failure(&mut self, failure: ScheduledFailure) {
+        self.simulation.failure_scheduler_mut().schedule(failure);
+    }
+
+    fn cancel_scheduled_failure(&mut self, failure_type: FailureType) {
+        self.simulation.failure_scheduler_mut().cancel(failure_type);
+    }
+
+    fn failure_history(&self) -> &[FailureEvent] {
+        self.simulation.failure_scheduler().history()
+    }
+
+    pub fn enable_profiling(&mut self) {
+        self.simulation.enable_profiling();
+    }
+
+    pub fn profiler(&self) -> Option<&TickProfiler> {
+        self.simulation.profiler()
+    }
+
+    fn snapshot(&mut self) -> SimulationSnapshot {
+        self.simulation.snapshot()
+    }
+
+    fn restore(&mut self, snapshot: &SimulationSnapshot) -> Result<(), SnapshotError> {
+        self.simulation.restore(snapshot)
+    }
+
     fn aircraft(&self) -> &T {
         self.simulation.aircraft()
     }
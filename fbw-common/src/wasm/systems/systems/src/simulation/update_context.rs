@@ -15,7 +15,7 @@ use uom::si::{
 use super::{Read, Reader, SimulatorReader};
 use crate::{
     shared::{low_pass_filter::LowPassFilter, MachNumber},
-    simulation::{InitContext, VariableIdentifier, Write, Writer},
+    simulation::{InitContext, RunwayCondition, VariableIdentifier, Write, Writer},
 };
 use nalgebra::{Rotation3, Vector3};
 
@@ -52,7 +52,7 @@ impl Attitude {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SurfaceTypeMsfs {
     Concrete = 0,
     Grass = 1,
@@ -296,6 +296,7 @@ pub struct UpdateContext {
     in_cloud: bool,
 
     surface: SurfaceTypeMsfs,
+    runway_condition: RunwayCondition,
 
     rotation_accel: Vector3<AngularAcceleration>,
     rotation_vel: Vector3<AngularVelocity>,
@@ -476,6 +477,7 @@ impl UpdateContext {
             in_cloud: false,
 
             surface: SurfaceTypeMsfs::Asphalt,
+            runway_condition: RunwayCondition::default(),
 
             rotation_accel: Vector3::default(),
             rotation_vel: Vector3::default(),
@@ -576,6 +578,7 @@ impl UpdateContext {
             in_cloud: false,
 
             surface: SurfaceTypeMsfs::Asphalt,
+            runway_condition: RunwayCondition::default(),
 
             rotation_accel: Vector3::default(),
             rotation_vel: Vector3::default(),
@@ -655,6 +658,14 @@ impl UpdateContext {
             SurfaceTypeMsfs::Macadam,
         );
 
+        self.runway_condition.update(
+            delta,
+            self.is_on_ground,
+            self.surface,
+            self.precipitation_rate,
+            self.ambient_temperature,
+        );
+
         self.rotation_accel = Vector3::new(
             AngularAcceleration::new::<radian_per_second_squared>(
                 reader.read(&self.rotation_acc_x_id),
@@ -824,6 +835,14 @@ impl UpdateContext {
         self.surface
     }
 
+    pub fn runway_condition(&self) -> &RunwayCondition {
+        &self.runway_condition
+    }
+
+    pub(super) fn runway_condition_mut(&mut self) -> &mut RunwayCondition {
+        &mut self.runway_condition
+    }
+
     pub fn local_acceleration_without_gravity(&self) -> Vector3<f64> {
         // Gives the local acceleration in plane reference. If msfs local accel is free falling -9.81
         //      then it's locally a up acceleration.
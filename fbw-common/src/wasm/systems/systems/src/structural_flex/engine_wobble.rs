@@ -11,7 +11,7 @@ use crate::{
 
 use nalgebra::Vector3;
 use std::fmt::Debug;
-use uom::si::{acceleration::meter_per_second_squared, f64::*};
+use uom::si::{acceleration::meter_per_second_squared, f64::*, length::meter};
 
 /// Solves a basic mass connected to a static point through a spring damper system
 /// Mass center of gravity position reacting to external accelerations is then used to model engine wobbling movement
@@ -78,6 +78,11 @@ impl EngineFlexPhysics {
         self.update_animation_position();
     }
 
+    /// The displacement of the engine center of gravity from its rest position.
+    pub fn displacement(&self) -> Length {
+        Length::new::<meter>(self.wobble_physics.position().norm())
+    }
+
     fn update_animation_position(&mut self) {
         let cg_position = self.wobble_physics.position();
 
@@ -151,6 +156,10 @@ impl<const N: usize> EnginesFlexiblePhysics<N> {
             }
         }
     }
+
+    pub fn displacements(&self) -> [Length; N] {
+        std::array::from_fn(|index| self.engines_flex[index].displacement())
+    }
 }
 impl SimulationElement for EnginesFlexiblePhysics<4> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -0,0 +1,76 @@
+#[cfg(not(target_arch = "wasm32"))]
+use crate::msfs::legacy::execute_calculator_code;
+#[cfg(target_arch = "wasm32")]
+use msfs::legacy::execute_calculator_code;
+use systems::shared::to_bool;
+
+use crate::{ExecuteOn, MsfsAspectBuilder, Variable};
+use std::error::Error;
+
+/// Cuts the mixture of the simulator engine while the engine core signals a fuel cut off, e.g.
+/// after a flameout or seizure. The mixture lever doesn't exist on the aircraft: the cockpit keeps
+/// it rich unless the engine is cut off, and thus also restores it once the cut off is cleared.
+pub(super) fn engine_fuel_cut_off(
+    engine_count: usize,
+) -> impl FnOnce(&mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
+    move |builder: &mut MsfsAspectBuilder| {
+        for engine_number in 1..=engine_count {
+            builder.on_change(
+                ExecuteOn::PostTick,
+                vec![
+                    Variable::named(&format!("ENGINE_{}_FUEL_CUT_OFF", engine_number)),
+                    Variable::aircraft(
+                        "GENERAL ENG MIXTURE LEVER POSITION",
+                        "Percent",
+                        engine_number,
+                    ),
+                ],
+                Box::new(move |_, new_values| {
+                    let is_cut_off = to_bool(new_values[0]);
+                    let mixture_is_lean = new_values[1] <= 0.;
+
+                    if is_cut_off && !mixture_is_lean {
+                        execute_calculator_code::<()>(&format!(
+                            "0 (>K:MIXTURE{}_SET)",
+                            engine_number
+                        ));
+                    }
+                }),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Sets the simulator engine on fire when the engine core signals a fire, and extinguishes it once
+/// the fire is cleared. Only changes of the fire signal are forwarded, such that the fire
+/// extinguishing of the aircraft can still put out the fire of the simulator engine.
+pub(super) fn engine_fire(
+    engine_count: usize,
+) -> impl FnOnce(&mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
+    move |builder: &mut MsfsAspectBuilder| {
+        for engine_number in 1..=engine_count {
+            builder.on_change(
+                ExecuteOn::PostTick,
+                vec![Variable::named(&format!(
+                    "ENGINE_{}_ON_FIRE",
+                    engine_number
+                ))],
+                Box::new(move |prev_values, new_values| {
+                    let was_on_fire = to_bool(prev_values[0]);
+                    let is_on_fire = to_bool(new_values[0]);
+
+                    if is_on_fire != was_on_fire {
+                        execute_calculator_code::<()>(&format!(
+                            "{} (>A:ENG ON FIRE:{}, Bool)",
+                            is_on_fire as u8, engine_number
+                        ));
+                    }
+                }),
+            );
+        }
+
+        Ok(())
+    }
+}
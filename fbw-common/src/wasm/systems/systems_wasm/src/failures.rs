@@ -1,11 +1,21 @@
+use num_traits::FromPrimitive;
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde::de::{Deserializer, SeqAccess, Visitor};
-use systems::failures::FailureType;
+use serde_json::Value;
+use std::time::Duration;
+use systems::{
+    failures::{FailureTrigger, FailureType, ScheduledFailure},
+    shared::FwcFlightPhase,
+};
+use uom::si::{f64::*, length::foot, velocity::knot};
 
 #[derive(Default)]
 pub(super) struct Failures {
     identifier_to_failure_type: FxHashMap<u64, FailureType>,
     active_failures: Option<FxHashSet<FailureType>>,
+    scheduled_failures: Vec<ScheduledFailure>,
+    cancelled_failures: Vec<FailureType>,
+    published_scheduled_failures: Option<FxHashSet<FailureType>>,
 }
 impl Failures {
     pub(super) fn add_failures(&mut self, failures: impl IntoIterator<Item = (u64, FailureType)>) {
@@ -16,7 +26,11 @@ impl Failures {
         let visitor = FailureIdVisitor(&self.identifier_to_failure_type);
         self.active_failures =
             match serde_json::Deserializer::from_str(data).deserialize_seq(visitor) {
-                Ok(active_failures) => Some(active_failures),
+                Ok(active_failures) => {
+                    // The orchestrator (re)started, thus it needs the scheduled failures again
+                    self.published_scheduled_failures = None;
+                    Some(active_failures)
+                }
                 Err(e) => {
                     eprintln!("SYSTEMS: Failed to parse failure update message: '{e}'");
                     None
@@ -27,6 +41,100 @@ impl Failures {
     pub(super) fn get_updated_active_failures(&mut self) -> Option<FxHashSet<FailureType>> {
         self.active_failures.take()
     }
+
+    /// Handles a request to schedule a failure, formatted as
+    /// `{"id": 24000, "trigger": "after", "value": 60, "intermittent": 10}`.
+    /// See [`Failures::parse_trigger`] for the supported triggers. `intermittent` is optional.
+    pub(super) fn handle_schedule_request(&mut self, data: &str) {
+        match self.parse_scheduled_failure(data) {
+            Ok(failure) => self.scheduled_failures.push(failure),
+            Err(e) => eprintln!("SYSTEMS: Failed to parse failure schedule message: '{e}'"),
+        }
+    }
+
+    /// Handles a request to cancel all scheduled failures with the given id.
+    pub(super) fn handle_cancel_request(&mut self, data: &str) {
+        match serde_json::from_str::<u64>(data) {
+            Ok(failure_id) => {
+                if let Some(failure) = self.identifier_to_failure_type.get(&failure_id).copied() {
+                    self.cancelled_failures.push(failure);
+                }
+            }
+            Err(e) => eprintln!("SYSTEMS: Failed to parse failure cancel message: '{e}'"),
+        }
+    }
+
+    pub(super) fn take_scheduled_failures(&mut self) -> Vec<ScheduledFailure> {
+        std::mem::take(&mut self.scheduled_failures)
+    }
+
+    pub(super) fn take_cancelled_failures(&mut self) -> Vec<FailureType> {
+        std::mem::take(&mut self.cancelled_failures)
+    }
+
+    /// Returns the identifiers of the failures activated by the failure scheduler, formatted as
+    /// a JSON array, when they changed since they were last published.
+    pub(super) fn scheduled_failures_update(
+        &mut self,
+        scheduled_failures: &FxHashSet<FailureType>,
+    ) -> Option<String> {
+        if self.published_scheduled_failures.as_ref() == Some(scheduled_failures) {
+            return None;
+        }
+        self.published_scheduled_failures = Some(scheduled_failures.clone());
+
+        let identifiers: Vec<u64> = self
+            .identifier_to_failure_type
+            .iter()
+            .filter(|(_, failure_type)| scheduled_failures.contains(failure_type))
+            .map(|(&identifier, _)| identifier)
+            .collect();
+        serde_json::to_string(&identifiers).ok()
+    }
+
+    fn parse_scheduled_failure(&self, data: &str) -> Result<ScheduledFailure, String> {
+        let message: Value = serde_json::from_str(data).map_err(|e| e.to_string())?;
+
+        let failure_type = message["id"]
+            .as_u64()
+            .and_then(|failure_id| self.identifier_to_failure_type.get(&failure_id).copied())
+            .ok_or("unknown failure id")?;
+        let value = message["value"].as_f64().ok_or("missing trigger value")?;
+        let trigger = Self::parse_trigger(message["trigger"].as_str(), value)?;
+
+        let failure = ScheduledFailure::new(failure_type, trigger);
+        Ok(match message["intermittent"].as_f64() {
+            Some(active_seconds) => failure.intermittent(Self::parse_duration(active_seconds)?),
+            None => failure,
+        })
+    }
+
+    fn parse_duration(seconds: f64) -> Result<Duration, String> {
+        Duration::try_from_secs_f64(seconds).map_err(|e| format!("invalid duration {seconds}: {e}"))
+    }
+
+    /// Durations are given in seconds, altitudes in feet, airspeeds in knots and flight
+    /// phases as the number of the FWC flight phase.
+    fn parse_trigger(trigger: Option<&str>, value: f64) -> Result<FailureTrigger, String> {
+        Ok(match trigger {
+            Some("after") => FailureTrigger::After(Self::parse_duration(value)?),
+            Some("altitudeAbove") => FailureTrigger::AltitudeAbove(Length::new::<foot>(value)),
+            Some("altitudeBelow") => FailureTrigger::AltitudeBelow(Length::new::<foot>(value)),
+            Some("indicatedAirspeedAbove") => {
+                FailureTrigger::IndicatedAirspeedAbove(Velocity::new::<knot>(value))
+            }
+            Some("indicatedAirspeedBelow") => {
+                FailureTrigger::IndicatedAirspeedBelow(Velocity::new::<knot>(value))
+            }
+            Some("flightPhase") => FailureTrigger::FlightPhase(
+                FwcFlightPhase::from_f64(value).ok_or("invalid flight phase")?,
+            ),
+            Some("meanTimeBetweenFailures") if value > 0. => {
+                FailureTrigger::MeanTimeBetweenFailures(Self::parse_duration(value)?)
+            }
+            _ => return Err(format!("invalid trigger {:?}", trigger)),
+        })
+    }
 }
 
 struct FailureIdVisitor<'a>(&'a FxHashMap<u64, FailureType>);
@@ -2,6 +2,7 @@
 pub mod aspects;
 mod anti_ice;
 mod electrical;
+mod engine;
 mod failures;
 mod fuel;
 mod msfs;
@@ -20,6 +21,7 @@ use ::msfs::commbus::{CommBus, CommBusBroadcastFlags};
 use crate::anti_ice::{engine_anti_ice, wing_anti_ice};
 use crate::aspects::{Aspect, ExecuteOn, MsfsAspectBuilder};
 use crate::electrical::{auxiliary_power_unit, electrical_buses};
+use crate::engine::{engine_fire, engine_fuel_cut_off};
 use ::msfs::{
     sim_connect::{data_definition, Period, SimConnect, SimConnectRecv, SIMCONNECT_OBJECT_ID_USER},
     sys, MSFSEvent,
@@ -28,6 +30,8 @@ use failures::Failures;
 use rustc_hash::FxHashMap;
 use std::cell::RefCell;
 use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{BufWriter, Write};
 use std::rc::Rc;
 use std::{error::Error, time::Duration};
 use systems::shared::{set_diagnostics_reporter, ElectricalBusType};
@@ -35,7 +39,8 @@ use systems::simulation::{InitContext, StartState};
 use systems::{
     failures::FailureType,
     simulation::{
-        Aircraft, Simulation, SimulatorReaderWriter, VariableIdentifier, VariableRegistry,
+        Aircraft, Simulation, SimulatorReaderWriter, VariableIdentifier, VariableNames,
+        VariableRegistry, VariableTraceFormat, VariableTraceRecorder,
     },
 };
 
@@ -47,6 +52,8 @@ pub struct MsfsSimulationBuilder<'a, 'b> {
     sim_connect: &'a mut SimConnect<'b>,
     failures: Failures,
     aspects: Vec<Box<dyn Aspect>>,
+    recorder: Option<VariableTraceRecorder<Box<dyn Write>>>,
+    recording_variable: Option<VariableIdentifier>,
 }
 
 impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
@@ -63,6 +70,8 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
             sim_connect,
             failures: Failures::default(),
             aspects: vec![],
+            recorder: None,
+            recording_variable: None,
         }
     }
 
@@ -75,7 +84,14 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
 
         Ok((
             simulation,
-            MsfsHandler::new(registry, self.aspects, self.failures, self.sim_connect)?,
+            MsfsHandler::new(
+                registry,
+                self.aspects,
+                self.failures,
+                self.recorder,
+                self.recording_variable,
+                self.sim_connect,
+            )?,
         ))
     }
 
@@ -126,6 +142,14 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
         self.with_aspect(wing_anti_ice())
     }
 
+    pub fn with_engine_fuel_cut_off(self, engine_count: usize) -> Result<Self, Box<dyn Error>> {
+        self.with_aspect(engine_fuel_cut_off(engine_count))
+    }
+
+    pub fn with_engine_fire(self, engine_count: usize) -> Result<Self, Box<dyn Error>> {
+        self.with_aspect(engine_fire(engine_count))
+    }
+
     pub fn with_fuel_pumps(
         self,
         pump_indexes: impl IntoIterator<Item = u32>,
@@ -138,6 +162,25 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
         self
     }
 
+    /// Records all variables read and written by the simulation into the given recorder.
+    pub fn with_variable_trace_recorder(
+        mut self,
+        recorder: VariableTraceRecorder<Box<dyn Write>>,
+    ) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// Records all variables read and written by the simulation while the given variable is
+    /// set, into a new file within the `\work` folder for each recording.
+    pub fn with_variable_trace_recording(mut self, recording_variable: Variable) -> Self {
+        if let Some(registry) = &mut self.variable_registry {
+            self.recording_variable = Some(registry.register(&recording_variable));
+        }
+
+        self
+    }
+
     pub fn provides_aircraft_variable(
         mut self,
         name: &str,
@@ -188,6 +231,9 @@ pub struct MsfsHandler {
     variables: Option<MsfsVariableRegistry>,
     aspects: Vec<Box<dyn Aspect>>,
     failures: Rc<RefCell<Failures>>,
+    recorder: Option<VariableTraceRecorder<Box<dyn Write>>>,
+    recording_variable: Option<VariableIdentifier>,
+    has_recording_failed: bool,
     _commbus: CommBus<'static>,
     time: Time,
 }
@@ -196,6 +242,8 @@ impl MsfsHandler {
         variables: MsfsVariableRegistry,
         aspects: Vec<Box<dyn Aspect>>,
         failures: Failures,
+        recorder: Option<VariableTraceRecorder<Box<dyn Write>>>,
+        recording_variable: Option<VariableIdentifier>,
         sim_connect: &mut SimConnect,
     ) -> Result<Self, Box<dyn Error>> {
         let failures = Rc::new(RefCell::new(failures));
@@ -215,11 +263,26 @@ impl MsfsHandler {
                 failures.borrow_mut().handle_failure_update(data);
             });
         }
+        {
+            let failures = failures.clone();
+            commbus.register("FBW_FAILURE_SCHEDULE", move |data| {
+                failures.borrow_mut().handle_schedule_request(data);
+            });
+        }
+        {
+            let failures = failures.clone();
+            commbus.register("FBW_FAILURE_CANCEL_SCHEDULE", move |data| {
+                failures.borrow_mut().handle_cancel_request(data);
+            });
+        }
         CommBus::call("FBW_FAILURE_REQUEST", "", CommBusBroadcastFlags::JS);
         Ok(Self {
             variables: Some(variables),
             aspects,
             failures,
+            recorder,
+            recording_variable,
+            has_recording_failed: false,
             _commbus: commbus,
             time: Time::new(sim_connect)?,
         })
@@ -238,8 +301,9 @@ impl MsfsHandler {
                     self.pre_tick(sim_connect, delta_time)?;
                     self.read_failures_into_simulation(simulation);
 
-                    simulation.tick(delta_time, self.time.simulation_time(), self);
+                    self.tick(simulation, delta_time)?;
                     self.post_tick(sim_connect)?;
+                    self.publish_scheduled_failures(simulation);
                 }
             }
             MSFSEvent::SimConnect(message) => match message {
@@ -257,6 +321,68 @@ impl MsfsHandler {
         Ok(())
     }
 
+    fn tick<T: Aircraft>(
+        &mut self,
+        simulation: &mut Simulation<T>,
+        delta: Duration,
+    ) -> Result<(), Box<dyn Error>> {
+        let simulation_time = self.time.simulation_time();
+        self.update_variable_trace_recording(simulation_time);
+
+        match self.recorder.take() {
+            Some(mut recorder) => {
+                simulation.tick(delta, simulation_time, &mut recorder.wrap(self));
+                let result = match &self.variables {
+                    Some(variables) => recorder.finish_tick(simulation_time, variables),
+                    None => Ok(()),
+                };
+
+                self.recorder = Some(recorder);
+
+                Ok(result?)
+            }
+            None => {
+                simulation.tick(delta, simulation_time, self);
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Starts and stops recording when the recording variable is set and cleared.
+    /// When the trace file cannot be created, recording is only attempted again
+    /// once the recording variable has been cleared and set again.
+    fn update_variable_trace_recording(&mut self, simulation_time: f64) {
+        let is_recording_requested = match (&self.recording_variable, &self.variables) {
+            (Some(recording_variable), Some(variables)) => variables.read(recording_variable) > 0.,
+            _ => return,
+        };
+
+        if is_recording_requested && self.recorder.is_none() && !self.has_recording_failed {
+            let path = format!("\\work\\systems-{:.0}.trace", simulation_time);
+            match File::create(&path) {
+                Ok(file) => {
+                    let output: Box<dyn Write> = Box::new(BufWriter::new(file));
+                    self.recorder = Some(VariableTraceRecorder::new(
+                        output,
+                        VariableTraceFormat::Binary,
+                    ));
+                }
+                Err(e) => {
+                    eprintln!("SYSTEMS: Failed to create trace file '{path}': '{e}'");
+                    self.has_recording_failed = true;
+                }
+            }
+        } else if !is_recording_requested {
+            self.has_recording_failed = false;
+            if let Some(mut recorder) = self.recorder.take() {
+                if let Err(e) = recorder.flush() {
+                    eprintln!("SYSTEMS: Failed to write trace file: '{e}'");
+                }
+            }
+        }
+    }
+
     fn handle_message(&mut self, message: &SimConnectRecv) {
         if let Some(mut variables) = self.variables.take() {
             for aspect in self.aspects.iter_mut() {
@@ -301,9 +427,34 @@ impl MsfsHandler {
     }
 
     fn read_failures_into_simulation<T: Aircraft>(&mut self, simulation: &mut Simulation<T>) {
-        if let Some(active_failures) = self.failures.borrow_mut().get_updated_active_failures() {
+        let mut failures = self.failures.borrow_mut();
+        if let Some(active_failures) = failures.get_updated_active_failures() {
             simulation.update_active_failures(active_failures);
         }
+
+        let scheduler = simulation.failure_scheduler_mut();
+        for failure_type in failures.take_cancelled_failures() {
+            scheduler.cancel(failure_type);
+        }
+        for failure in failures.take_scheduled_failures() {
+            scheduler.schedule(failure);
+        }
+    }
+
+    /// Informs the failures orchestrator of the failures activated by the failure scheduler,
+    /// such that the failures are shown as active and reach the consumers outside the systems.
+    fn publish_scheduled_failures<T: Aircraft>(&mut self, simulation: &Simulation<T>) {
+        if let Some(data) = self
+            .failures
+            .borrow_mut()
+            .scheduled_failures_update(simulation.failure_scheduler().active_failures())
+        {
+            CommBus::call(
+                "FBW_FAILURE_SCHEDULED_UPDATE",
+                &data,
+                CommBusBroadcastFlags::JS,
+            );
+        }
     }
 }
 impl SimulatorReaderWriter for MsfsHandler {
@@ -494,6 +645,7 @@ pub struct MsfsVariableRegistry {
     name_to_identifier: FxHashMap<String, VariableIdentifier>,
     next_variable_identifier: FxHashMap<VariableType, VariableIdentifier>,
     variables: [Vec<VariableValue>; 3],
+    names: [Vec<String>; 3],
 }
 
 impl MsfsVariableRegistry {
@@ -503,6 +655,7 @@ impl MsfsVariableRegistry {
             name_to_identifier: FxHashMap::default(),
             next_variable_identifier: FxHashMap::default(),
             variables: [vec![], vec![], vec![]],
+            names: [vec![], vec![], vec![]],
         }
     }
 
@@ -551,6 +704,8 @@ impl MsfsVariableRegistry {
                 let value: VariableValue = (&variable).into();
                 self.variables[identifier.identifier_type()]
                     .insert(identifier.identifier_index(), value);
+                self.names[identifier.identifier_type()]
+                    .insert(identifier.identifier_index(), variable.lookup_name());
 
                 identifier
             }
@@ -573,6 +728,14 @@ impl MsfsVariableRegistry {
     }
 }
 
+impl VariableNames for MsfsVariableRegistry {
+    fn variable_name(&self, identifier: &VariableIdentifier) -> Option<&str> {
+        self.names[identifier.identifier_type()]
+            .get(identifier.identifier_index())
+            .map(String::as_str)
+    }
+}
+
 impl VariableRegistry for MsfsVariableRegistry {
     fn get(&mut self, name: String) -> VariableIdentifier {
         match self.name_to_identifier.get(&name) {
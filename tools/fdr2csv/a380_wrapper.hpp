@@ -1,4 +0,0 @@
-#include "../../fbw-a380x/src/wasm/fbw_a380/src/interface/FuelSystemData.h"
-#include "../../fbw-a380x/src/wasm/fbw_a380/src/model/A380PrimComputerFctl_types.h"
-#include "../../fbw-a380x/src/wasm/fbw_a380/src/model/A380SecComputer_types.h"
-#include "../../fbw-a380x/src/wasm/fbw_a380/src/recording/RecordingDataTypes.h"
@@ -1,8 +1,42 @@
 use std::env;
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 use bindgen::callbacks::ParseCallbacks;
 
+// Every supported interface version has a snapshot of the headers defining its struct layout
+// in interfaces/<aircraft>/<interface version>/, of which wrapper.hpp includes all headers.
+const INTERFACES_DIRECTORY: &str = "interfaces";
+
+// The flight data recorders defining the interface version they currently write, together with
+// the live headers defining the struct layout of that version.
+const FLIGHT_DATA_RECORDERS: [(&str, &str, &[&str]); 2] = [
+    (
+        "a320",
+        "../../fbw-a32nx/src/wasm/fbw_a320/src/recording/FlightDataRecorder.h",
+        &[
+            "../../fbw-a32nx/src/wasm/fbw_a320/src/model/ElacComputer_types.h",
+            "../../fbw-a32nx/src/wasm/fbw_a320/src/model/FacComputer_types.h",
+            "../../fbw-a32nx/src/wasm/fbw_a320/src/model/FadecComputer_types.h",
+            "../../fbw-a32nx/src/wasm/fbw_a320/src/model/FmgcComputer_types.h",
+            "../../fbw-a32nx/src/wasm/fbw_a320/src/model/SecComputer_types.h",
+            "../../fbw-a32nx/src/wasm/fbw_a320/src/model/rtwtypes.h",
+            "../../fbw-a32nx/src/wasm/fbw_a320/src/recording/RecordingDataTypes.h",
+        ],
+    ),
+    (
+        "a380",
+        "../../fbw-a380x/src/wasm/fbw_a380/src/recording/FlightDataRecorder.h",
+        &[
+            "../../fbw-a380x/src/wasm/fbw_a380/src/interface/FuelSystemData.h",
+            "../../fbw-a380x/src/wasm/fbw_a380/src/model/A380PrimComputerFctl_types.h",
+            "../../fbw-a380x/src/wasm/fbw_a380/src/model/A380SecComputer_types.h",
+            "../../fbw-a380x/src/wasm/fbw_a380/src/model/rtwtypes.h",
+            "../../fbw-a380x/src/wasm/fbw_a380/src/recording/RecordingDataTypes.h",
+        ],
+    ),
+];
+
 #[derive(Debug)]
 struct CustomDeriveCallback {}
 impl CustomDeriveCallback {
@@ -20,13 +54,79 @@ fn main() {
     // Tell cargo to look for shared libraries in the specified directory
     println!("cargo:rustc-link-search=../../fbw-a32nx/src/wasm/fbw_a320/src/model");
 
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    for (aircraft, flight_data_recorder, live_headers) in FLIGHT_DATA_RECORDERS {
+        let aircraft_directory = Path::new(INTERFACES_DIRECTORY).join(aircraft);
+        println!("cargo:rerun-if-changed={}", aircraft_directory.display());
+
+        // Generate the bindings of every interface version side by side, such that files
+        // written by older versions of the aircraft remain convertible.
+        for entry in fs::read_dir(&aircraft_directory).expect("Couldn't read interfaces!") {
+            let version_directory = entry.expect("Couldn't read interfaces!").path();
+            let version = version_directory
+                .file_name()
+                .and_then(|name| name.to_str())
+                .expect("Invalid interface directory name!");
+
+            generate_bindings(
+                &version_directory.join("wrapper.hpp"),
+                &out_path.join(format!("bindings_{aircraft}_{version}.rs")),
+            );
+        }
+
+        // The aircraft must write the newest interface version of which a snapshot exists, and its
+        // headers must not have changed since that snapshot was taken. Otherwise the interface
+        // version has not been bumped along with a change of the recorded struct layout.
+        println!("cargo:rerun-if-changed={flight_data_recorder}");
+        let version = recorded_interface_version(flight_data_recorder).unwrap_or_else(|| {
+            panic!("Couldn't read the interface version written by {flight_data_recorder}!")
+        });
+        let snapshot_directory = aircraft_directory.join(&version);
+        if !snapshot_directory.exists() {
+            panic!(
+                "The {aircraft} writes interface version {version}, of which no snapshot exists. \
+                Add a snapshot of its headers to {}.",
+                snapshot_directory.display()
+            );
+        }
+
+        let newest_version = newest_interface_version(&aircraft_directory);
+        if newest_version != version {
+            panic!(
+                "The {aircraft} writes interface version {version}, but the newest snapshot is of \
+                interface version {newest_version}."
+            );
+        }
+
+        for live_header in live_headers {
+            println!("cargo:rerun-if-changed={live_header}");
+            let file_name = Path::new(live_header)
+                .file_name()
+                .expect("Invalid header path!");
+            let snapshot_header = snapshot_directory.join(file_name);
+            let live = fs::read(live_header)
+                .unwrap_or_else(|_| panic!("Couldn't read header {live_header}!"));
+            if fs::read(&snapshot_header).ok() != Some(live) {
+                panic!(
+                    "{live_header} differs from its snapshot {} of interface version {version}. \
+                    Bump the INTERFACE_VERSION of {flight_data_recorder} and add a snapshot of \
+                    the new headers.",
+                    snapshot_header.display()
+                );
+            }
+        }
+    }
+}
+
+fn generate_bindings(header: &Path, output: &Path) {
     // The bindgen::Builder is the main entry point
     // to bindgen, and lets you build up options for
     // the resulting bindings.
-    let bindings_320 = bindgen::Builder::default()
+    let bindings = bindgen::Builder::default()
         // The input header we would like to generate
         // bindings for.
-        .header("a320_wrapper.hpp")
+        .header(header.to_str().expect("Invalid header path!"))
         .clang_arg("-std=c++20")
         // Tell cargo to invalidate the built crate whenever any of the
         // included header files changed.
@@ -37,27 +137,30 @@ fn main() {
         // Unwrap the Result and panic on failure.
         .expect("Unable to generate bindings");
 
-    let bindings_380 = bindgen::Builder::default()
-        // The input header we would like to generate
-        // bindings for.
-        .header("a380_wrapper.hpp")
-        .clang_arg("-std=c++20")
-        // Tell cargo to invalidate the built crate whenever any of the
-        // included header files changed.
-        .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
-        .parse_callbacks(Box::new(CustomDeriveCallback::new()))
-        // Finish the builder and generate the bindings.
-        .generate()
-        // Unwrap the Result and panic on failure.
-        .expect("Unable to generate bindings");
-
-    // Write the bindings to the $OUT_DIR/bindings.rs file.
-    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
-    bindings_320
-        .write_to_file(out_path.join("bindings_320.rs"))
+    // Write the bindings to the $OUT_DIR/bindings_<aircraft>_<version>.rs file.
+    bindings
+        .write_to_file(output)
         .expect("Couldn't write bindings!");
+}
 
-    bindings_380
-        .write_to_file(out_path.join("bindings_380.rs"))
-        .expect("Couldn't write bindings!");
+// The highest interface version of which a snapshot exists in the aircraft directory.
+fn newest_interface_version(aircraft_directory: &Path) -> String {
+    fs::read_dir(aircraft_directory)
+        .expect("Couldn't read interfaces!")
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            Some((name.parse::<u64>().ok()?, name))
+        })
+        .max()
+        .map(|(_, name)| name)
+        .expect("No interface snapshot found!")
+}
+
+// Reads the value of the INTERFACE_VERSION constant from the flight data recorder header.
+fn recorded_interface_version(flight_data_recorder: &str) -> Option<String> {
+    fs::read_to_string(flight_data_recorder)
+        .ok()?
+        .lines()
+        .find_map(|line| line.split_once("INTERFACE_VERSION ="))
+        .map(|(_, value)| value.trim().trim_end_matches(';').trim().to_owned())
 }
@@ -0,0 +1,6 @@
+#include "ElacComputer_types.h"
+#include "FacComputer_types.h"
+#include "FadecComputer_types.h"
+#include "FmgcComputer_types.h"
+#include "SecComputer_types.h"
+#include "RecordingDataTypes.h"
@@ -0,0 +1,4 @@
+#include "FuelSystemData.h"
+#include "A380PrimComputerFctl_types.h"
+#include "A380SecComputer_types.h"
+#include "RecordingDataTypes.h"
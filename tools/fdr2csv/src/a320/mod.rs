@@ -0,0 +1,6 @@
+// Each module reads the records of one interface version, using the struct layout generated from
+// the header snapshot in interfaces/a320/<interface version>/.
+pub mod v3200006;
+
+// All interface versions which can be converted, the newest one last.
+pub const INTERFACE_VERSIONS: [u64; 1] = [v3200006::INTERFACE_VERSION];
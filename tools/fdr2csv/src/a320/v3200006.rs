@@ -1,74 +1,97 @@
-use crate::{
-    a320_headers::{
-        ap_raw_output, athr_output, base_ecu_bus, base_elac_analog_outputs,
-        base_elac_discrete_outputs, base_elac_out_bus, base_fac_analog_outputs, base_fac_bus,
-        base_fac_discrete_outputs, base_fmgc_ap_fd_logic_outputs, base_fmgc_athr_outputs,
-        base_fmgc_bus_inputs, base_fmgc_bus_outputs, base_fmgc_discrete_inputs,
-        base_fmgc_discrete_outputs, base_fmgc_logic_outputs, base_fms_inputs,
-        base_sec_analog_outputs, base_sec_discrete_outputs, base_sec_out_bus, AircraftSpecificData,
-        BaseData,
-    },
-    read_bytes,
+use crate::{read_bytes, AircraftType, FdrRecord};
+use headers::{
+    ap_raw_output, athr_output, base_ecu_bus, base_elac_analog_outputs, base_elac_discrete_outputs,
+    base_elac_out_bus, base_fac_analog_outputs, base_fac_bus, base_fac_discrete_outputs,
+    base_fmgc_ap_fd_logic_outputs, base_fmgc_athr_outputs, base_fmgc_bus_inputs,
+    base_fmgc_bus_outputs, base_fmgc_discrete_inputs, base_fmgc_discrete_outputs,
+    base_fmgc_logic_outputs, base_fms_inputs, base_sec_analog_outputs, base_sec_discrete_outputs,
+    base_sec_out_bus, AircraftSpecificData, BaseData,
 };
 use serde::Serialize;
 use std::io::{prelude::*, Error};
 
+pub mod headers {
+    #![allow(non_upper_case_globals)]
+    #![allow(non_camel_case_types)]
+    #![allow(non_snake_case)]
+    #![allow(dead_code)]
+
+    use serde::Serialize;
+
+    use bytemuck::AnyBitPattern;
+
+    include!(concat!(env!("OUT_DIR"), "/bindings_a320_3200006.rs"));
+}
+
 pub const INTERFACE_VERSION: u64 = 3200006;
 
 // A single FDR record
 #[derive(Serialize, Default)]
 pub struct FdrData {
-    base: BaseData,
-    specific: AircraftSpecificData,
-    elac_1: ElacData,
-    elac_2: ElacData,
-    sec_1: SecData,
-    sec_2: SecData,
-    sec_3: SecData,
-    fac_1: FacData,
-    fac_2: FacData,
-    fmgc_1: FmgcData,
-    fadec_1: FadecData,
+    pub base: BaseData,
+    pub specific: AircraftSpecificData,
+    pub elac_1: ElacData,
+    pub elac_2: ElacData,
+    pub sec_1: SecData,
+    pub sec_2: SecData,
+    pub sec_3: SecData,
+    pub fac_1: FacData,
+    pub fac_2: FacData,
+    pub fmgc_1: FmgcData,
+    pub fadec_1: FadecData,
 }
 
 #[derive(Serialize, Default)]
-struct ElacData {
-    bus_outputs: base_elac_out_bus,
-    discrete_outputs: base_elac_discrete_outputs,
-    analog_outputs: base_elac_analog_outputs,
+pub struct ElacData {
+    pub bus_outputs: base_elac_out_bus,
+    pub discrete_outputs: base_elac_discrete_outputs,
+    pub analog_outputs: base_elac_analog_outputs,
 }
 
 #[derive(Serialize, Default)]
-struct SecData {
-    bus_outputs: base_sec_out_bus,
-    discrete_outputs: base_sec_discrete_outputs,
-    analog_outputs: base_sec_analog_outputs,
+pub struct SecData {
+    pub bus_outputs: base_sec_out_bus,
+    pub discrete_outputs: base_sec_discrete_outputs,
+    pub analog_outputs: base_sec_analog_outputs,
 }
 
 #[derive(Serialize, Default)]
-struct FacData {
-    bus_outputs: base_fac_bus,
-    discrete_outputs: base_fac_discrete_outputs,
-    analog_outputs: base_fac_analog_outputs,
+pub struct FacData {
+    pub bus_outputs: base_fac_bus,
+    pub discrete_outputs: base_fac_discrete_outputs,
+    pub analog_outputs: base_fac_analog_outputs,
 }
 
 #[derive(Serialize, Default)]
-struct FmgcData {
-    logic: base_fmgc_logic_outputs,
-    ap_fd_logic: base_fmgc_ap_fd_logic_outputs,
-    ap_fd_outer_loops: ap_raw_output,
-    athr: base_fmgc_athr_outputs,
-    discrete_outputs: base_fmgc_discrete_outputs,
-    bus_outputs: base_fmgc_bus_outputs,
-    bus_inputs: base_fmgc_bus_inputs,
-    discrete_inputs: base_fmgc_discrete_inputs,
-    fms_inputs: base_fms_inputs,
+pub struct FmgcData {
+    pub logic: base_fmgc_logic_outputs,
+    pub ap_fd_logic: base_fmgc_ap_fd_logic_outputs,
+    pub ap_fd_outer_loops: ap_raw_output,
+    pub athr: base_fmgc_athr_outputs,
+    pub discrete_outputs: base_fmgc_discrete_outputs,
+    pub bus_outputs: base_fmgc_bus_outputs,
+    pub bus_inputs: base_fmgc_bus_inputs,
+    pub discrete_inputs: base_fmgc_discrete_inputs,
+    pub fms_inputs: base_fms_inputs,
 }
 
 #[derive(Serialize, Default)]
-struct FadecData {
-    bus_outputs: base_ecu_bus,
-    outputs: athr_output,
+pub struct FadecData {
+    pub bus_outputs: base_ecu_bus,
+    pub outputs: athr_output,
+}
+
+impl FdrRecord for FdrData {
+    const AIRCRAFT_TYPE: AircraftType = AircraftType::A320;
+    const INTERFACE_VERSION: u64 = self::INTERFACE_VERSION;
+
+    fn read(reader: &mut impl Read) -> Result<Self, Error> {
+        read_record(reader)
+    }
+
+    fn simulation_time(&self) -> f64 {
+        self.base.simulation_time_s
+    }
 }
 
 // These are helper functions to read in a whole FDR record.
@@ -0,0 +1,8 @@
+// Each module reads the records of one interface version, using the struct layout generated from
+// the header snapshot in interfaces/a380/<interface version>/.
+pub mod v3800008;
+
+pub const INTERFACE_MIN_VERSION: u64 = 3800000;
+
+// All interface versions which can be converted, the newest one last.
+pub const INTERFACE_VERSIONS: [u64; 1] = [v3800008::INTERFACE_VERSION];
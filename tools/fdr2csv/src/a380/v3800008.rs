@@ -1,58 +1,81 @@
-use crate::{
-    a380_headers::{
-        base_prim_analog_outputs, base_prim_ap_fd_logic_outputs, base_prim_discrete_outputs,
-        base_prim_fctl_logic_outputs, base_prim_fg_laws_outputs, base_prim_fg_logic_output,
-        base_prim_flight_envelope_outputs, base_prim_general_logic_outputs, base_prim_laws_outputs,
-        base_prim_out_bus, base_sec_analog_outputs, base_sec_discrete_outputs, base_sec_out_bus,
-        AircraftSpecificData, BaseData, FuelSystemData,
-    },
-    read_bytes,
+use crate::{read_bytes, AircraftType, FdrRecord};
+use headers::{
+    base_prim_analog_outputs, base_prim_ap_fd_logic_outputs, base_prim_discrete_outputs,
+    base_prim_fctl_logic_outputs, base_prim_fg_laws_outputs, base_prim_fg_logic_output,
+    base_prim_flight_envelope_outputs, base_prim_general_logic_outputs, base_prim_laws_outputs,
+    base_prim_out_bus, base_sec_analog_outputs, base_sec_discrete_outputs, base_sec_out_bus,
+    AircraftSpecificData, BaseData, FuelSystemData,
 };
 use serde::Serialize;
 use std::io::{prelude::*, Error};
 
+pub mod headers {
+    #![allow(non_upper_case_globals)]
+    #![allow(non_camel_case_types)]
+    #![allow(non_snake_case)]
+    #![allow(dead_code)]
+
+    use serde::Serialize;
+
+    use bytemuck::AnyBitPattern;
+
+    include!(concat!(env!("OUT_DIR"), "/bindings_a380_3800008.rs"));
+}
+
 pub const INTERFACE_VERSION: u64 = 3800008;
-pub const INTERFACE_MIN_VERSION: u64 = 3800000;
 
 // A single FDR record
 #[derive(Serialize, Default)]
 pub struct FdrData {
-    base: BaseData,
-    specific: AircraftSpecificData,
-    prim_1: PrimData,
-    prim_2: PrimData,
-    prim_3: PrimData,
-    master_prim_index: i32,
-    master_prim: MasterPrimData,
-    sec_1: SecData,
-    sec_2: SecData,
-    sec_3: SecData,
-    fuel: FuelSystemData,
+    pub base: BaseData,
+    pub specific: AircraftSpecificData,
+    pub prim_1: PrimData,
+    pub prim_2: PrimData,
+    pub prim_3: PrimData,
+    pub master_prim_index: i32,
+    pub master_prim: MasterPrimData,
+    pub sec_1: SecData,
+    pub sec_2: SecData,
+    pub sec_3: SecData,
+    pub fuel: FuelSystemData,
 }
 
 #[derive(Serialize, Default)]
-struct PrimData {
-    bus_outputs: base_prim_out_bus,
-    discrete_outputs: base_prim_discrete_outputs,
-    analog_outputs: base_prim_analog_outputs,
+pub struct PrimData {
+    pub bus_outputs: base_prim_out_bus,
+    pub discrete_outputs: base_prim_discrete_outputs,
+    pub analog_outputs: base_prim_analog_outputs,
 }
 
 #[derive(Serialize, Default)]
-struct MasterPrimData {
-    general_logic: base_prim_general_logic_outputs,
-    flight_envelope: base_prim_flight_envelope_outputs,
-    fg_logic: base_prim_fg_logic_output,
-    fg_mode_logic: base_prim_ap_fd_logic_outputs,
-    fg_laws: base_prim_fg_laws_outputs,
-    fctl_logic: base_prim_fctl_logic_outputs,
-    laws: base_prim_laws_outputs,
+pub struct MasterPrimData {
+    pub general_logic: base_prim_general_logic_outputs,
+    pub flight_envelope: base_prim_flight_envelope_outputs,
+    pub fg_logic: base_prim_fg_logic_output,
+    pub fg_mode_logic: base_prim_ap_fd_logic_outputs,
+    pub fg_laws: base_prim_fg_laws_outputs,
+    pub fctl_logic: base_prim_fctl_logic_outputs,
+    pub laws: base_prim_laws_outputs,
 }
 
 #[derive(Serialize, Default)]
-struct SecData {
-    bus_outputs: base_sec_out_bus,
-    discrete_outputs: base_sec_discrete_outputs,
-    analog_outputs: base_sec_analog_outputs,
+pub struct SecData {
+    pub bus_outputs: base_sec_out_bus,
+    pub discrete_outputs: base_sec_discrete_outputs,
+    pub analog_outputs: base_sec_analog_outputs,
+}
+
+impl FdrRecord for FdrData {
+    const AIRCRAFT_TYPE: AircraftType = AircraftType::A380;
+    const INTERFACE_VERSION: u64 = self::INTERFACE_VERSION;
+
+    fn read(reader: &mut impl Read) -> Result<Self, Error> {
+        read_record(reader)
+    }
+
+    fn simulation_time(&self) -> f64 {
+        self.base.simulation_time_s
+    }
 }
 
 // These are helper functions to read in a whole FDR record.
@@ -0,0 +1,265 @@
+use arrow::{
+    array::{
+        ArrayRef, BooleanBuilder, Float32Builder, Float64Builder, Int16Builder, Int32Builder,
+        Int64Builder, Int8Builder, UInt16Builder, UInt32Builder, UInt64Builder, UInt8Builder,
+    },
+    datatypes::{DataType, Field, Schema, SchemaRef},
+    ipc::writer::FileWriter,
+    record_batch::RecordBatch,
+};
+use parquet::{
+    arrow::ArrowWriter,
+    basic::{Compression, ZstdLevel},
+    file::properties::WriterProperties,
+};
+use serde::Serialize;
+use std::{
+    io::{self, Write},
+    sync::Arc,
+};
+
+use crate::{
+    error::{Error, Result},
+    scalar_serializer::{for_each_scalar, Scalar},
+    selection::ColumnSelection,
+};
+
+// The number of records which are buffered in memory before being written out as one batch.
+const BATCH_SIZE: usize = 8192;
+
+fn data_type(scalar: Scalar) -> DataType {
+    match scalar {
+        Scalar::Boolean(_) => DataType::Boolean,
+        Scalar::Int8(_) => DataType::Int8,
+        Scalar::Int16(_) => DataType::Int16,
+        Scalar::Int32(_) => DataType::Int32,
+        Scalar::Int64(_) => DataType::Int64,
+        Scalar::UInt8(_) => DataType::UInt8,
+        Scalar::UInt16(_) => DataType::UInt16,
+        Scalar::UInt32(_) => DataType::UInt32,
+        Scalar::UInt64(_) => DataType::UInt64,
+        Scalar::Float32(_) => DataType::Float32,
+        Scalar::Float64(_) => DataType::Float64,
+    }
+}
+
+enum ColumnBuilder {
+    Boolean(BooleanBuilder),
+    Int8(Int8Builder),
+    Int16(Int16Builder),
+    Int32(Int32Builder),
+    Int64(Int64Builder),
+    UInt8(UInt8Builder),
+    UInt16(UInt16Builder),
+    UInt32(UInt32Builder),
+    UInt64(UInt64Builder),
+    Float32(Float32Builder),
+    Float64(Float64Builder),
+}
+
+impl ColumnBuilder {
+    fn new(scalar: Scalar) -> Self {
+        match scalar {
+            Scalar::Boolean(_) => ColumnBuilder::Boolean(BooleanBuilder::with_capacity(BATCH_SIZE)),
+            Scalar::Int8(_) => ColumnBuilder::Int8(Int8Builder::with_capacity(BATCH_SIZE)),
+            Scalar::Int16(_) => ColumnBuilder::Int16(Int16Builder::with_capacity(BATCH_SIZE)),
+            Scalar::Int32(_) => ColumnBuilder::Int32(Int32Builder::with_capacity(BATCH_SIZE)),
+            Scalar::Int64(_) => ColumnBuilder::Int64(Int64Builder::with_capacity(BATCH_SIZE)),
+            Scalar::UInt8(_) => ColumnBuilder::UInt8(UInt8Builder::with_capacity(BATCH_SIZE)),
+            Scalar::UInt16(_) => ColumnBuilder::UInt16(UInt16Builder::with_capacity(BATCH_SIZE)),
+            Scalar::UInt32(_) => ColumnBuilder::UInt32(UInt32Builder::with_capacity(BATCH_SIZE)),
+            Scalar::UInt64(_) => ColumnBuilder::UInt64(UInt64Builder::with_capacity(BATCH_SIZE)),
+            Scalar::Float32(_) => ColumnBuilder::Float32(Float32Builder::with_capacity(BATCH_SIZE)),
+            Scalar::Float64(_) => ColumnBuilder::Float64(Float64Builder::with_capacity(BATCH_SIZE)),
+        }
+    }
+
+    fn append(&mut self, scalar: Scalar) -> Result<()> {
+        match (self, scalar) {
+            (ColumnBuilder::Boolean(builder), Scalar::Boolean(v)) => builder.append_value(v),
+            (ColumnBuilder::Int8(builder), Scalar::Int8(v)) => builder.append_value(v),
+            (ColumnBuilder::Int16(builder), Scalar::Int16(v)) => builder.append_value(v),
+            (ColumnBuilder::Int32(builder), Scalar::Int32(v)) => builder.append_value(v),
+            (ColumnBuilder::Int64(builder), Scalar::Int64(v)) => builder.append_value(v),
+            (ColumnBuilder::UInt8(builder), Scalar::UInt8(v)) => builder.append_value(v),
+            (ColumnBuilder::UInt16(builder), Scalar::UInt16(v)) => builder.append_value(v),
+            (ColumnBuilder::UInt32(builder), Scalar::UInt32(v)) => builder.append_value(v),
+            (ColumnBuilder::UInt64(builder), Scalar::UInt64(v)) => builder.append_value(v),
+            (ColumnBuilder::Float32(builder), Scalar::Float32(v)) => builder.append_value(v),
+            (ColumnBuilder::Float64(builder), Scalar::Float64(v)) => builder.append_value(v),
+            _ => return Err(Error::Message("Column type mismatch".to_owned())),
+        }
+
+        Ok(())
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        match self {
+            ColumnBuilder::Boolean(builder) => Arc::new(builder.finish()),
+            ColumnBuilder::Int8(builder) => Arc::new(builder.finish()),
+            ColumnBuilder::Int16(builder) => Arc::new(builder.finish()),
+            ColumnBuilder::Int32(builder) => Arc::new(builder.finish()),
+            ColumnBuilder::Int64(builder) => Arc::new(builder.finish()),
+            ColumnBuilder::UInt8(builder) => Arc::new(builder.finish()),
+            ColumnBuilder::UInt16(builder) => Arc::new(builder.finish()),
+            ColumnBuilder::UInt32(builder) => Arc::new(builder.finish()),
+            ColumnBuilder::UInt64(builder) => Arc::new(builder.finish()),
+            ColumnBuilder::Float32(builder) => Arc::new(builder.finish()),
+            ColumnBuilder::Float64(builder) => Arc::new(builder.finish()),
+        }
+    }
+}
+
+// Collects the selected columns of records into one typed column builder per column. The types
+// are discovered from an empty record.
+struct RecordBatchBuilder {
+    fields: Vec<Field>,
+    columns: Vec<ColumnBuilder>,
+    rows: usize,
+}
+
+impl RecordBatchBuilder {
+    fn new<T: Serialize + Default>(
+        column_names: &[String],
+        selection: &ColumnSelection,
+    ) -> Result<Self> {
+        let mut fields = Vec::new();
+        let mut columns = Vec::new();
+        let mut column = 0;
+        for_each_scalar(&T::default(), |scalar| {
+            if selection.is_selected(column) {
+                fields.push(Field::new(&column_names[column], data_type(scalar), false));
+                columns.push(ColumnBuilder::new(scalar));
+            }
+            column += 1;
+
+            Ok(())
+        })?;
+
+        Ok(RecordBatchBuilder {
+            fields,
+            columns,
+            rows: 0,
+        })
+    }
+
+    fn schema(&self) -> SchemaRef {
+        Arc::new(Schema::new(self.fields.clone()))
+    }
+
+    fn append<T: Serialize>(&mut self, record: &T, selection: &ColumnSelection) -> Result<()> {
+        let mut column = 0;
+        let mut builder = 0;
+        for_each_scalar(record, |scalar| {
+            if selection.is_selected(column) {
+                self.columns
+                    .get_mut(builder)
+                    .ok_or_else(|| Error::Message("Record contains additional columns".to_owned()))?
+                    .append(scalar)?;
+                builder += 1;
+            }
+            column += 1;
+
+            Ok(())
+        })?;
+
+        self.rows += 1;
+
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Vec<ArrayRef> {
+        self.rows = 0;
+        self.columns.iter_mut().map(ColumnBuilder::finish).collect()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum ColumnarFormat {
+    Parquet,
+    ArrowIpc,
+}
+
+enum ColumnarSink<W: Write + Send> {
+    Parquet(ArrowWriter<W>),
+    ArrowIpc(FileWriter<W>),
+}
+
+// Writes the selected columns of records as typed columns into a Parquet or Arrow IPC file, one
+// batch at a time.
+pub struct ColumnarWriter<W: Write + Send> {
+    builder: RecordBatchBuilder,
+    selection: ColumnSelection,
+    schema: SchemaRef,
+    sink: ColumnarSink<W>,
+}
+
+impl<W: Write + Send> ColumnarWriter<W> {
+    pub fn new<T: Serialize + Default>(
+        writer: W,
+        format: ColumnarFormat,
+        column_names: &[String],
+        selection: ColumnSelection,
+    ) -> io::Result<Self> {
+        let builder =
+            RecordBatchBuilder::new::<T>(column_names, &selection).map_err(io::Error::other)?;
+        let schema = builder.schema();
+
+        let sink = match format {
+            ColumnarFormat::Parquet => {
+                let properties = WriterProperties::builder()
+                    .set_compression(Compression::ZSTD(ZstdLevel::default()))
+                    .build();
+
+                ColumnarSink::Parquet(
+                    ArrowWriter::try_new(writer, schema.clone(), Some(properties))
+                        .map_err(io::Error::other)?,
+                )
+            }
+            ColumnarFormat::ArrowIpc => ColumnarSink::ArrowIpc(
+                FileWriter::try_new(writer, &schema).map_err(io::Error::other)?,
+            ),
+        };
+
+        Ok(ColumnarWriter {
+            builder,
+            selection,
+            schema,
+            sink,
+        })
+    }
+
+    pub fn serialize<T: Serialize>(&mut self, record: &T) -> io::Result<()> {
+        self.builder
+            .append(record, &self.selection)
+            .map_err(io::Error::other)?;
+
+        if self.builder.rows >= BATCH_SIZE {
+            self.write_batch()?;
+        }
+
+        Ok(())
+    }
+
+    fn write_batch(&mut self) -> io::Result<()> {
+        let batch = RecordBatch::try_new(self.schema.clone(), self.builder.finish())
+            .map_err(io::Error::other)?;
+
+        match &mut self.sink {
+            ColumnarSink::Parquet(writer) => writer.write(&batch).map_err(io::Error::other),
+            ColumnarSink::ArrowIpc(writer) => writer.write(&batch).map_err(io::Error::other),
+        }
+    }
+
+    // Writes the remaining records and the file footer.
+    pub fn finish(mut self) -> io::Result<()> {
+        if self.builder.rows > 0 {
+            self.write_batch()?;
+        }
+
+        match self.sink {
+            ColumnarSink::Parquet(writer) => writer.close().map(|_| ()).map_err(io::Error::other),
+            ColumnarSink::ArrowIpc(mut writer) => writer.finish().map_err(io::Error::other),
+        }
+    }
+}
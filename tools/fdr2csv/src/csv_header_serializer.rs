@@ -6,41 +6,35 @@ use crate::error::{Error, Result};
 // functions such as `to_string`, `to_bytes`, or `to_writer` depending on what
 // Rust types the serializer is able to produce as output.
 //
-// This basic serializer supports only `to_string`.
-pub fn to_string<T>(value: &T, delimiter: char) -> Result<String>
+// This basic serializer supports only `column_names`, which returns the name of each
+// column. The names are joined into the CSV header by the caller, as only a selection of the
+// columns may be written.
+pub fn column_names<T>(value: &T) -> Result<Vec<String>>
 where
     T: Serialize,
 {
     let mut serializer = CsvHeaderSerializer {
-        output: String::new(),
+        column_names: Vec::new(),
         field_name_list: Vec::new(),
-        delimiter,
     };
     value.serialize(&mut serializer)?;
 
-    // Trim trailing comma
-    serializer.output.pop();
-    // Add newline and return
-    Ok(serializer.output + "\n")
+    Ok(serializer.column_names)
 }
 
 pub struct CsvHeaderSerializer {
-    // The output string will be populated after each elementary data type in the record.
-    output: String,
+    // The column names will be populated after each elementary data type in the record.
+    column_names: Vec<String>,
 
     // The field name list will keep track of the "higher" level field names
     field_name_list: Vec<String>,
-
-    delimiter: char,
 }
 
 impl CsvHeaderSerializer {
     // This method will be called if an elementary data type has been encountered.
-    // The field name list will then be joined together with a period as separator,
-    // and at the end the delimiter will be appended.
+    // The field name list will then be joined together with a period as separator.
     fn serialize_scalar(&mut self) -> Result<()> {
-        self.output += &self.field_name_list.join(".");
-        self.output.push(self.delimiter);
+        self.column_names.push(self.field_name_list.join("."));
 
         Ok(())
     }
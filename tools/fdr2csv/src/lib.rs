@@ -0,0 +1,53 @@
+//! Decodes the flight data recorder (FDR) files written by the fly-by-wire systems of the
+//! aircraft, such that they can be analysed directly from Rust.
+//!
+//! ```no_run
+//! use fdr2csv::{a320, AircraftType, FdrFile};
+//!
+//! let file = FdrFile::open("recording.fdr")?;
+//! assert_eq!(file.aircraft_type(), AircraftType::A320);
+//!
+//! for record in file.records::<a320::v3200006::FdrData>()? {
+//!     let record = record?;
+//!     println!("{}", record.base.simulation_time_s);
+//! }
+//! # Ok::<(), fdr2csv::FdrError>(())
+//! ```
+use bytemuck::AnyBitPattern;
+use serde::Serialize;
+use std::{
+    io::{prelude::*, Error},
+    mem,
+};
+
+pub mod a320;
+pub mod a380;
+mod reader;
+
+pub use reader::{AircraftType, FdrError, FdrFile, Records};
+
+// A single FDR record of one interface version of an aircraft
+pub trait FdrRecord: Serialize + Default + Sized {
+    const AIRCRAFT_TYPE: AircraftType;
+    const INTERFACE_VERSION: u64;
+
+    fn read(reader: &mut impl Read) -> Result<Self, Error>;
+
+    fn simulation_time(&self) -> f64;
+}
+
+// Read number of bytes specified by the size of T from the binary file
+pub(crate) fn read_bytes<T: AnyBitPattern>(reader: &mut impl Read) -> Result<T, Error> {
+    let size = mem::size_of::<T>();
+
+    // allocate the buffer that will hold the value read from the binary
+    let mut buf = vec![0u8; size];
+
+    // now read from the reader into the buffer
+    reader.read_exact(&mut buf)?;
+
+    // If the read was successful, reinterpret the bytes as the struct, and return
+    let res = bytemuck::from_bytes::<T>(buf.as_slice());
+
+    Ok(*res)
+}
@@ -1,24 +1,25 @@
-use bytemuck::AnyBitPattern;
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+use columnar::{ColumnarFormat, ColumnarWriter};
 use csv::WriterBuilder;
-use flate2::bufread::GzDecoder;
+use fdr2csv::{a320, a380, AircraftType, FdrError, FdrFile, FdrRecord, Records};
+use scalar_serializer::for_each_scalar;
+use selection::{ColumnSelection, RecordFilter};
 use std::{
     fs::{File, OpenOptions},
-    io::{prelude::*, BufReader, BufWriter, Error, ErrorKind},
-    mem,
+    io::{prelude::*, BufWriter, Error, ErrorKind},
 };
 
-mod a320;
-mod a320_headers;
-mod a380;
-mod a380_headers;
+mod columnar;
 mod csv_header_serializer;
 mod error;
+mod scalar_serializer;
+mod selection;
 
-#[derive(Debug)]
-enum AircraftType {
-    A320,
-    A380,
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum OutputFormat {
+    Csv,
+    Parquet,
+    ArrowIpc,
 }
 
 #[derive(Parser, Debug)]
@@ -30,9 +31,28 @@ struct Args {
     /// Output file
     #[arg(short, long, required_unless_present_any(["get_input_file_version", "get_raw_input_file_version"]))]
     output: Option<String>,
-    /// Delimiter
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Csv)]
+    format: OutputFormat,
+    /// Delimiter, only used for the CSV output format
     #[arg(short, long, default_value = ",")]
     delimiter: char,
+    /// Only convert the columns matching the glob pattern, e.g. 'elac_1.*'. Can be repeated.
+    /// The simulation time is always converted.
+    #[arg(short, long = "select", value_name = "GLOB")]
+    select: Vec<String>,
+    /// Only convert the columns matching the regular expression. Can be repeated.
+    #[arg(long = "select-regex", value_name = "REGEX")]
+    select_regex: Vec<String>,
+    /// Only convert records with a simulation time at or after the given time in seconds
+    #[arg(long)]
+    start_time: Option<f64>,
+    /// Only convert records with a simulation time at or before the given time in seconds
+    #[arg(long)]
+    end_time: Option<f64>,
+    /// Only convert every n-th record within the time window
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    decimation: u32,
     /// Input file is not compressed
     #[arg(short, long, default_value_t = false)]
     no_compression: bool,
@@ -45,54 +65,33 @@ struct Args {
     /// Print raw interface version of input file
     #[arg(short = 'r', long, default_value_t = false)]
     get_raw_input_file_version: bool,
-    /// Disregard the detected FDR file version, will output garbled data if version is mismatched
+    /// Convert files of an unsupported interface version using the newest supported struct layout,
+    /// will output garbled data if the layouts don't match
     #[arg(long, default_value_t = false)]
     override_interface_version: bool,
 }
 
-// Read number of bytes specified by the size of T from the binary file
-pub fn read_bytes<T: AnyBitPattern>(reader: &mut impl Read) -> Result<T, Error> {
-    let size = mem::size_of::<T>();
-
-    // allocate the buffer that will hold the value read from the binary
-    let mut buf = vec![0u8; size];
-
-    // now read from the reader into the buffer
-    reader.read_exact(&mut buf)?;
-
-    // If the read was successful, reinterpret the bytes as the struct, and return
-    let res = bytemuck::from_bytes::<T>(buf.as_slice());
-
-    Ok(*res)
-}
-
 fn main() -> Result<(), std::io::Error> {
     // Parse CLI arguments
     let args = Args::parse();
 
-    // Open the input file
-    let in_file = File::open(args.input.trim())
-        .map_err(|e| std::io::Error::new(e.kind(), "Failed to open input file!"))?;
-
-    // Create Gzip Reader
-    let mut reader: Box<dyn Read> = if args.no_compression {
-        Box::new(BufReader::new(in_file))
+    // Open the input file and read its interface version
+    let input = args.input.trim();
+    let fdr_file = if args.no_compression {
+        FdrFile::open_uncompressed(input)
     } else {
-        Box::new(GzDecoder::new(BufReader::new(in_file)))
-    };
-
-    // Read file version
-    let file_format_version = read_bytes::<u64>(&mut reader)?;
-    let aircraft_type = if file_format_version > a380::INTERFACE_MIN_VERSION {
-        AircraftType::A380
-    } else {
-        AircraftType::A320
-    };
+        FdrFile::open(input)
+    }
+    .map_err(|e| match e {
+        FdrError::Io(e) => std::io::Error::new(e.kind(), "Failed to open input file!"),
+        e => e.into(),
+    })?;
 
-    let aircraft_interface_version = match aircraft_type {
-        AircraftType::A320 => a320::INTERFACE_VERSION,
-        AircraftType::A380 => a380::INTERFACE_VERSION,
-    };
+    let file_format_version = fdr_file.interface_version();
+    let aircraft_type = fdr_file.aircraft_type();
+    let supported_interface_versions = aircraft_type.interface_versions();
+    let newest_interface_version = *supported_interface_versions.last().unwrap();
+    let is_supported = fdr_file.is_supported();
 
     // Print or check file version
     if args.get_input_file_version {
@@ -104,24 +103,34 @@ fn main() -> Result<(), std::io::Error> {
     } else if args.get_raw_input_file_version {
         println!("{}", file_format_version);
         return Ok(());
-    } else if aircraft_interface_version != file_format_version && args.override_interface_version {
-        println!("Mismatch between converter and file version (expected {aircraft_interface_version}, got {file_format_version}). \
-        override_interface_version is set, converting anyway.\n \
+    } else if !is_supported && args.override_interface_version {
+        println!("Unsupported file version {file_format_version} (supported are {supported_interface_versions:?}). \
+        override_interface_version is set, converting anyway using version {newest_interface_version}.\n \
         \x1b[31mWARNING: Will create garbled data if the struct definitions don't match.\x1b[0m");
-    } else if aircraft_interface_version != file_format_version && !args.override_interface_version
-    {
+    } else if !is_supported && !args.override_interface_version {
         return Err(std::io::Error::new(
             ErrorKind::InvalidInput,
             format!(
-                "Mismatch between converter and file version (expected {aircraft_interface_version}, got {file_format_version})",
+                "Unsupported file version {file_format_version} (supported are {supported_interface_versions:?})",
             ),
         ));
     }
 
+    // The interface version of which the struct layout is used to read the records
+    let interface_version = if is_supported {
+        file_format_version
+    } else {
+        newest_interface_version
+    };
+
     // Print info on conversion start
     println!(
-        "Converting from '{}' to '{}' for aircraft type '{:?}' with interface version '{}' and delimiter '{}'",
-        args.input, args.output.clone().unwrap(), aircraft_type, file_format_version, args.delimiter
+        "Converting from '{}' to '{}' as {:?} for aircraft type '{:?}' with interface version '{}'",
+        args.input,
+        args.output.clone().unwrap(),
+        args.format,
+        aircraft_type,
+        file_format_version
     );
 
     // Open or create output file in truncate mode
@@ -132,57 +141,139 @@ fn main() -> Result<(), std::io::Error> {
         .open(args.output.clone().unwrap().trim())
         .map_err(|e| std::io::Error::new(e.kind(), "Failed to open output file!"))?;
 
-    let mut buf_writer = BufWriter::new(out_file);
+    let buf_writer = BufWriter::new(out_file);
+
+    // Every supported interface version needs to be listed here
+    let counter = match (aircraft_type, interface_version) {
+        (AircraftType::A320, a320::v3200006::INTERFACE_VERSION) => convert(
+            fdr_file.records_ignoring_interface_version::<a320::v3200006::FdrData>(),
+            buf_writer,
+            &args,
+        )?,
+        (AircraftType::A380, a380::v3800008::INTERFACE_VERSION) => convert(
+            fdr_file.records_ignoring_interface_version::<a380::v3800008::FdrData>(),
+            buf_writer,
+            &args,
+        )?,
+        _ => {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "Unsupported interface version {interface_version} for aircraft type {aircraft_type:?}",
+                ),
+            ))
+        }
+    };
+
+    println!("Processed {counter} entries...");
+
+    Result::Ok(())
+}
+
+// Reads all records from the file and writes the selected columns of the records passing the
+// filter in the requested format. Records are converted one at a time, such that the file never
+// needs to be loaded into memory as a whole. Returns the number of converted records.
+fn convert<T: FdrRecord>(
+    records: Records<T>,
+    mut buf_writer: BufWriter<File>,
+    args: &Args,
+) -> Result<usize, Error> {
+    let column_names = csv_header_serializer::column_names(&T::default())
+        .map_err(|_| std::io::Error::other("Failed to generate header."))?;
+    let selection = ColumnSelection::new(&column_names, &args.select, &args.select_regex)
+        .map_err(|e| std::io::Error::new(ErrorKind::InvalidInput, e))?;
+    let mut filter = RecordFilter::new(args.start_time, args.end_time, args.decimation as usize);
 
     let mut counter = 0;
+    let mut on_record = || -> Result<(), Error> {
+        counter += 1;
 
-    // Generate and write the header
-    let header = match aircraft_type {
-        AircraftType::A320 => {
-            csv_header_serializer::to_string(&a320::FdrData::default(), args.delimiter)
+        if counter % 1000 == 0 {
+            print!("Processed {counter} entries...\r");
+            std::io::stdout().flush()?;
         }
-        AircraftType::A380 => {
-            csv_header_serializer::to_string(&a380::FdrData::default(), args.delimiter)
-        }
-    }
-    .map_err(|_| std::io::Error::other("Failed to generate header."))?;
 
-    buf_writer.write_all(header.as_bytes())?;
+        Ok(())
+    };
 
-    // Create the CSV writer, and serialize the file.
-    let mut writer = WriterBuilder::new()
-        .delimiter(args.delimiter as u8)
-        .has_headers(false)
-        .from_writer(buf_writer);
+    let columnar_format = match args.format {
+        OutputFormat::Csv => None,
+        OutputFormat::Parquet => Some(ColumnarFormat::Parquet),
+        OutputFormat::ArrowIpc => Some(ColumnarFormat::ArrowIpc),
+    };
 
-    match aircraft_type {
-        AircraftType::A320 => {
-            while let Ok(fdr_data) = a320::read_record(&mut reader) {
-                writer.serialize(&fdr_data)?;
+    match columnar_format {
+        None => {
+            // Generate and write the header
+            let header = selection
+                .selected_names(&column_names)
+                .join(&args.delimiter.to_string());
+
+            buf_writer.write_all(header.as_bytes())?;
+            buf_writer.write_all(b"\n")?;
 
-                counter += 1;
+            // Create the CSV writer, and serialize the file.
+            let mut writer = WriterBuilder::new()
+                .delimiter(args.delimiter as u8)
+                .has_headers(false)
+                .from_writer(buf_writer);
 
-                if counter % 1000 == 0 {
-                    print!("Processed {counter} entries...\r");
-                    std::io::stdout().flush()?;
+            for fdr_data in complete_records(records) {
+                let fdr_data = fdr_data?;
+                if !filter.keep(fdr_data.simulation_time()) {
+                    continue;
                 }
+
+                let mut column = 0;
+                for_each_scalar(&fdr_data, |scalar| {
+                    if selection.is_selected(column) {
+                        scalar
+                            .write_csv_field(&mut writer)
+                            .map_err(|e| error::Error::Message(e.to_string()))?;
+                    }
+                    column += 1;
+
+                    Ok(())
+                })
+                .map_err(std::io::Error::other)?;
+                writer.write_record(None::<&[u8]>)?;
+
+                on_record()?;
             }
-        }
-        AircraftType::A380 => {
-            while let Ok(fdr_data) = a380::read_record(&mut reader) {
-                writer.serialize(&fdr_data)?;
 
-                counter += 1;
+            writer.flush()?;
+        }
+        Some(format) => {
+            let mut writer =
+                ColumnarWriter::new::<T>(buf_writer, format, &column_names, selection)?;
 
-                if counter % 1000 == 0 {
-                    print!("Processed {counter} entries...\r");
-                    std::io::stdout().flush()?;
+            for fdr_data in complete_records(records) {
+                let fdr_data = fdr_data?;
+                if !filter.keep(fdr_data.simulation_time()) {
+                    continue;
                 }
+
+                writer.serialize(&fdr_data)?;
+                on_record()?;
             }
+
+            writer.finish()?;
         }
     }
 
-    println!("Processed {counter} entries...");
+    Ok(counter)
+}
 
-    Result::Ok(())
+// Ends the iteration at a truncated record, which is usually the last record of a recording that
+// was interrupted, such that all records before it are still converted.
+fn complete_records<T: FdrRecord>(
+    records: Records<T>,
+) -> impl Iterator<Item = Result<T, FdrError>> {
+    records.map_while(|record| match record {
+        Err(e @ FdrError::TruncatedRecord { .. }) => {
+            println!("\x1b[33mWARNING: {e}, ignoring the incomplete record.\x1b[0m");
+            None
+        }
+        record => Some(record),
+    })
 }
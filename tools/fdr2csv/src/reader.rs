@@ -0,0 +1,376 @@
+use flate2::read::GzDecoder;
+use std::{
+    fmt::{self, Display},
+    fs::File,
+    io::{self, prelude::*, BufReader, ErrorKind},
+    marker::PhantomData,
+    path::Path,
+};
+
+use crate::{a320, a380, read_bytes, FdrRecord};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AircraftType {
+    A320,
+    A380,
+}
+
+impl AircraftType {
+    // The A380 interface versions are numbered from 3800000 onwards.
+    pub fn from_interface_version(interface_version: u64) -> Self {
+        if interface_version > a380::INTERFACE_MIN_VERSION {
+            AircraftType::A380
+        } else {
+            AircraftType::A320
+        }
+    }
+
+    // All interface versions which can be decoded, the newest one last.
+    pub fn interface_versions(&self) -> &'static [u64] {
+        match self {
+            AircraftType::A320 => &a320::INTERFACE_VERSIONS,
+            AircraftType::A380 => &a380::INTERFACE_VERSIONS,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum FdrError {
+    Io(io::Error),
+    // The file ended before its interface version could be read.
+    MissingInterfaceVersion,
+    // The records were requested with a struct layout of another interface version.
+    InterfaceVersionMismatch { expected: u64, actual: u64 },
+    // The file ended in the middle of the record with the given index.
+    TruncatedRecord { record: u64 },
+}
+
+impl Display for FdrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FdrError::Io(error) => write!(f, "{error}"),
+            FdrError::MissingInterfaceVersion => {
+                write!(f, "The file does not contain an interface version")
+            }
+            FdrError::InterfaceVersionMismatch { expected, actual } => write!(
+                f,
+                "Mismatch between record and file version (expected {expected}, got {actual})"
+            ),
+            FdrError::TruncatedRecord { record } => {
+                write!(f, "The file ends in the middle of record {record}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FdrError {}
+
+impl From<io::Error> for FdrError {
+    fn from(error: io::Error) -> Self {
+        FdrError::Io(error)
+    }
+}
+
+impl From<FdrError> for io::Error {
+    fn from(error: FdrError) -> Self {
+        match error {
+            FdrError::Io(error) => error,
+            FdrError::InterfaceVersionMismatch { .. } => {
+                io::Error::new(ErrorKind::InvalidInput, error)
+            }
+            _ => io::Error::new(ErrorKind::InvalidData, error),
+        }
+    }
+}
+
+// An FDR file of which the interface version has been read, ready to decode its records.
+pub struct FdrFile {
+    reader: CountingReader<Box<dyn Read>>,
+    interface_version: u64,
+}
+
+impl FdrFile {
+    // Opens a gzip compressed FDR file, as written by the aircraft.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, FdrError> {
+        Self::from_reader(BufReader::new(File::open(path)?), true)
+    }
+
+    // Opens an FDR file which is not compressed.
+    pub fn open_uncompressed(path: impl AsRef<Path>) -> Result<Self, FdrError> {
+        Self::from_reader(BufReader::new(File::open(path)?), false)
+    }
+
+    pub fn from_reader(reader: impl Read + 'static, compressed: bool) -> Result<Self, FdrError> {
+        let reader: Box<dyn Read> = if compressed {
+            Box::new(GzDecoder::new(reader))
+        } else {
+            Box::new(reader)
+        };
+        let mut reader = CountingReader {
+            inner: reader,
+            count: 0,
+        };
+
+        let interface_version = read_bytes::<u64>(&mut reader).map_err(|e| {
+            if e.kind() == ErrorKind::UnexpectedEof {
+                FdrError::MissingInterfaceVersion
+            } else {
+                e.into()
+            }
+        })?;
+
+        Ok(FdrFile {
+            reader,
+            interface_version,
+        })
+    }
+
+    pub fn interface_version(&self) -> u64 {
+        self.interface_version
+    }
+
+    pub fn aircraft_type(&self) -> AircraftType {
+        AircraftType::from_interface_version(self.interface_version)
+    }
+
+    pub fn is_supported(&self) -> bool {
+        self.aircraft_type()
+            .interface_versions()
+            .contains(&self.interface_version)
+    }
+
+    // Returns an iterator over the records, which must be of the interface version of the file.
+    pub fn records<T: FdrRecord>(self) -> Result<Records<T>, FdrError> {
+        if self.interface_version != T::INTERFACE_VERSION {
+            return Err(FdrError::InterfaceVersionMismatch {
+                expected: T::INTERFACE_VERSION,
+                actual: self.interface_version,
+            });
+        }
+
+        Ok(self.records_ignoring_interface_version())
+    }
+
+    // Returns an iterator over the records, decoding them with the given struct layout regardless
+    // of the interface version of the file. This produces garbled data if the layouts don't match.
+    pub fn records_ignoring_interface_version<T: FdrRecord>(self) -> Records<T> {
+        Records {
+            reader: self.reader,
+            record: 0,
+            finished: false,
+            record_type: PhantomData,
+        }
+    }
+}
+
+// Iterates over the records of an FDR file. The iteration ends after the first error.
+pub struct Records<T: FdrRecord> {
+    reader: CountingReader<Box<dyn Read>>,
+    record: u64,
+    finished: bool,
+    record_type: PhantomData<T>,
+}
+
+impl<T: FdrRecord> Iterator for Records<T> {
+    type Item = Result<T, FdrError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let start = self.reader.count;
+        match T::read(&mut self.reader) {
+            Ok(record) => {
+                self.record += 1;
+                Some(Ok(record))
+            }
+            // Only a file ending in between two records is complete.
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => {
+                self.finished = true;
+                if self.reader.count == start {
+                    None
+                } else {
+                    Some(Err(FdrError::TruncatedRecord {
+                        record: self.record,
+                    }))
+                }
+            }
+            Err(e) => {
+                self.finished = true;
+                Some(Err(e.into()))
+            }
+        }
+    }
+}
+
+// Keeps track of the number of bytes read, to tell the end of a file from a truncated record.
+struct CountingReader<R: Read> {
+    inner: R,
+    count: u64,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.count += read as u64;
+
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{a320::v3200006, a380::v3800008};
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::{self, Cursor};
+
+    // The size of a record of the given struct layout, found by decoding a record from zeros.
+    fn record_size<T: FdrRecord>() -> usize {
+        let mut reader = CountingReader {
+            inner: io::repeat(0),
+            count: 0,
+        };
+        T::read(&mut reader).unwrap();
+
+        reader.count as usize
+    }
+
+    // An uncompressed file of the given interface version, containing zeroed records of the struct
+    // layout of T which only have their simulation time set.
+    fn file_bytes<T: FdrRecord>(interface_version: u64, simulation_times: &[f64]) -> Vec<u8> {
+        let mut bytes = interface_version.to_le_bytes().to_vec();
+        for simulation_time in simulation_times {
+            let mut record = vec![0; record_size::<T>()];
+            record[..8].copy_from_slice(&simulation_time.to_le_bytes());
+            bytes.extend(record);
+        }
+
+        bytes
+    }
+
+    fn open(bytes: Vec<u8>) -> FdrFile {
+        FdrFile::from_reader(Cursor::new(bytes), false).unwrap()
+    }
+
+    fn simulation_times<T: FdrRecord>(file: FdrFile) -> Vec<f64> {
+        file.records::<T>()
+            .unwrap()
+            .map(|record| record.unwrap().simulation_time())
+            .collect()
+    }
+
+    fn assert_reads_records_of<T: FdrRecord>() {
+        let file = open(file_bytes::<T>(T::INTERFACE_VERSION, &[1.5, 2.]));
+
+        assert_eq!(file.aircraft_type(), T::AIRCRAFT_TYPE);
+        assert!(file.is_supported());
+        assert_eq!(simulation_times::<T>(file), [1.5, 2.]);
+    }
+
+    #[test]
+    fn reads_records_of_every_supported_interface_version() {
+        assert_reads_records_of::<v3200006::FdrData>();
+        assert_reads_records_of::<v3800008::FdrData>();
+    }
+
+    #[test]
+    fn reads_gzip_compressed_files() {
+        let mut encoder = GzEncoder::new(vec![], Compression::default());
+        encoder
+            .write_all(&file_bytes::<v3200006::FdrData>(
+                v3200006::INTERFACE_VERSION,
+                &[3.],
+            ))
+            .unwrap();
+        let file = FdrFile::from_reader(Cursor::new(encoder.finish().unwrap()), true).unwrap();
+
+        assert_eq!(file.interface_version(), v3200006::INTERFACE_VERSION);
+        assert_eq!(simulation_times::<v3200006::FdrData>(file), [3.]);
+    }
+
+    #[test]
+    fn reports_missing_interface_version() {
+        assert!(matches!(
+            FdrFile::from_reader(Cursor::new(vec![0; 4]), false),
+            Err(FdrError::MissingInterfaceVersion)
+        ));
+    }
+
+    #[test]
+    fn rejects_records_of_another_interface_version() {
+        let file = open(file_bytes::<v3200006::FdrData>(
+            v3200006::INTERFACE_VERSION,
+            &[1.],
+        ));
+
+        assert!(matches!(
+            file.records::<v3800008::FdrData>(),
+            Err(FdrError::InterfaceVersionMismatch {
+                expected: v3800008::INTERFACE_VERSION,
+                actual: v3200006::INTERFACE_VERSION
+            })
+        ));
+    }
+
+    #[test]
+    fn ignoring_the_interface_version_decodes_records_of_another_layout() {
+        let file = open(file_bytes::<v3800008::FdrData>(
+            v3800008::INTERFACE_VERSION + 1,
+            &[1.],
+        ));
+
+        let mut records = file.records_ignoring_interface_version::<v3800008::FdrData>();
+        assert_eq!(records.next().unwrap().unwrap().simulation_time(), 1.);
+        assert!(records.next().is_none());
+    }
+
+    #[test]
+    fn reports_truncated_record_and_ends_iteration() {
+        let mut bytes = file_bytes::<v3800008::FdrData>(v3800008::INTERFACE_VERSION, &[1., 2.]);
+        bytes.pop();
+        let mut records = open(bytes).records::<v3800008::FdrData>().unwrap();
+
+        assert_eq!(records.next().unwrap().unwrap().simulation_time(), 1.);
+        assert!(matches!(
+            records.next(),
+            Some(Err(FdrError::TruncatedRecord { record: 1 }))
+        ));
+        assert!(records.next().is_none());
+    }
+
+    #[test]
+    fn file_without_records_is_complete() {
+        let file = open(file_bytes::<v3800008::FdrData>(
+            v3800008::INTERFACE_VERSION,
+            &[],
+        ));
+
+        assert!(file
+            .records::<v3800008::FdrData>()
+            .unwrap()
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn truncated_record_is_reported_as_invalid_data() {
+        let error: io::Error = FdrError::TruncatedRecord { record: 3 }.into();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "The file ends in the middle of record 3");
+    }
+
+    #[test]
+    fn every_interface_version_is_listed_as_supported() {
+        assert_eq!(
+            AircraftType::A320.interface_versions(),
+            [v3200006::INTERFACE_VERSION]
+        );
+        assert_eq!(
+            AircraftType::A380.interface_versions(),
+            [v3800008::INTERFACE_VERSION]
+        );
+    }
+}
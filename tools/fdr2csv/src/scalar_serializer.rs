@@ -0,0 +1,235 @@
+use serde::{ser, Serialize};
+use std::io::Write;
+
+use crate::error::{Error, Result};
+
+// A single elementary value of a record, typed as it is defined in the bindgen generated structs.
+#[derive(Clone, Copy)]
+pub enum Scalar {
+    Boolean(bool),
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    UInt8(u8),
+    UInt16(u16),
+    UInt32(u32),
+    UInt64(u64),
+    Float32(f32),
+    Float64(f64),
+}
+
+impl Scalar {
+    // Writes the value as a field of the current record, formatted like the csv crate
+    // serializes it.
+    pub fn write_csv_field<W: Write>(&self, writer: &mut csv::Writer<W>) -> csv::Result<()> {
+        let mut integer = itoa::Buffer::new();
+        let mut float = ryu::Buffer::new();
+
+        match *self {
+            Scalar::Boolean(v) => writer.write_field(if v { "true" } else { "false" }),
+            Scalar::Int8(v) => writer.write_field(integer.format(v)),
+            Scalar::Int16(v) => writer.write_field(integer.format(v)),
+            Scalar::Int32(v) => writer.write_field(integer.format(v)),
+            Scalar::Int64(v) => writer.write_field(integer.format(v)),
+            Scalar::UInt8(v) => writer.write_field(integer.format(v)),
+            Scalar::UInt16(v) => writer.write_field(integer.format(v)),
+            Scalar::UInt32(v) => writer.write_field(integer.format(v)),
+            Scalar::UInt64(v) => writer.write_field(integer.format(v)),
+            Scalar::Float32(v) => writer.write_field(float.format(v)),
+            Scalar::Float64(v) => writer.write_field(float.format(v)),
+        }
+    }
+}
+
+// Calls the given function for each elementary value of the record, in the same order as the
+// column names returned by the csv_header_serializer.
+pub fn for_each_scalar<T, F>(value: &T, visit: F) -> Result<()>
+where
+    T: Serialize,
+    F: FnMut(Scalar) -> Result<()>,
+{
+    let mut serializer = ScalarSerializer { visit };
+    value.serialize(&mut serializer)
+}
+
+struct ScalarSerializer<F: FnMut(Scalar) -> Result<()>> {
+    visit: F,
+}
+
+impl<F: FnMut(Scalar) -> Result<()>> ser::Serializer for &mut ScalarSerializer<F> {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = ser::Impossible<(), Error>;
+    type SerializeTuple = ser::Impossible<(), Error>;
+    type SerializeTupleStruct = ser::Impossible<(), Error>;
+    type SerializeTupleVariant = ser::Impossible<(), Error>;
+    type SerializeMap = ser::Impossible<(), Error>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = ser::Impossible<(), Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        (self.visit)(Scalar::Boolean(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        (self.visit)(Scalar::Int8(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        (self.visit)(Scalar::Int16(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        (self.visit)(Scalar::Int32(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        (self.visit)(Scalar::Int64(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        (self.visit)(Scalar::UInt8(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        (self.visit)(Scalar::UInt16(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        (self.visit)(Scalar::UInt32(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        (self.visit)(Scalar::UInt64(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<()> {
+        (self.visit)(Scalar::Float32(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<()> {
+        (self.visit)(Scalar::Float64(v))
+    }
+
+    fn serialize_char(self, _v: char) -> Result<()> {
+        Err(Error::Message("Unsupported datatype".to_owned()))
+    }
+
+    fn serialize_str(self, _v: &str) -> Result<()> {
+        Err(Error::Message("Unsupported datatype".to_owned()))
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<()> {
+        Err(Error::Message("Unsupported datatype".to_owned()))
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        Err(Error::Message("Unsupported datatype".to_owned()))
+    }
+
+    fn serialize_some<T>(self, _value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(Error::Message("Unsupported datatype".to_owned()))
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        Err(Error::Message("Unsupported datatype".to_owned()))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        Err(Error::Message("Unsupported datatype".to_owned()))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<()> {
+        Err(Error::Message("Unsupported datatype".to_owned()))
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, _value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(Error::Message("Unsupported datatype".to_owned()))
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(Error::Message("Unsupported datatype".to_owned()))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Err(Error::Message("Unsupported datatype".to_owned()))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        Err(Error::Message("Unsupported datatype".to_owned()))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        Err(Error::Message("Unsupported datatype".to_owned()))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(Error::Message("Unsupported datatype".to_owned()))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Err(Error::Message("Unsupported datatype".to_owned()))
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(Error::Message("Unsupported datatype".to_owned()))
+    }
+}
+
+impl<F: FnMut(Scalar) -> Result<()>> ser::SerializeStruct for &mut ScalarSerializer<F> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,263 @@
+use regex::RegexSet;
+
+// The column which contains the simulation time of a record. It is always selected, as
+// records without it cannot be put into relation with each other.
+pub const TIME_COLUMN: &str = "base.simulation_time_s";
+
+// Selects the columns to convert, by matching the flattened header names produced by the
+// csv_header_serializer against glob and regular expression patterns.
+pub struct ColumnSelection {
+    selected: Vec<bool>,
+}
+
+impl ColumnSelection {
+    // Selects the columns matching any of the patterns. Without any pattern, all columns are
+    // selected.
+    pub fn new(
+        column_names: &[String],
+        globs: &[String],
+        regexes: &[String],
+    ) -> Result<Self, regex::Error> {
+        let selected = if globs.is_empty() && regexes.is_empty() {
+            vec![true; column_names.len()]
+        } else {
+            let patterns = RegexSet::new(
+                globs
+                    .iter()
+                    .map(|glob| glob_to_regex(glob))
+                    .chain(regexes.iter().cloned()),
+            )?;
+
+            column_names
+                .iter()
+                .map(|name| name == TIME_COLUMN || patterns.is_match(name))
+                .collect()
+        };
+
+        Ok(ColumnSelection { selected })
+    }
+
+    pub fn is_selected(&self, column: usize) -> bool {
+        self.selected.get(column).copied().unwrap_or(false)
+    }
+
+    pub fn selected_names<'a>(&self, column_names: &'a [String]) -> Vec<&'a str> {
+        column_names
+            .iter()
+            .enumerate()
+            .filter(|(column, _)| self.is_selected(*column))
+            .map(|(_, name)| name.as_str())
+            .collect()
+    }
+}
+
+// Converts a glob pattern, in which '*' matches any sequence of characters and '?' matches a
+// single character, into a regular expression matching the whole column name.
+fn glob_to_regex(glob: &str) -> String {
+    let mut regex = String::from("^");
+    for c in glob.chars() {
+        match c {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            _ => regex.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+        }
+    }
+    regex.push('$');
+
+    regex
+}
+
+// Keeps only the records within a time window, and of those only every n-th record.
+pub struct RecordFilter {
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    decimation: usize,
+    records_in_window: usize,
+}
+
+impl RecordFilter {
+    pub fn new(start_time: Option<f64>, end_time: Option<f64>, decimation: usize) -> Self {
+        RecordFilter {
+            start_time,
+            end_time,
+            decimation: decimation.max(1),
+            records_in_window: 0,
+        }
+    }
+
+    pub fn keep(&mut self, simulation_time: f64) -> bool {
+        let in_window = self.start_time.is_none_or(|start| simulation_time >= start)
+            && self.end_time.is_none_or(|end| simulation_time <= end);
+        if !in_window {
+            return false;
+        }
+
+        self.records_in_window += 1;
+
+        (self.records_in_window - 1).is_multiple_of(self.decimation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column_names() -> Vec<String> {
+        [
+            TIME_COLUMN,
+            "elac_1.bus_outputs.pitch_law",
+            "elac_2.bus_outputs.pitch_law",
+            "sec_1.analog_outputs.spoiler_pos",
+            "fac_1.bus_outputs.gamma_a",
+        ]
+        .map(String::from)
+        .to_vec()
+    }
+
+    fn selected_names(globs: &[&str], regexes: &[&str]) -> Vec<String> {
+        let column_names = column_names();
+        let selection = ColumnSelection::new(
+            &column_names,
+            &globs
+                .iter()
+                .map(|glob| glob.to_string())
+                .collect::<Vec<_>>(),
+            &regexes
+                .iter()
+                .map(|regex| regex.to_string())
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+
+        selection
+            .selected_names(&column_names)
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn selects_all_columns_without_patterns() {
+        assert_eq!(selected_names(&[], &[]), column_names());
+    }
+
+    #[test]
+    fn glob_star_matches_any_sequence() {
+        assert_eq!(
+            selected_names(&["elac_*"], &[]),
+            [
+                TIME_COLUMN,
+                "elac_1.bus_outputs.pitch_law",
+                "elac_2.bus_outputs.pitch_law"
+            ]
+        );
+    }
+
+    #[test]
+    fn glob_question_mark_matches_a_single_character() {
+        assert_eq!(
+            selected_names(&["elac_?.bus_outputs.pitch_law"], &[]),
+            [
+                TIME_COLUMN,
+                "elac_1.bus_outputs.pitch_law",
+                "elac_2.bus_outputs.pitch_law"
+            ]
+        );
+        assert_eq!(selected_names(&["elac_?"], &[]), [TIME_COLUMN]);
+    }
+
+    #[test]
+    fn glob_matches_the_whole_name_and_treats_dots_literally() {
+        assert_eq!(selected_names(&["pitch_law"], &[]), [TIME_COLUMN]);
+        assert_eq!(selected_names(&["elac_1_bus*"], &[]), [TIME_COLUMN]);
+    }
+
+    #[test]
+    fn regex_matches_anywhere_in_the_name() {
+        assert_eq!(
+            selected_names(&[], &["outputs\\.(spoiler|gamma)"]),
+            [
+                TIME_COLUMN,
+                "sec_1.analog_outputs.spoiler_pos",
+                "fac_1.bus_outputs.gamma_a"
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_columns_only_select_the_time() {
+        assert_eq!(selected_names(&["fmgc_1.*"], &["^unknown$"]), [TIME_COLUMN]);
+    }
+
+    #[test]
+    fn columns_outside_of_the_record_are_not_selected() {
+        let column_names = column_names();
+        let selection = ColumnSelection::new(&column_names, &[], &[]).unwrap();
+
+        assert!(!selection.is_selected(column_names.len()));
+    }
+
+    #[test]
+    fn selected_columns_keep_the_record_order() {
+        assert_eq!(
+            selected_names(&["fac_1.*", "sec_1.*"], &["elac_2"]),
+            [
+                TIME_COLUMN,
+                "elac_2.bus_outputs.pitch_law",
+                "sec_1.analog_outputs.spoiler_pos",
+                "fac_1.bus_outputs.gamma_a"
+            ]
+        );
+    }
+
+    fn kept_times(mut filter: RecordFilter, times: &[f64]) -> Vec<f64> {
+        times
+            .iter()
+            .copied()
+            .filter(|&time| filter.keep(time))
+            .collect()
+    }
+
+    #[test]
+    fn keeps_all_records_without_window_and_decimation() {
+        assert_eq!(
+            kept_times(RecordFilter::new(None, None, 1), &[0., 1., 2.]),
+            [0., 1., 2.]
+        );
+    }
+
+    #[test]
+    fn keeps_records_within_the_time_window_inclusively() {
+        assert_eq!(
+            kept_times(
+                RecordFilter::new(Some(1.), Some(3.), 1),
+                &[0., 1., 2., 3., 4.]
+            ),
+            [1., 2., 3.]
+        );
+    }
+
+    #[test]
+    fn decimation_starts_at_the_first_record_within_the_window() {
+        assert_eq!(
+            kept_times(
+                RecordFilter::new(Some(1.), None, 2),
+                &[0., 1., 2., 3., 4., 5.]
+            ),
+            [1., 3., 5.]
+        );
+    }
+
+    #[test]
+    fn zero_decimation_keeps_every_record() {
+        assert_eq!(
+            kept_times(RecordFilter::new(None, None, 0), &[0., 1.]),
+            [0., 1.]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_regex() {
+        assert!(ColumnSelection::new(&column_names(), &[], &["(".to_string()]).is_err());
+    }
+}
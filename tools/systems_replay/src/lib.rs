@@ -0,0 +1,451 @@
+//! Runs an [`Aircraft`] outside of the simulator by replaying a recorded time series of
+//! simulator variables into it, while capturing every variable the simulation writes.
+//!
+//! The recorded trace contains the variables as seen by the systems simulation, thus after
+//! any transformation applied by the aspects of the `systems_wasm` bridging layer.
+use std::{
+    error::Error,
+    io::{self, BufRead, Write},
+    time::Duration,
+};
+use systems::simulation::{
+    Aircraft, InitContext, Simulation, SimulatorReaderWriter, StartState, VariableIdentifier,
+    VariableTraceRecorder,
+};
+
+mod registry;
+mod trace;
+
+pub use registry::ReplayVariableRegistry;
+pub use trace::{TraceError, TraceReader, TraceSample, TraceWriter, TIME_COLUMN};
+
+/// The simulator caps the delta time of a single tick. Recorded deltas exceeding this duration
+/// are split into multiple ticks, so a trace sampled at a low rate still replays in real time.
+pub const MAX_ALLOWED_DELTA_TIME: Duration = Duration::from_millis(500);
+
+/// Provides the simulation with the recorded variables and stores whatever it writes.
+///
+/// Variables which are not part of the trace retain the last written value, just like
+/// named variables within the simulator.
+pub struct ReplayReaderWriter {
+    values: Vec<f64>,
+    written: Vec<bool>,
+}
+
+impl ReplayReaderWriter {
+    fn new(variable_count: usize) -> Self {
+        Self {
+            values: vec![0.; variable_count],
+            written: vec![false; variable_count],
+        }
+    }
+
+    fn ensure_capacity(&mut self, identifier: &VariableIdentifier) {
+        let index = identifier.identifier_index();
+        if index >= self.values.len() {
+            self.values.resize(index + 1, 0.);
+            self.written.resize(index + 1, false);
+        }
+    }
+
+    fn set(&mut self, identifier: &VariableIdentifier, value: f64) {
+        self.ensure_capacity(identifier);
+        self.values[identifier.identifier_index()] = value;
+    }
+
+    fn get(&self, identifier: &VariableIdentifier) -> f64 {
+        self.values
+            .get(identifier.identifier_index())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn is_written(&self, identifier: &VariableIdentifier) -> bool {
+        self.written
+            .get(identifier.identifier_index())
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+impl SimulatorReaderWriter for ReplayReaderWriter {
+    fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+        self.get(identifier)
+    }
+
+    fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+        self.set(identifier, value);
+        self.written[identifier.identifier_index()] = true;
+    }
+}
+
+/// Drives a [`Simulation`] using the samples of a recorded trace.
+pub struct Replay<T: Aircraft> {
+    simulation: Simulation<T>,
+    registry: ReplayVariableRegistry,
+    reader_writer: ReplayReaderWriter,
+    column_identifiers: Vec<Option<VariableIdentifier>>,
+    previous_simulation_time: Option<f64>,
+}
+
+impl<T: Aircraft> Replay<T> {
+    /// Creates the aircraft of which all random behaviour is derived from the given seed, such
+    /// that replaying the same trace with the same seed produces the same output.
+    pub fn new<U: FnOnce(&mut InitContext) -> T>(
+        start_state: StartState,
+        seed: u64,
+        named_variable_prefix: &str,
+        columns: &[String],
+        aircraft_ctor_fn: U,
+    ) -> Self {
+        let mut registry =
+            ReplayVariableRegistry::new(named_variable_prefix, columns.iter().map(String::as_str));
+        let simulation =
+            Simulation::new_with_seed(start_state, seed, aircraft_ctor_fn, &mut registry);
+        let column_identifiers = columns.iter().map(|column| registry.find(column)).collect();
+
+        Self {
+            reader_writer: ReplayReaderWriter::new(registry.len()),
+            simulation,
+            registry,
+            column_identifiers,
+            previous_simulation_time: None,
+        }
+    }
+
+    /// Runs the simulation up to the moment the sample was recorded. The first sample is used
+    /// for a tick without any passage of time.
+    pub fn tick(&mut self, sample: &TraceSample) {
+        for (delta, simulation_time) in self.steps(sample) {
+            self.simulation
+                .tick(delta, simulation_time, &mut self.reader_writer);
+        }
+    }
+
+    /// Runs the simulation like [`tick`], while recording all variables it reads and writes.
+    ///
+    /// [`tick`]: #method.tick
+    pub fn tick_recorded<W: Write>(
+        &mut self,
+        sample: &TraceSample,
+        recorder: &mut VariableTraceRecorder<W>,
+    ) -> io::Result<()> {
+        for (delta, simulation_time) in self.steps(sample) {
+            self.simulation.tick(
+                delta,
+                simulation_time,
+                &mut recorder.wrap(&mut self.reader_writer),
+            );
+            recorder.finish_tick(simulation_time, &self.registry)?;
+        }
+
+        Ok(())
+    }
+
+    /// Provides the simulation with the values of the sample and returns the delta and
+    /// simulation time of each tick needed to reach it.
+    fn steps(&mut self, sample: &TraceSample) -> Vec<(Duration, f64)> {
+        for (identifier, value) in self
+            .column_identifiers
+            .iter()
+            .zip(sample.values())
+            .filter_map(|(identifier, value)| identifier.zip(*value))
+        {
+            self.reader_writer.set(&identifier, value);
+        }
+
+        let delta = Duration::from_secs_f64(
+            self.previous_simulation_time
+                .map_or(0., |previous| sample.simulation_time() - previous),
+        );
+        self.previous_simulation_time = Some(sample.simulation_time());
+
+        let steps = (delta.as_secs_f64() / MAX_ALLOWED_DELTA_TIME.as_secs_f64())
+            .ceil()
+            .max(1.) as u32;
+        let step_delta = delta / steps;
+        (1..=steps)
+            .map(|step| {
+                (
+                    step_delta,
+                    sample.simulation_time() - (delta - step_delta * step).as_secs_f64(),
+                )
+            })
+            .collect()
+    }
+
+    pub fn simulation_mut(&mut self) -> &mut Simulation<T> {
+        &mut self.simulation
+    }
+
+    pub fn registry(&self) -> &ReplayVariableRegistry {
+        &self.registry
+    }
+
+    /// Columns of the trace which were not requested by the aircraft and are thus ignored.
+    pub fn unused_columns<'a>(
+        &'a self,
+        columns: &'a [String],
+    ) -> impl Iterator<Item = &'a str> + 'a {
+        columns
+            .iter()
+            .zip(self.column_identifiers.iter())
+            .filter(|(column, identifier)| identifier.is_none() && *column != TIME_COLUMN)
+            .map(|(column, _)| column.as_str())
+    }
+
+    /// All variables written by the simulation so far, in the order of registration.
+    pub fn written_variables(&self) -> Vec<VariableIdentifier> {
+        self.registry
+            .identifiers()
+            .filter(|identifier| self.reader_writer.is_written(identifier))
+            .collect()
+    }
+
+    pub fn read(&self, identifier: &VariableIdentifier) -> f64 {
+        self.reader_writer.get(identifier)
+    }
+
+    pub fn read_by_name(&self, name: &str) -> Option<f64> {
+        self.registry
+            .find(name)
+            .map(|identifier| self.read(&identifier))
+    }
+}
+
+/// The outcome of replaying a whole trace.
+#[derive(Debug, PartialEq)]
+pub struct ReplaySummary {
+    pub sample_count: usize,
+    pub unused_columns: Vec<String>,
+}
+
+/// Replays the whole trace into a newly created aircraft and writes every variable written by
+/// the simulation to the output, one line per sample. The set of output variables is
+/// determined by the first tick.
+///
+/// When a recorder is given, every variable read and written by the simulation is recorded
+/// into it as well.
+///
+/// Returns the number of replayed samples and the columns of the trace which were not used
+/// by the aircraft.
+pub fn replay<T: Aircraft, R: BufRead, W: Write, U: FnOnce(&mut InitContext) -> T>(
+    trace: TraceReader<R>,
+    output: W,
+    mut recorder: Option<&mut VariableTraceRecorder<Box<dyn Write>>>,
+    start_state: StartState,
+    seed: u64,
+    named_variable_prefix: &str,
+    aircraft_ctor_fn: U,
+) -> Result<ReplaySummary, Box<dyn Error>> {
+    let columns = trace.columns().to_vec();
+    let mut replay = Replay::new(
+        start_state,
+        seed,
+        named_variable_prefix,
+        &columns,
+        aircraft_ctor_fn,
+    );
+    let unused_columns = replay.unused_columns(&columns).map(str::to_owned).collect();
+
+    let mut output = Some(output);
+    let mut writer = None;
+    let mut outputs = vec![];
+    let mut values = vec![];
+    let mut count = 0;
+    for sample in trace {
+        let sample = sample?;
+        match recorder.as_deref_mut() {
+            Some(recorder) => replay.tick_recorded(&sample, recorder)?,
+            None => replay.tick(&sample),
+        }
+
+        if writer.is_none() {
+            outputs = replay.written_variables();
+            let names: Vec<&str> = outputs
+                .iter()
+                .map(|identifier| replay.registry().name(identifier))
+                .collect();
+            writer = Some(TraceWriter::new(output.take().unwrap(), &names)?);
+        }
+
+        values.clear();
+        values.extend(outputs.iter().map(|identifier| replay.read(identifier)));
+        if let Some(writer) = writer.as_mut() {
+            writer.write_sample(sample.simulation_time(), &values)?;
+        }
+
+        count += 1;
+    }
+
+    if let Some(mut writer) = writer {
+        writer.flush()?;
+    }
+
+    if let Some(recorder) = recorder {
+        recorder.flush()?;
+    }
+
+    Ok(ReplaySummary {
+        sample_count: count,
+        unused_columns,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use systems::simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableTraceFormat, Write,
+    };
+
+    struct Doubler {
+        input_id: VariableIdentifier,
+        output_id: VariableIdentifier,
+        elapsed_id: VariableIdentifier,
+        ticks_id: VariableIdentifier,
+        input: f64,
+        elapsed: Duration,
+        ticks: usize,
+    }
+    impl Doubler {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                input_id: context.get_identifier("INPUT".to_owned()),
+                output_id: context.get_identifier("OUTPUT".to_owned()),
+                elapsed_id: context.get_identifier("ELAPSED".to_owned()),
+                ticks_id: context.get_identifier("TICKS".to_owned()),
+                input: 0.,
+                elapsed: Duration::ZERO,
+                ticks: 0,
+            }
+        }
+    }
+    impl Aircraft for Doubler {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.elapsed += context.delta();
+            self.ticks += 1;
+        }
+    }
+    impl SimulationElement for Doubler {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            visitor.visit(self);
+        }
+
+        fn read(&mut self, reader: &mut SimulatorReader) {
+            self.input = reader.read(&self.input_id);
+        }
+
+        fn write(&self, writer: &mut SimulatorWriter) {
+            writer.write(&self.output_id, self.input * 2.);
+            writer.write(&self.elapsed_id, self.elapsed);
+            writer.write(&self.ticks_id, self.ticks);
+        }
+    }
+
+    fn run(trace: &str) -> String {
+        let mut output = vec![];
+        replay(
+            TraceReader::new(trace.as_bytes()).unwrap(),
+            &mut output,
+            None,
+            StartState::Cruise,
+            0,
+            "A32NX_",
+            Doubler::new,
+        )
+        .unwrap();
+
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn writes_outputs_for_every_sample() {
+        let output = run("SIMULATION TIME,A32NX_INPUT\n10,1\n10.25,2\n10.5,\n");
+
+        assert_eq!(
+            output,
+            "SIMULATION TIME,A32NX_OUTPUT,A32NX_ELAPSED,A32NX_TICKS\n10,2,0,1\n10.25,4,0.25,2\n10.5,4,0.5,3\n"
+        );
+    }
+
+    #[test]
+    fn uses_recorded_names_without_prefix() {
+        let output = run("SIMULATION TIME,INPUT\n0,3\n");
+
+        assert_eq!(
+            output,
+            "SIMULATION TIME,A32NX_OUTPUT,A32NX_ELAPSED,A32NX_TICKS\n0,6,0,1\n"
+        );
+    }
+
+    #[test]
+    fn returns_sample_count_and_unused_columns() {
+        let summary = replay(
+            TraceReader::new("SIMULATION TIME,A32NX_INPUT,UNUSED\n0,1,2\n1,2,3\n".as_bytes())
+                .unwrap(),
+            vec![],
+            None,
+            StartState::Cruise,
+            0,
+            "A32NX_",
+            Doubler::new,
+        )
+        .unwrap();
+
+        assert_eq!(
+            summary,
+            ReplaySummary {
+                sample_count: 2,
+                unused_columns: vec!["UNUSED".to_owned()],
+            }
+        );
+    }
+
+    #[test]
+    fn splits_long_deltas_into_multiple_ticks() {
+        let columns = vec![TIME_COLUMN.to_owned()];
+        let mut replay = Replay::new(StartState::Cruise, 0, "A32NX_", &columns, Doubler::new);
+        let mut trace = TraceReader::new("SIMULATION TIME\n0\n2\n".as_bytes()).unwrap();
+
+        replay.tick(&trace.next().unwrap().unwrap());
+        replay.tick(&trace.next().unwrap().unwrap());
+
+        assert_eq!(replay.read_by_name("A32NX_ELAPSED"), Some(2.));
+        assert_eq!(replay.read_by_name("A32NX_TICKS"), Some(5.));
+    }
+
+    #[test]
+    fn records_variable_accesses() {
+        let columns = vec![TIME_COLUMN.to_owned(), "A32NX_INPUT".to_owned()];
+        let mut replay = Replay::new(StartState::Cruise, 0, "A32NX_", &columns, Doubler::new);
+        let mut trace = TraceReader::new("SIMULATION TIME,A32NX_INPUT\n0,3\n".as_bytes()).unwrap();
+        let mut recorder = VariableTraceRecorder::new(vec![], VariableTraceFormat::Csv)
+            .with_name_prefixes(["A32NX_INPUT", "A32NX_OUTPUT"]);
+
+        replay
+            .tick_recorded(&trace.next().unwrap().unwrap(), &mut recorder)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(recorder.into_inner()).unwrap(),
+            "time,access,name,value\n0,R,A32NX_INPUT,3\n0,W,A32NX_OUTPUT,6\n"
+        );
+    }
+
+    #[test]
+    fn reports_unused_columns() {
+        let columns = vec![
+            TIME_COLUMN.to_owned(),
+            "A32NX_INPUT".to_owned(),
+            "UNUSED".to_owned(),
+        ];
+        let replay = Replay::new(StartState::Cruise, 0, "A32NX_", &columns, Doubler::new);
+
+        assert_eq!(
+            replay.unused_columns(&columns).collect::<Vec<_>>(),
+            ["UNUSED"]
+        );
+    }
+}
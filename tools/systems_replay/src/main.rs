@@ -0,0 +1,168 @@
+use a320_systems::A320;
+use a380_systems::A380;
+use std::{
+    env,
+    error::Error,
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+    process,
+};
+use systems::simulation::{StartState, VariableTraceFormat, VariableTraceRecorder};
+use systems_replay::{replay, TraceReader};
+
+const USAGE: &str = "\
+Usage: systems_replay <a320|a380> <trace> <output> [--start-state <1-8>] [--seed <seed>]
+                      [--prefix <prefix>] [--record <file>] [--record-prefix <prefix>]...
+
+Replays a recorded trace of simulator variables into the aircraft systems simulation and writes
+every variable written by the simulation to the output file.
+
+The trace is a comma delimited file of which the first line contains the variable names. It must
+contain a 'SIMULATION TIME' column in seconds, from which the delta of each tick is derived.
+
+Options:
+    --start-state <1-8>   The start state as written to START_STATE by the simulator [default: 6]
+    --seed <seed>         The seed from which all random behaviour is derived [default: 0]
+    --prefix <prefix>     The prefix of named variables [default: A32NX_]
+    --record <file>       Records every variable read and written by the simulation, as CSV when
+                          the file name ends with '.csv' and in a compact binary format otherwise
+    --record-prefix <prefix>
+                          Only records variables of which the name starts with the prefix";
+
+enum AircraftType {
+    A320,
+    A380,
+}
+
+struct Args {
+    aircraft_type: AircraftType,
+    input: String,
+    output: String,
+    start_state: StartState,
+    seed: u64,
+    prefix: String,
+    record: Option<String>,
+    record_prefixes: Vec<String>,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let aircraft_type = match args.next().as_deref() {
+            Some("a320") => AircraftType::A320,
+            Some("a380") => AircraftType::A380,
+            Some(other) => return Err(format!("Unknown aircraft type '{}'.", other)),
+            None => return Err("Missing aircraft type.".into()),
+        };
+        let input = args.next().ok_or("Missing trace file.")?;
+        let output = args.next().ok_or("Missing output file.")?;
+
+        let mut start_state = StartState::default();
+        let mut seed = 0;
+        let mut prefix = "A32NX_".to_owned();
+        let mut record = None;
+        let mut record_prefixes = vec![];
+        while let Some(option) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("Missing value for '{}'.", option))?;
+            match option.as_str() {
+                "--start-state" => {
+                    start_state = value
+                        .parse::<f64>()
+                        .map_err(|_| format!("Invalid start state '{}'.", value))?
+                        .into();
+                }
+                "--seed" => {
+                    seed = value
+                        .parse()
+                        .map_err(|_| format!("Invalid seed '{}'.", value))?;
+                }
+                "--prefix" => prefix = value,
+                "--record" => record = Some(value),
+                "--record-prefix" => record_prefixes.push(value),
+                _ => return Err(format!("Unknown option '{}'.", option)),
+            }
+        }
+
+        Ok(Self {
+            aircraft_type,
+            input,
+            output,
+            start_state,
+            seed,
+            prefix,
+            record,
+            record_prefixes,
+        })
+    }
+}
+
+fn run(args: Args) -> Result<(), Box<dyn Error>> {
+    let trace = TraceReader::new(BufReader::new(File::open(&args.input)?))?;
+    let output = BufWriter::new(File::create(&args.output)?);
+    let mut recorder = match &args.record {
+        Some(path) => {
+            let format = if path.ends_with(".csv") {
+                VariableTraceFormat::Csv
+            } else {
+                VariableTraceFormat::Binary
+            };
+            let output: Box<dyn Write> = Box::new(BufWriter::new(File::create(path)?));
+
+            Some(
+                VariableTraceRecorder::new(output, format)
+                    .with_name_prefixes(args.record_prefixes.iter().map(String::as_str)),
+            )
+        }
+        None => None,
+    };
+
+    println!(
+        "Replaying '{}' into '{}' with start state {:?} and seed {}",
+        args.input, args.output, args.start_state, args.seed
+    );
+
+    let summary = match args.aircraft_type {
+        AircraftType::A320 => replay(
+            trace,
+            output,
+            recorder.as_mut(),
+            args.start_state,
+            args.seed,
+            &args.prefix,
+            A320::new,
+        )?,
+        AircraftType::A380 => replay(
+            trace,
+            output,
+            recorder.as_mut(),
+            args.start_state,
+            args.seed,
+            &args.prefix,
+            A380::new,
+        )?,
+    };
+
+    for column in &summary.unused_columns {
+        eprintln!("Column '{}' is not used by the aircraft.", column);
+    }
+
+    println!("Replayed {} samples.", summary.sample_count);
+
+    Ok(())
+}
+
+fn main() {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Err(error) = run(args) {
+        eprintln!("Replay failed: {}", error);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,134 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use systems::simulation::{VariableIdentifier, VariableNames, VariableRegistry};
+
+/// Hands out identifiers for the variables requested by the aircraft, resolving each requested
+/// name to the name under which it is found in the recorded trace.
+///
+/// Within the simulator, aircraft variables are registered up front and thus shadow the
+/// prefixing of named variables. The recorded trace takes over that role here: a name which is
+/// present in the trace as-is is used without a prefix, all other names are prefixed.
+pub struct ReplayVariableRegistry {
+    named_variable_prefix: String,
+    recorded_names: FxHashSet<String>,
+    name_to_identifier: FxHashMap<String, VariableIdentifier>,
+    names: Vec<String>,
+    next_identifier: VariableIdentifier,
+}
+
+impl ReplayVariableRegistry {
+    pub fn new<'a>(
+        named_variable_prefix: &str,
+        recorded_names: impl IntoIterator<Item = &'a str>,
+    ) -> Self {
+        Self {
+            named_variable_prefix: named_variable_prefix.to_owned(),
+            recorded_names: recorded_names.into_iter().map(str::to_owned).collect(),
+            name_to_identifier: FxHashMap::default(),
+            names: vec![],
+            next_identifier: VariableIdentifier::default(),
+        }
+    }
+
+    pub fn find(&self, name: &str) -> Option<VariableIdentifier> {
+        self.name_to_identifier.get(name).copied()
+    }
+
+    pub fn name(&self, identifier: &VariableIdentifier) -> &str {
+        &self.names[identifier.identifier_index()]
+    }
+
+    /// The number of variables registered so far. Identifiers are indexed from zero up to this
+    /// number.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn identifiers(&self) -> impl Iterator<Item = VariableIdentifier> + '_ {
+        self.names
+            .iter()
+            .map(|name| self.name_to_identifier[name.as_str()])
+    }
+
+    fn get_or_create(&mut self, name: String) -> VariableIdentifier {
+        match self.name_to_identifier.get(&name) {
+            Some(identifier) => *identifier,
+            None => {
+                let identifier = self.next_identifier;
+                self.next_identifier = identifier.next();
+                self.name_to_identifier.insert(name.clone(), identifier);
+                self.names.push(name);
+
+                identifier
+            }
+        }
+    }
+}
+
+impl VariableNames for ReplayVariableRegistry {
+    fn variable_name(&self, identifier: &VariableIdentifier) -> Option<&str> {
+        self.names
+            .get(identifier.identifier_index())
+            .map(String::as_str)
+    }
+}
+
+impl VariableRegistry for ReplayVariableRegistry {
+    fn get(&mut self, name: String) -> VariableIdentifier {
+        if self.recorded_names.contains(&name) {
+            self.get_or_create(name)
+        } else {
+            let prefixed_name = format!("{}{}", self.named_variable_prefix, name);
+            self.get_or_create(prefixed_name)
+        }
+    }
+
+    fn get_unprefixed(&mut self, name: String) -> VariableIdentifier {
+        self.get_or_create(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefixes_names_which_are_not_recorded() {
+        let mut registry = ReplayVariableRegistry::new("A32NX_", []);
+        let identifier = registry.get("HYD_GREEN_PRESSURE".into());
+
+        assert_eq!(registry.name(&identifier), "A32NX_HYD_GREEN_PRESSURE");
+        assert_eq!(registry.find("A32NX_HYD_GREEN_PRESSURE"), Some(identifier));
+    }
+
+    #[test]
+    fn does_not_prefix_recorded_names() {
+        let mut registry = ReplayVariableRegistry::new("A32NX_", ["GENERAL ENG N2:1"]);
+        let identifier = registry.get("GENERAL ENG N2:1".into());
+
+        assert_eq!(registry.name(&identifier), "GENERAL ENG N2:1");
+    }
+
+    #[test]
+    fn does_not_prefix_unprefixed_names() {
+        let mut registry = ReplayVariableRegistry::new("A32NX_", []);
+        let identifier = registry.get_unprefixed("L:SOME_VARIABLE".into());
+
+        assert_eq!(registry.name(&identifier), "L:SOME_VARIABLE");
+    }
+
+    #[test]
+    fn returns_the_same_identifier_for_the_same_name() {
+        let mut registry = ReplayVariableRegistry::new("A32NX_", []);
+        let first = registry.get("A".into());
+        let second = registry.get_unprefixed("A32NX_A".into());
+        let other = registry.get("B".into());
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_eq!(registry.len(), 2);
+    }
+}
@@ -0,0 +1,353 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    io::{self, BufRead, Write},
+};
+
+/// The column which contains the simulator's `SIMULATION TIME` in seconds.
+/// Deltas between consecutive samples are derived from this column.
+pub const TIME_COLUMN: &str = "SIMULATION TIME";
+
+const DELIMITER: char = ',';
+
+#[derive(Debug)]
+pub enum TraceError {
+    Io(io::Error),
+    EmptyTrace,
+    MissingTimeColumn,
+    ColumnCountMismatch {
+        line: usize,
+        expected: usize,
+        actual: usize,
+    },
+    InvalidValue {
+        line: usize,
+        column: String,
+        value: String,
+    },
+    TimeNotIncreasing {
+        line: usize,
+    },
+}
+
+impl Display for TraceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "{}", error),
+            Self::EmptyTrace => write!(f, "The trace does not contain a header line"),
+            Self::MissingTimeColumn => {
+                write!(f, "The trace does not contain a '{}' column", TIME_COLUMN)
+            }
+            Self::ColumnCountMismatch {
+                line,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Line {} contains {} columns, while the header contains {}",
+                line, actual, expected
+            ),
+            Self::InvalidValue {
+                line,
+                column,
+                value,
+            } => write!(
+                f,
+                "Line {} contains the invalid value '{}' in column '{}'",
+                line, value, column
+            ),
+            Self::TimeNotIncreasing { line } => {
+                write!(f, "Line {} goes back in time", line)
+            }
+        }
+    }
+}
+
+impl Error for TraceError {}
+
+impl From<io::Error> for TraceError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// A single recorded sample of simulator variables.
+///
+/// Values are `None` when the cell was left empty, meaning the variable was not
+/// sampled at that moment and retains its previous value.
+#[derive(Debug, PartialEq)]
+pub struct TraceSample {
+    simulation_time: f64,
+    values: Vec<Option<f64>>,
+}
+
+impl TraceSample {
+    pub fn simulation_time(&self) -> f64 {
+        self.simulation_time
+    }
+
+    pub fn values(&self) -> &[Option<f64>] {
+        &self.values
+    }
+}
+
+/// Reads a recorded time series of simulator variables from delimited text.
+///
+/// The first line contains the variable names. Each following line contains one sample, in
+/// which the [`TIME_COLUMN`] is mandatory and all other cells may be left empty.
+pub struct TraceReader<R: BufRead> {
+    reader: R,
+    columns: Vec<String>,
+    time_column_index: usize,
+    line: usize,
+    previous_simulation_time: Option<f64>,
+}
+
+impl<R: BufRead> TraceReader<R> {
+    pub fn new(mut reader: R) -> Result<Self, TraceError> {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            return Err(TraceError::EmptyTrace);
+        }
+
+        let columns: Vec<String> = header
+            .trim_end()
+            .split(DELIMITER)
+            .map(|name| name.trim().to_owned())
+            .collect();
+        let time_column_index = columns
+            .iter()
+            .position(|name| name == TIME_COLUMN)
+            .ok_or(TraceError::MissingTimeColumn)?;
+
+        Ok(Self {
+            reader,
+            columns,
+            time_column_index,
+            line: 1,
+            previous_simulation_time: None,
+        })
+    }
+
+    /// The names of all columns, including the [`TIME_COLUMN`].
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    pub fn time_column_index(&self) -> usize {
+        self.time_column_index
+    }
+
+    fn parse_line(&mut self, line: &str) -> Result<TraceSample, TraceError> {
+        let cells: Vec<&str> = line.split(DELIMITER).map(str::trim).collect();
+        if cells.len() != self.columns.len() {
+            return Err(TraceError::ColumnCountMismatch {
+                line: self.line,
+                expected: self.columns.len(),
+                actual: cells.len(),
+            });
+        }
+
+        let values = cells
+            .iter()
+            .zip(self.columns.iter())
+            .map(|(cell, column)| {
+                if cell.is_empty() {
+                    Ok(None)
+                } else {
+                    cell.parse::<f64>()
+                        .map(Some)
+                        .map_err(|_| TraceError::InvalidValue {
+                            line: self.line,
+                            column: column.clone(),
+                            value: (*cell).to_owned(),
+                        })
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let simulation_time =
+            values[self.time_column_index].ok_or_else(|| TraceError::InvalidValue {
+                line: self.line,
+                column: TIME_COLUMN.to_owned(),
+                value: String::new(),
+            })?;
+        if !simulation_time.is_finite() {
+            return Err(TraceError::InvalidValue {
+                line: self.line,
+                column: TIME_COLUMN.to_owned(),
+                value: simulation_time.to_string(),
+            });
+        }
+        if matches!(self.previous_simulation_time, Some(previous) if simulation_time < previous) {
+            return Err(TraceError::TimeNotIncreasing { line: self.line });
+        }
+        self.previous_simulation_time = Some(simulation_time);
+
+        Ok(TraceSample {
+            simulation_time,
+            values,
+        })
+    }
+}
+
+impl<R: BufRead> Iterator for TraceReader<R> {
+    type Item = Result<TraceSample, TraceError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match self.reader.read_line(&mut line) {
+                Ok(0) => return None,
+                Ok(_) => {
+                    self.line += 1;
+                    if !line.trim().is_empty() {
+                        return Some(self.parse_line(line.trim_end()));
+                    }
+                }
+                Err(error) => return Some(Err(error.into())),
+            }
+        }
+    }
+}
+
+/// Writes the outputs of a replayed simulation in the same format as the input trace.
+pub struct TraceWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> TraceWriter<W> {
+    pub fn new(writer: W, columns: &[&str]) -> io::Result<Self> {
+        let mut trace_writer = Self { writer };
+        write!(trace_writer.writer, "{}", TIME_COLUMN)?;
+        for column in columns {
+            write!(trace_writer.writer, "{}{}", DELIMITER, column)?;
+        }
+        writeln!(trace_writer.writer)?;
+
+        Ok(trace_writer)
+    }
+
+    pub fn write_sample(&mut self, simulation_time: f64, values: &[f64]) -> io::Result<()> {
+        write!(self.writer, "{}", simulation_time)?;
+        for value in values {
+            write!(self.writer, "{}{}", DELIMITER, value)?;
+        }
+        writeln!(self.writer)
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reader(text: &str) -> Result<TraceReader<&[u8]>, TraceError> {
+        TraceReader::new(text.as_bytes())
+    }
+
+    #[test]
+    fn reads_columns_from_header() {
+        let trace = reader("SIMULATION TIME,A32NX_A, B:1\n").unwrap();
+
+        assert_eq!(trace.columns(), ["SIMULATION TIME", "A32NX_A", "B:1"]);
+        assert_eq!(trace.time_column_index(), 0);
+    }
+
+    #[test]
+    fn requires_time_column() {
+        assert!(matches!(
+            reader("A32NX_A,B\n"),
+            Err(TraceError::MissingTimeColumn)
+        ));
+    }
+
+    #[test]
+    fn requires_header() {
+        assert!(matches!(reader(""), Err(TraceError::EmptyTrace)));
+    }
+
+    #[test]
+    fn reads_samples_and_skips_blank_lines() {
+        let samples: Vec<TraceSample> = reader("A,SIMULATION TIME\n1.5,10\n\n,10.2\n")
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[0].simulation_time(), 10.);
+        assert_eq!(samples[0].values(), [Some(1.5), Some(10.)]);
+        assert_eq!(samples[1].values(), [None, Some(10.2)]);
+    }
+
+    #[test]
+    fn reports_invalid_values() {
+        let error = reader("SIMULATION TIME,A\n1,abc\n")
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+
+        assert!(matches!(error, TraceError::InvalidValue { line: 2, .. }));
+    }
+
+    #[test]
+    fn reports_non_finite_time() {
+        for time in ["inf", "-inf", "NaN"] {
+            let error = reader(&format!("SIMULATION TIME\n{}\n", time))
+                .unwrap()
+                .next()
+                .unwrap()
+                .unwrap_err();
+
+            assert!(matches!(error, TraceError::InvalidValue { line: 2, .. }));
+        }
+    }
+
+    #[test]
+    fn reports_column_count_mismatch() {
+        let error = reader("SIMULATION TIME,A\n1\n")
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            TraceError::ColumnCountMismatch {
+                line: 2,
+                expected: 2,
+                actual: 1
+            }
+        ));
+    }
+
+    #[test]
+    fn reports_time_going_backwards() {
+        let mut trace = reader("SIMULATION TIME\n2\n1\n").unwrap();
+
+        assert!(trace.next().unwrap().is_ok());
+        assert!(matches!(
+            trace.next().unwrap(),
+            Err(TraceError::TimeNotIncreasing { line: 3 })
+        ));
+    }
+
+    #[test]
+    fn writes_header_and_samples() {
+        let mut output = vec![];
+        {
+            let mut writer = TraceWriter::new(&mut output, &["A", "B"]).unwrap();
+            writer.write_sample(1.5, &[1., 0.25]).unwrap();
+        }
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "SIMULATION TIME,A,B\n1.5,1,0.25\n"
+        );
+    }
+}